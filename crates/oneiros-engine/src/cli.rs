@@ -112,6 +112,10 @@ pub(crate) enum Command {
     #[command(subcommand)]
     Continuity(ContinuityCommands),
 
+    /// Follow the event log live
+    #[command(subcommand)]
+    Events(EventCommands),
+
     /// Walk the events ↔ entities bridge
     #[command(subcommand)]
     Trail(TrailCommands),
@@ -156,9 +160,10 @@ impl Command {
             Command::Connection(connection) => connection.execute(config).await?,
             Command::Continuity(continuity) => continuity.execute(config).await?,
             Command::Doctor => DoctorCli::execute(config).await?,
+            Command::Events(events) => events.execute(config).await?,
             Command::Experience(experience) => experience.execute(config).await?,
             Command::Level(level) => level.execute(config).await?,
-            Command::Mcp(mcp) => mcp.execute(config)?,
+            Command::Mcp(mcp) => mcp.execute(config).await?,
             Command::Memory(memory) => memory.execute(config).await?,
             Command::Nature(nature) => nature.execute(config).await?,
             Command::Peer(peer) => peer.execute(config).await?,
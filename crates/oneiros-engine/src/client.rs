@@ -117,7 +117,38 @@ impl Client {
         Self::handle_response(resp).await
     }
 
+    /// Open a Server-Sent Events stream, resuming after `last_event_id`
+    /// when given. Returns the response with its body still unread.
+    #[tracing::instrument(skip(self, query), fields(path = %path))]
+    pub(crate) async fn stream(
+        &self,
+        path: &str,
+        query: &[(&str, String)],
+        last_event_id: Option<i64>,
+    ) -> Result<reqwest::Response, ClientError> {
+        let mut url = reqwest::Url::parse(&self.url(path))
+            .map_err(|error| ClientError::InvalidRequest(error.to_string()))?;
+        if !query.is_empty() {
+            url.query_pairs_mut().extend_pairs(query);
+        }
+
+        let mut request = self
+            .http
+            .get(url)
+            .header(reqwest::header::ACCEPT, "text/event-stream");
+        if let Some(sequence) = last_event_id {
+            request = request.header("last-event-id", sequence.to_string());
+        }
+
+        Self::check_status(request.send().await?).await
+    }
+
     async fn handle_response(resp: reqwest::Response) -> Result<Vec<u8>, ClientError> {
+        let resp = Self::check_status(resp).await?;
+        Ok(resp.bytes().await?.to_vec())
+    }
+
+    async fn check_status(resp: reqwest::Response) -> Result<reqwest::Response, ClientError> {
         let status = resp.status();
 
         if status == StatusCode::NOT_FOUND {
@@ -138,7 +169,7 @@ impl Client {
             });
         }
 
-        Ok(resp.bytes().await?.to_vec())
+        Ok(resp)
     }
 }
 
@@ -86,6 +86,10 @@ pub(crate) struct DreamCli {
     #[arg(long, global = true)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) experience_size: Option<usize>,
+    /// Approximate token budget for the rendered dream.
+    #[arg(long, global = true)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) dream_budget: Option<usize>,
 }
 
 /// CLI overrides for fetch/polling configuration.
@@ -228,6 +232,7 @@ impl DreamCli {
             && self.recollection_level.is_none()
             && self.recollection_size.is_none()
             && self.experience_size.is_none()
+            && self.dream_budget.is_none()
     }
 }
 
@@ -285,6 +290,15 @@ pub(crate) struct Config {
     /// Lens-specific configuration: aliases for query expressions.
     #[builder(default)]
     pub(crate) lens: LensConfig,
+    /// Trust policy for events imported from peers and files.
+    #[builder(default)]
+    pub(crate) trust: TrustConfig,
+    /// Background sync of peer follows.
+    #[builder(default)]
+    pub(crate) sync: SyncConfig,
+    /// Background enforcement of level retention policies.
+    #[builder(default)]
+    pub(crate) retention: RetentionConfig,
 }
 
 /// Configuration for the lens query language. Currently carries
@@ -315,6 +329,9 @@ impl Default for Config {
             color: ColorChoice::default(),
             verbosity: Verbosity::default(),
             lens: LensConfig::default(),
+            trust: TrustConfig::default(),
+            sync: SyncConfig::default(),
+            retention: RetentionConfig::default(),
         }
     }
 }
@@ -505,6 +522,7 @@ mod tests {
         assert_eq!(config.service.address, expected.service.address);
         assert_eq!(config.dream.cognition_size, expected.dream.cognition_size);
         assert_eq!(config.output, expected.output);
+        assert!(!config.retention.enabled, "retention sweeps are opt-in");
     }
 
     #[test]
@@ -562,6 +580,50 @@ timeout = "5s"
         assert_eq!(config.fetch.timeout, std::time::Duration::from_secs(5));
     }
 
+    #[test]
+    fn file_overrides_default_sync_config() {
+        let dir = tempfile::tempdir().unwrap();
+        write_config(
+            dir.path(),
+            r#"
+[sync]
+interval = "5m"
+on_change = true
+"#,
+        );
+        let config = resolve_in(dir.path(), &empty_overrides());
+
+        assert!(config.sync.enabled);
+        assert!(config.sync.on_change);
+        assert!(!config.sync.push);
+        assert_eq!(config.sync.interval, std::time::Duration::from_secs(300));
+        // Unspecified fields keep their defaults
+        assert_eq!(
+            config.sync.max_backoff,
+            std::time::Duration::from_secs(30 * 60)
+        );
+    }
+
+    #[test]
+    fn file_overrides_default_retention_config() {
+        let dir = tempfile::tempdir().unwrap();
+        write_config(
+            dir.path(),
+            r#"
+[retention]
+enabled = true
+interval = "10m"
+"#,
+        );
+        let config = resolve_in(dir.path(), &empty_overrides());
+
+        assert!(config.retention.enabled);
+        assert_eq!(
+            config.retention.interval,
+            std::time::Duration::from_secs(600)
+        );
+    }
+
     #[test]
     fn file_overrides_default_dream_config() {
         let dir = tempfile::tempdir().unwrap();
@@ -804,6 +866,7 @@ address = "127.0.0.1:4000"
                 recollection_level: Some(LevelName::new("session")),
                 recollection_size: Some(88),
                 experience_size: Some(77),
+                dream_budget: Some(6000),
             },
             fetch: FetchCli {
                 interval: Some(std::time::Duration::from_millis(123)),
@@ -840,6 +903,7 @@ address = "127.0.0.1:4000"
         assert_eq!(config.dream.recollection_level, LevelName::new("session"));
         assert_eq!(config.dream.recollection_size, 88);
         assert_eq!(config.dream.experience_size, 77);
+        assert_eq!(config.dream.dream_budget, Some(6000));
         assert_eq!(config.fetch.interval, std::time::Duration::from_millis(123));
         assert_eq!(config.fetch.timeout, std::time::Duration::from_secs(456));
         assert_eq!(config.database.limit_attached, 256);
@@ -72,13 +72,19 @@ mod agent_mcp {
             Tool::<CreateAgent>::new(
                 AgentRequestType::CreateAgent,
                 "Bring a new agent into the project",
+                PermissionOp::ProjectWrite,
+            )
+            .def(),
+            Tool::<UpdateAgent>::new(
+                AgentRequestType::UpdateAgent,
+                "Reshape an agent's identity",
+                PermissionOp::ProjectWrite,
             )
             .def(),
-            Tool::<UpdateAgent>::new(AgentRequestType::UpdateAgent, "Reshape an agent's identity")
-                .def(),
             Tool::<RemoveAgent>::new(
                 AgentRequestType::RemoveAgent,
                 "Remove an agent from the project",
+                PermissionOp::ProjectWrite,
             )
             .def(),
         ]
@@ -2,9 +2,11 @@
 //! the chronicle HAMT for any bookmark.
 //!
 //! First-time-seen `(project, bookmark)` pairs are caught up by walking
-//! the project event log and recording each event. Subsequent `Record`
-//! messages add one event each. Chronicle insertion is idempotent on
-//! event id, so partial replay or duplicates are safe.
+//! the project event log: an empty chronicle records every event, and one
+//! that already has a root (forked, or resumed at hydration) re-records
+//! only the events it witnessed. Subsequent `Record` messages add one
+//! event each. Chronicle insertion is idempotent on event id, so partial
+//! replay or duplicates are safe. Each write saves the bookmark's head.
 //!
 //! Receives the full `BookmarkMessage`; handles the chronicle variants
 //! and no-ops the rest.
@@ -99,14 +101,23 @@ impl BookmarkChronicleActor {
             return Ok(());
         }
 
-        let chronicle = self.chronicle_for(&scope.project().name, &scope.bookmark().name)?;
+        let (project, bookmark) = (&scope.project().name, &scope.bookmark().name);
+        let chronicle = self.chronicle_for(project, bookmark)?;
         let host_db = HostDb::open(scope).await?;
         let store = ChronicleStore::new(&host_db);
         store.migrate()?;
+        let witnessed = chronicle
+            .root()?
+            .map(|root| Ledger::collect_all_ids(Some(&root), &store.resolver()));
         for event in &events {
-            chronicle.record(event, &store.resolver(), &store.writer())?;
+            if witnessed
+                .as_ref()
+                .is_none_or(|ids| ids.contains(&event.id.to_string()))
+            {
+                chronicle.record(event, &store.resolver(), &store.writer())?;
+            }
         }
-        Ok(())
+        self.canons.save_head(&store, project, bookmark)
     }
 
     async fn record_one(
@@ -114,12 +125,13 @@ impl BookmarkChronicleActor {
         scope: &Scope<AtBookmark>,
         stored: &StoredEvent,
     ) -> Result<(), EventError> {
-        let chronicle = self.chronicle_for(&scope.project().name, &scope.bookmark().name)?;
+        let (project, bookmark) = (&scope.project().name, &scope.bookmark().name);
+        let chronicle = self.chronicle_for(project, bookmark)?;
         let host_db = HostDb::open(scope).await?;
         let store = ChronicleStore::new(&host_db);
         store.migrate()?;
         chronicle.record(stored, &store.resolver(), &store.writer())?;
-        Ok(())
+        self.canons.save_head(&store, project, bookmark)
     }
 
     fn chronicle_for(
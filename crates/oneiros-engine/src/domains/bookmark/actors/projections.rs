@@ -97,8 +97,13 @@ impl BookmarkProjectionsActor {
         let bookmark_db = BookmarkDb::open(scope).await?;
         let projections = self.projections_for(&scope.project().name)?;
         projections.migrate(&bookmark_db)?;
+        let superseded = {
+            let host_db = HostDb::open(scope).await?;
+            BookmarkHistory::load(&host_db, &scope.project().name)?
+                .superseded(&scope.bookmark().name)
+        };
         let log = EventLog::attached(&bookmark_db);
-        projections.replay_project(&bookmark_db, &log)?;
+        projections.replay_project_except(&bookmark_db, &log, &superseded)?;
         Ok(())
     }
 
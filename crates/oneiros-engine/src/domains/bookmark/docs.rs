@@ -5,11 +5,16 @@ pub(crate) enum BookmarkDocs {
     List,
     Switch,
     Merge,
+    PreviewMerge,
     Share,
     Follow,
     Unfollow,
     Collect,
+    PreviewCollect,
     Submit,
+    Delete,
+    Rename,
+    Gc,
 }
 
 impl BookmarkDocs {
@@ -45,7 +50,13 @@ impl BookmarkDocs {
                 .tag(tag)
                 .nickname("merge-bookmark")
                 .summary("Merge a bookmark")
-                .description("Integrate the events from a bookmark into the current active timeline.")
+                .description("Integrate the events from a bookmark into the current active timeline. Changes are compared against the point the bookmarks diverged; entities both sides changed are settled by the merge strategy (ours, theirs or fail), and the response reports what was added and what conflicted.")
+                .build(),
+            Self::PreviewMerge => ResourceDocs::builder()
+                .tag(tag)
+                .nickname("preview-merge")
+                .summary("Preview a bookmark merge")
+                .description("Report which events a merge would bring into the active bookmark, grouped by domain and agent, along with any conflicts the strategy would settle. Nothing is changed.")
                 .build(),
             Self::Share => ResourceDocs::builder()
                 .tag(tag)
@@ -71,12 +82,36 @@ impl BookmarkDocs {
                 .summary("Collect events into a bookmark")
                 .description("Collect events from a followed source or directly from a peer host (via --from). Uses the chronicle Merkle diff for efficient sync.")
                 .build(),
+            Self::PreviewCollect => ResourceDocs::builder()
+                .tag(tag)
+                .nickname("preview-collect")
+                .summary("Preview a bookmark collect")
+                .description("Report which events a collect would bring in from a followed source or a peer, grouped by domain and agent. Events are diffed and fetched but never imported.")
+                .build(),
             Self::Submit => ResourceDocs::builder()
                 .tag(tag)
                 .nickname("submit-bookmark")
                 .summary("Submit a bookmark to a remote")
                 .description("Submit a bookmark to a peer host. Requires a write-scoped ticket on the remote. Use --as to rename.")
                 .build(),
+            Self::Delete => ResourceDocs::builder()
+                .tag(tag)
+                .nickname("delete-bookmark")
+                .summary("Delete a bookmark")
+                .description("Delete a bookmark and its projection database. The active bookmark, the main trunk, and bookmarks a follow depends on can't be deleted. Events stay in the project log.")
+                .build(),
+            Self::Rename => ResourceDocs::builder()
+                .tag(tag)
+                .nickname("rename-bookmark")
+                .summary("Rename a bookmark")
+                .description("Give a bookmark a new name. Follows collecting into it move along, and an active bookmark stays active.")
+                .build(),
+            Self::Gc => ResourceDocs::builder()
+                .tag(tag)
+                .nickname("gc-bookmarks")
+                .summary("Reclaim chronicle storage")
+                .description("Mark every chronicle node reachable from a live bookmark and sweep the rest from the host's node store, reporting how many bytes were reclaimed.")
+                .build(),
         }
     }
 }
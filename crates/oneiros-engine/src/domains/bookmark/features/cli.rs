@@ -13,6 +13,10 @@ pub(crate) enum BookmarkCommands {
     Collect(CollectBookmark),
     Unfollow(UnfollowBookmark),
     Submit(SubmitBookmark),
+    Delete(DeleteBookmark),
+    Rename(RenameBookmark),
+    /// Reclaim chronicle storage no bookmark can reach anymore.
+    Gc(SweepChronicles),
 }
 
 impl BookmarkCommands {
@@ -32,6 +36,9 @@ impl BookmarkCommands {
             Self::Collect(collect) => collect.execute_request(&client).await?,
             Self::Unfollow(unfollow) => unfollow.execute_request(&client).await?,
             Self::Submit(submit) => submit.execute_request(&client).await?,
+            Self::Delete(deletion) => deletion.execute_request(&client).await?,
+            Self::Rename(rename) => rename.execute_request(&client).await?,
+            Self::Gc(sweep) => sweep.execute_request(&client).await?,
         };
 
         let response: BookmarkResponse = serde_json::from_slice(&bytes)?;
@@ -1,7 +1,7 @@
 use aide::axum::{ApiRouter, routing};
 use axum::{
     Json,
-    extract::{Query, State},
+    extract::{Path, Query, State},
     http::StatusCode,
 };
 
@@ -43,6 +43,14 @@ impl BookmarkRouter {
                             .response::<200, Json<BookmarkMergedResponse>>()
                     }),
                 )
+                .api_route(
+                    "/merge/preview",
+                    routing::post_with(preview_merge, |op| {
+                        resource_op!(op, BookmarkDocs::PreviewMerge)
+                            .security_requirement("BearerToken")
+                            .response::<200, Json<BookmarkPreviewResponse>>()
+                    }),
+                )
                 .api_route(
                     "/share",
                     routing::post_with(share, |op| {
@@ -67,6 +75,14 @@ impl BookmarkRouter {
                             .response::<200, Json<BookmarkCollectResult>>()
                     }),
                 )
+                .api_route(
+                    "/collect/preview",
+                    routing::post_with(preview_collect, |op| {
+                        resource_op!(op, BookmarkDocs::PreviewCollect)
+                            .security_requirement("BearerToken")
+                            .response::<200, Json<BookmarkPreviewResponse>>()
+                    }),
+                )
                 .api_route(
                     "/unfollow",
                     routing::post_with(unfollow, |op| {
@@ -82,6 +98,31 @@ impl BookmarkRouter {
                             .security_requirement("BearerToken")
                             .response::<200, Json<BookmarkSubmitResult>>()
                     }),
+                )
+                .api_route(
+                    "/rename",
+                    routing::post_with(rename, |op| {
+                        resource_op!(op, BookmarkDocs::Rename)
+                            .security_requirement("BearerToken")
+                            .response::<200, Json<BookmarkRenamedResponse>>()
+                    }),
+                )
+                .api_route(
+                    "/gc",
+                    routing::post_with(gc, |op| {
+                        resource_op!(op, BookmarkDocs::Gc)
+                            .security_requirement("BearerToken")
+                            .response::<200, Json<BookmarkSweptResponse>>()
+                    }),
+                )
+                .api_route(
+                    "/{name}",
+                    routing::delete_with(delete, |op| {
+                        resource_op!(op, BookmarkDocs::Delete)
+                            .security_requirement("BearerToken")
+                            .input::<NamePathParam<BookmarkName>>()
+                            .response::<200, Json<BookmarkDeletedResponse>>()
+                    }),
                 ),
         )
     }
@@ -122,6 +163,18 @@ async fn merge(
     ))
 }
 
+#[expect(deprecated)]
+async fn preview_merge(
+    context: ProjectLog,
+    State(state): State<ServerState>,
+    Json(body): Json<MergeBookmark>,
+) -> Result<Json<BookmarkResponse>, BookmarkError> {
+    let scope = ComposeScope::new(state.config().clone()).host()?;
+    Ok(Json(
+        BookmarkService::preview_merge(&scope, &state, context.project_name(), &body).await?,
+    ))
+}
+
 #[expect(deprecated)]
 async fn list(
     context: ProjectLog,
@@ -182,6 +235,18 @@ async fn collect(
     ))
 }
 
+#[expect(deprecated)]
+async fn preview_collect(
+    context: ProjectLog,
+    State(state): State<ServerState>,
+    Json(body): Json<CollectBookmark>,
+) -> Result<Json<BookmarkResponse>, BookmarkError> {
+    let scope = ComposeScope::new(state.config().clone()).host()?;
+    Ok(Json(
+        BookmarkService::preview_collect(&scope, &state, context.project_name(), &body).await?,
+    ))
+}
+
 #[expect(deprecated)]
 async fn submit(
     context: ProjectLog,
@@ -193,3 +258,35 @@ async fn submit(
         BookmarkService::submit(&scope, &state, context.project_name(), &body).await?,
     ))
 }
+
+#[expect(deprecated)]
+async fn delete(
+    context: ProjectLog,
+    State(state): State<ServerState>,
+    Path(name): Path<BookmarkName>,
+) -> Result<Json<BookmarkResponse>, BookmarkError> {
+    let scope = ComposeScope::new(state.config().clone()).host()?;
+    let request = DeleteBookmark::builder_v1().name(name).build().into();
+    Ok(Json(
+        BookmarkService::delete(&scope, &state, context.project_name(), &request).await?,
+    ))
+}
+
+#[expect(deprecated)]
+async fn rename(
+    context: ProjectLog,
+    State(state): State<ServerState>,
+    Json(body): Json<RenameBookmark>,
+) -> Result<Json<BookmarkResponse>, BookmarkError> {
+    let scope = ComposeScope::new(state.config().clone()).host()?;
+    Ok(Json(
+        BookmarkService::rename(&scope, &state, context.project_name(), &body).await?,
+    ))
+}
+
+async fn gc(
+    State(state): State<ServerState>,
+    Json(body): Json<SweepChronicles>,
+) -> Result<Json<BookmarkResponse>, BookmarkError> {
+    Ok(Json(BookmarkService::sweep(&state, &body).await?))
+}
@@ -0,0 +1,76 @@
+use crate::*;
+
+pub(crate) struct BookmarkMcp;
+
+impl BookmarkMcp {
+    pub(crate) fn defs(&self) -> Vec<ToolDef> {
+        bookmark_mcp::tool_defs()
+    }
+
+    pub(crate) async fn dispatch(
+        &self,
+        state: &ServerState,
+        config: &Config,
+        tool_name: &ToolName,
+        params: &serde_json::Value,
+    ) -> Result<McpResponse, ToolError> {
+        bookmark_mcp::dispatch(state, config, tool_name, params).await
+    }
+}
+
+mod bookmark_mcp {
+    use crate::*;
+
+    /// Only previews are exposed — agents can look at what a merge or
+    /// collect would bring in, but applying one stays a human decision.
+    pub(crate) fn tool_defs() -> Vec<ToolDef> {
+        vec![
+            Tool::<MergeBookmark>::new(
+                BookmarkRequestType::PreviewMerge,
+                "See which events merging a bookmark would bring in, and what would conflict",
+                PermissionOp::ProjectRead,
+            )
+            .def(),
+            Tool::<CollectBookmark>::new(
+                BookmarkRequestType::PreviewCollect,
+                "See which events collecting from a follow or peer would bring in",
+                PermissionOp::ProjectRead,
+            )
+            .def(),
+        ]
+    }
+
+    pub(crate) async fn dispatch(
+        state: &ServerState,
+        config: &Config,
+        tool_name: &ToolName,
+        params: &serde_json::Value,
+    ) -> Result<McpResponse, ToolError> {
+        let request_type: BookmarkRequestType = tool_name
+            .as_str()
+            .parse()
+            .map_err(|_| ToolError::UnknownTool(tool_name.to_string()))?;
+
+        let scope = ComposeScope::new(config.clone())
+            .host()
+            .map_err(Error::from)?;
+
+        let response = match request_type {
+            BookmarkRequestType::PreviewMerge => {
+                let merging: MergeBookmark = serde_json::from_value(params.clone())?;
+                BookmarkService::preview_merge(&scope, state, &config.project, &merging)
+                    .await
+                    .map_err(Error::from)?
+            }
+            BookmarkRequestType::PreviewCollect => {
+                let collecting: CollectBookmark = serde_json::from_value(params.clone())?;
+                BookmarkService::preview_collect(&scope, state, &config.project, &collecting)
+                    .await
+                    .map_err(Error::from)?
+            }
+            _ => return Err(ToolError::UnknownTool(tool_name.to_string())),
+        };
+
+        Ok(BookmarkView::new(response).mcp())
+    }
+}
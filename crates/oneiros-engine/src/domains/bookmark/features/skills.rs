@@ -14,6 +14,9 @@ impl BookmarkSkills {
             Skill::new("bookmark-collect", include_str!("skills/collect.md")),
             Skill::new("bookmark-unfollow", include_str!("skills/unfollow.md")),
             Skill::new("bookmark-submit", include_str!("skills/submit.md")),
+            Skill::new("bookmark-delete", include_str!("skills/delete.md")),
+            Skill::new("bookmark-rename", include_str!("skills/rename.md")),
+            Skill::new("bookmark-gc", include_str!("skills/gc.md")),
         ]
     }
 }
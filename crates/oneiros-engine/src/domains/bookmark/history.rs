@@ -0,0 +1,320 @@
+//! What the host log remembers about a project's bookmarks.
+//!
+//! A merge settles its conflicts once, but the losing events stay in the
+//! shared project log — every later replay of the target has to leave
+//! them out again. The settlement is recorded in `BookmarkMerged`, so the
+//! set each bookmark skips is folded back out of the host log rather than
+//! kept anywhere else. Forks are folded the same way, so a restarted host
+//! knows which bookmarks exist and the root each was forked from.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::*;
+
+/// Per-bookmark state rebuilt from the host log's bookmark events.
+#[derive(Debug, Default, Clone)]
+struct Lineage {
+    /// Whether the bookmark was created or forked, as opposed to only
+    /// named as a merge target (as `main` is).
+    recorded: bool,
+    base: Option<ContentHash>,
+    /// The bookmark this one was forked off, and when.
+    from: Option<BookmarkName>,
+    forked_at: Option<Timestamp>,
+    superseded: HashSet<EventId>,
+}
+
+/// The bookmark history of one project, as the host log records it.
+#[derive(Debug, Default)]
+pub(crate) struct BookmarkHistory {
+    lineages: HashMap<BookmarkName, Lineage>,
+}
+
+impl BookmarkHistory {
+    /// Fold the host log's bookmark events for `project`.
+    pub(crate) fn load(
+        host_db: &rusqlite::Connection,
+        project: &ProjectName,
+    ) -> Result<Self, EventError> {
+        Ok(Self::fold(&EventLog::new(host_db).load_all()?, project))
+    }
+
+    /// Fold bookmark events, in log order. A fork starts from what its
+    /// parent had settled; a merge adds the losers of each conflict it
+    /// recorded to its target; renames and deletions follow the name.
+    pub(crate) fn fold(events: &[StoredEvent], project: &ProjectName) -> Self {
+        let mut history = Self::default();
+
+        for stored in events {
+            let Event::Known(Events::Bookmark(event)) = &stored.data else {
+                continue;
+            };
+
+            match event {
+                BookmarkEvents::BookmarkCreated(created) => {
+                    if let Ok(created) = created.current()
+                        && created.bookmark.project == *project
+                    {
+                        history.lineages.insert(
+                            created.bookmark.name,
+                            Lineage {
+                                recorded: true,
+                                ..Lineage::default()
+                            },
+                        );
+                    }
+                }
+                BookmarkEvents::BookmarkForked(forked) => {
+                    if let Ok(forked) = forked.current()
+                        && forked.bookmark.project == *project
+                    {
+                        let inherited = history
+                            .lineages
+                            .get(&forked.from)
+                            .map(|parent| parent.superseded.clone())
+                            .unwrap_or_default();
+                        history.lineages.insert(
+                            forked.bookmark.name,
+                            Lineage {
+                                recorded: true,
+                                base: forked.base,
+                                from: Some(forked.from),
+                                forked_at: Some(stored.created_at),
+                                superseded: inherited,
+                            },
+                        );
+                    }
+                }
+                BookmarkEvents::BookmarkMerged(merged) => {
+                    if let Ok(merged) = merged.current()
+                        && merged.project == *project
+                    {
+                        history
+                            .lineages
+                            .entry(merged.target)
+                            .or_default()
+                            .superseded
+                            .extend(
+                                merged
+                                    .conflicts
+                                    .iter()
+                                    .flat_map(|conflict| conflict.losers().iter().copied()),
+                            );
+                    }
+                }
+                BookmarkEvents::BookmarkRenamed(renamed) => {
+                    if let Ok(renamed) = renamed.current()
+                        && renamed.project == *project
+                        && let Some(lineage) = history.lineages.remove(&renamed.from)
+                    {
+                        for child in history.lineages.values_mut() {
+                            if child.from.as_ref() == Some(&renamed.from) {
+                                child.from = Some(renamed.to.clone());
+                            }
+                        }
+                        history.lineages.insert(renamed.to, lineage);
+                    }
+                }
+                BookmarkEvents::BookmarkDeleted(deleted) => {
+                    if let Ok(deleted) = deleted.current()
+                        && deleted.project == *project
+                    {
+                        history.lineages.remove(&deleted.name);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        history
+    }
+
+    /// The events a replay of `bookmark` must skip: the losing side of
+    /// every conflict settled into it, or into what it was forked from.
+    pub(crate) fn superseded(&self, bookmark: &BookmarkName) -> HashSet<EventId> {
+        self.lineages
+            .get(bookmark)
+            .map(|lineage| lineage.superseded.clone())
+            .unwrap_or_default()
+    }
+
+    /// The bookmarks created or forked beside `main` that still exist,
+    /// each with the root it was forked from.
+    pub(crate) fn branches(&self) -> impl Iterator<Item = (&BookmarkName, Option<&ContentHash>)> {
+        self.lineages
+            .iter()
+            .filter(|(name, lineage)| lineage.recorded && **name != BookmarkName::main())
+            .map(|(name, lineage)| (name, lineage.base.as_ref()))
+    }
+
+    /// The bookmark `bookmark` was forked off, if it was forked.
+    pub(crate) fn forked_from(&self, bookmark: &BookmarkName) -> Option<&BookmarkName> {
+        self.lineages.get(bookmark)?.from.as_ref()
+    }
+
+    /// When `bookmark` was forked, if it was.
+    pub(crate) fn forked_at(&self, bookmark: &BookmarkName) -> Option<Timestamp> {
+        self.lineages.get(bookmark)?.forked_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stored(event: BookmarkEvents) -> StoredEvent {
+        StoredEvent::builder()
+            .id(EventId::new())
+            .sequence(0)
+            .created_at(Timestamp::now())
+            .source(Source::default())
+            .data(Event::Known(Events::Bookmark(event)))
+            .build()
+    }
+
+    fn project() -> ProjectName {
+        ProjectName::new("dreamer")
+    }
+
+    fn forked(name: &str, from: &str) -> StoredEvent {
+        stored(BookmarkEvents::BookmarkForked(
+            BookmarkForked::builder_v1()
+                .bookmark(Bookmark::builder().project(project()).name(name).build())
+                .from(BookmarkName::new(from))
+                .build()
+                .into(),
+        ))
+    }
+
+    fn merged(source: &str, target: &str, conflict: MergeConflict) -> StoredEvent {
+        stored(BookmarkEvents::BookmarkMerged(
+            BookmarkMerged::builder_v2()
+                .project(project())
+                .source(BookmarkName::new(source))
+                .target(BookmarkName::new(target))
+                .strategy(conflict.resolution)
+                .conflicts(vec![conflict])
+                .build()
+                .into(),
+        ))
+    }
+
+    fn conflict(ours: EventId, theirs: EventId, resolution: MergeStrategy) -> MergeConflict {
+        MergeConflict::builder()
+            .subject("texture:musing")
+            .ours(vec![ours])
+            .theirs(vec![theirs])
+            .resolution(resolution)
+            .build()
+    }
+
+    #[test]
+    fn merges_supersede_the_losing_side() {
+        let (ours, theirs) = (EventId::new(), EventId::new());
+        let events = [
+            forked("experiment", "main"),
+            merged(
+                "experiment",
+                "main",
+                conflict(ours, theirs, MergeStrategy::Theirs),
+            ),
+        ];
+
+        let history = BookmarkHistory::fold(&events, &project());
+
+        assert_eq!(
+            history.superseded(&BookmarkName::main()),
+            HashSet::from([ours])
+        );
+        assert!(
+            history
+                .superseded(&BookmarkName::new("experiment"))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn forks_inherit_and_renames_carry_what_was_settled() {
+        let (ours, theirs) = (EventId::new(), EventId::new());
+        let events = [
+            merged(
+                "experiment",
+                "main",
+                conflict(ours, theirs, MergeStrategy::Ours),
+            ),
+            forked("draft", "main"),
+            forked("sketch", "draft"),
+            stored(BookmarkEvents::BookmarkRenamed(
+                BookmarkRenamed::builder_v1()
+                    .id(BookmarkId::new())
+                    .project(project())
+                    .from(BookmarkName::new("draft"))
+                    .to(BookmarkName::new("final"))
+                    .build()
+                    .into(),
+            )),
+        ];
+
+        let history = BookmarkHistory::fold(&events, &project());
+
+        assert!(history.superseded(&BookmarkName::new("draft")).is_empty());
+        assert_eq!(
+            history.superseded(&BookmarkName::new("final")),
+            HashSet::from([theirs])
+        );
+        assert_eq!(
+            history.forked_from(&BookmarkName::new("sketch")),
+            Some(&BookmarkName::new("final"))
+        );
+    }
+
+    #[test]
+    fn branches_keep_their_fork_base_until_deleted() {
+        let base = ContentHash::compute(b"fork point");
+        let events = [
+            stored(BookmarkEvents::BookmarkForked(
+                BookmarkForked::builder_v2()
+                    .bookmark(
+                        Bookmark::builder()
+                            .project(project())
+                            .name("experiment")
+                            .build(),
+                    )
+                    .from(BookmarkName::main())
+                    .base(base.clone())
+                    .build()
+                    .into(),
+            )),
+            forked("draft", "main"),
+            stored(BookmarkEvents::BookmarkDeleted(
+                BookmarkDeleted::builder_v1()
+                    .id(BookmarkId::new())
+                    .project(project())
+                    .name(BookmarkName::new("draft"))
+                    .build()
+                    .into(),
+            )),
+        ];
+
+        let history = BookmarkHistory::fold(&events, &project());
+        let branches: Vec<_> = history.branches().collect();
+
+        assert_eq!(
+            branches,
+            vec![(&BookmarkName::new("experiment"), Some(&base))]
+        );
+    }
+
+    #[test]
+    fn other_projects_are_ignored() {
+        let events = [merged(
+            "experiment",
+            "main",
+            conflict(EventId::new(), EventId::new(), MergeStrategy::Ours),
+        )];
+
+        let history = BookmarkHistory::fold(&events, &ProjectName::new("elsewhere"));
+
+        assert!(history.superseded(&BookmarkName::main()).is_empty());
+    }
+}
@@ -0,0 +1,299 @@
+use std::collections::{BTreeMap, HashSet};
+
+use crate::*;
+
+/// The outcome of comparing two bookmarks against their fork ancestor.
+///
+/// `added` is what the target will newly witness. `excluded` holds the
+/// source events the target must not witness (conflicts it lost), and
+/// `superseded` holds every losing event — from either side — that the
+/// replay skips so the winner's state is what lands in projections.
+#[derive(Debug, Default)]
+pub(crate) struct MergePlan {
+    pub(crate) added: Vec<EventId>,
+    pub(crate) conflicts: Vec<MergeConflict>,
+    pub(crate) excluded: HashSet<EventId>,
+    pub(crate) superseded: HashSet<EventId>,
+}
+
+impl MergePlan {
+    /// Compute a three-way merge plan.
+    ///
+    /// Each side's changes are the events it gained since `base`, minus
+    /// anything the other side already witnessed (so repeated merges
+    /// don't re-contest material that was settled last time).
+    pub(crate) fn compute(
+        base: Option<&ContentHash>,
+        ours: Option<&ContentHash>,
+        theirs: Option<&ContentHash>,
+        log: &EventLog,
+        strategy: MergeStrategy,
+        resolve: &impl Fn(&ContentHash) -> Option<LedgerNode>,
+    ) -> Result<Self, EventError> {
+        let ours_ids = Ledger::collect_all_ids(ours, resolve);
+        let theirs_ids = Ledger::collect_all_ids(theirs, resolve);
+
+        let gained = |root: Option<&ContentHash>, other: &HashSet<String>| -> Vec<EventId> {
+            Ledger::diff(base, root, resolve)
+                .into_iter()
+                .filter_map(|change| match change {
+                    LedgerChange::Added(id) if !other.contains(&id.to_string()) => Some(id),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        let ours_events = log.get_batch(&gained(ours, &theirs_ids))?;
+        let theirs_events = log.get_batch(&gained(theirs, &ours_ids))?;
+
+        Ok(Self::plan(&ours_events, &theirs_events, strategy))
+    }
+
+    /// Detect conflicts between two sets of diverged events and decide
+    /// which side's events survive under `strategy`.
+    pub(crate) fn plan(
+        ours: &[StoredEvent],
+        theirs: &[StoredEvent],
+        strategy: MergeStrategy,
+    ) -> Self {
+        let ours_by_subject = by_subject(ours);
+        let theirs_by_subject = by_subject(theirs);
+
+        let mut plan = Self::default();
+
+        for (subject, ours_ids) in &ours_by_subject {
+            let Some(theirs_ids) = theirs_by_subject.get(subject) else {
+                continue;
+            };
+
+            let conflict = MergeConflict::builder()
+                .subject(subject.clone())
+                .ours(ours_ids.clone())
+                .theirs(theirs_ids.clone())
+                .resolution(strategy)
+                .build();
+
+            plan.superseded.extend(conflict.losers().iter().copied());
+            if strategy != MergeStrategy::Theirs {
+                plan.excluded.extend(theirs_ids.iter().copied());
+            }
+
+            plan.conflicts.push(conflict);
+        }
+
+        plan.added = theirs
+            .iter()
+            .map(|event| event.id)
+            .filter(|id| !plan.excluded.contains(id))
+            .collect();
+
+        plan
+    }
+
+    /// The contested subjects, for reporting a refused merge.
+    pub(crate) fn subjects(&self) -> Vec<String> {
+        self.conflicts
+            .iter()
+            .map(|conflict| conflict.subject.clone())
+            .collect()
+    }
+}
+
+fn by_subject(events: &[StoredEvent]) -> BTreeMap<String, Vec<EventId>> {
+    let mut subjects: BTreeMap<String, Vec<EventId>> = BTreeMap::new();
+    for event in events {
+        if let Event::Known(data) = &event.data
+            && let Some(subject) = contested_subject(data)
+        {
+            subjects.entry(subject).or_default().push(event.id);
+        }
+    }
+    subjects
+}
+
+/// The mutable entity an event writes to, if any.
+///
+/// Append-only records (cognitions, memories, experiences) never
+/// conflict — both sides' additions simply coexist. Entities that are
+/// set, updated or removed in place are keyed by their identity so two
+/// bookmarks touching the same one can be detected.
+pub(crate) fn contested_subject(event: &Events) -> Option<String> {
+    fn key(kind: &str, name: impl core::fmt::Display) -> String {
+        format!("{kind}:{name}")
+    }
+
+    match event {
+        Events::Agent(AgentEvents::AgentCreated(created)) => {
+            created.current().ok().map(|v| key("agent", v.agent.name))
+        }
+        Events::Agent(AgentEvents::AgentUpdated(updated)) => {
+            updated.current().ok().map(|v| key("agent", v.agent.name))
+        }
+        Events::Agent(AgentEvents::AgentRemoved(removed)) => {
+            removed.current().ok().map(|v| key("agent", v.name))
+        }
+        Events::Persona(PersonaEvents::PersonaSet(set)) => {
+            set.current().ok().map(|v| key("persona", v.persona.name))
+        }
+        Events::Persona(PersonaEvents::PersonaRemoved(removed)) => {
+            removed.current().ok().map(|v| key("persona", v.name))
+        }
+        Events::Storage(StorageEvents::StorageSet(set)) => {
+            set.current().ok().map(|v| key("storage", v.entry.key))
+        }
+        Events::Storage(StorageEvents::StorageRemoved(removed)) => {
+            removed.current().ok().map(|v| key("storage", v.key))
+        }
+        Events::Level(LevelEvents::LevelSet(set)) => {
+            set.current().ok().map(|v| key("level", v.level.name))
+        }
+        Events::Level(LevelEvents::LevelRemoved(removed)) => {
+            removed.current().ok().map(|v| key("level", v.name))
+        }
+        Events::Texture(TextureEvents::TextureSet(set)) => {
+            set.current().ok().map(|v| key("texture", v.texture.name))
+        }
+        Events::Texture(TextureEvents::TextureRemoved(removed)) => {
+            removed.current().ok().map(|v| key("texture", v.name))
+        }
+        Events::Sensation(SensationEvents::SensationSet(set)) => set
+            .current()
+            .ok()
+            .map(|v| key("sensation", v.sensation.name)),
+        Events::Sensation(SensationEvents::SensationRemoved(removed)) => {
+            removed.current().ok().map(|v| key("sensation", v.name))
+        }
+        Events::Nature(NatureEvents::NatureSet(set)) => {
+            set.current().ok().map(|v| key("nature", v.nature.name))
+        }
+        Events::Nature(NatureEvents::NatureRemoved(removed)) => {
+            removed.current().ok().map(|v| key("nature", v.name))
+        }
+        Events::Urge(UrgeEvents::UrgeSet(set)) => {
+            set.current().ok().map(|v| key("urge", v.urge.name))
+        }
+        Events::Urge(UrgeEvents::UrgeRemoved(removed)) => {
+            removed.current().ok().map(|v| key("urge", v.name))
+        }
+        Events::Connection(ConnectionEvents::ConnectionCreated(created)) => created
+            .current()
+            .ok()
+            .map(|v| key("connection", v.connection.id)),
+        Events::Connection(ConnectionEvents::ConnectionRemoved(removed)) => {
+            removed.current().ok().map(|v| key("connection", v.id))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stored(data: Events) -> StoredEvent {
+        StoredEvent::builder()
+            .id(EventId::new())
+            .sequence(0)
+            .data(Event::Known(data))
+            .source(Source::default())
+            .created_at(Timestamp::now())
+            .build()
+    }
+
+    fn storage_set(key: &str) -> StoredEvent {
+        stored(Events::Storage(StorageEvents::StorageSet(
+            StorageSet::builder_v1()
+                .entry(StorageEntry {
+                    key: StorageKey::new(key),
+                    description: Description::new("notes"),
+                    hash: ContentHash::compute(key.as_bytes()),
+                })
+                .build()
+                .into(),
+        )))
+    }
+
+    fn persona_removed(name: &str) -> StoredEvent {
+        stored(Events::Persona(PersonaEvents::PersonaRemoved(
+            PersonaRemoved::builder_v1().name(name).build().into(),
+        )))
+    }
+
+    fn cognition() -> StoredEvent {
+        let cognition = Cognition::builder()
+            .agent_id(AgentId::new())
+            .texture("observation")
+            .content("a thought")
+            .build();
+        stored(Events::Cognition(CognitionEvents::CognitionAdded(
+            CognitionAdded::builder_v1()
+                .cognition(cognition)
+                .build()
+                .into(),
+        )))
+    }
+
+    #[test]
+    fn mutable_entities_have_subjects() {
+        let Event::Known(set) = storage_set("notes").data else {
+            panic!("expected known event");
+        };
+        assert_eq!(contested_subject(&set).as_deref(), Some("storage:notes"));
+
+        let Event::Known(removed) = persona_removed("process").data else {
+            panic!("expected known event");
+        };
+        assert_eq!(
+            contested_subject(&removed).as_deref(),
+            Some("persona:process")
+        );
+    }
+
+    #[test]
+    fn append_only_records_never_conflict() {
+        let Event::Known(added) = cognition().data else {
+            panic!("expected known event");
+        };
+        assert_eq!(contested_subject(&added), None);
+    }
+
+    #[test]
+    fn disjoint_changes_merge_cleanly() {
+        let ours = vec![storage_set("a"), cognition()];
+        let theirs = vec![storage_set("b"), cognition()];
+
+        let plan = MergePlan::plan(&ours, &theirs, MergeStrategy::Fail);
+
+        assert!(plan.conflicts.is_empty());
+        assert!(plan.superseded.is_empty());
+        assert_eq!(plan.added, theirs.iter().map(|e| e.id).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn ours_keeps_target_changes() {
+        let ours = vec![storage_set("notes")];
+        let theirs = vec![storage_set("notes"), cognition()];
+
+        let plan = MergePlan::plan(&ours, &theirs, MergeStrategy::Ours);
+
+        assert_eq!(plan.conflicts.len(), 1);
+        assert_eq!(plan.conflicts[0].subject, "storage:notes");
+        assert_eq!(plan.conflicts[0].resolution, MergeStrategy::Ours);
+        assert!(plan.excluded.contains(&theirs[0].id));
+        assert!(plan.superseded.contains(&theirs[0].id));
+        assert_eq!(plan.added, vec![theirs[1].id]);
+    }
+
+    #[test]
+    fn theirs_supersedes_target_changes() {
+        let ours = vec![storage_set("notes")];
+        let theirs = vec![storage_set("notes")];
+
+        let plan = MergePlan::plan(&ours, &theirs, MergeStrategy::Theirs);
+
+        assert_eq!(plan.conflicts.len(), 1);
+        assert!(plan.excluded.is_empty());
+        assert!(plan.superseded.contains(&ours[0].id));
+        assert_eq!(plan.added, vec![theirs[0].id]);
+    }
+}
@@ -1,7 +1,10 @@
 mod actors;
 mod docs;
 mod features;
+mod history;
+mod merge;
 mod model;
+mod preview;
 mod protocol;
 mod repo;
 mod service;
@@ -11,7 +14,10 @@ mod view;
 pub(crate) use actors::*;
 pub(crate) use docs::*;
 pub(crate) use features::*;
+pub(crate) use history::*;
+pub(crate) use merge::*;
 pub(crate) use model::*;
+pub(crate) use preview::*;
 pub(crate) use protocol::*;
 pub(crate) use repo::*;
 pub(crate) use service::*;
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use bon::Builder;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -28,3 +30,74 @@ impl BookmarkName {
         Self::new("main")
     }
 }
+
+/// How a bookmark merge settles entities changed on both sides.
+///
+/// - `Ours` — keep the target bookmark's changes, drop the source's.
+/// - `Theirs` — take the source bookmark's changes over the target's.
+/// - `Fail` — refuse the merge and report the conflicts.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema, clap::ValueEnum,
+)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum MergeStrategy {
+    #[default]
+    Ours,
+    Theirs,
+    Fail,
+}
+
+impl core::fmt::Display for MergeStrategy {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Ours => write!(f, "ours"),
+            Self::Theirs => write!(f, "theirs"),
+            Self::Fail => write!(f, "fail"),
+        }
+    }
+}
+
+/// A mutable entity that both sides of a merge changed since they
+/// diverged, and the side that won.
+#[derive(Builder, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub(crate) struct MergeConflict {
+    /// The contested entity, e.g. `agent:gardener` or `storage:notes`.
+    #[builder(into)]
+    pub(crate) subject: String,
+    /// Events from the target bookmark that touched the subject.
+    pub(crate) ours: Vec<EventId>,
+    /// Events from the source bookmark that touched the subject.
+    pub(crate) theirs: Vec<EventId>,
+    /// The side whose changes were kept.
+    pub(crate) resolution: MergeStrategy,
+}
+
+impl MergeConflict {
+    /// The events the resolution set aside — the source's when the
+    /// target's changes were kept, the target's when the source's won.
+    pub(crate) fn losers(&self) -> &[EventId] {
+        match self.resolution {
+            MergeStrategy::Ours | MergeStrategy::Fail => &self.theirs,
+            MergeStrategy::Theirs => &self.ours,
+        }
+    }
+}
+
+/// An event a merge or collect would bring into a bookmark.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub(crate) struct PreviewedEvent {
+    pub(crate) id: EventId,
+    pub(crate) event_type: String,
+    pub(crate) domain: String,
+    /// The agent the event belongs to, when it carries one.
+    pub(crate) agent_id: Option<AgentId>,
+}
+
+/// Previewed events for one domain and agent, counted per event type.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub(crate) struct PreviewGroup {
+    pub(crate) domain: String,
+    pub(crate) agent_id: Option<AgentId>,
+    pub(crate) count: usize,
+    pub(crate) kinds: BTreeMap<String, usize>,
+}
@@ -0,0 +1,117 @@
+use std::collections::BTreeMap;
+
+use crate::*;
+
+/// Describe a set of incoming events without applying them.
+///
+/// Events come back in log order alongside a summary grouped by
+/// domain and agent, so a caller can see at a glance whose material
+/// a merge or collect would bring in.
+pub(crate) fn preview_events(events: &[StoredEvent]) -> (Vec<PreviewedEvent>, Vec<PreviewGroup>) {
+    let previewed: Vec<PreviewedEvent> = events
+        .iter()
+        .filter_map(|event| match &event.data {
+            Event::Known(data) => Some(PreviewedEvent {
+                id: event.id,
+                event_type: data.event_type(),
+                domain: data.domain().to_string(),
+                agent_id: event_agent(data),
+            }),
+            _ => None,
+        })
+        .collect();
+
+    let mut groups: BTreeMap<(String, Option<String>), PreviewGroup> = BTreeMap::new();
+    for event in &previewed {
+        let key = (
+            event.domain.clone(),
+            event.agent_id.map(|id| id.to_string()),
+        );
+        let group = groups.entry(key).or_insert_with(|| PreviewGroup {
+            domain: event.domain.clone(),
+            agent_id: event.agent_id,
+            count: 0,
+            kinds: BTreeMap::new(),
+        });
+        group.count += 1;
+        *group.kinds.entry(event.event_type.clone()).or_default() += 1;
+    }
+
+    (previewed, groups.into_values().collect())
+}
+
+/// The agent an event belongs to, for events that carry one.
+fn event_agent(event: &Events) -> Option<AgentId> {
+    match event {
+        Events::Agent(AgentEvents::AgentCreated(created)) => {
+            created.current().ok().map(|v| v.agent.id)
+        }
+        Events::Agent(AgentEvents::AgentUpdated(updated)) => {
+            updated.current().ok().map(|v| v.agent.id)
+        }
+        Events::Cognition(CognitionEvents::CognitionAdded(added)) => {
+            added.current().ok().map(|v| v.cognition.agent_id)
+        }
+        Events::Memory(MemoryEvents::MemoryAdded(added)) => {
+            added.current().ok().map(|v| v.memory.agent_id)
+        }
+        Events::Memory(MemoryEvents::MemorySuperseded(superseded)) => {
+            superseded.current().ok().map(|v| v.replacement.agent_id)
+        }
+        Events::Experience(ExperienceEvents::ExperienceCreated(created)) => {
+            created.current().ok().map(|v| v.experience.agent_id)
+        }
+        Events::Experience(ExperienceEvents::ExperienceAmended(amended)) => {
+            amended.current().ok().map(|v| v.replacement.agent_id)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cognition(agent_id: AgentId) -> StoredEvent {
+        let cognition = Cognition::builder()
+            .agent_id(agent_id)
+            .texture("observation")
+            .content("a thought")
+            .build();
+        StoredEvent::builder()
+            .id(EventId::new())
+            .sequence(0)
+            .data(Event::Known(Events::Cognition(
+                CognitionEvents::CognitionAdded(
+                    CognitionAdded::builder_v1()
+                        .cognition(cognition)
+                        .build()
+                        .into(),
+                ),
+            )))
+            .source(Source::default())
+            .created_at(Timestamp::now())
+            .build()
+    }
+
+    #[test]
+    fn groups_by_domain_and_agent() {
+        let thinker = AgentId::new();
+        let scribe = AgentId::new();
+        let events = vec![cognition(thinker), cognition(scribe), cognition(thinker)];
+
+        let (previewed, groups) = preview_events(&events);
+
+        assert_eq!(previewed.len(), 3);
+        assert_eq!(previewed[0].event_type, "cognition-added");
+        assert_eq!(previewed[0].domain, "cognition");
+        assert_eq!(groups.len(), 2);
+
+        let thinker_group = groups
+            .iter()
+            .find(|group| group.agent_id == Some(thinker))
+            .expect("thinker group");
+        assert_eq!(thinker_group.count, 2);
+        assert_eq!(thinker_group.kinds.get("cognition-added"), Some(&2));
+    }
+}
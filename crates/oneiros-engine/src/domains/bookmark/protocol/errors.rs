@@ -23,6 +23,24 @@ pub(crate) enum BookmarkError {
     #[error("Follow not found for bookmark: {0}")]
     FollowNotFound(BookmarkName),
 
+    #[error("Merge conflicts on: {}", .0.join(", "))]
+    MergeConflict(Vec<String>),
+
+    #[error("Bookmark already exists: {0}")]
+    AlreadyExists(BookmarkName),
+
+    #[error("Bookmark {0} is active — switch to another bookmark first")]
+    Active(BookmarkName),
+
+    #[error("Bookmark {0} is the project trunk and can't be deleted or renamed")]
+    Trunk(BookmarkName),
+
+    #[error("Bookmark {0} is tied to a follow — unfollow it first")]
+    Followed(BookmarkName),
+
+    #[error("Blob {0} from the peer doesn't match its hash")]
+    BlobMismatch(ContentHash),
+
     #[error(transparent)]
     Follow(#[from] FollowError),
 
@@ -50,6 +68,9 @@ pub(crate) enum BookmarkError {
     #[error(transparent)]
     Event(#[from] EventError),
 
+    #[error(transparent)]
+    Provenance(#[from] ProvenanceError),
+
     #[error(transparent)]
     IdParse(#[from] IdParseError),
 
@@ -58,6 +79,9 @@ pub(crate) enum BookmarkError {
 
     #[error(transparent)]
     Json(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    Upcast(#[from] UpcastError),
 }
 
 resource_op_error!(BookmarkError);
@@ -68,7 +92,16 @@ impl IntoResponse for BookmarkError {
             BookmarkError::NotFound(_)
             | BookmarkError::ProjectNotFound(_)
             | BookmarkError::FollowNotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
-            BookmarkError::InvalidUri(_) => (StatusCode::UNPROCESSABLE_ENTITY, self.to_string()),
+            BookmarkError::InvalidUri(_)
+            | BookmarkError::Provenance(_)
+            | BookmarkError::BlobMismatch(_) => {
+                (StatusCode::UNPROCESSABLE_ENTITY, self.to_string())
+            }
+            BookmarkError::MergeConflict(_)
+            | BookmarkError::AlreadyExists(_)
+            | BookmarkError::Active(_)
+            | BookmarkError::Trunk(_)
+            | BookmarkError::Followed(_) => (StatusCode::CONFLICT, self.to_string()),
             BookmarkError::NoActor
             | BookmarkError::Database(_)
             | BookmarkError::HostDb(_)
@@ -79,7 +112,8 @@ impl IntoResponse for BookmarkError {
             | BookmarkError::Follow(_)
             | BookmarkError::Peer(_)
             | BookmarkError::Ticket(_)
-            | BookmarkError::Compose(_) => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()),
+            | BookmarkError::Compose(_)
+            | BookmarkError::Upcast(_) => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()),
             BookmarkError::Client(_) | BookmarkError::Json(_) => {
                 (StatusCode::BAD_GATEWAY, self.to_string())
             }
@@ -32,6 +32,8 @@ pub(crate) enum BookmarkEvents {
     BookmarkCollected(BookmarkCollected),
     /// Distribution — a follow was removed.
     BookmarkUnfollowed(BookmarkUnfollowed),
+    BookmarkDeleted(BookmarkDeleted),
+    BookmarkRenamed(BookmarkRenamed),
 }
 
 impl BookmarkEvents {
@@ -51,7 +53,9 @@ impl BookmarkEvents {
             | BookmarkEvents::BookmarkShared(_)
             | BookmarkEvents::BookmarkFollowed(_)
             | BookmarkEvents::BookmarkCollected(_)
-            | BookmarkEvents::BookmarkUnfollowed(_) => None,
+            | BookmarkEvents::BookmarkUnfollowed(_)
+            | BookmarkEvents::BookmarkDeleted(_)
+            | BookmarkEvents::BookmarkRenamed(_) => None,
         }
     }
 }
@@ -66,10 +70,34 @@ versioned! {
 
 versioned! {
     pub(crate) enum BookmarkForked {
+        V2 => {
+            #[serde(flatten)] pub(crate) bookmark: Bookmark,
+            pub(crate) from: BookmarkName,
+            /// The chronicle root at the fork — the ancestor merges
+            /// between the two bookmarks are computed against.
+            pub(crate) base: Option<ContentHash>,
+        },
         V1 => {
             #[serde(flatten)] pub(crate) bookmark: Bookmark,
             pub(crate) from: BookmarkName,
-        }
+        },
+    }
+}
+
+impl TryFrom<BookmarkForkedV1> for BookmarkForkedV2 {
+    type Error = UpcastError;
+    fn try_from(v1: BookmarkForkedV1) -> Result<Self, Self::Error> {
+        Ok(BookmarkForkedV2 {
+            bookmark: v1.bookmark,
+            from: v1.from,
+            base: None,
+        })
+    }
+}
+
+impl From<BookmarkForkedV1> for BookmarkForked {
+    fn from(v: BookmarkForkedV1) -> Self {
+        Self::V1(v)
     }
 }
 
@@ -84,11 +112,37 @@ versioned! {
 
 versioned! {
     pub(crate) enum BookmarkMerged {
+        V2 => {
+            pub(crate) project: ProjectName,
+            pub(crate) source: BookmarkName,
+            pub(crate) target: BookmarkName,
+            pub(crate) strategy: MergeStrategy,
+            #[builder(default)] pub(crate) conflicts: Vec<MergeConflict>,
+        },
         V1 => {
             #[serde(alias = "brain")] pub(crate) project: ProjectName,
             pub(crate) source: BookmarkName,
             pub(crate) target: BookmarkName,
-        }
+        },
+    }
+}
+
+impl TryFrom<BookmarkMergedV1> for BookmarkMergedV2 {
+    type Error = UpcastError;
+    fn try_from(v1: BookmarkMergedV1) -> Result<Self, Self::Error> {
+        Ok(BookmarkMergedV2 {
+            project: v1.project,
+            source: v1.source,
+            target: v1.target,
+            strategy: MergeStrategy::default(),
+            conflicts: vec![],
+        })
+    }
+}
+
+impl From<BookmarkMergedV1> for BookmarkMerged {
+    fn from(v: BookmarkMergedV1) -> Self {
+        Self::V1(v)
     }
 }
 
@@ -136,6 +190,27 @@ versioned! {
     }
 }
 
+versioned! {
+    pub(crate) enum BookmarkDeleted {
+        V1 => {
+            pub(crate) id: BookmarkId,
+            pub(crate) project: ProjectName,
+            pub(crate) name: BookmarkName,
+        }
+    }
+}
+
+versioned! {
+    pub(crate) enum BookmarkRenamed {
+        V1 => {
+            pub(crate) id: BookmarkId,
+            pub(crate) project: ProjectName,
+            pub(crate) from: BookmarkName,
+            pub(crate) to: BookmarkName,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -161,6 +236,8 @@ mod tests {
                 BookmarkEventsType::BookmarkUnfollowed,
                 "bookmark-unfollowed",
             ),
+            (BookmarkEventsType::BookmarkDeleted, "bookmark-deleted"),
+            (BookmarkEventsType::BookmarkRenamed, "bookmark-renamed"),
         ];
         for (event_type, expectation) in cases {
             assert_eq!(&event_type.to_string(), expectation);
@@ -242,4 +319,27 @@ mod tests {
         assert_eq!(switched.project.as_str(), "legacy-project");
         assert_eq!(switched.name.as_str(), "main");
     }
+
+    #[test]
+    fn legacy_bookmark_merged_upcasts_with_default_strategy() {
+        let json = serde_json::json!({
+            "type": "bookmark-merged",
+            "data": {
+                "brain": "legacy-project",
+                "source": "experiment",
+                "target": "main"
+            }
+        });
+        let event: BookmarkEvents = serde_json::from_value(json).expect("legacy decode");
+        let merged = match event {
+            BookmarkEvents::BookmarkMerged(inner) => {
+                assert!(matches!(inner, BookmarkMerged::V1(_)));
+                inner.current().unwrap()
+            }
+            other => panic!("expected BookmarkMerged, got {other:?}"),
+        };
+        assert_eq!(merged.project.as_str(), "legacy-project");
+        assert_eq!(merged.strategy, MergeStrategy::Ours);
+        assert!(merged.conflicts.is_empty());
+    }
 }
@@ -33,9 +33,40 @@ versioned! {
     #[derive(JsonSchema)]
     pub(crate) enum MergeBookmark {
         #[derive(clap::Args)]
+        V2 => {
+            #[builder(into)] pub(crate) source: BookmarkName,
+            /// How to settle entities both bookmarks changed since they forked.
+            #[arg(long, value_enum, default_value_t)]
+            #[builder(default)]
+            #[serde(default)]
+            pub(crate) strategy: MergeStrategy,
+            /// Report what the merge would do without changing anything.
+            #[arg(long)]
+            #[builder(default)]
+            #[serde(default)]
+            pub(crate) dry_run: bool,
+        },
+        #[derive(clap::Args, schemars::JsonSchema)]
         V1 => {
             #[builder(into)] pub(crate) source: BookmarkName,
-        }
+        },
+    }
+}
+
+impl TryFrom<MergeBookmarkV1> for MergeBookmarkV2 {
+    type Error = UpcastError;
+    fn try_from(v1: MergeBookmarkV1) -> Result<Self, Self::Error> {
+        Ok(MergeBookmarkV2 {
+            source: v1.source,
+            strategy: MergeStrategy::default(),
+            dry_run: false,
+        })
+    }
+}
+
+impl From<MergeBookmarkV1> for MergeBookmark {
+    fn from(v: MergeBookmarkV1) -> Self {
+        Self::V1(v)
     }
 }
 
@@ -107,7 +138,7 @@ versioned! {
     #[derive(JsonSchema)]
     pub(crate) enum CollectBookmark {
         #[derive(clap::Args)]
-        V2 => {
+        V3 => {
             /// For follow-based collect: the local bookmark name.
             /// For peer collect: the bookmark name on the remote.
             #[builder(into)] pub(crate) name: BookmarkName,
@@ -118,6 +149,19 @@ versioned! {
             /// Defaults to the peer bookmark name.
             #[arg(long = "as")]
             pub(crate) as_name: Option<BookmarkName>,
+            /// Report which events would be collected without importing them.
+            #[arg(long)]
+            #[builder(default)]
+            #[serde(default)]
+            pub(crate) dry_run: bool,
+        },
+        #[derive(clap::Args, schemars::JsonSchema)]
+        V2 => {
+            #[builder(into)] pub(crate) name: BookmarkName,
+            #[arg(long, alias = "peer")]
+            pub(crate) from: Option<PeerName>,
+            #[arg(long = "as")]
+            pub(crate) as_name: Option<BookmarkName>,
         },
         #[derive(clap::Args, schemars::JsonSchema)]
         V1 => {
@@ -127,17 +171,36 @@ versioned! {
     }
 }
 
-impl TryFrom<CollectBookmarkV1> for CollectBookmarkV2 {
+impl TryFrom<CollectBookmarkV2> for CollectBookmarkV3 {
+    type Error = UpcastError;
+    fn try_from(v2: CollectBookmarkV2) -> Result<Self, Self::Error> {
+        Ok(CollectBookmarkV3 {
+            name: v2.name,
+            from: v2.from,
+            as_name: v2.as_name,
+            dry_run: false,
+        })
+    }
+}
+
+impl TryFrom<CollectBookmarkV1> for CollectBookmarkV3 {
     type Error = UpcastError;
     fn try_from(v1: CollectBookmarkV1) -> Result<Self, Self::Error> {
-        Ok(CollectBookmarkV2 {
+        Ok(CollectBookmarkV3 {
             name: v1.name,
             from: None,
             as_name: None,
+            dry_run: false,
         })
     }
 }
 
+impl From<CollectBookmarkV2> for CollectBookmark {
+    fn from(v: CollectBookmarkV2) -> Self {
+        Self::V2(v)
+    }
+}
+
 impl From<CollectBookmarkV1> for CollectBookmark {
     fn from(v: CollectBookmarkV1) -> Self {
         Self::V1(v)
@@ -178,6 +241,37 @@ versioned! {
     }
 }
 
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum DeleteBookmark {
+        #[derive(clap::Args)]
+        V1 => {
+            #[builder(into)] pub(crate) name: BookmarkName,
+        }
+    }
+}
+
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum RenameBookmark {
+        #[derive(clap::Args)]
+        V1 => {
+            /// The bookmark's current name.
+            #[builder(into)] pub(crate) from: BookmarkName,
+            /// The name to give it.
+            #[builder(into)] pub(crate) to: BookmarkName,
+        }
+    }
+}
+
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum SweepChronicles {
+        #[derive(clap::Args)]
+        V1 => {}
+    }
+}
+
 resource_requests! {
     CreateBookmark => |this, client| { client.post("/bookmarks", this).await },
     SwitchBookmark => |this, client| { client.post("/bookmarks/switch", this).await },
@@ -201,7 +295,13 @@ resource_requests! {
     FollowBookmark => |this, client| { client.post("/bookmarks/follow", this).await },
     CollectBookmark => |this, client| { client.post("/bookmarks/collect", this).await },
     UnfollowBookmark => |this, client| { client.post("/bookmarks/unfollow", this).await },
-    SubmitBookmark => |this, client| { client.post("/bookmarks/submit", this).await }
+    SubmitBookmark => |this, client| { client.post("/bookmarks/submit", this).await },
+    DeleteBookmark => |this, client| {
+        let DeleteBookmark::V1(deletion) = this;
+        client.delete(&format!("/bookmarks/{}", deletion.name)).await
+    },
+    RenameBookmark => |this, client| { client.post("/bookmarks/rename", this).await },
+    SweepChronicles => |this, client| { client.post("/bookmarks/gc", this).await }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Kinded)]
@@ -211,12 +311,19 @@ pub(crate) enum BookmarkRequest {
     CreateBookmark(CreateBookmark),
     SwitchBookmark(SwitchBookmark),
     MergeBookmark(MergeBookmark),
+    /// A merge run as a dry run — reports, never applies.
+    PreviewMerge(MergeBookmark),
     ListBookmarks(ListBookmarks),
     ShareBookmark(ShareBookmark),
     FollowBookmark(FollowBookmark),
     CollectBookmark(CollectBookmark),
+    /// A collect run as a dry run — reports, never imports.
+    PreviewCollect(CollectBookmark),
     UnfollowBookmark(UnfollowBookmark),
     SubmitBookmark(SubmitBookmark),
+    DeleteBookmark(DeleteBookmark),
+    RenameBookmark(RenameBookmark),
+    SweepChronicles(SweepChronicles),
 }
 
 impl TryFrom<SubmitBookmarkV1> for SubmitBookmarkV2 {
@@ -12,12 +12,16 @@ pub(crate) enum BookmarkResponse {
     Forked(BookmarkForkedResponse),
     Switched(BookmarkSwitchedResponse),
     Merged(BookmarkMergedResponse),
+    Previewed(BookmarkPreviewResponse),
     Bookmarks(Listed<Bookmark>),
     Shared(BookmarkShareResult),
     Followed(Follow),
     Collected(BookmarkCollectResult),
     Unfollowed(BookmarkUnfollowedResponse),
     Submitted(BookmarkSubmitResult),
+    Deleted(BookmarkDeletedResponse),
+    Renamed(BookmarkRenamedResponse),
+    Swept(BookmarkSweptResponse),
 }
 
 versioned! {
@@ -50,10 +54,65 @@ versioned! {
 versioned! {
     #[derive(JsonSchema)]
     pub(crate) enum BookmarkMergedResponse {
+        V2 => {
+            pub(crate) project: ProjectName,
+            pub(crate) source: BookmarkName,
+            pub(crate) target: BookmarkName,
+            pub(crate) strategy: MergeStrategy,
+            /// Source events newly witnessed by the target.
+            #[builder(default)]
+            pub(crate) added: Vec<EventId>,
+            /// Entities both sides changed, and how each was settled.
+            #[builder(default)]
+            pub(crate) conflicts: Vec<MergeConflict>,
+        },
+        #[derive(schemars::JsonSchema)]
+        V1 => {
+            pub(crate) project: ProjectName,
+            pub(crate) source: BookmarkName,
+            pub(crate) target: BookmarkName,
+        },
+    }
+}
+
+impl TryFrom<BookmarkMergedResponseV1> for BookmarkMergedResponseV2 {
+    type Error = UpcastError;
+    fn try_from(v1: BookmarkMergedResponseV1) -> Result<Self, Self::Error> {
+        Ok(BookmarkMergedResponseV2 {
+            project: v1.project,
+            source: v1.source,
+            target: v1.target,
+            strategy: MergeStrategy::default(),
+            added: vec![],
+            conflicts: vec![],
+        })
+    }
+}
+
+impl From<BookmarkMergedResponseV1> for BookmarkMergedResponse {
+    fn from(v: BookmarkMergedResponseV1) -> Self {
+        Self::V1(v)
+    }
+}
+
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum BookmarkPreviewResponse {
         V1 => {
             pub(crate) project: ProjectName,
+            /// The bookmark events would come from.
             pub(crate) source: BookmarkName,
+            /// The peer holding the source bookmark, for collect previews.
+            pub(crate) from: Option<PeerName>,
+            /// The bookmark events would land in.
             pub(crate) target: BookmarkName,
+            #[builder(default)]
+            pub(crate) events: Vec<PreviewedEvent>,
+            #[builder(default)]
+            pub(crate) groups: Vec<PreviewGroup>,
+            /// Conflicts a merge would settle, under the requested strategy.
+            #[builder(default)]
+            pub(crate) conflicts: Vec<MergeConflict>,
         }
     }
 }
@@ -69,6 +128,41 @@ versioned! {
     }
 }
 
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum BookmarkDeletedResponse {
+        V1 => {
+            pub(crate) project: ProjectName,
+            pub(crate) name: BookmarkName,
+        }
+    }
+}
+
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum BookmarkRenamedResponse {
+        V1 => {
+            pub(crate) project: ProjectName,
+            pub(crate) from: BookmarkName,
+            pub(crate) to: BookmarkName,
+        }
+    }
+}
+
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum BookmarkSweptResponse {
+        V1 => {
+            /// Chronicle nodes still reachable from a live bookmark.
+            pub(crate) kept: u64,
+            /// Chronicle nodes no bookmark could reach, now deleted.
+            pub(crate) removed: u64,
+            /// Serialized size of the removed nodes.
+            pub(crate) bytes_reclaimed: u64,
+        }
+    }
+}
+
 /// The outcome of a successful `bookmark share` — the minted ticket and
 /// the composed URI. The URI is derivable from the ticket plus current
 /// host identity, but it's convenient to return it alongside so callers
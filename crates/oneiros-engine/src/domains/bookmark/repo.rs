@@ -9,6 +9,41 @@ impl<'a> BookmarkRepo<'a> {
         Self { scope }
     }
 
+    pub(crate) async fn get(
+        &self,
+        project: &ProjectName,
+        name: &BookmarkName,
+    ) -> Result<Option<Bookmark>, BookmarkError> {
+        let db = HostDb::open(self.scope).await?;
+        let mut stmt = db.prepare(
+            "SELECT id, project, name, created_at FROM bookmarks
+             WHERE project = ?1 AND name = ?2",
+        )?;
+
+        let raw = stmt.query_row(
+            rusqlite::params![project.to_string(), name.to_string()],
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                ))
+            },
+        );
+
+        match raw {
+            Ok((id, project, name, created_at)) => Ok(Some(Bookmark {
+                id: id.parse()?,
+                project: ProjectName::new(project),
+                name: BookmarkName::new(name),
+                created_at: Timestamp::parse_str(created_at)?,
+            })),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(error) => Err(error.into()),
+        }
+    }
+
     pub(crate) async fn list(
         &self,
         project: &ProjectName,
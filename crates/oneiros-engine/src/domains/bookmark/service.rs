@@ -3,7 +3,16 @@ use crate::*;
 /// Internal result from `collect_from_peer_link`.
 pub(crate) struct PeerCollectResult {
     pub(crate) count: u64,
-    pub(crate) diff_result: DiffResult,
+    pub(crate) server_root: Option<ContentHash>,
+}
+
+/// How far a collect from a peer link got. Kept up to date as pages
+/// arrive, so a caller whose collect fails part way can record what
+/// was received and what was still to fetch.
+#[derive(Default)]
+pub(crate) struct CollectProgress {
+    pub(crate) received: u64,
+    pub(crate) pending: Option<PendingFetch>,
 }
 
 pub(crate) struct BookmarkService;
@@ -24,19 +33,20 @@ impl BookmarkService {
             return Ok(());
         }
 
+        let from = canons.active_bookmark(project)?;
         canons.fork_project(project, bookmark_name)?;
-        Self::create_bookmark_db(config, project, bookmark_name, &[])?;
+        Self::create_bookmark_db(config, project, bookmark_name, &from, &[])?;
 
-        let from = canons.active_bookmark(project)?;
         let bookmark = Bookmark::builder()
             .project(project.clone())
             .name(bookmark_name.clone())
             .build();
         let new_event = NewEvent::builder()
             .data(Events::Bookmark(BookmarkEvents::BookmarkForked(
-                BookmarkForked::builder_v1()
+                BookmarkForked::builder_v2()
                     .bookmark(bookmark.clone())
                     .from(from)
+                    .maybe_base(canons.bookmark_base(project, bookmark_name)?)
                     .build()
                     .into(),
             )))
@@ -88,7 +98,7 @@ impl BookmarkService {
 
         // Create the new bookmark's DB and replay the source events into it.
         // If event_ids is non-empty, only those events are replayed (scoped fork).
-        Self::create_bookmark_db(state.config(), project, name, &event_ids)?;
+        Self::create_bookmark_db(state.config(), project, name, &from, &event_ids)?;
 
         let bookmark = Bookmark::builder()
             .project(project.clone())
@@ -97,9 +107,10 @@ impl BookmarkService {
 
         let new_event = NewEvent::builder()
             .data(Events::Bookmark(BookmarkEvents::BookmarkForked(
-                BookmarkForked::builder_v1()
+                BookmarkForked::builder_v2()
                     .bookmark(bookmark.clone())
                     .from(from.clone())
+                    .maybe_base(state.canons().bookmark_base(project, name)?)
                     .build()
                     .into(),
             )))
@@ -162,17 +173,33 @@ impl BookmarkService {
         project: &ProjectName,
         request: &MergeBookmark,
     ) -> Result<BookmarkResponse, BookmarkError> {
-        let MergeBookmark::V1(merging) = request;
+        let merging = request.current()?;
         let source = &merging.source;
+        let strategy = merging.strategy;
         let target = state.canons().active_bookmark(project)?;
-        state.canons().merge_project(project, source, &target)?;
 
-        Self::replay_bookmark(state.config(), project, &target)?;
+        let plan = Self::plan_merge(state, project, source, &target, strategy)?;
 
-        let event = BookmarkMerged::builder_v1()
+        if merging.dry_run {
+            return Self::describe_merge(state.config(), project, source, &target, plan);
+        }
+
+        if strategy == MergeStrategy::Fail && !plan.conflicts.is_empty() {
+            return Err(BookmarkError::MergeConflict(plan.subjects()));
+        }
+
+        state
+            .canons()
+            .merge_project(state.config(), project, source, &target, &plan.excluded)?;
+
+        Self::replay_bookmark(state.config(), project, &target, &plan.superseded)?;
+
+        let event = BookmarkMerged::builder_v2()
             .project(project.clone())
             .source(source.clone())
             .target(target.clone())
+            .strategy(strategy)
+            .conflicts(plan.conflicts.clone())
             .build();
 
         let new_event = NewEvent::builder()
@@ -188,15 +215,94 @@ impl BookmarkService {
         ));
 
         Ok(BookmarkResponse::Merged(
-            BookmarkMergedResponse::builder_v1()
+            BookmarkMergedResponse::builder_v2()
                 .project(project.clone())
                 .source(source.clone())
                 .target(target)
+                .strategy(strategy)
+                .added(plan.added)
+                .conflicts(plan.conflicts)
+                .build()
+                .into(),
+        ))
+    }
+
+    /// Preview a merge — `merge` with `dry_run` forced on.
+    pub(crate) async fn preview_merge(
+        scope: &Scope<AtHost>,
+        state: &ServerState,
+        project: &ProjectName,
+        request: &MergeBookmark,
+    ) -> Result<BookmarkResponse, BookmarkError> {
+        let mut previewing = request.current()?;
+        previewing.dry_run = true;
+        Self::merge(scope, state, project, &previewing.into()).await
+    }
+
+    /// Describe a planned merge without applying it.
+    fn describe_merge(
+        config: &Config,
+        project: &ProjectName,
+        source: &BookmarkName,
+        target: &BookmarkName,
+        plan: MergePlan,
+    ) -> Result<BookmarkResponse, BookmarkError> {
+        let mut project_config = config.clone();
+        project_config.project = project.clone();
+        project_config.bookmark = target.clone();
+
+        let db = project_config.bookmark_conn()?;
+        let incoming = EventLog::attached(&db).get_batch(&plan.added)?;
+        let (events, groups) = preview_events(&incoming);
+
+        Ok(BookmarkResponse::Previewed(
+            BookmarkPreviewResponse::builder_v1()
+                .project(project.clone())
+                .source(source.clone())
+                .target(target.clone())
+                .events(events)
+                .groups(groups)
+                .conflicts(plan.conflicts)
                 .build()
                 .into(),
         ))
     }
 
+    /// Compare the source and target chronicles against their fork
+    /// ancestor and decide how conflicting changes are settled.
+    fn plan_merge(
+        state: &ServerState,
+        project: &ProjectName,
+        source: &BookmarkName,
+        target: &BookmarkName,
+        strategy: MergeStrategy,
+    ) -> Result<MergePlan, BookmarkError> {
+        let canons = state.canons();
+        let base = canons.merge_base(project, source, target)?;
+        let ours = canons.bookmark_chronicle(project, target)?.root()?;
+        let theirs = canons.bookmark_chronicle(project, source)?.root()?;
+
+        let mut project_config = state.config().clone();
+        project_config.project = project.clone();
+        project_config.bookmark = target.clone();
+
+        let host_db = project_config.host_db()?;
+        let store = ChronicleStore::new(&host_db);
+        store.migrate()?;
+
+        let db = project_config.bookmark_conn()?;
+        let log = EventLog::attached(&db);
+
+        Ok(MergePlan::compute(
+            base.as_ref(),
+            ours.as_ref(),
+            theirs.as_ref(),
+            &log,
+            strategy,
+            &store.resolver(),
+        )?)
+    }
+
     pub(crate) async fn list(
         scope: &Scope<AtHost>,
         state: &ServerState,
@@ -307,7 +413,12 @@ impl BookmarkService {
             .project(project_record)
             .actor_id(resolved_actor_id)
             .target(target)
-            .permissions(vec![])
+            .permissions(vec![
+                Permission::builder_v1()
+                    .operation(PermissionOp::BookmarkCollect)
+                    .build()
+                    .into(),
+            ])
             .build()
             .into();
         let ticket = TicketService::issue(scope, mailbox, &request).await?;
@@ -386,13 +497,19 @@ impl BookmarkService {
                 .build(),
         ));
 
+        let from = state.canons().active_bookmark(project)?;
         state.canons().fork_project(project, name)?;
 
         // Create the new bookmark's DB (empty — collect will populate it).
-        Self::create_bookmark_db(state.config(), project, name, &[])?;
+        Self::create_bookmark_db(state.config(), project, name, &from, &[])?;
 
         let follow =
             FollowService::create(scope, mailbox, project.clone(), name.clone(), source).await?;
+
+        mailbox.tell(HostMessage::from(
+            SubscribeFollow::builder().follow(follow.clone()).build(),
+        ));
+
         Ok(BookmarkResponse::Followed(follow))
     }
 
@@ -404,72 +521,135 @@ impl BookmarkService {
         project: &ProjectName,
         request: &CollectBookmark,
     ) -> Result<BookmarkResponse, BookmarkError> {
-        match request {
-            CollectBookmark::V2(v2) => {
-                let Some(ref peer_name) = v2.from else {
-                    // V2 without --from: fall through to follow-based collect.
-                    let mailbox = state.mailbox();
-                    let follow = FollowService::for_bookmark(scope, project, &v2.name)
-                        .await?
-                        .ok_or_else(|| BookmarkError::FollowNotFound(v2.name.clone()))?;
-
-                    return match follow.source.clone() {
-                        FollowSource::Local(_) => {
-                            let checkpoint = Checkpoint::empty();
-                            FollowService::advance(
-                                scope,
-                                mailbox,
-                                follow.id,
-                                checkpoint.clone(),
-                                0,
-                            )
-                            .await?;
-                            Ok(BookmarkResponse::Collected(BookmarkCollectResult {
-                                follow_id: Some(follow.id),
-                                events_received: 0,
-                                checkpoint,
-                            }))
-                        }
-                        FollowSource::Peer(peer_link) => {
-                            Self::collect_from_peer(scope, state, project, &follow, peer_link).await
-                        }
-                    };
-                };
-                return Self::collect_from_remote(
-                    scope,
+        let collecting = request.current()?;
+
+        if let Some(ref peer_name) = collecting.from {
+            if collecting.dry_run {
+                let peer_link = Self::peer_link(scope, peer_name).await?;
+                let local_name = collecting.as_name.as_ref().unwrap_or(&collecting.name);
+                return Self::describe_collect(
                     state,
                     project,
-                    &v2.name,
-                    peer_name,
-                    v2.as_name.as_ref(),
+                    &collecting.name,
+                    Some(peer_name),
+                    local_name,
+                    &peer_link,
                 )
                 .await;
             }
-            CollectBookmark::V1(v1) => {
-                let mailbox = state.mailbox();
-                let follow = FollowService::for_bookmark(scope, project, &v1.name)
-                    .await?
-                    .ok_or_else(|| BookmarkError::FollowNotFound(v1.name.clone()))?;
-
-                match follow.source.clone() {
-                    FollowSource::Local(_) => {
-                        let checkpoint = Checkpoint::empty();
-                        FollowService::advance(scope, mailbox, follow.id, checkpoint.clone(), 0)
-                            .await?;
-                        Ok(BookmarkResponse::Collected(BookmarkCollectResult {
-                            follow_id: Some(follow.id),
-                            events_received: 0,
-                            checkpoint,
-                        }))
-                    }
-                    FollowSource::Peer(peer_link) => {
-                        Self::collect_from_peer(scope, state, project, &follow, peer_link).await
-                    }
-                }
+            return Self::collect_from_remote(
+                scope,
+                state,
+                project,
+                &collecting.name,
+                peer_name,
+                collecting.as_name.as_ref(),
+            )
+            .await;
+        }
+
+        let mailbox = state.mailbox();
+        let follow = FollowService::for_bookmark(scope, project, &collecting.name)
+            .await?
+            .ok_or_else(|| BookmarkError::FollowNotFound(collecting.name.clone()))?;
+
+        match follow.source.clone() {
+            FollowSource::Local(_) if collecting.dry_run => Ok(BookmarkResponse::Previewed(
+                BookmarkPreviewResponse::builder_v1()
+                    .project(project.clone())
+                    .source(follow.bookmark.clone())
+                    .target(follow.bookmark.clone())
+                    .build()
+                    .into(),
+            )),
+            FollowSource::Local(_) => {
+                let checkpoint = Checkpoint::empty();
+                FollowService::advance(scope, mailbox, follow.id, checkpoint.clone(), 0).await?;
+                Ok(BookmarkResponse::Collected(BookmarkCollectResult {
+                    follow_id: Some(follow.id),
+                    events_received: 0,
+                    checkpoint,
+                }))
+            }
+            FollowSource::Peer(peer_link) if collecting.dry_run => {
+                Self::describe_collect(
+                    state,
+                    project,
+                    &follow.bookmark,
+                    None,
+                    &follow.bookmark,
+                    &peer_link,
+                )
+                .await
+            }
+            FollowSource::Peer(peer_link) => {
+                Self::collect_from_peer(scope, state, &follow, peer_link).await
             }
         }
     }
 
+    /// Resolve a named peer into a link we can dial.
+    async fn peer_link(
+        scope: &Scope<AtHost>,
+        peer_name: &PeerName,
+    ) -> Result<PeerLink, BookmarkError> {
+        let peer = PeerRepo::new(scope)
+            .get_by_name(peer_name)
+            .await?
+            .ok_or_else(|| BookmarkError::InvalidUri(format!("peer not found: {}", peer_name)))?;
+        let ticket = peer.ticket.ok_or_else(|| {
+            BookmarkError::InvalidUri(format!("peer {} has no ticket", peer_name))
+        })?;
+        Ok(PeerLink::new(peer.address, ticket))
+    }
+
+    /// Preview a collect — `collect` with `dry_run` forced on.
+    pub(crate) async fn preview_collect(
+        scope: &Scope<AtHost>,
+        state: &ServerState,
+        project: &ProjectName,
+        request: &CollectBookmark,
+    ) -> Result<BookmarkResponse, BookmarkError> {
+        let mut previewing = request.current()?;
+        previewing.dry_run = true;
+        Self::collect(scope, state, project, &previewing.into()).await
+    }
+
+    /// Describe what a collect from a peer would bring in, without
+    /// importing anything. The events are fetched so their types can be
+    /// reported, but never reach `events.db` or the bookmark projections.
+    async fn describe_collect(
+        state: &ServerState,
+        project: &ProjectName,
+        source: &BookmarkName,
+        from: Option<&PeerName>,
+        local_name: &BookmarkName,
+        peer_link: &PeerLink,
+    ) -> Result<BookmarkResponse, BookmarkError> {
+        let incoming = Self::fetch_missing(
+            state.bridge(),
+            state.canons(),
+            state.config(),
+            project,
+            local_name,
+            peer_link,
+        )
+        .await?;
+        let (events, groups) = preview_events(&incoming);
+
+        Ok(BookmarkResponse::Previewed(
+            BookmarkPreviewResponse::builder_v1()
+                .project(project.clone())
+                .source(source.clone())
+                .maybe_from(from.cloned())
+                .target(local_name.clone())
+                .events(events)
+                .groups(groups)
+                .build()
+                .into(),
+        ))
+    }
+
     /// Collect directly from a peer, using the chronicle diff
     /// protocol. Creates the local bookmark if it doesn't exist.
     async fn collect_from_remote(
@@ -480,11 +660,7 @@ impl BookmarkService {
         peer_name: &PeerName,
         as_name: Option<&BookmarkName>,
     ) -> Result<BookmarkResponse, BookmarkError> {
-        let peer = PeerRepo::new(scope)
-            .get_by_name(peer_name)
-            .await?
-            .ok_or_else(|| BookmarkError::InvalidUri(format!("peer not found: {}", peer_name)))?;
-
+        let peer_link = Self::peer_link(scope, peer_name).await?;
         let local_name = as_name.unwrap_or(peer_bookmark_name);
 
         // Ensure the bookmark exists locally.
@@ -497,10 +673,6 @@ impl BookmarkService {
             local_name,
         )?;
 
-        let ticket = peer.ticket.ok_or_else(|| {
-            BookmarkError::InvalidUri(format!("peer {} has no ticket", peer_name))
-        })?;
-        let peer_link = PeerLink::new(peer.address, ticket);
         let result = Self::collect_from_peer_link(
             state.mailbox(),
             state.bridge(),
@@ -509,14 +681,16 @@ impl BookmarkService {
             project,
             local_name,
             peer_link,
+            &mut CollectProgress::default(),
         )
         .await?;
 
         let checkpoint = Checkpoint {
             sequence: result.count,
-            cumulative_hash: result.diff_result.server_root.unwrap_or_default(),
+            cumulative_hash: result.server_root.unwrap_or_default(),
             head: None,
             taken_at: Timestamp::now(),
+            pending: None,
         };
 
         Ok(BookmarkResponse::Collected(BookmarkCollectResult {
@@ -532,38 +706,30 @@ impl BookmarkService {
     /// messages. The inbound actor handles the events.db insert
     /// (insert-or-ignore by id), then forwards `Stored` to the
     /// bookmark + chronicle children — which project and record
-    /// without distinction from locally-emitted events.
+    /// without distinction from locally-emitted events. The outcome
+    /// lands in the follow's sync status either way.
     async fn collect_from_peer(
         scope: &Scope<AtHost>,
         state: &ServerState,
-        project: &ProjectName,
         follow: &Follow,
         peer_link: PeerLink,
     ) -> Result<BookmarkResponse, BookmarkError> {
-        let mailbox = state.mailbox();
-
-        let result = Self::collect_from_peer_link(
+        let statuses = state.follow_statuses();
+        let (events_received, checkpoint) = Self::pull_follow(
             state.mailbox(),
             state.bridge(),
             state.canons(),
             state.config(),
-            project,
-            &follow.bookmark,
+            follow,
             peer_link,
         )
-        .await?;
-        let events_received = result.count;
-
-        let checkpoint = Checkpoint {
-            sequence: follow.checkpoint.sequence + events_received,
-            cumulative_hash: result.diff_result.server_root.unwrap_or_default(),
-            head: None,
-            taken_at: Timestamp::now(),
-        };
+        .await
+        .inspect(|(received, _)| statuses.succeeded(follow.id, *received))
+        .inspect_err(|error| statuses.failed(follow.id, error))?;
 
         FollowService::advance(
             scope,
-            mailbox,
+            state.mailbox(),
             follow.id,
             checkpoint.clone(),
             events_received,
@@ -577,11 +743,92 @@ impl BookmarkService {
         }))
     }
 
+    /// Pull a peer follow's missing events into its bookmark and compute
+    /// the checkpoint that records them. The caller decides whether to
+    /// advance the follow — manual collects always do, background sync
+    /// only when something arrived.
+    ///
+    /// A collect the follow's checkpoint left unfinished resumes from its
+    /// pending fetch. If this one fails part way, the follow is advanced
+    /// here with what did arrive and what's left, before the error is
+    /// returned.
+    pub(crate) async fn pull_follow(
+        mailbox: &Mailbox,
+        bridge: &Bridge,
+        canons: &CanonIndex,
+        config: &Config,
+        follow: &Follow,
+        peer_link: PeerLink,
+    ) -> Result<(u64, Checkpoint), BookmarkError> {
+        let mut progress = CollectProgress {
+            received: 0,
+            pending: follow.checkpoint.pending.clone(),
+        };
+
+        let collected = Self::collect_from_peer_link(
+            mailbox,
+            bridge,
+            canons,
+            config,
+            &follow.project,
+            &follow.bookmark,
+            peer_link,
+            &mut progress,
+        )
+        .await;
+
+        let result = match collected {
+            Ok(result) => result,
+            Err(error) => {
+                if progress.received > 0 {
+                    let checkpoint = Checkpoint {
+                        sequence: follow.checkpoint.sequence + progress.received,
+                        cumulative_hash: follow.checkpoint.cumulative_hash.clone(),
+                        head: None,
+                        taken_at: Timestamp::now(),
+                        pending: progress.pending,
+                    };
+                    let scope = ComposeScope::new(config.clone()).host()?;
+                    FollowService::advance(
+                        &scope,
+                        mailbox,
+                        follow.id,
+                        checkpoint,
+                        progress.received,
+                    )
+                    .await?;
+                }
+                return Err(error);
+            }
+        };
+        let events_received = result.count;
+
+        let checkpoint = Checkpoint {
+            sequence: follow.checkpoint.sequence + events_received,
+            cumulative_hash: result.server_root.unwrap_or_default(),
+            head: None,
+            taken_at: Timestamp::now(),
+            pending: None,
+        };
+
+        Ok((events_received, checkpoint))
+    }
+
     /// Collect events from a peer link into a named bookmark, without
     /// requiring a Follow record. Used by both the existing collect flow
     /// and the submit handshake.
     ///
+    /// Missing events are fetched a page at a time, and each page is
+    /// imported and recorded in the chronicle before the next is asked
+    /// for; `progress` tracks what's left. A collect with a pending fetch
+    /// resumes from it instead of diffing again. Blobs a page's
+    /// `StorageSet` events point at are fetched, in chunks, before the
+    /// page is imported — the storage projection refers to them — and
+    /// once the events are in, any entry still missing its blob is
+    /// fetched too.
+    ///
     /// The caller must ensure the bookmark already exists on this host.
+    #[expect(clippy::too_many_arguments)]
     pub(crate) async fn collect_from_peer_link(
         mailbox: &Mailbox,
         bridge: &Bridge,
@@ -590,7 +837,295 @@ impl BookmarkService {
         project: &ProjectName,
         bookmark_name: &BookmarkName,
         peer_link: PeerLink,
+        progress: &mut CollectProgress,
     ) -> Result<PeerCollectResult, BookmarkError> {
+        let chronicle = canons.bookmark_chronicle(project, bookmark_name)?;
+        let (server_root, mut remaining) = match progress.pending.take() {
+            Some(pending) => (Some(pending.server_root), pending.remaining),
+            None => {
+                let diff_result =
+                    Self::diff_peer(bridge, canons, config, project, bookmark_name, &peer_link)
+                        .await?;
+                (diff_result.server_root, diff_result.missing)
+            }
+        };
+
+        while !remaining.is_empty() {
+            progress.pending = Some(PendingFetch {
+                server_root: server_root.clone().unwrap_or_default(),
+                remaining: remaining.clone(),
+            });
+
+            let page = remaining.len().min(FETCH_PAGE_IDS);
+            let events =
+                Self::fetch_admitted(bridge, config, &peer_link, &remaining[..page]).await?;
+            Self::prefetch_blobs(bridge, config, project, bookmark_name, &peer_link, &events).await;
+
+            let received = events.len() as u64;
+            if !events.is_empty() {
+                let expected_ids: std::collections::HashSet<String> =
+                    events.iter().map(|event| event.id.to_string()).collect();
+                Self::import_events(mailbox, config, project, bookmark_name, events)?;
+                Self::recorded(config, &chronicle, expected_ids).await?;
+            }
+
+            remaining.drain(..page);
+            progress.received += received;
+        }
+        progress.pending = None;
+
+        if progress.received > 0 {
+            Self::fetch_dangling(bridge, config, project, bookmark_name, &peer_link).await?;
+        }
+
+        Ok(PeerCollectResult {
+            count: progress.received,
+            server_root,
+        })
+    }
+
+    /// Wait until a bookmark's chronicle has recorded every expected event.
+    async fn recorded(
+        config: &Config,
+        chronicle: &Chronicle,
+        expected_ids: std::collections::HashSet<String>,
+    ) -> Result<(), BookmarkError> {
+        let resolve = {
+            let config = config.clone();
+            move |hash: &ContentHash| -> Option<LedgerNode> {
+                let db = config.host_db().ok()?;
+                ChronicleStore::new(&db).get(hash)
+            }
+        };
+        config
+            .fetch
+            .eventual(|| async {
+                let root = chronicle.root()?;
+                let seen = match root.as_ref() {
+                    Some(hash) => Ledger::collect_all_ids(Some(hash), &resolve),
+                    None => std::collections::HashSet::new(),
+                };
+                Ok::<_, EventError>(if expected_ids.is_subset(&seen) {
+                    Some(())
+                } else {
+                    None
+                })
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Fetch the blobs that incoming `StorageSet` events point at, so
+    /// they're here when the events are projected. A blob the peer
+    /// can't supply is left for [`Self::fetch_dangling`].
+    async fn prefetch_blobs(
+        bridge: &Bridge,
+        config: &Config,
+        project: &ProjectName,
+        bookmark_name: &BookmarkName,
+        peer_link: &PeerLink,
+        events: &[StoredEvent],
+    ) {
+        for hash in events.iter().filter_map(Self::stored_blob) {
+            if let Err(error) =
+                Self::fetch_blob(bridge, config, project, bookmark_name, peer_link, &hash).await
+            {
+                tracing::warn!(%error, %hash, "collect: blob unavailable from peer");
+            }
+        }
+    }
+
+    /// Fetch the blobs behind a bookmark's dangling storage entries —
+    /// set by an event, but with no blob here — and project each entry
+    /// once its blob arrives. Whatever the peer can't supply stays
+    /// dangling, for `storage fsck` to report.
+    async fn fetch_dangling(
+        bridge: &Bridge,
+        config: &Config,
+        project: &ProjectName,
+        bookmark_name: &BookmarkName,
+        peer_link: &PeerLink,
+    ) -> Result<(), BookmarkError> {
+        let scope =
+            ComposeScope::new(config.clone()).bookmark(project.clone(), bookmark_name.clone())?;
+        for entry in StorageRepo::new(&scope).dangling().await? {
+            match Self::fetch_blob(
+                bridge,
+                config,
+                project,
+                bookmark_name,
+                peer_link,
+                &entry.hash,
+            )
+            .await
+            {
+                Ok(()) => {
+                    let db = BookmarkDb::open(&scope).await?;
+                    StorageStore::new(&db).set_storage(&entry)?;
+                }
+                Err(error) => {
+                    tracing::warn!(%error, key = %entry.key, "collect: blob unavailable from peer");
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The blob a `StorageSet` event points at.
+    fn stored_blob(event: &StoredEvent) -> Option<ContentHash> {
+        match &event.data {
+            Event::Known(Events::Storage(StorageEvents::StorageSet(set))) => {
+                set.current().ok().map(|set| set.entry.hash)
+            }
+            _ => None,
+        }
+    }
+
+    /// Fetch a blob from a peer into a bookmark, unless it's already
+    /// there. Chunks are kept as they arrive, so an interrupted fetch
+    /// picks up after the last one; the assembled blob is checked
+    /// against its hash before it's stored.
+    async fn fetch_blob(
+        bridge: &Bridge,
+        config: &Config,
+        project: &ProjectName,
+        bookmark_name: &BookmarkName,
+        peer_link: &PeerLink,
+        hash: &ContentHash,
+    ) -> Result<(), BookmarkError> {
+        let scope =
+            ComposeScope::new(config.clone()).bookmark(project.clone(), bookmark_name.clone())?;
+
+        let mut offset = {
+            let db = BookmarkDb::open(&scope).await?;
+            let storage = StorageStore::new(&db);
+            if storage.has_blob(hash)? {
+                return Ok(());
+            }
+            storage.chunks_received(hash)?
+        };
+
+        loop {
+            let chunk = bridge
+                .fetch_blob_chunk(&peer_link.host, &peer_link.link, hash, offset)
+                .await
+                .map_err(|error: BridgeError| BookmarkError::InvalidUri(error.to_string()))?;
+            let bytes = chunk.data.decode().map_err(EventError::from)?;
+
+            if !bytes.is_empty() {
+                let db = BookmarkDb::open(&scope).await?;
+                StorageStore::new(&db).put_chunk(hash, offset, &bytes)?;
+                offset += bytes.len() as u64;
+            }
+
+            if offset >= chunk.total {
+                break;
+            }
+            if bytes.is_empty() {
+                return Err(BookmarkError::InvalidUri(
+                    BridgeProtocolError::BlobShort(hash.clone()).to_string(),
+                ));
+            }
+        }
+
+        let db = BookmarkDb::open(&scope).await?;
+        let storage = StorageStore::new(&db);
+        let content = storage
+            .assemble_chunks(hash)?
+            .ok_or_else(|| BookmarkError::BlobMismatch(hash.clone()))?;
+        storage.put_blob(&content)?;
+
+        Ok(())
+    }
+
+    /// Import events a peer pushed over a follow's subscription into the
+    /// follow's bookmark, after the blobs they point at. The batch is
+    /// refused if any event fails the host's trust policy. Returns how
+    /// many events were handed off.
+    pub(crate) async fn import_pushed(
+        mailbox: &Mailbox,
+        bridge: &Bridge,
+        config: &Config,
+        follow: &Follow,
+        peer_link: &PeerLink,
+        events: Vec<StoredEvent>,
+    ) -> Result<u64, BookmarkError> {
+        let origin = peer_link.host.key();
+        for event in &events {
+            config.trust.admit(event, Some(&origin))?;
+        }
+
+        Self::prefetch_blobs(
+            bridge,
+            config,
+            &follow.project,
+            &follow.bookmark,
+            peer_link,
+            &events,
+        )
+        .await;
+
+        let count = events.len() as u64;
+        Self::import_events(mailbox, config, &follow.project, &follow.bookmark, events)?;
+        Ok(count)
+    }
+
+    /// Hand foreign events to the project import actor, which
+    /// inserts-or-ignores each into the bookmark and records it in the
+    /// bookmark's chronicle.
+    fn import_events(
+        mailbox: &Mailbox,
+        config: &Config,
+        project: &ProjectName,
+        bookmark_name: &BookmarkName,
+        events: Vec<StoredEvent>,
+    ) -> Result<(), BookmarkError> {
+        let bookmark_scope =
+            ComposeScope::new(config.clone()).bookmark(project.clone(), bookmark_name.clone())?;
+
+        for event in events {
+            mailbox.tell(ProjectMessage::from(
+                ImportProjectEvent::builder()
+                    .scope(bookmark_scope.clone())
+                    .stored(event)
+                    .build(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Diff a local bookmark's chronicle against a peer and fetch the
+    /// events it is missing. Nothing is imported, and the whole batch is
+    /// refused if any event fails the host's trust policy.
+    async fn fetch_missing(
+        bridge: &Bridge,
+        canons: &CanonIndex,
+        config: &Config,
+        project: &ProjectName,
+        bookmark_name: &BookmarkName,
+        peer_link: &PeerLink,
+    ) -> Result<Vec<StoredEvent>, BookmarkError> {
+        let diff_result =
+            Self::diff_peer(bridge, canons, config, project, bookmark_name, peer_link).await?;
+
+        if diff_result.missing.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        Self::fetch_admitted(bridge, config, peer_link, &diff_result.missing).await
+    }
+
+    /// Diff a local bookmark's chronicle against a peer's.
+    async fn diff_peer(
+        bridge: &Bridge,
+        canons: &CanonIndex,
+        config: &Config,
+        project: &ProjectName,
+        bookmark_name: &BookmarkName,
+        peer_link: &PeerLink,
+    ) -> Result<DiffResult, BookmarkError> {
         let chronicle = canons.bookmark_chronicle(project, bookmark_name)?;
         let local_root = chronicle.root()?;
 
@@ -606,7 +1141,7 @@ impl BookmarkService {
             }
         };
 
-        let diff_result = bridge
+        bridge
             .diff(
                 &peer_link.host,
                 &peer_link.link,
@@ -614,71 +1149,28 @@ impl BookmarkService {
                 &local_resolve,
             )
             .await
-            .map_err(|e: BridgeError| BookmarkError::InvalidUri(e.to_string()))?;
-
-        let events_received = diff_result.missing.len() as u64;
-
-        if !diff_result.missing.is_empty() {
-            let event_ids: Vec<String> = diff_result
-                .missing
-                .iter()
-                .map(|id| id.to_string())
-                .collect();
-
-            let fetch_request = BridgeRequest::BridgeFetchEvents(BridgeFetchEvents {
-                link: peer_link.link.clone(),
-                event_ids,
-            });
-
-            let events = bridge
-                .fetch_events(&peer_link.host, &fetch_request)
-                .await
-                .map_err(|error: BridgeError| BookmarkError::InvalidUri(error.to_string()))?;
-
-            let bookmark_scope = ComposeScope::new(config.clone())
-                .bookmark(project.clone(), bookmark_name.clone())?;
-
-            let expected_ids: std::collections::HashSet<String> =
-                events.iter().map(|event| event.id.to_string()).collect();
+            .map_err(|e: BridgeError| BookmarkError::InvalidUri(e.to_string()))
+    }
 
-            for event in events {
-                mailbox.tell(ProjectMessage::from(
-                    ImportProjectEvent::builder()
-                        .scope(bookmark_scope.clone())
-                        .stored(event)
-                        .build(),
-                ));
-            }
+    /// Fetch events by id from a peer, refusing the whole batch if any
+    /// event fails the host's trust policy.
+    async fn fetch_admitted(
+        bridge: &Bridge,
+        config: &Config,
+        peer_link: &PeerLink,
+        ids: &[EventId],
+    ) -> Result<Vec<StoredEvent>, BookmarkError> {
+        let events = bridge
+            .fetch_events(&peer_link.host, &peer_link.link, ids)
+            .await
+            .map_err(|error: BridgeError| BookmarkError::InvalidUri(error.to_string()))?;
 
-            let chronicle_for_wait = chronicle.clone();
-            let resolve_for_wait = {
-                let config = config.clone();
-                move |hash: &ContentHash| -> Option<LedgerNode> {
-                    let db = config.host_db().ok()?;
-                    ChronicleStore::new(&db).get(hash)
-                }
-            };
-            config
-                .fetch
-                .eventual(|| async {
-                    let root = chronicle_for_wait.root()?;
-                    let seen = match root.as_ref() {
-                        Some(hash) => Ledger::collect_all_ids(Some(hash), &resolve_for_wait),
-                        None => std::collections::HashSet::new(),
-                    };
-                    Ok::<_, EventError>(if expected_ids.is_subset(&seen) {
-                        Some(())
-                    } else {
-                        None
-                    })
-                })
-                .await?;
+        let origin = peer_link.host.key();
+        for event in &events {
+            config.trust.admit(event, Some(&origin))?;
         }
 
-        Ok(PeerCollectResult {
-            count: events_received,
-            diff_result,
-        })
+        Ok(events)
     }
 
     /// Remove a follow.
@@ -698,6 +1190,10 @@ impl BookmarkService {
         let id = follow.id;
         FollowService::remove(scope, mailbox, id).await?;
 
+        mailbox.tell(HostMessage::from(
+            UnsubscribeFollow::builder().follow(id).build(),
+        ));
+
         Ok(BookmarkResponse::Unfollowed(
             BookmarkUnfollowedResponse::builder_v1()
                 .follow_id(id)
@@ -772,21 +1268,226 @@ impl BookmarkService {
         }))
     }
 
-    /// Replay the event log into a specific bookmark's projection DB.
+    /// Delete a bookmark — its entry, its projection DB, and its row in
+    /// the host. Refused for the trunk, the active bookmark, and any
+    /// bookmark a follow still depends on.
+    pub(crate) async fn delete(
+        scope: &Scope<AtHost>,
+        state: &ServerState,
+        project: &ProjectName,
+        request: &DeleteBookmark,
+    ) -> Result<BookmarkResponse, BookmarkError> {
+        let DeleteBookmark::V1(deleting) = request;
+        let name = &deleting.name;
+
+        if *name == BookmarkName::main() {
+            return Err(BookmarkError::Trunk(name.clone()));
+        }
+        if state.canons().active_bookmark(project)? == *name {
+            return Err(BookmarkError::Active(name.clone()));
+        }
+
+        let bookmark = BookmarkRepo::new(scope)
+            .get(project, name)
+            .await?
+            .ok_or_else(|| BookmarkError::NotFound(name.clone()))?;
+        Self::ensure_unfollowed(scope, &bookmark).await?;
+
+        state.canons().remove_bookmark(project, name)?;
+        let host_db = state.config().host_db()?;
+        let chronicles = ChronicleStore::new(&host_db);
+        chronicles.migrate()?;
+        chronicles.remove_head(project, name)?;
+
+        let mut project_config = state.config().clone();
+        project_config.project = project.clone();
+        project_config.bookmark = name.clone();
+        let platform = project_config.platform();
+        for path in Self::bookmark_db_files(&project_config) {
+            if path.exists() {
+                platform
+                    .remove_file(&path)
+                    .map_err(|e| BookmarkError::InvalidUri(e.to_string()))?;
+            }
+        }
+
+        let new_event = NewEvent::builder()
+            .data(Events::Bookmark(BookmarkEvents::BookmarkDeleted(
+                BookmarkDeleted::builder_v1()
+                    .id(bookmark.id)
+                    .project(project.clone())
+                    .name(name.clone())
+                    .build()
+                    .into(),
+            )))
+            .build();
+        state.mailbox().tell(HostMessage::from(
+            AppendHostLog::builder()
+                .scope(scope.clone())
+                .event(new_event)
+                .build(),
+        ));
+
+        Ok(BookmarkResponse::Deleted(
+            BookmarkDeletedResponse::builder_v1()
+                .project(project.clone())
+                .name(name.clone())
+                .build()
+                .into(),
+        ))
+    }
+
+    /// Rename a bookmark. Its entry, projection DB and any follow
+    /// collecting into it move to the new name; a rename of the active
+    /// bookmark keeps it active.
+    pub(crate) async fn rename(
+        scope: &Scope<AtHost>,
+        state: &ServerState,
+        project: &ProjectName,
+        request: &RenameBookmark,
+    ) -> Result<BookmarkResponse, BookmarkError> {
+        let RenameBookmark::V1(renaming) = request;
+        let (from, to) = (&renaming.from, &renaming.to);
+
+        if *from == BookmarkName::main() {
+            return Err(BookmarkError::Trunk(from.clone()));
+        }
+
+        let repo = BookmarkRepo::new(scope);
+        let bookmark = repo
+            .get(project, from)
+            .await?
+            .ok_or_else(|| BookmarkError::NotFound(from.clone()))?;
+        if repo.get(project, to).await?.is_some() || state.canons().has_bookmark(project, to)? {
+            return Err(BookmarkError::AlreadyExists(to.clone()));
+        }
+
+        state.canons().rename_bookmark(project, from, to)?;
+        let host_db = state.config().host_db()?;
+        let chronicles = ChronicleStore::new(&host_db);
+        chronicles.migrate()?;
+        chronicles.rename_head(project, from, to)?;
+
+        let mut from_config = state.config().clone();
+        from_config.project = project.clone();
+        from_config.bookmark = from.clone();
+        let mut to_config = from_config.clone();
+        to_config.bookmark = to.clone();
+        let platform = from_config.platform();
+        for (old, new) in Self::bookmark_db_files(&from_config)
+            .into_iter()
+            .zip(Self::bookmark_db_files(&to_config))
+        {
+            if old.exists() {
+                platform
+                    .rename(&old, &new)
+                    .map_err(|e| BookmarkError::InvalidUri(e.to_string()))?;
+            }
+        }
+
+        let new_event = NewEvent::builder()
+            .data(Events::Bookmark(BookmarkEvents::BookmarkRenamed(
+                BookmarkRenamed::builder_v1()
+                    .id(bookmark.id)
+                    .project(project.clone())
+                    .from(from.clone())
+                    .to(to.clone())
+                    .build()
+                    .into(),
+            )))
+            .build();
+        state.mailbox().tell(HostMessage::from(
+            AppendHostLog::builder()
+                .scope(scope.clone())
+                .event(new_event)
+                .build(),
+        ));
+
+        Ok(BookmarkResponse::Renamed(
+            BookmarkRenamedResponse::builder_v1()
+                .project(project.clone())
+                .from(from.clone())
+                .to(to.clone())
+                .build()
+                .into(),
+        ))
+    }
+
+    /// Sweep chronicle nodes no live bookmark can reach.
+    pub(crate) async fn sweep(
+        state: &ServerState,
+        request: &SweepChronicles,
+    ) -> Result<BookmarkResponse, BookmarkError> {
+        let SweepChronicles::V1(_) = request;
+        let sweep = state.canons().collect_garbage(state.config())?;
+
+        Ok(BookmarkResponse::Swept(
+            BookmarkSweptResponse::builder_v1()
+                .kept(sweep.kept)
+                .removed(sweep.removed)
+                .bytes_reclaimed(sweep.bytes_reclaimed)
+                .build()
+                .into(),
+        ))
+    }
+
+    /// Refuse when a follow collects into this bookmark or reads from it.
+    async fn ensure_unfollowed(
+        scope: &Scope<AtHost>,
+        bookmark: &Bookmark,
+    ) -> Result<(), BookmarkError> {
+        let all = SearchFilters {
+            limit: Limit(usize::MAX),
+            offset: Offset(0),
+        };
+        let source = FollowSource::Local(Ref::bookmark(bookmark.id));
+        let follows = FollowService::list(scope, &all).await?;
+
+        let followed = follows.items.iter().any(|follow| {
+            (follow.project == bookmark.project && follow.bookmark == bookmark.name)
+                || follow.source == source
+        });
+
+        if followed {
+            return Err(BookmarkError::Followed(bookmark.name.clone()));
+        }
+        Ok(())
+    }
+
+    /// A bookmark DB and its WAL sidecars.
+    fn bookmark_db_files(config: &Config) -> Vec<std::path::PathBuf> {
+        let path = config.bookmark_db_path();
+        let mut files = vec![path.clone()];
+        for suffix in ["-wal", "-shm"] {
+            let mut sidecar = path.clone().into_os_string();
+            sidecar.push(suffix);
+            files.push(sidecar.into());
+        }
+        files
+    }
+
+    /// Replay the event log into a specific bookmark's projection DB,
+    /// skipping what its earlier merges superseded along with `settled`
+    /// — the losers of a merge not yet on the host log.
     fn replay_bookmark(
         config: &Config,
         project: &ProjectName,
         bookmark: &BookmarkName,
+        settled: &std::collections::HashSet<EventId>,
     ) -> Result<(), BookmarkError> {
         let mut project_config = config.clone();
 
         project_config.project = project.clone();
         project_config.bookmark = bookmark.clone();
 
+        let host_db = project_config.host_db()?;
+        let mut superseded = BookmarkHistory::load(&host_db, project)?.superseded(bookmark);
+        superseded.extend(settled.iter().copied());
+
         let db = project_config.bookmark_conn()?;
         let log = EventLog::attached(&db);
 
-        Projections::<ProjectCanon>::project().replay_project(&db, &log)?;
+        Projections::<ProjectCanon>::project().replay_project_except(&db, &log, &superseded)?;
 
         Ok(())
     }
@@ -794,13 +1495,15 @@ impl BookmarkService {
     /// Create a new bookmark DB and replay events into it.
     ///
     /// Migrates the schema, then replays the event log through
-    /// projections so the new bookmark starts with the source's state.
-    /// When `event_ids` is non-empty, only matching events are replayed
-    /// (scoped fork — used by slice bookmarking).
+    /// projections so the new bookmark starts with the state of `from`,
+    /// leaving out what `from`'s merges superseded. When `event_ids` is
+    /// non-empty, only matching events are replayed (scoped fork — used
+    /// by slice bookmarking).
     pub(crate) fn create_bookmark_db(
         config: &Config,
         project: &ProjectName,
         bookmark: &BookmarkName,
+        from: &BookmarkName,
         event_ids: &[EventId],
     ) -> Result<(), BookmarkError> {
         let mut project_config = config.clone();
@@ -825,7 +1528,9 @@ impl BookmarkService {
 
         if event_ids.is_empty() {
             // Full replay: all events from the attached log.
-            projections.replay_project(&db, &log)?;
+            let host_db = project_config.host_db()?;
+            let superseded = BookmarkHistory::load(&host_db, project)?.superseded(from);
+            projections.replay_project_except(&db, &log, &superseded)?;
         } else {
             // Scoped replay: only events whose IDs are in the filter set.
             let filter: std::collections::HashSet<EventId> = event_ids.iter().copied().collect();
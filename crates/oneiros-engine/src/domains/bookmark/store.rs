@@ -31,10 +31,30 @@ impl<'a> BookmarkStore<'a> {
     }
 
     pub(crate) fn handle(&self, event: &StoredEvent) -> Result<(), EventError> {
-        if let Event::Known(Events::Bookmark(bookmark_event)) = &event.data
-            && let Some(bookmark) = bookmark_event.maybe_bookmark()
-        {
-            self.write_bookmark(&bookmark)?;
+        let Event::Known(Events::Bookmark(bookmark_event)) = &event.data else {
+            return Ok(());
+        };
+
+        match bookmark_event {
+            BookmarkEvents::BookmarkDeleted(deleted) => {
+                let current = deleted.current()?;
+                self.db.execute(
+                    "DELETE FROM bookmarks WHERE id = ?1",
+                    rusqlite::params![current.id.to_string()],
+                )?;
+            }
+            BookmarkEvents::BookmarkRenamed(renamed) => {
+                let current = renamed.current()?;
+                self.db.execute(
+                    "UPDATE bookmarks SET name = ?1 WHERE id = ?2",
+                    rusqlite::params![current.to.to_string(), current.id.to_string()],
+                )?;
+            }
+            other => {
+                if let Some(bookmark) = other.maybe_bookmark() {
+                    self.write_bookmark(&bookmark)?;
+                }
+            }
         }
         Ok(())
     }
@@ -9,6 +9,50 @@ impl BookmarkView {
         Self { response }
     }
 
+    pub(crate) fn mcp(&self) -> McpResponse {
+        match &self.response {
+            BookmarkResponse::Previewed(BookmarkPreviewResponse::V1(preview)) => {
+                let source = match &preview.from {
+                    Some(peer) => format!("{peer}/{}", preview.source),
+                    None => preview.source.to_string(),
+                };
+                let mut md = format!(
+                    "# Preview — {source} → {}\n\n{} events would land.\n",
+                    preview.target,
+                    preview.events.len()
+                );
+                if !preview.groups.is_empty() {
+                    md.push_str("\n| Domain | Agent | Events | Kinds |\n");
+                    md.push_str("|--------|-------|--------|-------|\n");
+                    for group in &preview.groups {
+                        let agent = group.agent_id.map(|id| id.to_string()).unwrap_or_default();
+                        let kinds = group
+                            .kinds
+                            .iter()
+                            .map(|(kind, count)| format!("{kind} ×{count}"))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        md.push_str(&format!(
+                            "| {} | {agent} | {} | {kinds} |\n",
+                            group.domain, group.count
+                        ));
+                    }
+                }
+                if !preview.conflicts.is_empty() {
+                    md.push_str("\n## Conflicts\n\n");
+                    for conflict in &preview.conflicts {
+                        md.push_str(&format!(
+                            "- **{}** — kept {}\n",
+                            conflict.subject, conflict.resolution
+                        ));
+                    }
+                }
+                McpResponse::new(md)
+            }
+            other => McpResponse::new(format!("Bookmark {}", other.kind())),
+        }
+    }
+
     pub(crate) fn render(self) -> Rendered<BookmarkResponse> {
         match self.response {
             BookmarkResponse::Created(BookmarkCreatedResponse::V1(created)) => {
@@ -44,6 +88,53 @@ impl BookmarkView {
                     String::new(),
                 )
             }
+            BookmarkResponse::Merged(BookmarkMergedResponse::V2(merged)) => {
+                let confirmation = Confirmation::new(
+                    "Bookmark",
+                    merged.source.to_string(),
+                    format!("merged into '{}'", merged.target),
+                );
+                let summary = format!(
+                    "{} events added, {} conflicts (strategy: {})",
+                    merged.added.len(),
+                    merged.conflicts.len(),
+                    merged.strategy,
+                );
+                let prompt = if merged.conflicts.is_empty() {
+                    format!(
+                        "{confirmation}
+{}",
+                        summary.muted()
+                    )
+                } else {
+                    let mut table = Table::new(vec![
+                        Column::new("Subject"),
+                        Column::new("Ours"),
+                        Column::new("Theirs"),
+                        Column::new("Kept"),
+                    ]);
+                    for conflict in &merged.conflicts {
+                        table.push_row(vec![
+                            conflict.subject.clone(),
+                            conflict.ours.len().to_string(),
+                            conflict.theirs.len().to_string(),
+                            conflict.resolution.to_string(),
+                        ]);
+                    }
+                    format!(
+                        "{confirmation}
+{}
+
+{table}",
+                        summary.muted()
+                    )
+                };
+                Rendered::new(
+                    BookmarkResponse::Merged(BookmarkMergedResponse::V2(merged)),
+                    prompt,
+                    String::new(),
+                )
+            }
             BookmarkResponse::Merged(BookmarkMergedResponse::V1(merged)) => {
                 let prompt = Confirmation::new(
                     "Bookmark",
@@ -57,6 +148,69 @@ impl BookmarkView {
                     String::new(),
                 )
             }
+            BookmarkResponse::Previewed(BookmarkPreviewResponse::V1(preview)) => {
+                let source = match &preview.from {
+                    Some(peer) => format!("{peer}/{}", preview.source),
+                    None => preview.source.to_string(),
+                };
+                let confirmation = Confirmation::new(
+                    "Preview",
+                    source,
+                    format!(
+                        "{} events would land in '{}'",
+                        preview.events.len(),
+                        preview.target
+                    ),
+                );
+
+                let mut prompt = confirmation.to_string();
+                if !preview.groups.is_empty() {
+                    let mut table = Table::new(vec![
+                        Column::new("Domain"),
+                        Column::new("Agent"),
+                        Column::new("Events"),
+                        Column::new("Kinds"),
+                    ]);
+                    for group in &preview.groups {
+                        let kinds = group
+                            .kinds
+                            .iter()
+                            .map(|(kind, count)| format!("{kind} ×{count}"))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        table.push_row(vec![
+                            group.domain.clone(),
+                            group.agent_id.map(|id| id.to_string()).unwrap_or_default(),
+                            group.count.to_string(),
+                            kinds,
+                        ]);
+                    }
+                    prompt.push_str(&format!("\n\n{table}"));
+                }
+                if !preview.conflicts.is_empty() {
+                    let mut table = Table::new(vec![
+                        Column::new("Conflict"),
+                        Column::new("Ours"),
+                        Column::new("Theirs"),
+                        Column::new("Kept"),
+                    ]);
+                    for conflict in &preview.conflicts {
+                        table.push_row(vec![
+                            conflict.subject.clone(),
+                            conflict.ours.len().to_string(),
+                            conflict.theirs.len().to_string(),
+                            conflict.resolution.to_string(),
+                        ]);
+                    }
+                    prompt.push_str(&format!("\n\n{table}"));
+                }
+
+                Rendered::new(
+                    BookmarkResponse::Previewed(BookmarkPreviewResponse::V1(preview)),
+                    prompt,
+                    String::new(),
+                )
+            }
             BookmarkResponse::Bookmarks(listed) => {
                 let mut table = Table::new(vec![Column::new("Name")]);
                 for bookmark in &listed.items {
@@ -115,6 +269,44 @@ impl BookmarkView {
                 .to_string();
                 Rendered::new(BookmarkResponse::Submitted(result), prompt, String::new())
             }
+            BookmarkResponse::Deleted(BookmarkDeletedResponse::V1(deleted)) => {
+                let prompt =
+                    Confirmation::new("Bookmark", deleted.name.to_string(), "deleted").to_string();
+                Rendered::new(
+                    BookmarkResponse::Deleted(BookmarkDeletedResponse::V1(deleted)),
+                    prompt,
+                    String::new(),
+                )
+            }
+            BookmarkResponse::Renamed(BookmarkRenamedResponse::V1(renamed)) => {
+                let prompt = Confirmation::new(
+                    "Bookmark",
+                    renamed.from.to_string(),
+                    format!("renamed to '{}'", renamed.to),
+                )
+                .to_string();
+                Rendered::new(
+                    BookmarkResponse::Renamed(BookmarkRenamedResponse::V1(renamed)),
+                    prompt,
+                    String::new(),
+                )
+            }
+            BookmarkResponse::Swept(BookmarkSweptResponse::V1(swept)) => {
+                let prompt = format!(
+                    "{}\n{}",
+                    Confirmation::new(
+                        "Chronicle",
+                        format!("{} bytes", swept.bytes_reclaimed),
+                        "reclaimed",
+                    ),
+                    format_args!("{} nodes removed, {} kept", swept.removed, swept.kept).muted(),
+                );
+                Rendered::new(
+                    BookmarkResponse::Swept(BookmarkSweptResponse::V1(swept)),
+                    prompt,
+                    String::new(),
+                )
+            }
         }
     }
 }
@@ -5,12 +5,24 @@ use crate::*;
 
 /// The ALPN string advertised and required by the oneiros sync protocol.
 /// Only clients explicitly negotiating this ALPN can reach the sync handler.
-pub(crate) const SYNC_ALPN: &[u8] = b"/oneiros/sync/1";
+pub(crate) const SYNC_ALPN: &[u8] = b"/oneiros/sync/2";
 
 /// Maximum message size on the sync wire, in bytes. Guards against
 /// absurdly large payloads.
 pub(crate) const MAX_MESSAGE_SIZE: usize = 64 * 1024 * 1024;
 
+/// The most encoded event bytes the server puts in one `BridgeEvents`
+/// page. Larger fetches come back `truncated` and are asked for again.
+pub(crate) const FETCH_PAGE_BYTES: usize = 4 * 1024 * 1024;
+
+/// How many event ids a collect asks for at once. Each page is imported
+/// and recorded before the next is requested, so an interrupted collect
+/// keeps the pages it finished.
+pub(crate) const FETCH_PAGE_IDS: usize = 256;
+
+/// The most stored blob bytes sent in one `BridgeBlobChunk`.
+pub(crate) const BLOB_CHUNK_SIZE: usize = 1024 * 1024;
+
 /// The result of a Merkle diff — the server's chronicle root hash
 /// and the event IDs the client is missing.
 pub(crate) struct DiffResult {
@@ -23,19 +35,21 @@ pub(crate) struct DiffResult {
 ///
 /// A Bridge is bound once at service start using the host's persisted
 /// keypair, lives on `ServerState`, and produces connections on demand
-/// when the host needs to talk to a peer.
+/// when the host needs to talk to a peer. A session that only works on
+/// local state holds an offline bridge instead: it carries the host's
+/// key but no endpoint, and refuses to reach peers.
 #[derive(Clone)]
 pub(crate) struct Bridge {
-    endpoint: iroh::Endpoint,
-    public_key: PeerKey,
+    endpoint: Option<iroh::Endpoint>,
+    id: iroh::PublicKey,
     router: Arc<OnceLock<iroh::protocol::Router>>,
 }
 
 impl Bridge {
     /// Bind a Bridge using the given iroh secret key. Advertises the
-    /// `/oneiros/sync/1` ALPN so peers can negotiate the sync protocol.
+    /// `/oneiros/sync/2` ALPN so peers can negotiate the sync protocol.
     pub(crate) async fn bind(secret: iroh::SecretKey) -> Result<Self, BridgeError> {
-        let public = secret.public();
+        let id = secret.public();
         let endpoint = iroh::Endpoint::builder(iroh::endpoint::presets::Minimal)
             .secret_key(secret)
             .alpns(vec![SYNC_ALPN.to_vec()])
@@ -43,23 +57,44 @@ impl Bridge {
             .await
             .map_err(|e| BridgeError::from(IrohError::from(e)))?;
 
-        let public_key = PeerKey::from_bytes(*public.as_bytes());
-
         Ok(Self {
-            endpoint,
-            public_key,
+            endpoint: Some(endpoint),
+            id,
             router: Arc::new(OnceLock::new()),
         })
     }
 
+    /// A bridge that knows the host's key but binds no endpoint, for
+    /// sessions that leave the network to the running service.
+    pub(crate) fn offline(secret: &iroh::SecretKey) -> Self {
+        Self {
+            endpoint: None,
+            id: secret.public(),
+            router: Arc::new(OnceLock::new()),
+        }
+    }
+
+    fn endpoint(&self) -> Result<&iroh::Endpoint, IrohError> {
+        self.endpoint.as_ref().ok_or(IrohError::Offline)
+    }
+
     /// Register the sync protocol handler on this bridge's endpoint.
     /// Idempotent: calling it multiple times has no effect after the first.
-    pub(crate) fn serve(&self, config: Config, canons: CanonIndex, mailbox: Mailbox) {
+    pub(crate) fn serve(
+        &self,
+        config: Config,
+        canons: CanonIndex,
+        mailbox: Mailbox,
+        feed: EventFeed,
+    ) {
+        let Some(endpoint) = &self.endpoint else {
+            return;
+        };
         if self.router.get().is_some() {
             return;
         }
-        let handler = SyncHandler::new(config, canons, self.clone(), mailbox);
-        let router = iroh::protocol::Router::builder(self.endpoint.clone())
+        let handler = SyncHandler::new(config, canons, self.clone(), mailbox, feed);
+        let router = iroh::protocol::Router::builder(endpoint.clone())
             .accept(SYNC_ALPN, handler)
             .spawn();
         let _ = self.router.set(router);
@@ -154,32 +189,115 @@ impl Bridge {
     }
 
     /// Fetch specific events by ID from a peer. Issued after the Merkle
-    /// diff has identified which events the local side is missing.
+    /// diff has identified which events the local side is missing. The
+    /// peer answers in pages to stay within its byte budget; whatever a
+    /// truncated page left out is asked for again.
     pub(crate) async fn fetch_events(
         &self,
         address: &PeerAddress,
-        request: &BridgeRequest,
+        link: &Link,
+        ids: &[EventId],
     ) -> Result<Vec<StoredEvent>, BridgeError> {
-        let response = self.send(address, request).await?;
+        let mut wanted = ids.to_vec();
+        let mut fetched = Vec::new();
+
+        while !wanted.is_empty() {
+            let request = BridgeRequest::BridgeFetchEvents(BridgeFetchEvents {
+                link: link.clone(),
+                event_ids: wanted.iter().map(ToString::to_string).collect(),
+            });
+
+            match self.send(address, &request).await? {
+                BridgeResponse::BridgeEvents(page) => {
+                    let more = page.truncated && !page.events.is_empty();
+                    wanted.retain(|id| !page.events.iter().any(|event| event.id == *id));
+                    fetched.extend(page.events);
+                    if !more {
+                        break;
+                    }
+                }
+                BridgeResponse::BridgeCurrent => break,
+                BridgeResponse::BridgeDenied(d) => {
+                    return Err(DenyReason::Remote(d.reason.into()).into());
+                }
+                _ => {
+                    return Err(
+                        BridgeProtocolError::UnexpectedResponse(BridgeOp::FetchEvents).into(),
+                    );
+                }
+            }
+        }
 
-        match response {
-            BridgeResponse::BridgeEvents(be) => Ok(be.events),
-            BridgeResponse::BridgeCurrent => Ok(Vec::new()),
+        Ok(fetched)
+    }
+
+    /// Fetch the chunk of a blob's stored bytes that starts at `offset`.
+    pub(crate) async fn fetch_blob_chunk(
+        &self,
+        address: &PeerAddress,
+        link: &Link,
+        hash: &ContentHash,
+        offset: u64,
+    ) -> Result<BridgeBlobChunk, BridgeError> {
+        let request = BridgeRequest::BridgeFetchBlob(BridgeFetchBlob {
+            link: link.clone(),
+            hash: hash.clone(),
+            offset,
+        });
+
+        match self.send(address, &request).await? {
+            BridgeResponse::BridgeBlobChunk(chunk) => Ok(chunk),
             BridgeResponse::BridgeDenied(d) => Err(DenyReason::Remote(d.reason.into()).into()),
-            _ => Err(BridgeProtocolError::UnexpectedResponse(BridgeOp::FetchEvents).into()),
+            _ => Err(BridgeProtocolError::UnexpectedResponse(BridgeOp::FetchBlob).into()),
+        }
+    }
+
+    /// Open a push subscription to a peer. Resolves once the peer has
+    /// accepted the ticket; events then arrive through
+    /// [`BridgeSubscription::next`] as the peer appends them.
+    pub(crate) async fn subscribe(
+        &self,
+        address: &PeerAddress,
+        link: &Link,
+    ) -> Result<BridgeSubscription, BridgeError> {
+        let conn = self
+            .endpoint()?
+            .connect(address.inner().clone(), SYNC_ALPN)
+            .await
+            .map_err(IrohError::from)?;
+        let (mut send, recv) = conn.open_bi().await.map_err(IrohError::from)?;
+
+        let request = BridgeRequest::BridgeSubscribe(BridgeSubscribe { link: link.clone() });
+        let encoded = request.to_bytes();
+        let len = (encoded.len() as u32).to_be_bytes();
+        send.write_all(&len).await.map_err(IrohError::from)?;
+        send.write_all(&encoded).await.map_err(IrohError::from)?;
+        send.finish().map_err(IrohError::from)?;
+
+        let mut subscription = BridgeSubscription { conn, recv };
+        match subscription.read_frame().await? {
+            Some(BridgeResponse::BridgeSubscribed) => Ok(subscription),
+            Some(BridgeResponse::BridgeDenied(d)) => {
+                Err(DenyReason::Remote(d.reason.into()).into())
+            }
+            _ => Err(BridgeProtocolError::UnexpectedResponse(BridgeOp::Subscribe).into()),
         }
     }
 
     /// The host's public key — stable across restarts if the caller binds
     /// with the same secret key.
     pub(crate) fn key(&self) -> PeerKey {
-        self.public_key
+        PeerKey::from_bytes(*self.id.as_bytes())
     }
 
-    /// The host's current reachability information.
+    /// The host's current reachability information. An offline bridge
+    /// has only its key to offer.
     pub(crate) fn address(&self) -> PeerAddress {
-        let mut addr = iroh::EndpointAddr::new(self.endpoint.id());
-        for socket in self.endpoint.bound_sockets() {
+        let Some(endpoint) = &self.endpoint else {
+            return PeerAddress::new(iroh::EndpointAddr::new(self.id));
+        };
+        let mut addr = iroh::EndpointAddr::new(endpoint.id());
+        for socket in endpoint.bound_sockets() {
             addr = addr.with_ip_addr(socket);
         }
         PeerAddress::new(addr)
@@ -193,7 +311,9 @@ impl Bridge {
     /// Shut down the bridge gracefully, closing the iroh endpoint.
     #[cfg(test)]
     pub(crate) async fn shutdown(&self) {
-        self.endpoint.close().await;
+        if let Some(endpoint) = &self.endpoint {
+            endpoint.close().await;
+        }
     }
 
     /// Send a sync request to a peer and read the response.
@@ -221,7 +341,7 @@ impl Bridge {
         request: &BridgeRequest,
     ) -> Result<(Vec<u8>, iroh::endpoint::Connection), IrohError> {
         let conn = self
-            .endpoint
+            .endpoint()?
             .connect(address.inner().clone(), SYNC_ALPN)
             .await?;
 
@@ -246,6 +366,61 @@ impl Bridge {
     }
 }
 
+/// A live push subscription to a peer: the open connection, and the
+/// stream the peer writes a frame to for each event it appends.
+/// Dropping the subscription hangs up.
+pub(crate) struct BridgeSubscription {
+    conn: iroh::endpoint::Connection,
+    recv: iroh::endpoint::RecvStream,
+}
+
+impl BridgeSubscription {
+    /// The next events the peer pushed, or `None` once it has closed
+    /// the stream.
+    pub(crate) async fn next(&mut self) -> Result<Option<Vec<StoredEvent>>, BridgeError> {
+        match self.read_frame().await? {
+            None => Ok(None),
+            Some(BridgeResponse::BridgeEvents(be)) => Ok(Some(be.events)),
+            Some(BridgeResponse::BridgeDenied(d)) => {
+                Err(DenyReason::Remote(d.reason.into()).into())
+            }
+            Some(_) => Err(BridgeProtocolError::UnexpectedResponse(BridgeOp::Subscribe).into()),
+        }
+    }
+
+    /// Read one length-prefixed response, or `None` if the stream
+    /// finished cleanly between frames.
+    async fn read_frame(&mut self) -> Result<Option<BridgeResponse>, BridgeError> {
+        let mut len_buf = [0u8; 4];
+        match self.recv.read_exact(&mut len_buf).await {
+            Ok(()) => {}
+            Err(iroh::endpoint::ReadExactError::FinishedEarly(0)) => return Ok(None),
+            Err(error) => return Err(IrohError::from(error).into()),
+        }
+
+        let len = u32::from_be_bytes(len_buf) as usize;
+        if len > MAX_MESSAGE_SIZE {
+            return Err(BridgeProtocolError::ResponseTooLarge(len).into());
+        }
+
+        let mut buf = vec![0u8; len];
+        self.recv
+            .read_exact(&mut buf)
+            .await
+            .map_err(IrohError::from)?;
+
+        BridgeResponse::from_bytes(&buf)
+            .map(Some)
+            .map_err(|e| BridgeProtocolError::Decode(e).into())
+    }
+}
+
+impl Drop for BridgeSubscription {
+    fn drop(&mut self) {
+        self.conn.close(0u32.into(), b"done");
+    }
+}
+
 /// Walk the server's HAMT tree as far as cached, collecting hashes of
 /// server nodes we haven't resolved yet and that differ from our local tree.
 ///
@@ -309,7 +484,7 @@ fn collect_unresolved(
 impl core::fmt::Debug for Bridge {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Bridge")
-            .field("public_key", &self.public_key.to_string())
+            .field("public_key", &self.key().to_string())
             .finish()
     }
 }
@@ -340,6 +515,23 @@ mod tests {
         bridge.shutdown().await;
     }
 
+    #[tokio::test]
+    async fn offline_bridge_keeps_its_key_but_reaches_no_one() {
+        let secret = iroh::SecretKey::generate();
+        let online = Bridge::bind(secret.clone()).await.unwrap();
+        let offline = Bridge::offline(&secret);
+
+        assert_eq!(offline.key(), online.key());
+        let request = BridgeRequest::BridgeDiff(BridgeDiff {
+            link: Link::new(Ref::project(ProjectId::new()), Token::from("t")),
+            root_hash: None,
+        });
+        let sent = offline.send(&online.address(), &request).await;
+        assert!(matches!(sent, Err(BridgeError::Iroh(IrohError::Offline))));
+
+        online.shutdown().await;
+    }
+
     #[tokio::test]
     async fn two_bridges_have_distinct_keys() {
         let a = Bridge::bind(iroh::SecretKey::generate()).await.unwrap();
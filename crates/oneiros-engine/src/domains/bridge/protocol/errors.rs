@@ -1,3 +1,6 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
 use crate::*;
 
 /// Iroh transport errors — contained within the bridge domain so iroh
@@ -21,17 +24,48 @@ pub(crate) enum IrohError {
 
     #[error("stream closed: {0}")]
     Closed(#[from] iroh::endpoint::ClosedStream),
+
+    #[error("this session has no network bridge; run the service to reach peers")]
+    Offline,
 }
 
-/// Which bridge operation a protocol error pertains to.
-#[derive(Debug, Clone, Copy)]
-#[allow(dead_code)]
+/// Which bridge operation a protocol error or ticket use pertains to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub(crate) enum BridgeOp {
+    #[serde(rename = "bridge-diff")]
     Diff,
+    #[serde(rename = "bridge-resolve")]
     Resolve,
+    #[serde(rename = "bridge-fetch-events")]
     FetchEvents,
+    #[serde(rename = "bridge-fetch-blob")]
+    FetchBlob,
+    #[serde(rename = "bridge-list-bookmarks")]
     ListBookmarks,
+    #[serde(rename = "bridge-submit-bookmark")]
     SubmitBookmark,
+    #[serde(rename = "bridge-subscribe")]
+    Subscribe,
+}
+
+impl BridgeOp {
+    /// The ticket capability this operation requires.
+    pub(crate) fn permission(self) -> PermissionOp {
+        match self {
+            Self::Diff | Self::Resolve | Self::FetchEvents | Self::FetchBlob | Self::Subscribe => {
+                PermissionOp::BookmarkCollect
+            }
+            Self::ListBookmarks => PermissionOp::BookmarkList,
+            Self::SubmitBookmark => PermissionOp::BookmarkSubmit,
+        }
+    }
+
+    /// Whether this request opens a session of its own — a collect's
+    /// diff, a subscription, a submission — and so spends a ticket use.
+    /// The resolves and fetches that follow a diff ride on its use.
+    pub(crate) fn opens_session(self) -> bool {
+        matches!(self, Self::Diff | Self::Subscribe | Self::SubmitBookmark)
+    }
 }
 
 impl core::fmt::Display for BridgeOp {
@@ -40,8 +74,10 @@ impl core::fmt::Display for BridgeOp {
             Self::Diff => f.write_str("bridge-diff"),
             Self::Resolve => f.write_str("bridge-resolve"),
             Self::FetchEvents => f.write_str("bridge-fetch-events"),
+            Self::FetchBlob => f.write_str("bridge-fetch-blob"),
             Self::ListBookmarks => f.write_str("bridge-list-bookmarks"),
             Self::SubmitBookmark => f.write_str("bridge-submit-bookmark"),
+            Self::Subscribe => f.write_str("bridge-subscribe"),
         }
     }
 }
@@ -56,6 +92,15 @@ pub(crate) enum BridgeProtocolError {
     #[error("unexpected response to {0} request")]
     UnexpectedResponse(BridgeOp),
 
+    #[error("{0} requests are streamed, not answered")]
+    Streamed(BridgeOp),
+
+    #[error("blob not found: {0}")]
+    BlobMissing(ContentHash),
+
+    #[error("blob {0} stopped short of its length")]
+    BlobShort(ContentHash),
+
     #[error("response too large: {0} bytes")]
     ResponseTooLarge(usize),
 
@@ -135,6 +180,9 @@ pub(crate) enum BridgeError {
     #[error(transparent)]
     EventsDb(#[from] EventsDbError),
 
+    #[error(transparent)]
+    BookmarkDb(#[from] BookmarkDbError),
+
     /// A scope hydration error during sync handling.
     #[error(transparent)]
     Scope(#[from] ScopeError),
@@ -1,6 +1,6 @@
 //! Bridge protocol — peer-to-peer chronicle sync over iroh.
 //!
-//! These shapes carry over the `/oneiros/sync/1` ALPN via QUIC. They are
+//! These shapes carry over the `/oneiros/sync/2` ALPN via QUIC. They are
 //! the wire format for the Merkle diff exchange between two peers that
 //! share a bookmark.
 //!
@@ -40,8 +40,9 @@
 //! ## How bridge versions today
 //!
 //! Protocol-level, not shape-level: the ALPN identifier
-//! `/oneiros/sync/1` is the version. If the protocol shape changes,
-//! the path is to introduce `/oneiros/sync/2` and run both during
+//! `/oneiros/sync/2` is the version — `2` since event fetches became
+//! paged and blob bodies chunked. If the protocol shape changes again,
+//! the path is to introduce `/oneiros/sync/3` and run both during
 //! transition. That's how QUIC application protocols typically
 //! evolve.
 //!
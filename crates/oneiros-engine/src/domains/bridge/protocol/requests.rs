@@ -34,6 +34,29 @@ pub(crate) struct BridgeFetchEvents {
     pub(crate) event_ids: Vec<String>,
 }
 
+/// A blob request — "give me this blob's stored bytes from `offset` on."
+///
+/// Blobs are keyed by content hash and sent in chunks, so a large one
+/// never has to fit in a single message and an interrupted transfer
+/// picks up from the bytes it already has.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct BridgeFetchBlob {
+    pub(crate) link: Link,
+    pub(crate) hash: ContentHash,
+    pub(crate) offset: u64,
+}
+
+/// A subscribe request — "send me new events as they're appended."
+///
+/// Unlike every other request, the stream stays open: the server
+/// acknowledges with `BridgeSubscribed` and then writes a
+/// `BridgeEvents` frame for each event appended under the shared
+/// bookmark, until either side hangs up.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct BridgeSubscribe {
+    pub(crate) link: Link,
+}
+
 /// List bookmarks on a remote project.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub(crate) struct BridgeListBookmarks {
@@ -56,7 +79,7 @@ pub(crate) struct BridgeSubmitBookmark {
 
 /// A request issued over the oneiros sync protocol.
 ///
-/// Carried over the `/oneiros/sync/1` ALPN via iroh's QUIC transport.
+/// Carried over the `/oneiros/sync/2` ALPN via iroh's QUIC transport.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type", content = "data", rename_all = "kebab-case")]
 #[expect(
@@ -73,14 +96,33 @@ pub(crate) enum BridgeRequest {
     /// "Give me these specific events by ID."
     BridgeFetchEvents(BridgeFetchEvents),
 
+    /// "Give me the next chunk of this blob."
+    BridgeFetchBlob(BridgeFetchBlob),
+
     /// "What bookmarks do you have for this project?"
     BridgeListBookmarks(BridgeListBookmarks),
 
     /// "Please accept this bookmark submission."
     BridgeSubmitBookmark(BridgeSubmitBookmark),
+
+    /// "Keep sending me new events."
+    BridgeSubscribe(BridgeSubscribe),
 }
 
 impl BridgeRequest {
+    /// The operation this request performs.
+    pub(crate) fn op(&self) -> BridgeOp {
+        match self {
+            Self::BridgeDiff(_) => BridgeOp::Diff,
+            Self::BridgeResolve(_) => BridgeOp::Resolve,
+            Self::BridgeFetchEvents(_) => BridgeOp::FetchEvents,
+            Self::BridgeFetchBlob(_) => BridgeOp::FetchBlob,
+            Self::BridgeListBookmarks(_) => BridgeOp::ListBookmarks,
+            Self::BridgeSubmitBookmark(_) => BridgeOp::SubmitBookmark,
+            Self::BridgeSubscribe(_) => BridgeOp::Subscribe,
+        }
+    }
+
     /// Encode this request to JSON bytes for transport.
     pub(crate) fn to_bytes(&self) -> Vec<u8> {
         serde_json::to_vec(self).expect("sync request serialization should not fail")
@@ -142,6 +184,30 @@ mod tests {
         assert_eq!(original, decoded);
     }
 
+    #[test]
+    fn blob_request_roundtrip_through_bytes() {
+        let original = BridgeRequest::BridgeFetchBlob(BridgeFetchBlob {
+            link: Link::new(Ref::bookmark(BookmarkId::new()), Token::from("testtoken")),
+            hash: ContentHash::new("blobhash"),
+            offset: 1024,
+        });
+        let bytes = original.to_bytes();
+        let decoded = BridgeRequest::from_bytes(&bytes).unwrap();
+        assert_eq!(original, decoded);
+        assert_eq!(decoded.op(), BridgeOp::FetchBlob);
+    }
+
+    #[test]
+    fn subscribe_request_roundtrip_through_bytes() {
+        let original = BridgeRequest::BridgeSubscribe(BridgeSubscribe {
+            link: Link::new(Ref::bookmark(BookmarkId::new()), Token::from("testtoken")),
+        });
+        let bytes = original.to_bytes();
+        let decoded = BridgeRequest::from_bytes(&bytes).unwrap();
+        assert_eq!(original, decoded);
+        assert_eq!(decoded.op(), BridgeOp::Subscribe);
+    }
+
     #[test]
     fn diff_request_roundtrip_through_serde_json() {
         let original = sample_diff();
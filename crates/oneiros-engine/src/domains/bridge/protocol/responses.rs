@@ -23,9 +23,61 @@ pub(crate) struct BridgeNodes {
 }
 
 /// The requested events, fetched by ID after a Merkle diff.
+///
+/// `truncated` is set when the server stopped short of the requested
+/// ids to keep the response within its page budget; the client asks
+/// again for the rest.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct BridgeEvents {
     pub(crate) events: Vec<StoredEvent>,
+    #[serde(default)]
+    pub(crate) truncated: bool,
+}
+
+impl BridgeEvents {
+    /// Every event in one response.
+    pub(crate) fn all(events: Vec<StoredEvent>) -> Self {
+        Self {
+            events,
+            truncated: false,
+        }
+    }
+
+    /// As many events as fit in `budget` encoded bytes, in order —
+    /// always at least one, so an oversized event still gets through on
+    /// its own page.
+    pub(crate) fn paged(events: Vec<StoredEvent>, budget: usize) -> Self {
+        let total = events.len();
+        let mut used = 0;
+        let mut page = Vec::new();
+
+        for event in events {
+            let size = serde_json::to_vec(&event)
+                .map(|bytes| bytes.len())
+                .unwrap_or(0);
+            if !page.is_empty() && used + size > budget {
+                break;
+            }
+            used += size;
+            page.push(event);
+        }
+
+        Self {
+            truncated: page.len() < total,
+            events: page,
+        }
+    }
+}
+
+/// One chunk of a blob's stored bytes, starting at `offset`. `total` is
+/// the stored length; the blob is complete once the client holds that
+/// many bytes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct BridgeBlobChunk {
+    pub(crate) hash: ContentHash,
+    pub(crate) offset: u64,
+    pub(crate) total: u64,
+    pub(crate) data: Blob,
 }
 
 /// A denial — the server rejected the request.
@@ -42,7 +94,7 @@ pub(crate) struct BridgeBookmarkList {
 
 /// The response to a [`BridgeRequest`] over the oneiros sync protocol.
 ///
-/// Carried over the `/oneiros/sync/1` ALPN via iroh's QUIC transport.
+/// Carried over the `/oneiros/sync/2` ALPN via iroh's QUIC transport.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", content = "data", rename_all = "kebab-case")]
 #[expect(
@@ -62,12 +114,19 @@ pub(crate) enum BridgeResponse {
     /// The requested events, fetched by ID after the diff.
     BridgeEvents(BridgeEvents),
 
+    /// The next chunk of a requested blob.
+    BridgeBlobChunk(BridgeBlobChunk),
+
     /// A list of bookmark names (response to BridgeListBookmarks).
     BridgeBookmarkList(BridgeBookmarkList),
 
     /// Submit accepted — the remote is collecting the data.
     BridgeSubmitAccepted,
 
+    /// Subscription accepted — `BridgeEvents` frames follow as events
+    /// are appended.
+    BridgeSubscribed,
+
     /// Submit rejected.
     BridgeSubmitRejected(BridgeDenied),
 
@@ -157,6 +216,56 @@ mod tests {
         }
     }
 
+    fn sample_event(note: &str) -> StoredEvent {
+        StoredEvent::builder()
+            .id(EventId::new())
+            .sequence(0)
+            .data(Event::Known(Events::Storage(
+                StorageEvents::StorageRemoved(
+                    StorageRemoved::builder_v1()
+                        .key(StorageKey::new(note))
+                        .build()
+                        .into(),
+                ),
+            )))
+            .source(Source::default())
+            .created_at(Timestamp::now())
+            .build()
+    }
+
+    #[test]
+    fn paged_events_stop_at_the_budget() {
+        let events: Vec<StoredEvent> = (0..10).map(|i| sample_event(&format!("k{i}"))).collect();
+        let one = serde_json::to_vec(&events[0]).unwrap().len();
+
+        let page = BridgeEvents::paged(events.clone(), one * 3);
+        assert_eq!(page.events.len(), 3);
+        assert!(page.truncated);
+        assert_eq!(page.events[0].id, events[0].id);
+
+        let everything = BridgeEvents::paged(events, usize::MAX);
+        assert_eq!(everything.events.len(), 10);
+        assert!(!everything.truncated);
+    }
+
+    #[test]
+    fn paged_events_always_carry_one() {
+        let page = BridgeEvents::paged(vec![sample_event("big"), sample_event("next")], 1);
+        assert_eq!(page.events.len(), 1);
+        assert!(page.truncated);
+    }
+
+    #[test]
+    fn events_without_truncated_flag_decode() {
+        let decoded =
+            BridgeResponse::from_bytes(br#"{"type":"bridge-events","data":{"events":[]}}"#)
+                .unwrap();
+        match decoded {
+            BridgeResponse::BridgeEvents(be) => assert!(!be.truncated),
+            _ => panic!("expected BridgeEvents variant"),
+        }
+    }
+
     #[test]
     fn current_is_not_denied() {
         assert!(!BridgeResponse::BridgeCurrent.is_denied());
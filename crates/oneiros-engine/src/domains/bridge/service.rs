@@ -1,14 +1,24 @@
+use tokio::sync::broadcast;
+
 use crate::*;
 
 /// Server-side handler for incoming sync requests. Validates tickets
-/// against the host DB and serves chronicle nodes for the Merkle
-/// diff protocol.
+/// against the host DB, serves chronicle nodes for the Merkle diff
+/// protocol, and streams appended events to push subscribers.
 #[derive(Clone)]
 pub(crate) struct SyncHandler {
     config: Config,
     canons: CanonIndex,
     bridge: Bridge,
     mailbox: Mailbox,
+    feed: EventFeed,
+}
+
+/// Who is presenting a ticket, and with which request.
+#[derive(Clone, Copy)]
+struct Caller {
+    peer: PeerKey,
+    op: BridgeOp,
 }
 
 impl core::fmt::Debug for SyncHandler {
@@ -23,19 +33,27 @@ impl SyncHandler {
         canons: CanonIndex,
         bridge: Bridge,
         mailbox: Mailbox,
+        feed: EventFeed,
     ) -> Self {
         Self {
             config,
             canons,
             bridge,
             mailbox,
+            feed,
         }
     }
 
+    /// Check the presented ticket — validity and the capability the
+    /// request needs. A request that opens a session counts against the
+    /// ticket's use limit and is recorded on the host log, attributed to
+    /// the calling peer; the follow-up requests of a session only need
+    /// the ticket to still be in standing.
     async fn validate_ticket(
         &self,
         scope: &Scope<AtHost>,
         link: &Link,
+        caller: Caller,
     ) -> Result<Ticket, BridgeError> {
         let ticket = TicketRepo::new(scope)
             .get_by_token(link.token.as_str())
@@ -46,14 +64,79 @@ impl SyncHandler {
             return Err(DenyReason::TargetMismatch.into());
         }
 
-        ticket.check_validity()?;
+        let opens_session = caller.op.opens_session();
+        if opens_session {
+            ticket.check_validity()?;
+        } else {
+            ticket.check_standing()?;
+        }
+
+        if !ticket.can(caller.op.permission()) {
+            return Err(DenyReason::InsufficientPermissions.into());
+        }
+
+        if !opens_session {
+            return Ok(ticket);
+        }
+
+        let new_event = NewEvent::builder()
+            .data(Events::Ticket(TicketEvents::TicketUsed(
+                TicketUsed::builder_v2()
+                    .ticket_id(ticket.id)
+                    .used_at(Timestamp::now())
+                    .peer(caller.peer)
+                    .operation(caller.op)
+                    .build()
+                    .into(),
+            )))
+            .build();
+
+        self.mailbox.tell(HostMessage::from(
+            AppendHostLog::builder()
+                .scope(scope.clone())
+                .event(new_event)
+                .build(),
+        ));
 
         Ok(ticket)
     }
 
-    async fn handle_diff(&self, diff: &BridgeDiff) -> Result<BridgeResponse, BridgeError> {
+    async fn handle(
+        &self,
+        request: &BridgeRequest,
+        peer: PeerKey,
+    ) -> Result<BridgeResponse, BridgeError> {
+        let caller = Caller {
+            peer,
+            op: request.op(),
+        };
+
+        match request {
+            BridgeRequest::BridgeDiff(diff) => self.handle_diff(diff, caller).await,
+            BridgeRequest::BridgeResolve(resolve) => self.handle_resolve(resolve, caller).await,
+            BridgeRequest::BridgeFetchEvents(fetch) => {
+                self.handle_fetch_events(fetch, caller).await
+            }
+            BridgeRequest::BridgeFetchBlob(fetch) => self.handle_fetch_blob(fetch, caller).await,
+            BridgeRequest::BridgeListBookmarks(list) => {
+                self.handle_list_bookmarks(list, caller).await
+            }
+            BridgeRequest::BridgeSubmitBookmark(submit) => {
+                self.handle_submit_bookmark(submit, caller).await
+            }
+            BridgeRequest::BridgeSubscribe(_) => {
+                Err(BridgeProtocolError::Streamed(BridgeOp::Subscribe).into())
+            }
+        }
+    }
+
+    async fn handle_diff(
+        &self,
+        diff: &BridgeDiff,
+        caller: Caller,
+    ) -> Result<BridgeResponse, BridgeError> {
         let scope = ComposeScope::new(self.config.clone()).host()?;
-        let ticket = self.validate_ticket(&scope, &diff.link).await?;
+        let ticket = self.validate_ticket(&scope, &diff.link, caller).await?;
         let chronicle = self.canons.chronicle(&ticket.project_name)?;
         let server_root = chronicle.root()?;
 
@@ -83,9 +166,12 @@ impl SyncHandler {
     async fn handle_resolve(
         &self,
         resolve_req: &BridgeResolve,
+        caller: Caller,
     ) -> Result<BridgeResponse, BridgeError> {
         let scope = ComposeScope::new(self.config.clone()).host()?;
-        let _ticket = self.validate_ticket(&scope, &resolve_req.link).await?;
+        let _ticket = self
+            .validate_ticket(&scope, &resolve_req.link, caller)
+            .await?;
 
         // Chronicle objects live in the host DB.
         let host_db = HostDb::open(&scope).await?;
@@ -104,9 +190,10 @@ impl SyncHandler {
     async fn handle_fetch_events(
         &self,
         fetch: &BridgeFetchEvents,
+        caller: Caller,
     ) -> Result<BridgeResponse, BridgeError> {
         let scope = ComposeScope::new(self.config.clone()).host()?;
-        let ticket = self.validate_ticket(&scope, &fetch.link).await?;
+        let ticket = self.validate_ticket(&scope, &fetch.link, caller).await?;
 
         // Compose at the target project's project tier — events DB
         // lives there. ComposeScope verifies the project exists.
@@ -124,19 +211,48 @@ impl SyncHandler {
 
         let events = EventLog::new(&db).get_batch(&ids)?;
 
-        Ok(BridgeResponse::BridgeEvents(BridgeEvents { events }))
+        Ok(BridgeResponse::BridgeEvents(BridgeEvents::paged(
+            events,
+            FETCH_PAGE_BYTES,
+        )))
+    }
+
+    async fn handle_fetch_blob(
+        &self,
+        fetch: &BridgeFetchBlob,
+        caller: Caller,
+    ) -> Result<BridgeResponse, BridgeError> {
+        let scope = ComposeScope::new(self.config.clone()).host()?;
+        let ticket = self.validate_ticket(&scope, &fetch.link, caller).await?;
+
+        // Blobs live in the bookmark DB — serve from the same bookmark
+        // a diff is served from.
+        let bookmark = self.canons.active_bookmark(&ticket.project_name)?;
+        let bookmark_scope =
+            ComposeScope::new(self.config.clone()).bookmark(ticket.project_name, bookmark)?;
+        let db = BookmarkDb::open(&bookmark_scope).await?;
+
+        let (bytes, total) = StorageStore::new(&db)
+            .blob_chunk(&fetch.hash, fetch.offset, BLOB_CHUNK_SIZE)?
+            .ok_or_else(|| BridgeProtocolError::BlobMissing(fetch.hash.clone()))?;
+
+        Ok(BridgeResponse::BridgeBlobChunk(BridgeBlobChunk {
+            hash: fetch.hash.clone(),
+            offset: fetch.offset,
+            total,
+            data: Blob::encode(&bytes),
+        }))
     }
 
     async fn handle_list_bookmarks(
         &self,
         request: &BridgeListBookmarks,
+        caller: Caller,
     ) -> Result<BridgeResponse, BridgeError> {
         let scope = ComposeScope::new(self.config.clone()).host()?;
-        let ticket = self.validate_ticket(&scope, &request.ticket).await?;
-
-        if !ticket.can(PermissionOp::BookmarkList) {
-            return Err(DenyReason::InsufficientPermissions.into());
-        }
+        let ticket = self
+            .validate_ticket(&scope, &request.ticket, caller)
+            .await?;
 
         // The request must target the same project the ticket was issued for.
         if request.project != ticket.project_name {
@@ -161,13 +277,12 @@ impl SyncHandler {
     async fn handle_submit_bookmark(
         &self,
         request: &BridgeSubmitBookmark,
+        caller: Caller,
     ) -> Result<BridgeResponse, BridgeError> {
         let scope = ComposeScope::new(self.config.clone()).host()?;
-        let ticket = self.validate_ticket(&scope, &request.ticket).await?;
-
-        if !ticket.can(PermissionOp::BookmarkSubmit) {
-            return Err(DenyReason::InsufficientPermissions.into());
-        }
+        let ticket = self
+            .validate_ticket(&scope, &request.ticket, caller)
+            .await?;
 
         // Ensure the target bookmark exists on our side.
         BookmarkService::ensure_bookmark_exists(
@@ -189,12 +304,73 @@ impl SyncHandler {
             &ticket.project_name,
             &request.bookmark_name,
             request.bookmark.clone(),
+            &mut CollectProgress::default(),
         )
         .await
         .map_err(|e| BridgeError::Denied(DenyReason::Remote(OpaquePeer::from(e.to_string()))))?;
 
         Ok(BridgeResponse::BridgeSubmitAccepted)
     }
+
+    /// Serve a push subscription: acknowledge it, then write a frame for
+    /// each event appended under the ticket's project's active bookmark
+    /// — the same chronicle a diff is served from. The stream ends when
+    /// the subscriber hangs up, when the ticket stops being valid, or
+    /// when the subscriber falls too far behind the feed; it picks up
+    /// anything it missed with its next pull.
+    async fn stream_subscription(
+        &self,
+        subscribe: &BridgeSubscribe,
+        caller: Caller,
+        send: &mut iroh::endpoint::SendStream,
+        connection: &iroh::endpoint::Connection,
+    ) -> Result<(), BridgeError> {
+        let scope = ComposeScope::new(self.config.clone()).host()?;
+
+        // Listen before acknowledging so nothing appended in between is lost.
+        let mut feed = self.feed.subscribe();
+        let ticket = self
+            .validate_ticket(&scope, &subscribe.link, caller)
+            .await?;
+        write_frame(send, &BridgeResponse::BridgeSubscribed).await?;
+
+        loop {
+            let appended = tokio::select! {
+                appended = feed.recv() => appended,
+                _ = connection.closed() => return Ok(()),
+            };
+            let appended = match appended {
+                Ok(appended) => appended,
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!(skipped, peer = %caller.peer, "sync: push subscriber fell behind");
+                    break;
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            };
+
+            // Imports aren't pushed on, so two hosts pushing to each other
+            // can't echo an event back and forth.
+            if appended.imported
+                || appended.project != ticket.project_name
+                || appended.bookmark != self.canons.active_bookmark(&ticket.project_name)?
+            {
+                continue;
+            }
+
+            // A ticket revoked or expired mid-stream ends the stream.
+            TicketRepo::new(&scope)
+                .get_by_token(subscribe.link.token.as_str())
+                .await?
+                .ok_or(DenyReason::TicketNotFound)?
+                .check_standing()?;
+
+            let events = BridgeResponse::BridgeEvents(BridgeEvents::all(vec![*appended.stored]));
+            write_frame(send, &events).await?;
+        }
+
+        send.finish().map_err(IrohError::from)?;
+        Ok(())
+    }
 }
 
 impl iroh::protocol::ProtocolHandler for SyncHandler {
@@ -226,43 +402,31 @@ impl iroh::protocol::ProtocolHandler for SyncHandler {
             .await
             .map_err(iroh::protocol::AcceptError::from_err)?;
 
+        let peer = PeerKey::from_bytes(*connection.remote_id().as_bytes());
+
         let response = match BridgeRequest::from_bytes(&buf) {
-            Ok(BridgeRequest::BridgeDiff(diff)) => {
-                self.handle_diff(&diff).await.unwrap_or_else(|e| {
-                    BridgeResponse::BridgeDenied(BridgeDenied {
+            Ok(BridgeRequest::BridgeSubscribe(subscribe)) => {
+                let caller = Caller {
+                    peer,
+                    op: BridgeOp::Subscribe,
+                };
+                if let Err(e) = self
+                    .stream_subscription(&subscribe, caller, &mut send, &connection)
+                    .await
+                {
+                    let response = BridgeResponse::BridgeDenied(BridgeDenied {
                         reason: e.to_string(),
-                    })
-                })
+                    });
+                    write_response(&mut send, &response).await.ok();
+                }
+                connection.closed().await;
+                return Ok(());
             }
-            Ok(BridgeRequest::BridgeResolve(resolve)) => {
-                self.handle_resolve(&resolve).await.unwrap_or_else(|e| {
-                    BridgeResponse::BridgeDenied(BridgeDenied {
-                        reason: e.to_string(),
-                    })
-                })
-            }
-            Ok(BridgeRequest::BridgeFetchEvents(fetch)) => {
-                self.handle_fetch_events(&fetch).await.unwrap_or_else(|e| {
-                    BridgeResponse::BridgeDenied(BridgeDenied {
-                        reason: e.to_string(),
-                    })
+            Ok(request) => self.handle(&request, peer).await.unwrap_or_else(|e| {
+                BridgeResponse::BridgeDenied(BridgeDenied {
+                    reason: e.to_string(),
                 })
-            }
-            Ok(BridgeRequest::BridgeListBookmarks(list)) => {
-                self.handle_list_bookmarks(&list).await.unwrap_or_else(|e| {
-                    BridgeResponse::BridgeDenied(BridgeDenied {
-                        reason: e.to_string(),
-                    })
-                })
-            }
-            Ok(BridgeRequest::BridgeSubmitBookmark(submit)) => self
-                .handle_submit_bookmark(&submit)
-                .await
-                .unwrap_or_else(|e| {
-                    BridgeResponse::BridgeDenied(BridgeDenied {
-                        reason: e.to_string(),
-                    })
-                }),
+            }),
             Err(e) => BridgeResponse::BridgeDenied(BridgeDenied {
                 reason: format!("invalid request: {e}"),
             }),
@@ -280,15 +444,24 @@ impl iroh::protocol::ProtocolHandler for SyncHandler {
     }
 }
 
+/// Write a final response and finish the stream.
 async fn write_response(
     send: &mut iroh::endpoint::SendStream,
     response: &BridgeResponse,
-) -> Result<(), std::io::Error> {
+) -> Result<(), IrohError> {
+    write_frame(send, response).await?;
+    send.finish()?;
+    Ok(())
+}
+
+/// Write one length-prefixed response, leaving the stream open.
+async fn write_frame(
+    send: &mut iroh::endpoint::SendStream,
+    response: &BridgeResponse,
+) -> Result<(), IrohError> {
     let encoded = response.to_bytes();
     let len = (encoded.len() as u32).to_be_bytes();
     send.write_all(&len).await?;
     send.write_all(&encoded).await?;
-    send.finish()
-        .map_err(|e| std::io::Error::other(e.to_string()))?;
     Ok(())
 }
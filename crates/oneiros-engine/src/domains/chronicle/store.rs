@@ -1,11 +1,25 @@
-use rusqlite::params;
+use std::collections::HashSet;
+
+use rusqlite::{OptionalExtension, params};
 
 use crate::*;
 
+/// What a sweep of the chronicle node table found and removed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ChronicleSweep {
+    pub(crate) kept: u64,
+    pub(crate) removed: u64,
+    pub(crate) bytes_reclaimed: u64,
+}
+
 /// Content-addressed object store for chronicle HAMT nodes.
 ///
 /// Nodes are stored by the BLAKE3/SHA256 hash of their serialized bytes.
 /// Structural sharing means identical subtrees are stored only once.
+///
+/// Beside the nodes it keeps each bookmark's head — the root its
+/// chronicle last reached, and the root it was forked from — so a
+/// restarted host resumes every bookmark where it stood.
 pub(crate) struct ChronicleStore<'a> {
     db: &'a rusqlite::Connection,
 }
@@ -20,11 +34,97 @@ impl<'a> ChronicleStore<'a> {
             "CREATE TABLE IF NOT EXISTS chronicle_objects (
                 hash TEXT PRIMARY KEY,
                 data BLOB NOT NULL
+            );
+            CREATE TABLE IF NOT EXISTS chronicle_heads (
+                project TEXT NOT NULL,
+                bookmark TEXT NOT NULL,
+                root TEXT,
+                base TEXT,
+                PRIMARY KEY (project, bookmark)
             )",
         )?;
         Ok(())
     }
 
+    /// Record where a bookmark's chronicle stands.
+    pub(crate) fn save_head(
+        &self,
+        project: &ProjectName,
+        bookmark: &BookmarkName,
+        root: Option<&ContentHash>,
+        base: Option<&ContentHash>,
+    ) -> Result<(), EventError> {
+        self.db.execute(
+            "INSERT OR REPLACE INTO chronicle_heads (project, bookmark, root, base)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![
+                project.to_string(),
+                bookmark.to_string(),
+                root.map(ToString::to_string),
+                base.map(ToString::to_string),
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// The root a bookmark's chronicle last reached, if one was saved.
+    pub(crate) fn head(
+        &self,
+        project: &ProjectName,
+        bookmark: &BookmarkName,
+    ) -> Result<Option<ContentHash>, EventError> {
+        let root: Option<Option<String>> = self
+            .db
+            .query_row(
+                "SELECT root FROM chronicle_heads WHERE project = ?1 AND bookmark = ?2",
+                params![project.to_string(), bookmark.to_string()],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(root.flatten().map(ContentHash::new))
+    }
+
+    /// Every saved root and fork base, across all projects.
+    pub(crate) fn heads(&self) -> Result<Vec<ContentHash>, EventError> {
+        let mut stmt = self.db.prepare("SELECT root, base FROM chronicle_heads")?;
+        let rows = stmt.query_map([], |row| {
+            Ok([row.get::<_, Option<String>>(0)?, row.get(1)?])
+        })?;
+
+        let mut heads = Vec::new();
+        for row in rows {
+            heads.extend(row?.into_iter().flatten().map(ContentHash::new));
+        }
+        Ok(heads)
+    }
+
+    /// Carry a bookmark's head over to its new name.
+    pub(crate) fn rename_head(
+        &self,
+        project: &ProjectName,
+        from: &BookmarkName,
+        to: &BookmarkName,
+    ) -> Result<(), EventError> {
+        self.db.execute(
+            "UPDATE chronicle_heads SET bookmark = ?3 WHERE project = ?1 AND bookmark = ?2",
+            params![project.to_string(), from.to_string(), to.to_string()],
+        )?;
+        Ok(())
+    }
+
+    /// Forget a deleted bookmark's head.
+    pub(crate) fn remove_head(
+        &self,
+        project: &ProjectName,
+        bookmark: &BookmarkName,
+    ) -> Result<(), EventError> {
+        self.db.execute(
+            "DELETE FROM chronicle_heads WHERE project = ?1 AND bookmark = ?2",
+            params![project.to_string(), bookmark.to_string()],
+        )?;
+        Ok(())
+    }
+
     /// Store a ledger node, returning its content hash.
     /// If the hash already exists, this is a no-op (content-addressing = dedup).
     pub(crate) fn put(&self, node: &LedgerNode) -> ContentHash {
@@ -53,6 +153,40 @@ impl<'a> ChronicleStore<'a> {
         serde_json::from_slice(&bytes).ok()
     }
 
+    /// Delete every node whose hash is not in `live`.
+    ///
+    /// The caller marks `live` from every root that must survive; any
+    /// node outside it is unreachable and can only have been left
+    /// behind by a root that has since moved on.
+    pub(crate) fn sweep(&self, live: &HashSet<String>) -> Result<ChronicleSweep, EventError> {
+        let transaction = self.db.unchecked_transaction()?;
+        let mut sweep = ChronicleSweep::default();
+
+        let nodes: Vec<(String, u64)> = {
+            let mut stmt =
+                transaction.prepare("SELECT hash, length(data) FROM chronicle_objects")?;
+            stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+                .collect::<Result<_, _>>()?
+        };
+
+        {
+            let mut delete =
+                transaction.prepare("DELETE FROM chronicle_objects WHERE hash = ?1")?;
+            for (hash, size) in nodes {
+                if live.contains(&hash) {
+                    sweep.kept += 1;
+                } else {
+                    delete.execute(params![hash])?;
+                    sweep.removed += 1;
+                    sweep.bytes_reclaimed += size;
+                }
+            }
+        }
+
+        transaction.commit()?;
+        Ok(sweep)
+    }
+
     /// Create a resolve closure for use with Ledger operations.
     pub(crate) fn resolver(&self) -> impl Fn(&ContentHash) -> Option<LedgerNode> + '_ {
         move |hash| self.get(hash)
@@ -63,3 +197,41 @@ impl<'a> ChronicleStore<'a> {
         move |node| self.put(node)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sweep_keeps_only_reachable_nodes() -> Result<(), Box<dyn core::error::Error>> {
+        let db = rusqlite::Connection::open_in_memory()?;
+        let store = ChronicleStore::new(&db);
+        store.migrate()?;
+
+        let first = Ledger::record(
+            None,
+            &EventId::new(),
+            ContentHash::compute(b"one"),
+            &store.resolver(),
+            &store.writer(),
+        );
+        let second = Ledger::record(
+            Some(&first),
+            &EventId::new(),
+            ContentHash::compute(b"two"),
+            &store.resolver(),
+            &store.writer(),
+        );
+
+        let mut live = HashSet::new();
+        Ledger::mark_reachable(&second, &store.resolver(), &mut live);
+        let sweep = store.sweep(&live)?;
+
+        assert_eq!(sweep.kept, 1);
+        assert_eq!(sweep.removed, 1);
+        assert!(sweep.bytes_reclaimed > 0);
+        assert!(store.get(&first).is_none());
+        assert!(store.get(&second).is_some());
+        Ok(())
+    }
+}
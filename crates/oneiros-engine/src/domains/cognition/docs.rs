@@ -4,6 +4,7 @@ pub(crate) enum CognitionDocs {
     Add,
     List,
     Show,
+    Retract,
 }
 
 impl CognitionDocs {
@@ -35,6 +36,12 @@ impl CognitionDocs {
                 .summary("Get a thought")
                 .description("Retrieve the full content of a specific thought by ID.")
                 .build(),
+            Self::Retract => ResourceDocs::builder()
+                .tag(tag)
+                .nickname("retract-cognition")
+                .summary("Retract a thought")
+                .description("Withdraw a thought with a reason. The original stays in the event log but is hidden from listings, search and dreams.")
+                .build(),
         }
     }
 }
@@ -7,6 +7,8 @@ pub(crate) enum CognitionCommands {
     Add(AddCognition),
     Show(GetCognition),
     List(ListCognitions),
+    /// Withdraw a thought that no longer holds.
+    Retract(RetractCognition),
 }
 
 impl CognitionCommands {
@@ -29,6 +31,10 @@ impl CognitionCommands {
                 listing.execute_request(&client).await?,
                 CognitionRequest::ListCognitions(listing.clone()),
             ),
+            Self::Retract(retraction) => (
+                retraction.execute_request(&client).await?,
+                CognitionRequest::RetractCognition(retraction.clone()),
+            ),
         };
 
         let response: CognitionResponse = serde_json::from_slice(&bytes)?;
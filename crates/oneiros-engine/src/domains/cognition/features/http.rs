@@ -4,6 +4,8 @@ use axum::{
     extract::{Path, Query},
     http::StatusCode,
 };
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::*;
 
@@ -35,11 +37,25 @@ impl CognitionRouter {
                             .input::<IdPathParam<CognitionId>>()
                             .response::<200, Json<CognitionDetailsResponse>>()
                     }),
+                )
+                .api_route(
+                    "/{id}/retract",
+                    routing::post_with(retract, |op| {
+                        resource_op!(op, CognitionDocs::Retract)
+                            .security_requirement("BearerToken")
+                            .input::<IdPathParam<CognitionId>>()
+                            .response::<200, Json<CognitionRetractedResponse>>()
+                    }),
                 ),
         )
     }
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+struct RetractBody {
+    reason: Description,
+}
+
 async fn add(
     scope: Scope<AtBookmark>,
     mailbox: Mailbox,
@@ -73,3 +89,23 @@ async fn show(
         CognitionService::get(&scope, &GetCognition::builder_v1().key(key).build().into()).await?,
     ))
 }
+
+async fn retract(
+    scope: Scope<AtBookmark>,
+    mailbox: Mailbox,
+    Path(id): Path<CognitionId>,
+    Json(body): Json<RetractBody>,
+) -> Result<Json<CognitionResponse>, CognitionError> {
+    Ok(Json(
+        CognitionService::retract(
+            &scope,
+            &mailbox,
+            &RetractCognition::builder_v1()
+                .id(id)
+                .reason(body.reason)
+                .build()
+                .into(),
+        )
+        .await?,
+    ))
+}
@@ -37,7 +37,18 @@ mod cognition_mcp {
 
     pub(crate) fn tool_defs() -> Vec<ToolDef> {
         vec![
-            Tool::<AddCognition>::new(CognitionRequestType::AddCognition, "Record a thought").def(),
+            Tool::<AddCognition>::new(
+                CognitionRequestType::AddCognition,
+                "Record a thought",
+                PermissionOp::CognitionWrite,
+            )
+            .def(),
+            Tool::<RetractCognition>::new(
+                CognitionRequestType::RetractCognition,
+                "Withdraw a thought that no longer holds",
+                PermissionOp::CognitionWrite,
+            )
+            .def(),
         ]
     }
 
@@ -63,6 +74,15 @@ mod cognition_mcp {
                     .map_err(Error::from)?;
                 Ok(CognitionView::new(response, &request).mcp())
             }
+            CognitionRequestType::RetractCognition => {
+                let retraction: RetractCognition = serde_json::from_value(params.clone())?;
+                let request = CognitionRequest::RetractCognition(retraction.clone());
+                let scope = context.scope().map_err(Error::from)?;
+                let response = CognitionService::retract(scope, mailbox, &retraction)
+                    .await
+                    .map_err(Error::from)?;
+                Ok(CognitionView::new(response, &request).mcp())
+            }
             CognitionRequestType::GetCognition | CognitionRequestType::ListCognitions => {
                 Err(ToolError::UnknownTool(tool_name.to_string()))
             }
@@ -92,9 +112,9 @@ mod cognition_mcp {
                         .map_err(Error::from)?
                 }
             }
-            CognitionRequest::AddCognition(_) => {
+            CognitionRequest::AddCognition(_) | CognitionRequest::RetractCognition(_) => {
                 return Err(ToolError::NotAResource(
-                    "Add is a tool, not a resource".to_string(),
+                    "Mutations are tools, not resources".to_string(),
                 ));
             }
         };
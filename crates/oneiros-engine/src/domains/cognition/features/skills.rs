@@ -8,6 +8,7 @@ impl CognitionSkills {
             Skill::new("cognition-add", include_str!("skills/add.md")),
             Skill::new("cognition-show", include_str!("skills/show.md")),
             Skill::new("cognition-list", include_str!("skills/list.md")),
+            Skill::new("cognition-retract", include_str!("skills/retract.md")),
         ]
     }
 }
@@ -4,10 +4,19 @@ pub(crate) struct CognitionState;
 
 impl CognitionState {
     pub(crate) fn reduce(mut canon: ProjectCanon, event: &Events) -> ProjectCanon {
-        if let Events::Cognition(CognitionEvents::CognitionAdded(added)) = event
-            && let Ok(current) = added.current()
-        {
-            canon.cognitions.set(&current.cognition);
+        match event {
+            Events::Cognition(CognitionEvents::CognitionAdded(added)) => {
+                if let Ok(current) = added.current() {
+                    canon.cognitions.set(&current.cognition);
+                }
+            }
+            // The canon holds the live picture; retracted cognitions leave it.
+            Events::Cognition(CognitionEvents::CognitionRetracted(retracted)) => {
+                if let Ok(current) = retracted.current() {
+                    canon.cognitions.remove(&current.id);
+                }
+            }
+            _ => {}
         }
 
         canon
@@ -40,4 +49,31 @@ mod tests {
 
         assert_eq!(next.cognitions.len(), 1);
     }
+
+    #[test]
+    fn retracting_removes_cognition() {
+        let cognition = Cognition::builder()
+            .agent_id(AgentId::new())
+            .texture("observation")
+            .content("Something misread")
+            .build();
+        let added = Events::Cognition(CognitionEvents::CognitionAdded(
+            CognitionAdded::builder_v1()
+                .cognition(cognition.clone())
+                .build()
+                .into(),
+        ));
+        let retracted = Events::Cognition(CognitionEvents::CognitionRetracted(
+            CognitionRetracted::builder_v1()
+                .id(cognition.id)
+                .retraction(Retraction::builder().reason("Wrong").build())
+                .build()
+                .into(),
+        ));
+
+        let canon = CognitionState::reduce(ProjectCanon::default(), &added);
+        let next = CognitionState::reduce(canon, &retracted);
+
+        assert!(next.cognitions.is_empty());
+    }
 }
@@ -15,12 +15,29 @@ pub(crate) struct Cognition {
     pub(crate) content: Content,
     #[builder(default = Timestamp::now())]
     pub(crate) created_at: Timestamp,
+    /// Set once the cognition is retracted. Retracted cognitions stay in
+    /// the log but are hidden from dreams, lists and search by default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) retraction: Option<Retraction>,
 }
 
 impl Cognition {
     pub(crate) fn ref_token(&self) -> RefToken {
         RefToken::from(Ref::cognition(self.id))
     }
+
+    pub(crate) fn is_retracted(&self) -> bool {
+        self.retraction.is_some()
+    }
+}
+
+/// Why and when a cognition was withdrawn.
+#[derive(Debug, Clone, Builder, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub(crate) struct Retraction {
+    #[builder(into)]
+    pub(crate) reason: Description,
+    #[builder(default = Timestamp::now())]
+    pub(crate) retracted_at: Timestamp,
 }
 
 impl Indexable<CognitionId> for Cognition {
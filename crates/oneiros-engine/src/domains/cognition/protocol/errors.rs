@@ -12,6 +12,9 @@ pub(crate) enum CognitionError {
     #[error("Cognition not found: {0}")]
     NotFound(crate::CognitionId),
 
+    #[error("Cognition already retracted: {0}")]
+    AlreadyRetracted(crate::CognitionId),
+
     #[error("Invalid ID: {0}")]
     InvalidId(#[from] crate::IdParseError),
 
@@ -44,6 +47,7 @@ impl IntoResponse for CognitionError {
         let (status, message) = match &self {
             CognitionError::AgentNotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
             CognitionError::NotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
+            CognitionError::AlreadyRetracted(_) => (StatusCode::CONFLICT, self.to_string()),
             CognitionError::InvalidId(_) => (StatusCode::UNPROCESSABLE_ENTITY, self.to_string()),
             CognitionError::Resolve(_) => (StatusCode::UNPROCESSABLE_ENTITY, self.to_string()),
             CognitionError::Database(_) | CognitionError::Event(_) => {
@@ -8,6 +8,7 @@ use crate::*;
 #[kinded(kind = CognitionEventsType, display = "kebab-case")]
 pub(crate) enum CognitionEvents {
     CognitionAdded(CognitionAdded),
+    CognitionRetracted(CognitionRetracted),
 }
 
 versioned! {
@@ -18,6 +19,15 @@ versioned! {
     }
 }
 
+versioned! {
+    pub(crate) enum CognitionRetracted {
+        V1 => {
+            pub(crate) id: CognitionId,
+            #[serde(flatten)] pub(crate) retraction: Retraction,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -32,10 +42,16 @@ mod tests {
 
     #[test]
     fn event_types_are_kebab_cased() {
-        assert_eq!(
-            &CognitionEventsType::CognitionAdded.to_string(),
-            "cognition-added"
-        );
+        let cases = [
+            (CognitionEventsType::CognitionAdded, "cognition-added"),
+            (
+                CognitionEventsType::CognitionRetracted,
+                "cognition-retracted",
+            ),
+        ];
+        for (event_type, expectation) in cases {
+            assert_eq!(&event_type.to_string(), expectation);
+        }
     }
 
     #[test]
@@ -60,4 +76,21 @@ mod tests {
             "V1 layer must not appear on the wire"
         );
     }
+
+    #[test]
+    fn cognition_retracted_wire_format_is_flat() {
+        let event = CognitionEvents::CognitionRetracted(
+            CognitionRetracted::builder_v1()
+                .id(CognitionId::new())
+                .retraction(Retraction::builder().reason("Misread the logs").build())
+                .build()
+                .into(),
+        );
+
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["type"], "cognition-retracted");
+        assert_eq!(json["data"]["reason"], "Misread the logs");
+        assert!(json["data"].get("retraction").is_none());
+        assert!(json["data"].get("retracted_at").is_some());
+    }
 }
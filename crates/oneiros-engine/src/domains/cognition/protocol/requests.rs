@@ -46,6 +46,11 @@ versioned! {
             #[arg(long)]
             #[builder(into)]
             pub(crate) lens: Option<String>,
+            /// Include cognitions that have been retracted.
+            #[arg(long)]
+            #[serde(default, deserialize_with = "query_flag")]
+            #[builder(default)]
+            pub(crate) include_retracted: bool,
             #[command(flatten)]
             #[serde(flatten)]
             #[builder(default)]
@@ -54,6 +59,20 @@ versioned! {
     }
 }
 
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum RetractCognition {
+        #[derive(clap::Args)]
+        V1 => {
+            #[builder(into)] pub(crate) id: CognitionId,
+            /// Why the thought no longer holds.
+            #[arg(long)]
+            #[builder(into)]
+            pub(crate) reason: Description,
+        }
+    }
+}
+
 resource_requests! {
     AddCognition => |this, client| { client.post("/cognitions", this).await },
     GetCognition => |this, client| {
@@ -80,6 +99,10 @@ resource_requests! {
             params.push(("lens", lens.clone()));
         }
 
+        if listing.include_retracted {
+            params.push(("include_retracted", "true".to_string()));
+        }
+
         params.push(("limit", listing.filters.limit.to_string()));
         params.push(("offset", listing.filters.offset.to_string()));
 
@@ -90,7 +113,16 @@ resource_requests! {
             .join("&");
 
         client.get(&format!("/cognitions?{query}")).await
-    }
+    },
+    RetractCognition => |this, client| {
+        let RetractCognition::V1(retraction) = this;
+        client
+            .post(
+                &format!("/cognitions/{}/retract", retraction.id),
+                &serde_json::json!({ "reason": retraction.reason }),
+            )
+            .await
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Kinded)]
@@ -100,6 +132,7 @@ pub(crate) enum CognitionRequest {
     AddCognition(AddCognition),
     GetCognition(GetCognition),
     ListCognitions(ListCognitions),
+    RetractCognition(RetractCognition),
 }
 
 #[cfg(test)]
@@ -112,6 +145,7 @@ mod tests {
             (CognitionRequestType::AddCognition, "add-cognition"),
             (CognitionRequestType::GetCognition, "get-cognition"),
             (CognitionRequestType::ListCognitions, "list-cognitions"),
+            (CognitionRequestType::RetractCognition, "retract-cognition"),
         ];
 
         for (request_type, expectation) in cases {
@@ -12,6 +12,7 @@ pub(crate) enum CognitionResponse {
     CognitionDetails(CognitionDetailsResponse),
     Cognitions(CognitionsResponse),
     NoCognitions,
+    CognitionRetracted(CognitionRetractedResponse),
 }
 
 versioned! {
@@ -37,3 +38,10 @@ versioned! {
         }
     }
 }
+
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum CognitionRetractedResponse {
+        V1 => { #[serde(flatten)] pub(crate) cognition: Cognition }
+    }
+}
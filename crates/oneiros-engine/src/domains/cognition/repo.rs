@@ -25,7 +25,7 @@ impl<'a> CognitionRepo<'a> {
     pub(crate) async fn get(&self, id: &CognitionId) -> Result<Option<Cognition>, EventError> {
         let db = BookmarkDb::open(self.scope).await?;
         let mut stmt = db.prepare(
-            "SELECT id, agent_id, texture, content, created_at
+            "SELECT id, agent_id, texture, content, created_at, retracted_at, retraction_reason
              FROM cognitions WHERE id = ?1",
         )?;
 
@@ -37,19 +37,27 @@ impl<'a> CognitionRepo<'a> {
                 row.get::<_, String>(2)?,
                 row.get::<_, String>(3)?,
                 row.get::<_, String>(4)?,
+                row.get::<_, Option<String>>(5)?,
+                row.get::<_, Option<String>>(6)?,
             ))
         });
 
         match result {
-            Ok((id, agent_id, texture, content, created_at)) => Ok(Some(
-                Cognition::builder()
-                    .id(id.parse()?)
-                    .agent_id(agent_id.parse()?)
-                    .texture(texture)
-                    .content(content)
-                    .created_at(Timestamp::parse_str(&created_at)?)
-                    .build(),
-            )),
+            Ok((id, agent_id, texture, content, created_at, retracted_at, retraction_reason)) => {
+                Ok(Some(
+                    Cognition::builder()
+                        .id(id.parse()?)
+                        .agent_id(agent_id.parse()?)
+                        .texture(texture)
+                        .content(content)
+                        .created_at(Timestamp::parse_str(&created_at)?)
+                        .maybe_retraction(CognitionStore::retraction(
+                            retracted_at,
+                            retraction_reason,
+                        )?)
+                        .build(),
+                ))
+            }
             Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
             Err(e) => Err(e.into()),
         }
@@ -67,7 +75,7 @@ impl<'a> CognitionRepo<'a> {
             .collect::<Vec<_>>()
             .join(", ");
         let sql = format!(
-            "SELECT id, agent_id, texture, content, created_at
+            "SELECT id, agent_id, texture, content, created_at, retracted_at, retraction_reason
              FROM cognitions WHERE id IN ({placeholders})"
         );
         let id_strs: Vec<String> = ids.iter().map(ToString::to_string).collect();
@@ -80,18 +88,21 @@ impl<'a> CognitionRepo<'a> {
                     row.get::<_, String>(2)?,
                     row.get::<_, String>(3)?,
                     row.get::<_, String>(4)?,
+                    row.get::<_, Option<String>>(5)?,
+                    row.get::<_, Option<String>>(6)?,
                 ))
             })?
             .collect::<Result<Vec<_>, _>>()?;
 
         let mut by_id: HashMap<CognitionId, Cognition> = HashMap::with_capacity(rows.len());
-        for (id, agent_id, texture, content, created_at) in rows {
+        for (id, agent_id, texture, content, created_at, retracted_at, retraction_reason) in rows {
             let cognition = Cognition::builder()
                 .id(id.parse()?)
                 .agent_id(agent_id.parse()?)
                 .texture(texture)
                 .content(content)
                 .created_at(Timestamp::parse_str(&created_at)?)
+                .maybe_retraction(CognitionStore::retraction(retracted_at, retraction_reason)?)
                 .build();
             by_id.insert(cognition.id, cognition);
         }
@@ -95,6 +95,7 @@ impl CognitionService {
             .kind(SearchKind::Cognition)
             .maybe_texture(listing.texture.clone())
             .maybe_query(listing.query.clone())
+            .include_retracted(listing.include_retracted)
             .filters(listing.filters)
             .build();
 
@@ -124,4 +125,63 @@ impl CognitionService {
                 .into(),
         ))
     }
+
+    /// Withdraw a thought that no longer holds. The original stays in
+    /// the event log; the projection marks it retracted and hides it
+    /// from listings, search and dreams.
+    pub(crate) async fn retract(
+        scope: &Scope<AtBookmark>,
+        mailbox: &Mailbox,
+        request: &RetractCognition,
+    ) -> Result<CognitionResponse, CognitionError> {
+        let RetractCognition::V1(retraction) = request;
+        let cognition = CognitionRepo::new(scope)
+            .fetch(&retraction.id)
+            .await?
+            .ok_or(CognitionError::NotFound(retraction.id))?;
+
+        if cognition.is_retracted() {
+            return Err(CognitionError::AlreadyRetracted(cognition.id));
+        }
+
+        let new_event = NewEvent::builder()
+            .data(Events::Cognition(CognitionEvents::CognitionRetracted(
+                CognitionRetracted::builder_v1()
+                    .id(cognition.id)
+                    .retraction(
+                        Retraction::builder()
+                            .reason(retraction.reason.clone())
+                            .build(),
+                    )
+                    .build()
+                    .into(),
+            )))
+            .build();
+
+        mailbox.tell(ProjectMessage::from(
+            AppendProjectLog::builder()
+                .scope(scope.clone())
+                .event(new_event)
+                .build(),
+        ));
+
+        let retracted = scope
+            .config()
+            .fetch
+            .eventual(|| async {
+                CognitionRepo::new(scope)
+                    .get(&retraction.id)
+                    .await
+                    .map(|found| found.filter(Cognition::is_retracted))
+            })
+            .await?
+            .ok_or(CognitionError::NotFound(retraction.id))?;
+
+        Ok(CognitionResponse::CognitionRetracted(
+            CognitionRetractedResponse::builder_v1()
+                .cognition(retracted)
+                .build()
+                .into(),
+        ))
+    }
 }
@@ -20,6 +20,19 @@ impl<'a> CognitionStore<'a> {
                     self.write_cognition(&cognition)?;
                     SearchStore::new(self.conn).index_entry(&IndexEntry::cognition(&cognition))?;
                 }
+                CognitionEvents::CognitionRetracted(retracted) => {
+                    let current = retracted.current()?;
+                    self.conn.execute(
+                        "UPDATE cognitions SET retracted_at = ?2, retraction_reason = ?3
+                         WHERE id = ?1",
+                        params![
+                            current.id.to_string(),
+                            current.retraction.retracted_at.as_string(),
+                            current.retraction.reason.to_string(),
+                        ],
+                    )?;
+                    SearchStore::new(self.conn).retract(&Ref::cognition(current.id))?;
+                }
             }
         }
         Ok(())
@@ -40,12 +53,34 @@ impl<'a> CognitionStore<'a> {
                 created_at TEXT NOT NULL DEFAULT ''
             )",
         )?;
+        // Added with retraction; older projections lack them.
+        for column in ["retracted_at TEXT", "retraction_reason TEXT"] {
+            self.conn
+                .execute(&format!("ALTER TABLE cognitions ADD COLUMN {column}"), [])
+                .ok();
+        }
         Ok(())
     }
 
+    /// Reassemble a retraction from its projection columns.
+    pub(crate) fn retraction(
+        retracted_at: Option<String>,
+        reason: Option<String>,
+    ) -> Result<Option<Retraction>, EventError> {
+        let Some(retracted_at) = retracted_at else {
+            return Ok(None);
+        };
+        Ok(Some(
+            Retraction::builder()
+                .reason(reason.unwrap_or_default())
+                .retracted_at(Timestamp::parse_str(&retracted_at)?)
+                .build(),
+        ))
+    }
+
     pub(crate) fn get(&self, id: &CognitionId) -> Result<Option<Cognition>, EventError> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, agent_id, texture, content, created_at
+            "SELECT id, agent_id, texture, content, created_at, retracted_at, retraction_reason
              FROM cognitions WHERE id = ?1",
         )?;
 
@@ -56,19 +91,27 @@ impl<'a> CognitionStore<'a> {
                 row.get::<_, String>(2)?,
                 row.get::<_, String>(3)?,
                 row.get::<_, String>(4)?,
+                row.get::<_, Option<String>>(5)?,
+                row.get::<_, Option<String>>(6)?,
             ))
         });
 
         match result {
-            Ok((id, agent_id, texture, content, created_at)) => Ok(Some(
-                Cognition::builder()
-                    .id(id.parse()?)
-                    .agent_id(agent_id.parse()?)
-                    .texture(texture)
-                    .content(content)
-                    .created_at(Timestamp::parse_str(&created_at)?)
-                    .build(),
-            )),
+            Ok((id, agent_id, texture, content, created_at, retracted_at, retraction_reason)) => {
+                Ok(Some(
+                    Cognition::builder()
+                        .id(id.parse()?)
+                        .agent_id(agent_id.parse()?)
+                        .texture(texture)
+                        .content(content)
+                        .created_at(Timestamp::parse_str(&created_at)?)
+                        .maybe_retraction(CognitionStore::retraction(
+                            retracted_at,
+                            retraction_reason,
+                        )?)
+                        .build(),
+                ))
+            }
             Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
             Err(e) => Err(e.into()),
         }
@@ -81,25 +124,25 @@ impl<'a> CognitionStore<'a> {
     ) -> Result<Vec<Cognition>, EventError> {
         let sql = match (agent, texture) {
             (Some(_), Some(_)) => {
-                "SELECT id, agent_id, texture, content, created_at
+                "SELECT id, agent_id, texture, content, created_at, retracted_at, retraction_reason
                  FROM cognitions
                  WHERE agent_id = ?1 AND texture = ?2
                  ORDER BY created_at"
             }
             (Some(_), None) => {
-                "SELECT id, agent_id, texture, content, created_at
+                "SELECT id, agent_id, texture, content, created_at, retracted_at, retraction_reason
                  FROM cognitions
                  WHERE agent_id = ?1
                  ORDER BY created_at"
             }
             (None, Some(_)) => {
-                "SELECT id, agent_id, texture, content, created_at
+                "SELECT id, agent_id, texture, content, created_at, retracted_at, retraction_reason
                  FROM cognitions
                  WHERE texture = ?1
                  ORDER BY created_at"
             }
             (None, None) => {
-                "SELECT id, agent_id, texture, content, created_at
+                "SELECT id, agent_id, texture, content, created_at, retracted_at, retraction_reason
                  FROM cognitions
                  ORDER BY created_at"
             }
@@ -114,6 +157,8 @@ impl<'a> CognitionStore<'a> {
                 row.get::<_, String>(2)?,
                 row.get::<_, String>(3)?,
                 row.get::<_, String>(4)?,
+                row.get::<_, Option<String>>(5)?,
+                row.get::<_, Option<String>>(6)?,
             ))
         };
 
@@ -126,7 +171,7 @@ impl<'a> CognitionStore<'a> {
         .collect::<Result<Vec<_>, _>>()?;
 
         let mut cognitions = vec![];
-        for (id, agent_id, texture, content, created_at) in raw {
+        for (id, agent_id, texture, content, created_at, retracted_at, retraction_reason) in raw {
             cognitions.push(
                 Cognition::builder()
                     .id(id.parse()?)
@@ -134,6 +179,7 @@ impl<'a> CognitionStore<'a> {
                     .texture(texture)
                     .content(content)
                     .created_at(Timestamp::parse_str(&created_at)?)
+                    .maybe_retraction(CognitionStore::retraction(retracted_at, retraction_reason)?)
                     .build(),
             );
         }
@@ -141,16 +187,16 @@ impl<'a> CognitionStore<'a> {
         Ok(cognitions)
     }
 
-    /// Most recent cognitions for an agent, ordered newest-first.
+    /// Most recent unretracted cognitions for an agent, ordered newest-first.
     pub(crate) fn list_recent(
         &self,
         agent_id: &AgentId,
         limit: usize,
     ) -> Result<Vec<Cognition>, EventError> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, agent_id, texture, content, created_at
+            "SELECT id, agent_id, texture, content, created_at, retracted_at, retraction_reason
              FROM cognitions
-             WHERE agent_id = ?1
+             WHERE agent_id = ?1 AND retracted_at IS NULL
              ORDER BY created_at DESC
              LIMIT ?2",
         )?;
@@ -162,6 +208,8 @@ impl<'a> CognitionStore<'a> {
                 row.get::<_, String>(2)?,
                 row.get::<_, String>(3)?,
                 row.get::<_, String>(4)?,
+                row.get::<_, Option<String>>(5)?,
+                row.get::<_, Option<String>>(6)?,
             ))
         };
 
@@ -170,7 +218,7 @@ impl<'a> CognitionStore<'a> {
             .collect::<Result<Vec<_>, _>>()?;
 
         let mut cognitions = vec![];
-        for (id, agent_id, texture, content, created_at) in raw {
+        for (id, agent_id, texture, content, created_at, retracted_at, retraction_reason) in raw {
             cognitions.push(
                 Cognition::builder()
                     .id(id.parse()?)
@@ -178,6 +226,7 @@ impl<'a> CognitionStore<'a> {
                     .texture(texture)
                     .content(content)
                     .created_at(Timestamp::parse_str(&created_at)?)
+                    .maybe_retraction(CognitionStore::retraction(retracted_at, retraction_reason)?)
                     .build(),
             );
         }
@@ -187,14 +236,19 @@ impl<'a> CognitionStore<'a> {
 
     fn write_cognition(&self, cognition: &Cognition) -> Result<(), EventError> {
         self.conn.execute(
-            "INSERT OR REPLACE INTO cognitions (id, agent_id, texture, content, created_at)
-             VALUES (?1, ?2, ?3, ?4, ?5)",
+            "INSERT OR REPLACE INTO cognitions (id, agent_id, texture, content, created_at, retracted_at, retraction_reason)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
             params![
                 cognition.id.to_string(),
                 cognition.agent_id.to_string(),
                 cognition.texture.to_string(),
                 cognition.content.to_string(),
                 cognition.created_at.as_string(),
+                cognition
+                    .retraction
+                    .as_ref()
+                    .map(|r| r.retracted_at.as_string()),
+                cognition.retraction.as_ref().map(|r| r.reason.to_string()),
             ],
         )?;
         Ok(())
@@ -25,10 +25,33 @@ impl<'a> CognitionView<'a> {
                         .build(),
                 ))
             }
+            CognitionResponse::CognitionRetracted(CognitionRetractedResponse::V1(retracted)) => {
+                let ref_token = RefToken::from(Ref::cognition(retracted.cognition.id));
+                let reason = retracted
+                    .cognition
+                    .retraction
+                    .as_ref()
+                    .map(|retraction| retraction.reason.to_string())
+                    .unwrap_or_default();
+                McpResponse::new(format!(
+                    "Thought retracted ({ref_token}).\n\n**reason:** {reason}"
+                ))
+                .hint(Hint::suggest(
+                    "add-cognition",
+                    "Record what you think now instead",
+                ))
+            }
             CognitionResponse::CognitionDetails(CognitionDetailsResponse::V1(details)) => {
                 let ref_token = RefToken::from(Ref::cognition(details.cognition.id));
+                let status = match &details.cognition.retraction {
+                    Some(retraction) => format!(
+                        "**retracted:** {} — {}\n",
+                        retraction.retracted_at, retraction.reason
+                    ),
+                    None => String::new(),
+                };
                 McpResponse::new(format!(
-                    "# Cognition\n\n**texture:** {}\n**agent:** {}\n**created:** {}\n\n{}\n",
+                    "# Cognition\n\n**texture:** {}\n**agent:** {}\n**created:** {}\n{status}\n{}\n",
                     details.cognition.texture,
                     details.cognition.agent_id,
                     details.cognition.created_at,
@@ -104,10 +127,32 @@ impl<'a> CognitionView<'a> {
                 )
                 .with_hints(hints)
             }
+            (
+                CognitionResponse::CognitionRetracted(CognitionRetractedResponse::V1(retracted)),
+                _,
+            ) => {
+                let ref_token = RefToken::from(Ref::cognition(retracted.cognition.id));
+                let subject = format!(
+                    "{} Cognition retracted: {}",
+                    "✓".success(),
+                    ref_token.muted()
+                );
+
+                Rendered::new(
+                    CognitionResponse::CognitionRetracted(CognitionRetractedResponse::V1(
+                        retracted,
+                    )),
+                    subject,
+                    String::new(),
+                )
+            }
             (CognitionResponse::CognitionDetails(CognitionDetailsResponse::V1(details)), _) => {
-                let prompt = Detail::new(details.cognition.texture.to_string())
-                    .field("content:", details.cognition.content.to_string())
-                    .to_string();
+                let mut detail = Detail::new(details.cognition.texture.to_string())
+                    .field("content:", details.cognition.content.to_string());
+                if let Some(retraction) = &details.cognition.retraction {
+                    detail = detail.field("retracted:", retraction.reason.to_string());
+                }
+                let prompt = detail.to_string();
                 let ref_token = RefToken::from(Ref::cognition(details.cognition.id));
                 let hints =
                     HintSet::mutation(MutationHints::builder().ref_token(ref_token).build());
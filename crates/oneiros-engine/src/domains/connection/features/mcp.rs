@@ -40,6 +40,7 @@ mod connection_mcp {
             Tool::<CreateConnection>::new(
                 ConnectionRequestType::CreateConnection,
                 "Draw a line between two related things",
+                PermissionOp::ProjectWrite,
             )
             .def(),
         ]
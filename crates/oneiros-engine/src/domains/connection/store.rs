@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use rusqlite::params;
 
 use crate::*;
@@ -46,6 +48,27 @@ impl<'a> ConnectionStore<'a> {
         Ok(())
     }
 
+    /// How many connections touch each entity, keyed by its serialized
+    /// ref — one query for what `list(Some(..)).len()` would count per
+    /// entity. A connection from an entity to itself counts once.
+    pub(crate) fn degrees(&self) -> Result<HashMap<String, usize>, EventError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT entity_ref, COUNT(*) FROM (
+                SELECT from_ref AS entity_ref FROM connections
+                UNION ALL
+                SELECT to_ref FROM connections WHERE to_ref != from_ref
+             ) GROUP BY entity_ref",
+        )?;
+
+        let degrees = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, usize>(1)?))
+            })?
+            .collect::<Result<_, _>>()?;
+
+        Ok(degrees)
+    }
+
     pub(crate) fn list(&self, entity_ref: Option<&str>) -> Result<Vec<Connection>, EventError> {
         let mut stmt = match entity_ref {
             Some(_) => self.conn.prepare(
@@ -104,13 +104,23 @@ impl ContinuityRouter {
 }
 
 async fn emerge(
+    axum::extract::State(state): axum::extract::State<ServerState>,
     scope: Scope<AtBookmark>,
     mailbox: Mailbox,
     Json(body): Json<EmergeAgent>,
 ) -> Result<(StatusCode, Json<ContinuityResponse>), ContinuityError> {
     Ok((
         StatusCode::CREATED,
-        Json(ContinuityService::emerge(&scope, &mailbox, &body, &DreamOverrides::default()).await?),
+        Json(
+            ContinuityService::emerge(
+                &scope,
+                &mailbox,
+                state.canons(),
+                &body,
+                &DreamOverrides::default(),
+            )
+            .await?,
+        ),
     ))
 }
 
@@ -137,6 +147,7 @@ async fn status(
 }
 
 async fn wake(
+    axum::extract::State(state): axum::extract::State<ServerState>,
     scope: Scope<AtBookmark>,
     mailbox: Mailbox,
     Path(agent): Path<AgentName>,
@@ -146,6 +157,7 @@ async fn wake(
         ContinuityService::wake(
             &scope,
             &mailbox,
+            state.canons(),
             &WakeAgent::builder_v1().agent(agent).build().into(),
             &overrides,
         )
@@ -154,6 +166,7 @@ async fn wake(
 }
 
 async fn dream(
+    axum::extract::State(state): axum::extract::State<ServerState>,
     scope: Scope<AtBookmark>,
     mailbox: Mailbox,
     Path(agent): Path<AgentName>,
@@ -163,6 +176,7 @@ async fn dream(
         ContinuityService::dream(
             &scope,
             &mailbox,
+            state.canons(),
             &DreamAgent::builder_v1().agent(agent).build().into(),
             &overrides,
         )
@@ -171,6 +185,7 @@ async fn dream(
 }
 
 async fn introspect(
+    axum::extract::State(state): axum::extract::State<ServerState>,
     scope: Scope<AtBookmark>,
     mailbox: Mailbox,
     Path(agent): Path<AgentName>,
@@ -180,6 +195,7 @@ async fn introspect(
         ContinuityService::introspect(
             &scope,
             &mailbox,
+            state.canons(),
             &IntrospectAgent::builder_v1().agent(agent).build().into(),
             &overrides,
         )
@@ -188,6 +204,7 @@ async fn introspect(
 }
 
 async fn reflect(
+    axum::extract::State(state): axum::extract::State<ServerState>,
     scope: Scope<AtBookmark>,
     mailbox: Mailbox,
     Path(agent): Path<AgentName>,
@@ -197,6 +214,7 @@ async fn reflect(
         ContinuityService::reflect(
             &scope,
             &mailbox,
+            state.canons(),
             &ReflectAgent::builder_v1().agent(agent).build().into(),
             &overrides,
         )
@@ -205,6 +223,7 @@ async fn reflect(
 }
 
 async fn sense(
+    axum::extract::State(state): axum::extract::State<ServerState>,
     scope: Scope<AtBookmark>,
     mailbox: Mailbox,
     Path(agent): Path<AgentName>,
@@ -217,11 +236,19 @@ async fn sense(
         .build()
         .into();
     Ok(Json(
-        ContinuityService::sense(&scope, &mailbox, &request, &DreamOverrides::default()).await?,
+        ContinuityService::sense(
+            &scope,
+            &mailbox,
+            state.canons(),
+            &request,
+            &DreamOverrides::default(),
+        )
+        .await?,
     ))
 }
 
 async fn sleep(
+    axum::extract::State(state): axum::extract::State<ServerState>,
     scope: Scope<AtBookmark>,
     mailbox: Mailbox,
     Path(agent): Path<AgentName>,
@@ -231,6 +258,7 @@ async fn sleep(
         ContinuityService::sleep(
             &scope,
             &mailbox,
+            state.canons(),
             &SleepAgent::builder_v1().agent(agent).build().into(),
             &overrides,
         )
@@ -239,6 +267,7 @@ async fn sleep(
 }
 
 async fn guidebook(
+    axum::extract::State(state): axum::extract::State<ServerState>,
     scope: Scope<AtBookmark>,
     Path(agent): Path<AgentName>,
     Query(overrides): Query<DreamOverrides>,
@@ -246,6 +275,7 @@ async fn guidebook(
     Ok(Json(
         ContinuityService::guidebook(
             &scope,
+            state.canons(),
             &GuidebookAgent::builder_v1().agent(agent).build().into(),
             &overrides,
         )
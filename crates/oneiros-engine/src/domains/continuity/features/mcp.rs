@@ -3,6 +3,21 @@ use crate::*;
 pub(crate) struct ContinuityMcp;
 
 impl ContinuityMcp {
+    pub(crate) fn defs(&self) -> Vec<ToolDef> {
+        continuity_mcp::tool_defs()
+    }
+
+    #[expect(deprecated)]
+    pub(crate) async fn dispatch(
+        &self,
+        context: &ProjectLog,
+        mailbox: &Mailbox,
+        tool_name: &ToolName,
+        params: &serde_json::Value,
+    ) -> Result<McpResponse, ToolError> {
+        continuity_mcp::dispatch(context, mailbox, tool_name, params).await
+    }
+
     pub(crate) fn resources(&self) -> Vec<ResourceDef> {
         vec![ResourcePathKind::Status.resource_def("Cross-agent activity table")]
     }
@@ -21,6 +36,93 @@ impl ContinuityMcp {
 mod continuity_mcp {
     use crate::*;
 
+    /// The session lifecycle. Dreaming stays a prompt, and emerging or
+    /// receding an agent stays a human decision.
+    pub(crate) fn tool_defs() -> Vec<ToolDef> {
+        vec![
+            Tool::<WakeAgent>::new(
+                ContinuityRequestType::WakeAgent,
+                "Start a session — restore your identity and cognitive context",
+                PermissionOp::ProjectWrite,
+            )
+            .def(),
+            Tool::<IntrospectAgent>::new(
+                ContinuityRequestType::IntrospectAgent,
+                "Look inward before context compacts",
+                PermissionOp::ProjectWrite,
+            )
+            .def(),
+            Tool::<ReflectAgent>::new(
+                ContinuityRequestType::ReflectAgent,
+                "Pause on something significant",
+                PermissionOp::ProjectWrite,
+            )
+            .def(),
+            Tool::<SenseContent>::new(
+                ContinuityRequestType::SenseContent,
+                "Take in something observed from outside",
+                PermissionOp::ProjectWrite,
+            )
+            .def(),
+            Tool::<SleepAgent>::new(
+                ContinuityRequestType::SleepAgent,
+                "End a session and capture continuity",
+                PermissionOp::ProjectWrite,
+            )
+            .def(),
+        ]
+    }
+
+    #[expect(deprecated)]
+    pub(crate) async fn dispatch(
+        context: &ProjectLog,
+        mailbox: &Mailbox,
+        tool_name: &ToolName,
+        params: &serde_json::Value,
+    ) -> Result<McpResponse, ToolError> {
+        let request_type: ContinuityRequestType = tool_name
+            .as_str()
+            .parse()
+            .map_err(|_| ToolError::UnknownTool(tool_name.to_string()))?;
+
+        let scope = context.scope().map_err(Error::from)?;
+        let canons = context.canons();
+        let overrides = DreamOverrides::default();
+
+        let response = match request_type {
+            ContinuityRequestType::WakeAgent => {
+                let waking: WakeAgent = serde_json::from_value(params.clone())?;
+                let WakeAgent::V1(details) = &waking;
+                let overrides = DreamOverrides {
+                    focus: details.focus.clone(),
+                    ..overrides
+                };
+                ContinuityService::wake(scope, mailbox, canons, &waking, &overrides).await
+            }
+            ContinuityRequestType::IntrospectAgent => {
+                let introspecting: IntrospectAgent = serde_json::from_value(params.clone())?;
+                ContinuityService::introspect(scope, mailbox, canons, &introspecting, &overrides)
+                    .await
+            }
+            ContinuityRequestType::ReflectAgent => {
+                let reflecting: ReflectAgent = serde_json::from_value(params.clone())?;
+                ContinuityService::reflect(scope, mailbox, canons, &reflecting, &overrides).await
+            }
+            ContinuityRequestType::SenseContent => {
+                let sensing: SenseContent = serde_json::from_value(params.clone())?;
+                ContinuityService::sense(scope, mailbox, canons, &sensing, &overrides).await
+            }
+            ContinuityRequestType::SleepAgent => {
+                let sleeping: SleepAgent = serde_json::from_value(params.clone())?;
+                ContinuityService::sleep(scope, mailbox, canons, &sleeping, &overrides).await
+            }
+            _ => return Err(ToolError::UnknownTool(tool_name.to_string())),
+        }
+        .map_err(Error::from)?;
+
+        Ok(ContinuityView::new(response).mcp())
+    }
+
     pub(crate) async fn resource(
         scope: &Scope<AtBookmark>,
         request: &ContinuityRequest,
@@ -1,11 +1,13 @@
 mod cli;
 mod http;
 mod mcp;
+mod projections;
 mod skills;
 mod template;
 
 pub(crate) use cli::*;
 pub(crate) use http::*;
 pub(crate) use mcp::*;
+pub(crate) use projections::*;
 pub(crate) use skills::*;
 pub(crate) use template::*;
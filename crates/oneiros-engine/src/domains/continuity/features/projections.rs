@@ -0,0 +1,16 @@
+use crate::*;
+
+pub(crate) struct ContinuityProjections;
+
+impl ContinuityProjections {
+    pub(crate) const fn all(&self) -> &'static [Projection] {
+        PROJECTIONS
+    }
+}
+
+const PROJECTIONS: &[Projection] = &[Projection {
+    name: "continuity",
+    migrate: |conn| ContinuityStore::new(conn).migrate(),
+    apply: |conn, event| ContinuityStore::new(conn).handle(event),
+    reset: |conn| ContinuityStore::new(conn).reset(),
+}];
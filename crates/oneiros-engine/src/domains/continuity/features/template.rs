@@ -30,10 +30,11 @@ impl<'a> DreamTemplate<'a> {
     }
 
     pub(crate) fn core_memories(&self) -> Vec<&Memory> {
+        let ranks = LevelRanks::new(&self.context.levels);
         self.context
             .memories
             .iter()
-            .filter(|m| m.level.as_str() == "core")
+            .filter(|m| ranks.is_core(&m.level))
             .collect()
     }
 
@@ -49,6 +50,11 @@ impl<'a> DreamTemplate<'a> {
         Self::tail(&self.context.connections, LATEST_LIMIT)
     }
 
+    /// The newest few of a since-last list.
+    pub(crate) fn latest_of<'b, T>(&self, items: &'b [T]) -> Vec<&'b T> {
+        Self::tail(items, LATEST_LIMIT)
+    }
+
     fn tail<T>(items: &[T], limit: usize) -> Vec<&T> {
         let len = items.len();
         let start = len.saturating_sub(limit);
@@ -2,13 +2,16 @@
 //!
 //! These are NOT standalone domains with repos. They compose other domains'
 //! services into higher-level operations: dream, introspect, reflect, sense, sleep.
-//! Each produces continuity events and returns composed results.
+//! Each produces continuity events and returns composed results. The one
+//! projection they keep is each agent's last dream receipt, for
+//! `--since-last`.
 
 mod docs;
 mod features;
 mod presenter;
 mod protocol;
 mod service;
+mod store;
 mod view;
 
 pub(crate) use docs::*;
@@ -16,4 +19,5 @@ pub(crate) use features::*;
 pub(crate) use presenter::*;
 pub(crate) use protocol::*;
 pub(crate) use service::*;
+pub(crate) use store::*;
 pub(crate) use view::*;
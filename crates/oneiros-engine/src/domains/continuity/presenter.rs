@@ -35,13 +35,22 @@ impl ContinuityPresenter {
                     ))
                     .hint(Hint::inspect(ResourcePath::Agents.uri(), "View all agents"))
             }
-            _ => McpResponse::new(String::new()),
+            _ => {
+                let (prompt, _, hints) = self.forms();
+                McpResponse::new(prompt).hint_set(hints)
+            }
         }
     }
 
     /// Render this continuity response into all available forms.
     pub(crate) fn render(self) -> Rendered<ContinuityResponse> {
-        let (prompt, text, hints) = match &self.response {
+        let (prompt, text, hints) = self.forms();
+        Rendered::new(self.response, prompt, text).with_hints(hints)
+    }
+
+    /// The prompt, text and hints for this response.
+    fn forms(&self) -> (String, String, HintSet) {
+        match &self.response {
             ContinuityResponse::Dreaming(DreamingResponse::V1(details)) => {
                 let context = &details.context;
                 let template = DreamTemplate::new(context).to_string();
@@ -130,9 +139,7 @@ impl ContinuityPresenter {
                 format!("Agent {} has receded.", details.agent),
                 HintSet::None,
             ),
-        };
-
-        Rendered::new(self.response, prompt, text).with_hints(hints)
+        }
     }
 
     fn relevant_pressures(context: &DreamContext) -> RelevantPressures {
@@ -12,6 +12,9 @@ pub(crate) enum ContinuityError {
     #[error(transparent)]
     Agent(#[from] AgentError),
 
+    #[error("Dream recipe failed: {0}")]
+    Lens(#[from] LensError),
+
     #[error("Database error: {0}")]
     Database(#[from] rusqlite::Error),
 
@@ -41,6 +44,7 @@ impl IntoResponse for ContinuityError {
         let (status, message) = match &self {
             ContinuityError::AgentNotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
             ContinuityError::Agent(_) => (StatusCode::UNPROCESSABLE_ENTITY, self.to_string()),
+            ContinuityError::Lens(_) => (StatusCode::UNPROCESSABLE_ENTITY, self.to_string()),
             ContinuityError::Database(_)
             | ContinuityError::Event(_)
             | ContinuityError::BookmarkDb(_) => {
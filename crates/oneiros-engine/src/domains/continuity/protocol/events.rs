@@ -14,12 +14,52 @@ pub(crate) enum ContinuityEvents {
     Slept(Slept),
 }
 
+impl ContinuityEvents {
+    /// The agent the lifecycle event happened to.
+    pub(crate) fn agent(&self) -> &AgentName {
+        match self {
+            ContinuityEvents::Dreamed(Dreamed::V2(event)) => &event.agent,
+            ContinuityEvents::Dreamed(Dreamed::V1(event)) => &event.agent,
+            ContinuityEvents::Introspected(Introspected::V1(event)) => &event.agent,
+            ContinuityEvents::Reflected(Reflected::V1(event)) => &event.agent,
+            ContinuityEvents::Sensed(Sensed::V1(event)) => &event.agent,
+            ContinuityEvents::Slept(Slept::V1(event)) => &event.agent,
+        }
+    }
+}
+
 versioned! {
     pub(crate) enum Dreamed {
+        V2 => {
+            #[builder(into)] pub(crate) agent: AgentName,
+            pub(crate) created_at: Timestamp,
+            /// The memories the dream surfaced.
+            #[builder(default)] pub(crate) memories: Vec<MemoryId>,
+            /// The pressures the dream reported.
+            #[builder(default)] pub(crate) pressures: Vec<PressureSummary>,
+        },
         V1 => {
             #[builder(into)] pub(crate) agent: AgentName,
             pub(crate) created_at: Timestamp,
-        }
+        },
+    }
+}
+
+impl TryFrom<DreamedV1> for DreamedV2 {
+    type Error = UpcastError;
+    fn try_from(v1: DreamedV1) -> Result<Self, Self::Error> {
+        Ok(DreamedV2 {
+            agent: v1.agent,
+            created_at: v1.created_at,
+            memories: vec![],
+            pressures: vec![],
+        })
+    }
+}
+
+impl From<DreamedV1> for Dreamed {
+    fn from(v: DreamedV1) -> Self {
+        Self::V1(v)
     }
 }
 
@@ -77,4 +117,26 @@ mod tests {
             assert_eq!(&event_type.to_string(), expectation);
         }
     }
+
+    #[test]
+    fn legacy_dreamed_upcasts_with_nothing_surfaced() {
+        let json = serde_json::json!({
+            "type": "dreamed",
+            "data": {
+                "agent": "thinker.process",
+                "created_at": Timestamp::now()
+            }
+        });
+        let event: ContinuityEvents = serde_json::from_value(json).expect("legacy decode");
+        let dreamed = match event {
+            ContinuityEvents::Dreamed(inner) => {
+                assert!(matches!(inner, Dreamed::V1(_)));
+                inner.current().unwrap()
+            }
+            other => panic!("expected Dreamed, got {other:?}"),
+        };
+        assert_eq!(dreamed.agent.as_str(), "thinker.process");
+        assert!(dreamed.memories.is_empty());
+        assert!(dreamed.pressures.is_empty());
+    }
 }
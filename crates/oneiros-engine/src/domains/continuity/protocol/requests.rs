@@ -10,6 +10,11 @@ versioned! {
         #[derive(clap::Args)]
         V1 => {
             #[builder(into)] pub(crate) agent: AgentName,
+            /// A topic to weigh memory recall toward.
+            #[arg(long)]
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            #[builder(into)]
+            pub(crate) focus: Option<String>,
         }
     }
 }
@@ -20,6 +25,16 @@ versioned! {
         #[derive(clap::Args)]
         V1 => {
             #[builder(into)] pub(crate) agent: AgentName,
+            /// A topic to weigh memory recall toward.
+            #[arg(long)]
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            #[builder(into)]
+            pub(crate) focus: Option<String>,
+            /// Lead with what changed since the agent last dreamed.
+            #[arg(long)]
+            #[serde(default)]
+            #[builder(default)]
+            pub(crate) since_last: bool,
         }
     }
 }
@@ -131,22 +146,39 @@ fn encode_dream_overrides(overrides: &DreamOverrides) -> String {
     if let Some(value) = overrides.experience_size {
         parts.push(format!("experience_size={value}"));
     }
+    if let Some(value) = overrides.dream_budget {
+        parts.push(format!("dream_budget={value}"));
+    }
+    if let Some(value) = &overrides.focus {
+        parts.push(format!("focus={value}"));
+    }
+    if let Some(value) = overrides.since_last {
+        parts.push(format!("since_last={value}"));
+    }
     parts.join("&")
 }
 
 resource_requests! {
     WakeAgent => |this, client| {
         let WakeAgent::V1(wake) = this;
-        client
-            .post(
-                &format!("/continuity/{agent}/wake", agent = wake.agent),
-                &serde_json::Value::Null,
-            )
-            .await
+        let query = encode_dream_overrides(&DreamOverrides {
+            focus: wake.focus.clone(),
+            ..Default::default()
+        });
+        let path = if query.is_empty() {
+            format!("/continuity/{agent}/wake", agent = wake.agent)
+        } else {
+            format!("/continuity/{agent}/wake?{query}", agent = wake.agent)
+        };
+        client.post(&path, &serde_json::Value::Null).await
     },
     DreamAgent => |this, client| {
         let DreamAgent::V1(dream) = this;
-        let query = encode_dream_overrides(&DreamOverrides::default());
+        let query = encode_dream_overrides(&DreamOverrides {
+            focus: dream.focus.clone(),
+            since_last: dream.since_last.then_some(true),
+            ..Default::default()
+        });
         let path = if query.is_empty() {
             format!("/continuity/{agent}/dream", agent = dream.agent)
         } else {
@@ -3,28 +3,35 @@
 //! These operations don't have their own repos. They gather data from
 //! multiple domains, perform the operation, and emit continuity events.
 
-use std::collections::{HashSet, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 use crate::*;
 
-fn level_priority(name: &LevelName) -> usize {
-    match name.as_str() {
-        "core" => 5,
-        "working" => 4,
-        "session" => 3,
-        "project" => 2,
-        "archival" => 1,
-        _ => 0,
-    }
-}
+/// How many search hits a dream's focus ranks before the rest count as
+/// off-topic.
+const FOCUS_CANDIDATES: usize = 200;
 
 pub(crate) struct ContinuityService;
 
+/// The dream's selected entities, before vocabulary and pressures join
+/// them. `depths` ranks each ref for the dream budget — BFS depth for the
+/// default recipe, section index for a persona's own. `recall` explains
+/// the default recipe's memory picks; a persona's recipe leaves it empty.
+struct Assembled {
+    memories: Vec<Memory>,
+    cognitions: Vec<Cognition>,
+    experiences: Vec<Experience>,
+    connections: Vec<Connection>,
+    depths: HashMap<Ref, usize>,
+    recall: Vec<RecallScore>,
+}
+
 impl ContinuityService {
     /// Emerge — create an agent and immediately activate its continuity.
     pub(crate) async fn emerge(
         scope: &Scope<AtBookmark>,
         mailbox: &Mailbox,
+        canons: &CanonIndex,
         request: &EmergeAgent,
         overrides: &DreamOverrides,
     ) -> Result<ContinuityResponse, ContinuityError> {
@@ -55,6 +62,7 @@ impl ContinuityService {
         Self::wake(
             scope,
             mailbox,
+            canons,
             &WakeAgent::builder_v1()
                 .agent(created_agent.name.clone())
                 .build()
@@ -62,7 +70,7 @@ impl ContinuityService {
             overrides,
         )
         .await?;
-        let dream = Self::gather_context_for(scope, &created_agent, overrides).await?;
+        let dream = Self::gather_context_for(scope, canons, &created_agent, overrides).await?;
         Ok(ContinuityResponse::Emerged(
             EmergedResponse::builder_v1().context(dream).build().into(),
         ))
@@ -170,17 +178,26 @@ impl ContinuityService {
     pub(crate) async fn wake(
         scope: &Scope<AtBookmark>,
         mailbox: &Mailbox,
+        canons: &CanonIndex,
         request: &WakeAgent,
         overrides: &DreamOverrides,
     ) -> Result<ContinuityResponse, ContinuityError> {
         let WakeAgent::V1(wake) = request;
-        let dream = Self::gather_context(scope, &wake.agent, overrides).await?;
+        let dream = Self::gather_context(scope, canons, &wake.agent, overrides).await?;
 
         let new_event = NewEvent::builder()
             .data(Events::Continuity(ContinuityEvents::Dreamed(
-                Dreamed::builder_v1()
+                Dreamed::builder_v2()
                     .agent(wake.agent.clone())
                     .created_at(Timestamp::now())
+                    .memories(dream.memories.iter().map(|m| m.id).collect())
+                    .pressures(
+                        dream
+                            .pressures
+                            .iter()
+                            .map(|r| PressureSummary::from(&r.pressure))
+                            .collect(),
+                    )
                     .build()
                     .into(),
             )))
@@ -202,17 +219,26 @@ impl ContinuityService {
     pub(crate) async fn dream(
         scope: &Scope<AtBookmark>,
         mailbox: &Mailbox,
+        canons: &CanonIndex,
         request: &DreamAgent,
         overrides: &DreamOverrides,
     ) -> Result<ContinuityResponse, ContinuityError> {
         let DreamAgent::V1(dreaming) = request;
-        let dream = Self::gather_context(scope, &dreaming.agent, overrides).await?;
+        let dream = Self::gather_context(scope, canons, &dreaming.agent, overrides).await?;
 
         let new_event = NewEvent::builder()
             .data(Events::Continuity(ContinuityEvents::Dreamed(
-                Dreamed::builder_v1()
+                Dreamed::builder_v2()
                     .agent(dreaming.agent.clone())
                     .created_at(Timestamp::now())
+                    .memories(dream.memories.iter().map(|m| m.id).collect())
+                    .pressures(
+                        dream
+                            .pressures
+                            .iter()
+                            .map(|r| PressureSummary::from(&r.pressure))
+                            .collect(),
+                    )
                     .build()
                     .into(),
             )))
@@ -234,11 +260,12 @@ impl ContinuityService {
     pub(crate) async fn introspect(
         scope: &Scope<AtBookmark>,
         mailbox: &Mailbox,
+        canons: &CanonIndex,
         request: &IntrospectAgent,
         overrides: &DreamOverrides,
     ) -> Result<ContinuityResponse, ContinuityError> {
         let IntrospectAgent::V1(introspecting) = request;
-        let dream = Self::gather_context(scope, &introspecting.agent, overrides).await?;
+        let dream = Self::gather_context(scope, canons, &introspecting.agent, overrides).await?;
 
         let new_event = NewEvent::builder()
             .data(Events::Continuity(ContinuityEvents::Introspected(
@@ -269,11 +296,12 @@ impl ContinuityService {
     pub(crate) async fn reflect(
         scope: &Scope<AtBookmark>,
         mailbox: &Mailbox,
+        canons: &CanonIndex,
         request: &ReflectAgent,
         overrides: &DreamOverrides,
     ) -> Result<ContinuityResponse, ContinuityError> {
         let ReflectAgent::V1(reflecting) = request;
-        let dream = Self::gather_context(scope, &reflecting.agent, overrides).await?;
+        let dream = Self::gather_context(scope, canons, &reflecting.agent, overrides).await?;
 
         let new_event = NewEvent::builder()
             .data(Events::Continuity(ContinuityEvents::Reflected(
@@ -304,11 +332,12 @@ impl ContinuityService {
     pub(crate) async fn sense(
         scope: &Scope<AtBookmark>,
         mailbox: &Mailbox,
+        canons: &CanonIndex,
         request: &SenseContent,
         overrides: &DreamOverrides,
     ) -> Result<ContinuityResponse, ContinuityError> {
         let SenseContent::V1(sensing) = request;
-        let dream = Self::gather_context(scope, &sensing.agent, overrides).await?;
+        let dream = Self::gather_context(scope, canons, &sensing.agent, overrides).await?;
 
         let new_event = NewEvent::builder()
             .data(Events::Continuity(ContinuityEvents::Sensed(
@@ -337,11 +366,12 @@ impl ContinuityService {
     pub(crate) async fn sleep(
         scope: &Scope<AtBookmark>,
         mailbox: &Mailbox,
+        canons: &CanonIndex,
         request: &SleepAgent,
         overrides: &DreamOverrides,
     ) -> Result<ContinuityResponse, ContinuityError> {
         let SleepAgent::V1(sleeping) = request;
-        let dream = Self::gather_context(scope, &sleeping.agent, overrides).await?;
+        let dream = Self::gather_context(scope, canons, &sleeping.agent, overrides).await?;
 
         let new_event = NewEvent::builder()
             .data(Events::Continuity(ContinuityEvents::Slept(
@@ -371,11 +401,12 @@ impl ContinuityService {
     /// sensations, levels, urges) without marking a continuity transition.
     pub(crate) async fn guidebook(
         scope: &Scope<AtBookmark>,
+        canons: &CanonIndex,
         request: &GuidebookAgent,
         overrides: &DreamOverrides,
     ) -> Result<ContinuityResponse, ContinuityError> {
         let GuidebookAgent::V1(lookup) = request;
-        let dream = Self::gather_context(scope, &lookup.agent, overrides).await?;
+        let dream = Self::gather_context(scope, canons, &lookup.agent, overrides).await?;
         Ok(ContinuityResponse::Guidebook(
             GuidebookResponse::builder_v1()
                 .context(dream)
@@ -392,6 +423,7 @@ impl ContinuityService {
     /// [`gather_context_for`]: ContinuityService::gather_context_for
     pub(crate) async fn gather_context(
         scope: &Scope<AtBookmark>,
+        canons: &CanonIndex,
         agent_name: &AgentName,
         overrides: &DreamOverrides,
     ) -> Result<DreamContext, ContinuityError> {
@@ -399,7 +431,7 @@ impl ContinuityService {
             .fetch(agent_name)
             .await?
             .ok_or_else(|| ContinuityError::AgentNotFound(agent_name.clone()))?;
-        Self::gather_context_for(scope, &agent, overrides).await
+        Self::gather_context_for(scope, canons, &agent, overrides).await
     }
 
     /// Gather the full cognitive context for a known agent.
@@ -409,19 +441,38 @@ impl ContinuityService {
     /// to avoid the re-lookup race. Uses Store types directly since we
     /// hold an owned Connection.
     ///
+    /// What the dream surfaces comes from the persona's [`DreamRecipe`]
+    /// when it has one, and from the default recipe otherwise.
+    ///
     /// [`emerge`]: ContinuityService::emerge
     pub(crate) async fn gather_context_for(
         scope: &Scope<AtBookmark>,
+        canons: &CanonIndex,
         agent: &Agent,
         overrides: &DreamOverrides,
     ) -> Result<DreamContext, ContinuityError> {
         let config = scope.config().dream.merge(overrides);
-        let db = BookmarkDb::open(scope).await?;
 
-        let persona_name = agent.persona.clone();
-        let persona = PersonaStore::new(&db).get(&persona_name)?;
+        // Recipe sections run through the lens pipeline, which opens its
+        // own connections — select before ours is held open.
+        let persona = {
+            let db = BookmarkDb::open(scope).await?;
+            PersonaStore::new(&db).get(&agent.persona)?
+        };
+        let selected = match persona.as_ref().and_then(|p| p.recipe.as_ref()) {
+            Some(recipe) if !recipe.is_empty() => {
+                Some(Self::select_recipe(scope, canons, agent, recipe).await?)
+            }
+            _ => None,
+        };
+        let focus = match overrides.focus.as_deref().map(str::trim) {
+            Some(topic) if !topic.is_empty() => {
+                Some(Self::focus_relevance(scope, agent, topic).await?)
+            }
+            _ => None,
+        };
 
-        let agent_id_str = agent.id.to_string();
+        let db = BookmarkDb::open(scope).await?;
 
         // Vocabulary — host-wide
         let textures = TextureStore::new(&db).list()?;
@@ -430,20 +481,210 @@ impl ContinuityService {
         let natures = NatureStore::new(&db).list()?;
         let urges = UrgeStore::new(&db).list()?;
 
+        // With a recipe, the sections decide priority under a budget;
+        // without one, the recent window comes first.
+        let (assembled, recent_window) = match selected {
+            Some(selected) => (Self::assemble_recipe(&db, agent, selected)?, 0),
+            None => (
+                Self::assemble_default(&db, agent, &config, focus.as_ref())?,
+                config.recent_window,
+            ),
+        };
+
+        // Pressure readings paired with urge CTAs
+        let raw_pressures = PressureStore::new(&db).get(&agent.name)?;
+        let pressures = PressureReading::from_pressures_and_urges(raw_pressures, &urges);
+
+        let since_last = if overrides.since_last == Some(true) {
+            Self::since_last(&db, agent, &pressures)?
+        } else {
+            None
+        };
+
+        let context = DreamContext::builder()
+            .agent(agent.clone())
+            .maybe_since_last(since_last.map(Box::new))
+            .maybe_persona(persona)
+            .memories(assembled.memories)
+            .cognitions(assembled.cognitions)
+            .experiences(assembled.experiences)
+            .connections(assembled.connections)
+            .textures(textures)
+            .levels(levels)
+            .sensations(sensations)
+            .natures(natures)
+            .urges(urges)
+            .pressures(pressures)
+            .recall(assembled.recall)
+            .build();
+
+        // Fit to the token budget last, after the count caps have had
+        // their say — the budget only ever removes.
+        let mut context = match config.dream_budget {
+            Some(limit) => DreamBudget::new(limit).fit(context, recent_window, &assembled.depths),
+            None => context,
+        };
+        let kept: HashSet<MemoryId> = context.memories.iter().map(|m| m.id).collect();
+        context.recall.retain(|score| kept.contains(&score.memory));
+        Ok(context)
+    }
+
+    /// What changed since the agent's last dream: its new memories, other
+    /// agents' new experiences, new connections, and moved pressures.
+    /// New means taken into the log after the dream's own event, so what
+    /// was collected or imported since counts however old it is.
+    /// `None` when the agent hasn't dreamed before.
+    fn since_last(
+        db: &rusqlite::Connection,
+        agent: &Agent,
+        pressures: &[PressureReading],
+    ) -> Result<Option<DreamDiff>, ContinuityError> {
+        let Some(receipt) = ContinuityStore::new(db).last_dream(&agent.name)? else {
+            return Ok(None);
+        };
+        let mut diff = DreamDiff::since(&receipt);
+
+        // Receipts from before sequences were kept fall back to time.
+        let arrived = if receipt.sequence > 0 {
+            Some(Self::arrived_after(db, receipt.sequence)?)
+        } else {
+            None
+        };
+        let is_new = |entity: Ref, created_at: Timestamp| match &arrived {
+            Some(arrived) => arrived.contains(&entity),
+            None => created_at > receipt.dreamed_at,
+        };
+
+        diff.memories = MemoryStore::new(db)
+            .list(Some(&agent.id.to_string()))?
+            .into_iter()
+            .filter(|m| is_new(Ref::memory(m.id), m.created_at) && !m.is_superseded())
+            .collect();
+        diff.experiences = ExperienceStore::new(db)
+            .list(None)?
+            .into_iter()
+            .filter(|e| {
+                e.agent_id != agent.id
+                    && is_new(Ref::experience(e.id), e.created_at)
+                    && !e.is_amended()
+            })
+            .collect();
+        diff.connections = ConnectionStore::new(db)
+            .list(None)?
+            .into_iter()
+            .filter(|c| is_new(Ref::connection(c.id), c.created_at))
+            .collect();
+
+        let current: Vec<PressureSummary> = pressures
+            .iter()
+            .map(|r| PressureSummary::from(&r.pressure))
+            .collect();
+        diff.pressures = DreamDiff::pressure_changes(&receipt.pressures, &current);
+
+        Ok(Some(diff))
+    }
+
+    /// The memories, experiences and connections the log took in after
+    /// `sequence` — made here, or collected from a peer.
+    fn arrived_after(
+        db: &rusqlite::Connection,
+        sequence: i64,
+    ) -> Result<HashSet<Ref>, ContinuityError> {
+        let mut arrived = HashSet::new();
+        EventLog::attached(db).each_after(sequence, |event| {
+            let Event::Known(events) = &event.data else {
+                return Ok::<_, EventError>(());
+            };
+            let entity = match events {
+                Events::Memory(MemoryEvents::MemoryAdded(added)) => {
+                    Ref::memory(added.current()?.memory.id)
+                }
+                Events::Memory(MemoryEvents::MemorySuperseded(superseded)) => {
+                    Ref::memory(superseded.current()?.replacement.id)
+                }
+                Events::Experience(ExperienceEvents::ExperienceCreated(created)) => {
+                    Ref::experience(created.current()?.experience.id)
+                }
+                Events::Experience(ExperienceEvents::ExperienceAmended(amended)) => {
+                    Ref::experience(amended.current()?.replacement.id)
+                }
+                Events::Connection(ConnectionEvents::ConnectionCreated(created)) => {
+                    Ref::connection(created.current()?.connection.id)
+                }
+                _ => return Ok(()),
+            };
+            arrived.insert(entity);
+            Ok(())
+        })?;
+        Ok(arrived)
+    }
+
+    /// Rank the agent's memories against a focus topic through the search
+    /// index. The best hit scores 1.0, falling off linearly by rank;
+    /// memories the search didn't return are absent.
+    async fn focus_relevance(
+        scope: &Scope<AtBookmark>,
+        agent: &Agent,
+        topic: &str,
+    ) -> Result<HashMap<MemoryId, f64>, ContinuityError> {
+        // Quote each word so punctuation can't trip FTS5 syntax, and
+        // match any of them — a focus is a topic, not a phrase.
+        let terms: Vec<String> = topic
+            .split_whitespace()
+            .map(|word| format!("\"{}\"", word.replace('"', "\"\"")))
+            .collect();
+        let query = SearchQuery::builder_v1()
+            .kind(SearchKind::Memory)
+            .query(terms.join(" OR "))
+            .filters(SearchFilters {
+                limit: Limit(FOCUS_CANDIDATES),
+                ..Default::default()
+            })
+            .build();
+
+        let results = SearchRepo::new(scope)
+            .search(&query, Some(&agent.id))
+            .await?;
+        let count = results.hits.len() as f64;
+
+        Ok(results
+            .hits
+            .iter()
+            .enumerate()
+            .filter_map(|(rank, hit)| match hit.resource_ref.resource() {
+                Resource::Memory(id) => Some((*id, 1.0 - rank as f64 / count)),
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// The default recipe: core memories, level-filtered memories,
+    /// recent experiences, and a BFS over connections from there.
+    fn assemble_default(
+        db: &rusqlite::Connection,
+        agent: &Agent,
+        config: &DreamConfig,
+        focus: Option<&HashMap<MemoryId, f64>>,
+    ) -> Result<Assembled, ContinuityError> {
+        let agent_id_str = agent.id.to_string();
+
         // Memory filtering — core always included, rest filtered by level + capped
-        let all_memories = MemoryStore::new(&db).list(Some(&agent_id_str))?;
+        let ranks = LevelRanks::new(&LevelStore::new(db).list()?);
+        let all_memories = MemoryStore::new(db).list(Some(&agent_id_str))?;
         let (core_memories, rest_memories): (Vec<_>, Vec<_>) = all_memories
             .into_iter()
-            .partition(|m| m.level.as_str() == "core");
+            .filter(|m| !m.is_archived() && !m.is_superseded())
+            .partition(|m| ranks.is_core(&m.level));
 
-        let filtered_rest = Self::filter_memories(&config, rest_memories);
+        let (filtered_rest, recall) =
+            Self::recall_memories(db, config, &ranks, rest_memories, focus)?;
         let mut memories: Vec<Memory> = core_memories;
         memories.extend(filtered_rest);
         memories.sort_by_key(|a| a.created_at);
 
         // Recent experiences provide orientation
         let recent_experiences =
-            ExperienceStore::new(&db).list_recent(&agent_id_str, config.recent_window)?;
+            ExperienceStore::new(db).list_recent(&agent_id_str, config.recent_window)?;
 
         // BFS seed: filtered memories + recent experiences
         let seed_refs: Vec<Ref> = memories
@@ -454,6 +695,7 @@ impl ContinuityService {
 
         // Graph traversal — BFS from foundation through all connection types.
         let mut visited: HashSet<Ref> = HashSet::new();
+        let mut depths: HashMap<Ref, usize> = seed_refs.iter().map(|r| (r.clone(), 0)).collect();
         let mut frontier: VecDeque<(Ref, usize)> = seed_refs.into_iter().map(|r| (r, 0)).collect();
 
         let mut cognition_ids: HashSet<CognitionId> = HashSet::new();
@@ -468,7 +710,7 @@ impl ContinuityService {
             }
 
             let ref_json = serde_json::to_string(&current).map_err(EventError::from)?;
-            let node_connections = ConnectionStore::new(&db).list(Some(&ref_json))?;
+            let node_connections = ConnectionStore::new(db).list(Some(&ref_json))?;
 
             for conn in node_connections {
                 if !seen_conn_ids.insert(conn.id) {
@@ -486,6 +728,7 @@ impl ContinuityService {
                 let within_depth = neighbor_depth <= config.dream_depth;
 
                 if within_depth {
+                    depths.entry(other.clone()).or_insert(neighbor_depth);
                     match other.resource() {
                         Resource::Cognition(id) => {
                             cognition_ids.insert(*id);
@@ -508,9 +751,13 @@ impl ContinuityService {
         // Decide cognition selection strategy
         let mut cognitions = if connections.is_empty() {
             // No connections means sparse graph — include all cognitions
-            CognitionStore::new(&db).list(Some(&agent.id), None)?
+            CognitionStore::new(db)
+                .list(Some(&agent.id), None)?
+                .into_iter()
+                .filter(|c| !c.is_retracted())
+                .collect()
         } else {
-            Self::assemble_cognitions(&db, agent, &config, &cognition_ids)?
+            Self::assemble_cognitions(db, agent, config, &cognition_ids)?
         };
 
         // Apply cognition_size cap — keep the most recent.
@@ -520,8 +767,7 @@ impl ContinuityService {
         }
 
         // Assemble experiences: recent + graph-discovered, deduped
-        let mut experiences =
-            Self::assemble_experiences(&db, &recent_experiences, &experience_ids)?;
+        let mut experiences = Self::assemble_experiences(db, &recent_experiences, &experience_ids)?;
 
         // Apply experience_size cap — keep the most recent.
         if experiences.len() > config.experience_size {
@@ -529,7 +775,147 @@ impl ContinuityService {
             experiences = experiences.split_off(experiences.len() - config.experience_size);
         }
 
-        // Filter connections: only retain edges whose endpoints are in the result.
+        let connections =
+            Self::prune_connections(connections, &memories, &cognitions, &experiences);
+
+        Ok(Assembled {
+            memories,
+            cognitions,
+            experiences,
+            connections,
+            depths,
+            recall,
+        })
+    }
+
+    /// Evaluate each recipe section through the lens pipeline, with
+    /// `self` bound to the dreaming agent. Returns the selected refs in
+    /// section order, each with the index of the section that chose it;
+    /// a ref claimed by an earlier section doesn't count against a later
+    /// section's cap.
+    async fn select_recipe(
+        scope: &Scope<AtBookmark>,
+        canons: &CanonIndex,
+        agent: &Agent,
+        recipe: &DreamRecipe,
+    ) -> Result<Vec<(Ref, usize)>, ContinuityError> {
+        let mut seen: HashSet<Ref> = HashSet::new();
+        let mut selected = Vec::new();
+
+        for (index, section) in recipe.sections.iter().enumerate() {
+            let hits = LensService::select_as(scope, canons, &section.lens, &agent.name)
+                .await?
+                .sorted_by_timestamp_desc();
+
+            let mut taken = 0;
+            for hit in hits {
+                if taken == section.cap {
+                    break;
+                }
+                let Hit::Entity(EntityHit { entity_ref, .. }) = hit else {
+                    continue;
+                };
+                let dreamable = matches!(
+                    entity_ref.resource(),
+                    Resource::Memory(_) | Resource::Cognition(_) | Resource::Experience(_)
+                );
+                if dreamable && seen.insert(entity_ref.clone()) {
+                    selected.push((entity_ref, index));
+                    taken += 1;
+                }
+            }
+        }
+
+        Ok(selected)
+    }
+
+    /// Hydrate what a recipe selected, alongside the agent's core
+    /// memories, and keep the connections running between them.
+    fn assemble_recipe(
+        db: &rusqlite::Connection,
+        agent: &Agent,
+        selected: Vec<(Ref, usize)>,
+    ) -> Result<Assembled, ContinuityError> {
+        let ranks = LevelRanks::new(&LevelStore::new(db).list()?);
+        let mut memories: Vec<Memory> = MemoryStore::new(db)
+            .list(Some(&agent.id.to_string()))?
+            .into_iter()
+            .filter(|m| ranks.is_core(&m.level) && !m.is_archived() && !m.is_superseded())
+            .collect();
+        let mut cognitions: Vec<Cognition> = Vec::new();
+        let mut experiences: Vec<Experience> = Vec::new();
+        let mut depths: HashMap<Ref, usize> = HashMap::new();
+
+        for (entity_ref, section) in selected {
+            match entity_ref.resource() {
+                Resource::Memory(id) => {
+                    if !memories.iter().any(|m| m.id == *id)
+                        && let Some(memory) = MemoryStore::new(db).get(id)?
+                        && !memory.is_archived()
+                        && !memory.is_superseded()
+                    {
+                        memories.push(memory);
+                    }
+                }
+                Resource::Cognition(id) => {
+                    if let Some(cognition) = CognitionStore::new(db).get(id)?
+                        && !cognition.is_retracted()
+                    {
+                        cognitions.push(cognition);
+                    }
+                }
+                Resource::Experience(id) => {
+                    if let Some(experience) = ExperienceStore::new(db).get(id)?
+                        && !experience.is_amended()
+                    {
+                        experiences.push(experience);
+                    }
+                }
+                _ => {}
+            }
+            depths.insert(entity_ref, section);
+        }
+
+        memories.sort_by_key(|a| a.created_at);
+        cognitions.sort_by_key(|a| a.created_at);
+        experiences.sort_by_key(|a| a.created_at);
+
+        let mut connections: Vec<Connection> = Vec::new();
+        let mut seen_conn_ids: HashSet<ConnectionId> = HashSet::new();
+        let refs = memories
+            .iter()
+            .map(|m| Ref::memory(m.id))
+            .chain(cognitions.iter().map(|c| Ref::cognition(c.id)))
+            .chain(experiences.iter().map(|e| Ref::experience(e.id)));
+        for entity_ref in refs {
+            let ref_json = serde_json::to_string(&entity_ref).map_err(EventError::from)?;
+            for conn in ConnectionStore::new(db).list(Some(&ref_json))? {
+                if seen_conn_ids.insert(conn.id) {
+                    connections.push(conn);
+                }
+            }
+        }
+
+        let connections =
+            Self::prune_connections(connections, &memories, &cognitions, &experiences);
+
+        Ok(Assembled {
+            memories,
+            cognitions,
+            experiences,
+            connections,
+            depths,
+            recall: Vec::new(),
+        })
+    }
+
+    /// Only retain edges whose endpoints are both in the result.
+    fn prune_connections(
+        connections: Vec<Connection>,
+        memories: &[Memory],
+        cognitions: &[Cognition],
+        experiences: &[Experience],
+    ) -> Vec<Connection> {
         let included_refs: HashSet<Ref> = memories
             .iter()
             .map(|m| Ref::memory(m.id))
@@ -537,45 +923,57 @@ impl ContinuityService {
             .chain(experiences.iter().map(|e| Ref::experience(e.id)))
             .collect();
 
-        let connections = connections
+        connections
             .into_iter()
             .filter(|conn| {
                 included_refs.contains(&conn.from_ref) && included_refs.contains(&conn.to_ref)
             })
-            .collect();
-
-        // Pressure readings paired with urge CTAs
-        let raw_pressures = PressureStore::new(&db).get(&agent.name)?;
-        let pressures = PressureReading::from_pressures_and_urges(raw_pressures, &urges);
-
-        Ok(DreamContext::builder()
-            .agent(agent.clone())
-            .maybe_persona(persona)
-            .memories(memories)
-            .cognitions(cognitions)
-            .experiences(experiences)
-            .connections(connections)
-            .textures(textures)
-            .levels(levels)
-            .sensations(sensations)
-            .natures(natures)
-            .urges(urges)
-            .pressures(pressures)
-            .build())
+            .collect()
     }
 
-    fn filter_memories(config: &DreamConfig, mut memories: Vec<Memory>) -> Vec<Memory> {
+    /// Pick which non-core memories to recall: filter by level, score
+    /// what's left, and keep the best `recollection_size`. Returns the
+    /// kept memories with their scores, best first.
+    fn recall_memories(
+        db: &rusqlite::Connection,
+        config: &DreamConfig,
+        ranks: &LevelRanks,
+        mut memories: Vec<Memory>,
+        focus: Option<&HashMap<MemoryId, f64>>,
+    ) -> Result<(Vec<Memory>, Vec<RecallScore>), ContinuityError> {
         // Filter by level threshold (log-level semantics)
-        let min_priority = level_priority(&config.recollection_level);
-        memories.retain(|m| level_priority(&m.level) >= min_priority);
-
-        // Sort by created_at descending for recency-based capping
-        memories.sort_by_key(|b| std::cmp::Reverse(b.created_at));
+        let min_rank = ranks.of(&config.recollection_level);
+        memories.retain(|m| ranks.of(&m.level) >= min_rank);
+
+        let now = Timestamp::now();
+        let degrees = ConnectionStore::new(db).degrees()?;
+        let surfaced = MemoryStore::new(db).surfaced_counts()?;
+        let mut scored = Vec::with_capacity(memories.len());
+        for memory in memories {
+            let ref_json =
+                serde_json::to_string(&Ref::memory(memory.id)).map_err(EventError::from)?;
+            let inputs = RecallInputs {
+                level: f64::from(ranks.of(&memory.level)) / f64::from(ranks.highest()),
+                age: now.duration_since(memory.created_at),
+                connections: degrees.get(&ref_json).copied().unwrap_or(0),
+                surfaced: surfaced.get(&memory.id).copied().unwrap_or(0),
+                focus: focus.map(|hits| hits.get(&memory.id).copied().unwrap_or(0.0)),
+            };
+            scored.push((RecallScore::new(memory.id, inputs), memory));
+        }
 
-        // Cap at recollection_size
-        memories.truncate(config.recollection_size);
+        // Best first, newest breaking ties, capped at recollection_size
+        scored.sort_by(|(a, am), (b, bm)| {
+            b.total
+                .total_cmp(&a.total)
+                .then(bm.created_at.cmp(&am.created_at))
+        });
+        scored.truncate(config.recollection_size);
 
-        memories
+        Ok(scored
+            .into_iter()
+            .map(|(score, memory)| (memory, score))
+            .unzip())
     }
 
     fn assemble_cognitions(
@@ -603,6 +1001,7 @@ impl ContinuityService {
         for id in graph_ids {
             if seen.insert(*id)
                 && let Some(cog) = store.get(id)?
+                && !cog.is_retracted()
             {
                 cognitions.push(cog);
             }
@@ -632,6 +1031,7 @@ impl ContinuityService {
         for id in graph_ids {
             if seen.insert(*id)
                 && let Some(exp) = store.get(id)?
+                && !exp.is_amended()
             {
                 experiences.push(exp);
             }
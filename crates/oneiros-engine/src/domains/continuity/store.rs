@@ -0,0 +1,87 @@
+use rusqlite::params;
+
+use crate::*;
+
+/// Continuity projection store — each agent's last dream receipt.
+///
+/// Keeps what a `Dreamed` event handed the agent, so the next dream can
+/// say what changed since. Later dreams replace earlier ones; replaying
+/// out of order can't roll the receipt back.
+pub(crate) struct ContinuityStore<'a> {
+    conn: &'a rusqlite::Connection,
+}
+
+impl<'a> ContinuityStore<'a> {
+    pub(crate) fn new(conn: &'a rusqlite::Connection) -> Self {
+        Self { conn }
+    }
+
+    pub(crate) fn handle(&self, event: &StoredEvent) -> Result<(), EventError> {
+        if let Event::Known(Events::Continuity(ContinuityEvents::Dreamed(dreamed))) = &event.data {
+            let dreamed = dreamed.current()?;
+            self.conn.execute(
+                "INSERT INTO dream_receipts (agent, dreamed_at, sequence, pressures)
+                 VALUES (?1, ?2, ?3, ?4)
+                 ON CONFLICT(agent) DO UPDATE SET
+                    dreamed_at = excluded.dreamed_at,
+                    sequence = excluded.sequence,
+                    pressures = excluded.pressures
+                 WHERE excluded.dreamed_at >= dream_receipts.dreamed_at",
+                params![
+                    dreamed.agent.to_string(),
+                    dreamed.created_at.as_string(),
+                    event.sequence,
+                    serde_json::to_string(&dreamed.pressures)?,
+                ],
+            )?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn reset(&self) -> Result<(), EventError> {
+        self.conn.execute("DELETE FROM dream_receipts", [])?;
+        Ok(())
+    }
+
+    pub(crate) fn migrate(&self) -> Result<(), EventError> {
+        self.conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS dream_receipts (
+                agent TEXT PRIMARY KEY,
+                dreamed_at TEXT NOT NULL,
+                pressures TEXT NOT NULL DEFAULT '[]'
+            )",
+        )?;
+        // Migration: add columns if missing from older schemas.
+        self.conn
+            .execute_batch(
+                "ALTER TABLE dream_receipts ADD COLUMN sequence INTEGER NOT NULL DEFAULT 0",
+            )
+            .ok();
+        Ok(())
+    }
+
+    /// The agent's most recent dream, if it has dreamed.
+    pub(crate) fn last_dream(&self, agent: &AgentName) -> Result<Option<DreamReceipt>, EventError> {
+        let result = self.conn.query_row(
+            "SELECT dreamed_at, sequence, pressures FROM dream_receipts WHERE agent = ?1",
+            params![agent.to_string()],
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, i64>(1)?,
+                    row.get::<_, String>(2)?,
+                ))
+            },
+        );
+
+        match result {
+            Ok((dreamed_at, sequence, pressures)) => Ok(Some(DreamReceipt {
+                dreamed_at: Timestamp::parse_str(&dreamed_at)?,
+                sequence,
+                pressures: serde_json::from_str(&pressures)?,
+            })),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
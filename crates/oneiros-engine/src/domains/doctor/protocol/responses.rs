@@ -77,6 +77,9 @@ pub(crate) enum DoctorCheck {
     McpTokenRejected(DiagnosticMessage),
     McpUnreachable(DiagnosticMessage),
     McpNotVerified,
+    HarnessConfigured(Harness),
+    HarnessServerMissing(Harness),
+    HarnessSkillsMissing(Harness),
     ServiceRunning,
     ServiceStopped,
     ServiceNotInstalled,
@@ -158,6 +158,25 @@ impl DoctorService {
             checks.push(DoctorCheck::McpMissing);
         }
 
+        // Other harnesses are opt-in, so they only report once their
+        // config file turns up in the project.
+        let root = std::env::current_dir().unwrap_or_default();
+        for harness in Harness::ALL {
+            if harness == Harness::Claude {
+                continue;
+            }
+
+            match McpConfigService::harness_status(config, harness) {
+                Some(true) => checks.push(DoctorCheck::HarnessConfigured(harness)),
+                Some(false) => checks.push(DoctorCheck::HarnessServerMissing(harness)),
+                None => continue,
+            }
+
+            if SkillPackage::is_installed(&root, harness) == Some(false) {
+                checks.push(DoctorCheck::HarnessSkillsMissing(harness));
+            }
+        }
+
         DoctorResponse::CheckupStatus(
             CheckupStatusResponse::builder_v1()
                 .checks(checks)
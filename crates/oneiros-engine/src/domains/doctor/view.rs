@@ -117,6 +117,27 @@ impl DoctorView {
                         "—".muted()
                     )
                 }
+                DoctorCheck::HarnessConfigured(harness) => {
+                    format!("  {} MCP config present for {}", "✓".success(), harness)
+                }
+                DoctorCheck::HarnessServerMissing(harness) => {
+                    format!(
+                        "  {} {} config has no oneiros server {} run `oneiros mcp init --harness {}`",
+                        "!".warning(),
+                        harness,
+                        "—".muted(),
+                        harness
+                    )
+                }
+                DoctorCheck::HarnessSkillsMissing(harness) => {
+                    format!(
+                        "  {} Skills missing for {} {} run `oneiros setup --init-mcp --harness {}`",
+                        "!".warning(),
+                        harness,
+                        "—".muted(),
+                        harness
+                    )
+                }
                 DoctorCheck::ServiceRunning => {
                     format!("  {} Service running", "✓".success())
                 }
@@ -0,0 +1,103 @@
+use clap::Subcommand;
+use futures::StreamExt;
+use std::io::Write;
+
+use crate::*;
+
+#[derive(Debug, Subcommand)]
+pub(crate) enum EventCommands {
+    /// Follow events as they're appended, writing each as a JSON line.
+    Tail {
+        #[command(flatten)]
+        request: StreamEvents,
+        /// Follow the host log instead of the project's.
+        #[arg(long)]
+        host: bool,
+        /// Replay the log after this event sequence before following.
+        #[arg(long)]
+        since: Option<i64>,
+        /// Stop after this many events.
+        #[arg(long)]
+        count: Option<usize>,
+    },
+}
+
+impl EventCommands {
+    pub(crate) async fn execute(
+        &self,
+        config: &Config,
+    ) -> Result<Rendered<Responses>, EventStreamError> {
+        let client = Client::from_config(config)?;
+
+        match self {
+            Self::Tail {
+                request,
+                host,
+                since,
+                count,
+            } => {
+                let StreamEvents::V1(filters) = request;
+                let path = if *host {
+                    "/host/events/stream"
+                } else {
+                    "/events/stream"
+                };
+                let response = client.stream(path, &filters.query_pairs(), *since).await?;
+
+                let written = Self::tail(response, *count).await?;
+                Ok(Rendered::silent(Responses::Event(EventResponse::Tailed(
+                    TailedEventsResponse::builder_v1()
+                        .count(written)
+                        .build()
+                        .into(),
+                ))))
+            }
+        }
+    }
+
+    /// Write each event's data to stdout until the stream ends or
+    /// `limit` events have been written.
+    async fn tail(
+        response: reqwest::Response,
+        limit: Option<usize>,
+    ) -> Result<usize, EventStreamError> {
+        let mut body = response.bytes_stream();
+        let mut buffer = Vec::new();
+        let mut written = 0;
+        let mut stdout = std::io::stdout();
+
+        while limit.is_none_or(|limit| written < limit)
+            && let Some(chunk) = body.next().await
+        {
+            buffer.extend_from_slice(&chunk.map_err(ClientError::from)?);
+
+            while let Some(end) = buffer.windows(2).position(|pair| pair == b"\n\n") {
+                let frame: Vec<u8> = buffer.drain(..end + 2).collect();
+                // Keep-alives are comment-only frames with no data.
+                let Some(data) = Self::frame_data(&frame) else {
+                    continue;
+                };
+
+                writeln!(stdout, "{data}")?;
+                stdout.flush()?;
+                written += 1;
+                if limit.is_some_and(|limit| written >= limit) {
+                    break;
+                }
+            }
+        }
+
+        Ok(written)
+    }
+
+    /// The joined `data:` lines of one SSE frame.
+    fn frame_data(frame: &[u8]) -> Option<String> {
+        let frame = String::from_utf8_lossy(frame);
+        let data: Vec<&str> = frame
+            .lines()
+            .filter_map(|line| line.strip_prefix("data:"))
+            .map(|data| data.strip_prefix(' ').unwrap_or(data))
+            .collect();
+        (!data.is_empty()).then(|| data.join("\n"))
+    }
+}
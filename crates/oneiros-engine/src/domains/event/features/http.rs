@@ -0,0 +1,92 @@
+use std::convert::Infallible;
+
+use aide::axum::ApiRouter;
+use axum::{
+    extract::{Query, State},
+    http::HeaderMap,
+    response::sse::{self, KeepAlive, Sse},
+};
+use futures::{Stream, stream};
+
+use crate::*;
+
+pub(crate) struct EventRouter;
+
+impl EventRouter {
+    pub(crate) fn routes(&self) -> ApiRouter<ServerState> {
+        // Server-Sent Events — a long-lived text/event-stream, not JSON,
+        // so these live outside aide's typed routing. The host stream
+        // sits under `/host` so it takes the host's permission.
+        ApiRouter::new()
+            .route("/events/stream", axum::routing::get(project_stream))
+            .route("/host/events/stream", axum::routing::get(host_stream))
+    }
+}
+
+async fn project_stream(
+    scope: Scope<AtBookmark>,
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Query(request): Query<StreamEvents>,
+) -> Result<Sse<impl Stream<Item = Result<sse::Event, Infallible>>>, EventStreamError> {
+    let last_event_id = last_event_id(&headers)?;
+    let tail = EventTail::project(
+        &scope,
+        state.canons(),
+        state.feed(),
+        &request,
+        last_event_id,
+    )
+    .await?;
+    Ok(sse(tail))
+}
+
+async fn host_stream(
+    scope: Scope<AtHost>,
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Query(request): Query<StreamEvents>,
+) -> Result<Sse<impl Stream<Item = Result<sse::Event, Infallible>>>, EventStreamError> {
+    let last_event_id = last_event_id(&headers)?;
+    let tail = EventTail::host(&scope, state.feed(), &request, last_event_id).await?;
+    Ok(sse(tail))
+}
+
+/// The `Last-Event-ID` a reconnecting client sent — the sequence of the
+/// last event it saw.
+fn last_event_id(headers: &HeaderMap) -> Result<Option<i64>, EventStreamError> {
+    headers
+        .get("last-event-id")
+        .map(|value| {
+            let raw = String::from_utf8_lossy(value.as_bytes()).into_owned();
+            raw.trim()
+                .parse()
+                .map_err(|_| EventStreamError::InvalidLastEventId(raw))
+        })
+        .transpose()
+}
+
+/// Frame each event with its sequence as the SSE id and its type as the
+/// SSE event name. A tail that errors ends the stream; the client
+/// reconnects with `Last-Event-ID` and picks up where it stopped.
+fn sse(tail: EventTail) -> Sse<impl Stream<Item = Result<sse::Event, Infallible>>> {
+    let events = stream::unfold(tail, |mut tail| async move {
+        let stored = match tail.next().await {
+            Ok(Some(stored)) => stored,
+            Ok(None) => return None,
+            Err(error) => {
+                tracing::warn!(%error, "event stream: ending after error");
+                return None;
+            }
+        };
+
+        let frame = sse::Event::default()
+            .id(stored.sequence.to_string())
+            .event(stored.data.event_type())
+            .json_data(&stored)
+            .unwrap_or_else(|error| sse::Event::default().comment(error.to_string()));
+        Some((Ok(frame), tail))
+    });
+
+    Sse::new(events).keep_alive(KeepAlive::default())
+}
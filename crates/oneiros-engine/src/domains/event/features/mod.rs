@@ -0,0 +1,7 @@
+mod cli;
+mod http;
+mod skills;
+
+pub(crate) use cli::*;
+pub(crate) use http::*;
+pub(crate) use skills::*;
@@ -0,0 +1,9 @@
+use crate::Skill;
+
+pub(crate) struct EventSkills;
+
+impl EventSkills {
+    pub(crate) fn all() -> Vec<Skill> {
+        vec![Skill::new("events-tail", include_str!("skills/tail.md"))]
+    }
+}
@@ -1,5 +1,9 @@
+mod features;
 mod model;
 mod protocol;
+mod service;
 
+pub(crate) use features::*;
 pub(crate) use model::*;
 pub(crate) use protocol::*;
+pub(crate) use service::*;
@@ -1,5 +1,8 @@
+use std::borrow::Cow;
+
 use bon::Builder;
 use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
 
 use crate::*;
 
@@ -18,14 +21,194 @@ pub(crate) struct NewEvent {
 ///
 /// Serializes `created_at` as `created_at` internally and accepts
 /// `timestamp` on deserialization for wire format compatibility.
-#[derive(Debug, Clone, Builder, Serialize, Deserialize)]
+/// `signature` is absent on events written before signing existed.
+///
+/// A copy read from a log or the wire keeps `data` exactly as its author
+/// wrote it. Signatures and chronicle hashes cover those bytes, and they
+/// are what gets stored and sent on, so fields and event types this
+/// version doesn't know survive the trip intact.
+#[derive(Debug, Clone, Builder, Deserialize)]
+#[serde(try_from = "ReadEvent")]
 pub(crate) struct StoredEvent {
     pub(crate) id: EventId,
-    #[serde(default)]
     pub(crate) sequence: i64,
     pub(crate) data: Event,
-    #[serde(default)]
     pub(crate) source: Source,
-    #[serde(alias = "timestamp")]
     pub(crate) created_at: Timestamp,
+    pub(crate) signature: Option<EventSignature>,
+    /// `data` as it was written, when this copy was read rather than made.
+    #[builder(skip)]
+    payload: Option<Box<RawValue>>,
+}
+
+/// A [`StoredEvent`] as it is written out.
+#[derive(Serialize)]
+struct WriteEvent<'a> {
+    id: &'a EventId,
+    sequence: i64,
+    data: &'a RawValue,
+    source: &'a Source,
+    created_at: &'a Timestamp,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signature: &'a Option<EventSignature>,
+}
+
+/// A [`StoredEvent`] as it is read back in.
+#[derive(Deserialize)]
+struct ReadEvent {
+    id: EventId,
+    #[serde(default)]
+    sequence: i64,
+    data: Box<RawValue>,
+    #[serde(default)]
+    source: Source,
+    #[serde(alias = "timestamp")]
+    created_at: Timestamp,
+    #[serde(default)]
+    signature: Option<EventSignature>,
+}
+
+impl Serialize for StoredEvent {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        WriteEvent {
+            id: &self.id,
+            sequence: self.sequence,
+            data: &self.payload().map_err(serde::ser::Error::custom)?,
+            source: &self.source,
+            created_at: &self.created_at,
+            signature: &self.signature,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl TryFrom<ReadEvent> for StoredEvent {
+    type Error = serde_json::Error;
+
+    fn try_from(wire: ReadEvent) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: wire.id,
+            sequence: wire.sequence,
+            data: serde_json::from_str(wire.data.get())?,
+            source: wire.source,
+            created_at: wire.created_at,
+            signature: wire.signature,
+            payload: Some(wire.data),
+        })
+    }
+}
+
+/// The fields a signature covers. Sequence is local to each log and
+/// is left out, so an event keeps its signature across hosts.
+#[derive(Serialize)]
+struct SignedFields<'a> {
+    id: &'a EventId,
+    data: &'a RawValue,
+    source: &'a Source,
+    created_at: String,
+}
+
+impl StoredEvent {
+    /// Rebuild an event from a log row, keeping `data` as it was stored.
+    /// A row whose data doesn't parse comes back `Malformed`.
+    pub(crate) fn read(
+        id: EventId,
+        sequence: i64,
+        data: String,
+        source: Source,
+        created_at: Timestamp,
+        signature: Option<EventSignature>,
+    ) -> Self {
+        let parsed = RawValue::from_string(data).and_then(|payload| {
+            let data = serde_json::from_str(payload.get())?;
+            Ok((data, payload))
+        });
+        let (data, payload) = match parsed {
+            Ok((data, payload)) => (data, Some(payload)),
+            Err(error) => {
+                tracing::warn!(%error, "skipping event row with malformed json");
+                (Event::Malformed, None)
+            }
+        };
+
+        Self {
+            id,
+            sequence,
+            data,
+            source,
+            created_at,
+            signature,
+            payload,
+        }
+    }
+
+    /// The `data` JSON this event is stored, sent, signed and hashed as:
+    /// the author's bytes when this copy was read, else freshly written.
+    pub(crate) fn payload(&self) -> Result<Cow<'_, RawValue>, serde_json::Error> {
+        match &self.payload {
+            Some(payload) => Ok(Cow::Borrowed(payload)),
+            None => Ok(Cow::Owned(serde_json::value::to_raw_value(&self.data)?)),
+        }
+    }
+
+    /// The bytes a signature is made over.
+    fn signing_payload(&self) -> Result<Vec<u8>, serde_json::Error> {
+        serde_json::to_vec(&SignedFields {
+            id: &self.id,
+            data: &self.payload()?,
+            source: &self.source,
+            created_at: self.created_at.as_string(),
+        })
+    }
+
+    /// Sign this event with the host's secret key.
+    pub(crate) fn signed(mut self, secret: &iroh::SecretKey) -> Result<Self, EventError> {
+        self.payload = Some(self.payload()?.into_owned());
+        self.signature = Some(EventSignature::sign(secret, &self.signing_payload()?));
+        Ok(self)
+    }
+
+    /// Check this event's signature against its content.
+    pub(crate) fn provenance(&self) -> Provenance {
+        if self.is_tombstone() {
+            return Provenance::Redacted;
+        }
+
+        match &self.signature {
+            None => Provenance::Unsigned,
+            Some(signature) => match self.signing_payload() {
+                Ok(payload) if signature.verify(&payload) => Provenance::Signed(signature.signer),
+                _ => Provenance::Tampered(signature.signer),
+            },
+        }
+    }
+
+    /// Whether redaction has already replaced this event's payload.
+    pub(crate) fn is_tombstone(&self) -> bool {
+        matches!(
+            self.data,
+            Event::Known(Events::Project(ProjectEvents::Tombstone(_)))
+        )
+    }
+
+    /// This event with its payload replaced by a tombstone. The id,
+    /// source and time survive; the signature goes with the content it
+    /// covered.
+    pub(crate) fn tombstoned(self, redacted_at: Timestamp) -> Self {
+        if self.is_tombstone() {
+            return self;
+        }
+
+        let tombstone = Tombstone::builder_v1()
+            .event_type(self.data.event_type())
+            .redacted_at(redacted_at)
+            .build();
+
+        Self {
+            data: Event::Known(Events::Project(ProjectEvents::Tombstone(tombstone.into()))),
+            signature: None,
+            payload: None,
+            ..self
+        }
+    }
 }
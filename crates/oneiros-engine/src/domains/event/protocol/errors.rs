@@ -1,3 +1,7 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+
 use crate::*;
 
 /// Event infrastructure errors.
@@ -38,4 +42,90 @@ pub(crate) enum EventError {
 
     #[error("Database lock poisoned: {0}")]
     Lock(String),
+
+    #[error(transparent)]
+    Provenance(#[from] ProvenanceError),
+}
+
+/// An incoming event refused by the host's trust policy.
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum ProvenanceError {
+    #[error("Event {0} is unsigned, and its origin requires signatures")]
+    Unsigned(EventId),
+
+    #[error("Event {id} does not match its signature from {signer}")]
+    Tampered { id: EventId, signer: PeerKey },
+
+    #[error("Event {id} comes from untrusted peer {peer}")]
+    Untrusted { id: EventId, peer: PeerKey },
+
+    #[error("Event {0} is a tombstone, and its origin requires signatures")]
+    Redacted(EventId),
+
+    #[error("Redaction notice {0} names events its signer did not write")]
+    UnauthorizedRedaction(EventId),
+}
+
+/// Errors opening or following a live event stream.
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum EventStreamError {
+    #[error("Agent not found: {0}")]
+    AgentNotFound(AgentName),
+
+    #[error("Lens filters need a project stream; the host log has no lens view")]
+    HostLens,
+
+    #[error("Invalid Last-Event-ID: {0}")]
+    InvalidLastEventId(String),
+
+    #[error(transparent)]
+    Lens(#[from] LensError),
+
+    #[error(transparent)]
+    Event(#[from] EventError),
+
+    #[error(transparent)]
+    EventsDb(#[from] EventsDbError),
+
+    #[error(transparent)]
+    HostDb(#[from] HostDbError),
+
+    #[error(transparent)]
+    Compose(#[from] ComposeError),
+
+    #[error(transparent)]
+    Client(#[from] ClientError),
+
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+resource_op_error!(EventStreamError);
+
+impl IntoResponse for EventStreamError {
+    fn into_response(self) -> Response {
+        // A lens that doesn't parse or validate answers as the lens
+        // endpoints would.
+        if let EventStreamError::Lens(error) = self {
+            return error.into_response();
+        }
+
+        let (status, message) = match &self {
+            EventStreamError::AgentNotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
+            EventStreamError::HostLens => (StatusCode::UNPROCESSABLE_ENTITY, self.to_string()),
+            EventStreamError::InvalidLastEventId(_) => (StatusCode::BAD_REQUEST, self.to_string()),
+            EventStreamError::Lens(_) => unreachable!("answered above"),
+            EventStreamError::Event(_)
+            | EventStreamError::EventsDb(_)
+            | EventStreamError::HostDb(_)
+            | EventStreamError::Compose(_)
+            | EventStreamError::Io(_) => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()),
+            EventStreamError::Client(_) => (StatusCode::BAD_GATEWAY, self.to_string()),
+            EventStreamError::Json(_) => (StatusCode::BAD_GATEWAY, self.to_string()),
+        };
+        (status, Json(ErrorResponse::new(message))).into_response()
+    }
 }
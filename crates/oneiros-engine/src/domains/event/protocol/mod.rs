@@ -1,3 +1,7 @@
 mod errors;
+mod requests;
+mod responses;
 
 pub(crate) use errors::*;
+pub(crate) use requests::*;
+pub(crate) use responses::*;
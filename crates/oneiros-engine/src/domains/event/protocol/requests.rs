@@ -0,0 +1,49 @@
+use schemars::JsonSchema;
+
+use crate::*;
+
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum StreamEvents {
+        #[derive(clap::Args)]
+        V1 => {
+            /// Only events of these types, comma-separated — e.g.
+            /// `cognition-added,memory-added`.
+            #[arg(long = "type")]
+            #[serde(rename = "type")]
+            pub(crate) types: Option<String>,
+            /// Only events concerning this agent.
+            #[arg(long)] pub(crate) agent: Option<AgentName>,
+            /// Only events whose entities the lens expression selects.
+            #[arg(long)] pub(crate) lens: Option<String>,
+        }
+    }
+}
+
+impl StreamEventsV1 {
+    /// The requested event types, split and trimmed.
+    pub(crate) fn type_list(&self) -> Vec<String> {
+        self.types
+            .iter()
+            .flat_map(|types| types.split(','))
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(String::from)
+            .collect()
+    }
+
+    /// The filters as query parameters.
+    pub(crate) fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = Vec::new();
+        if let Some(types) = &self.types {
+            pairs.push(("type", types.clone()));
+        }
+        if let Some(agent) = &self.agent {
+            pairs.push(("agent", agent.to_string()));
+        }
+        if let Some(lens) = &self.lens {
+            pairs.push(("lens", lens.clone()));
+        }
+        pairs
+    }
+}
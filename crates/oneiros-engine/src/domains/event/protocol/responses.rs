@@ -0,0 +1,22 @@
+use kinded::Kinded;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+#[derive(Debug, Clone, Kinded, Serialize, Deserialize, JsonSchema)]
+#[kinded(kind = EventResponseType, display = "kebab-case")]
+#[serde(tag = "type", content = "data", rename_all = "kebab-case")]
+pub(crate) enum EventResponse {
+    Tailed(TailedEventsResponse),
+}
+
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum TailedEventsResponse {
+        V1 => {
+            /// How many events the tail wrote before it stopped.
+            pub(crate) count: usize,
+        }
+    }
+}
@@ -0,0 +1,301 @@
+use std::collections::VecDeque;
+
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::*;
+
+/// Which log a tail follows, and the feed it hears appends on.
+enum TailSource {
+    Project {
+        scope: Box<Scope<AtBookmark>>,
+        canons: CanonIndex,
+        live: broadcast::Receiver<AppendedEvent>,
+    },
+    Host {
+        scope: Box<Scope<AtHost>>,
+        live: broadcast::Receiver<Box<StoredEvent>>,
+    },
+}
+
+/// The agent a stream is filtered to. Cognitions and the like carry
+/// the agent's id; continuity events carry its name.
+struct AgentFilter {
+    name: serde_json::Value,
+    id: Option<serde_json::Value>,
+}
+
+/// A live tail of an event log.
+///
+/// Subscribes to the host's [`EventFeed`] before reading anything, then
+/// replays the log after the resume point — or starts from the log's
+/// current end — so nothing written in between is missed. The cursor
+/// is the last sequence handed out: events at or below it are dropped
+/// as duplicates, and a feed event that skips past the next sequence,
+/// or a tail that falls behind the feed, sends it back to the log for
+/// what it missed. Imports reach the feed as well as local appends.
+/// Project tails follow the whole project log — sequences are per
+/// project, not per bookmark — and evaluate lens filters against the
+/// request's bookmark.
+pub(crate) struct EventTail {
+    source: TailSource,
+    types: Vec<String>,
+    agent: Option<AgentFilter>,
+    /// The lens filter, compiled once when the tail starts.
+    lens: Option<Ir>,
+    last: i64,
+    pending: VecDeque<StoredEvent>,
+}
+
+impl EventTail {
+    /// Tail the project log of the given scope.
+    pub(crate) async fn project(
+        scope: &Scope<AtBookmark>,
+        canons: &CanonIndex,
+        feed: &EventFeed,
+        request: &StreamEvents,
+        last_event_id: Option<i64>,
+    ) -> Result<Self, EventStreamError> {
+        let StreamEvents::V1(request) = request;
+        let live = feed.subscribe();
+
+        let lens = match &request.lens {
+            Some(lens) => {
+                Some(LensService::compile(scope, lens, &scope.config().lens.aliases).await?)
+            }
+            None => None,
+        };
+
+        let agent = match &request.agent {
+            Some(name) => {
+                let agent = AgentRepo::new(scope)
+                    .get(name)
+                    .await?
+                    .ok_or_else(|| EventStreamError::AgentNotFound(name.clone()))?;
+                Some(AgentFilter {
+                    name: serde_json::to_value(name)?,
+                    id: Some(serde_json::to_value(agent.id)?),
+                })
+            }
+            None => None,
+        };
+
+        let source = TailSource::Project {
+            scope: Box::new(scope.clone()),
+            canons: canons.clone(),
+            live,
+        };
+        Self::start(source, request, agent, lens, last_event_id).await
+    }
+
+    /// Tail the host log.
+    pub(crate) async fn host(
+        scope: &Scope<AtHost>,
+        feed: &EventFeed,
+        request: &StreamEvents,
+        last_event_id: Option<i64>,
+    ) -> Result<Self, EventStreamError> {
+        let StreamEvents::V1(request) = request;
+        let live = feed.subscribe_host();
+
+        if request.lens.is_some() {
+            return Err(EventStreamError::HostLens);
+        }
+
+        let agent = match &request.agent {
+            Some(name) => Some(AgentFilter {
+                name: serde_json::to_value(name)?,
+                id: None,
+            }),
+            None => None,
+        };
+
+        let source = TailSource::Host {
+            scope: Box::new(scope.clone()),
+            live,
+        };
+        Self::start(source, request, agent, None, last_event_id).await
+    }
+
+    async fn start(
+        source: TailSource,
+        request: &StreamEventsV1,
+        agent: Option<AgentFilter>,
+        lens: Option<Ir>,
+        last_event_id: Option<i64>,
+    ) -> Result<Self, EventStreamError> {
+        let mut tail = Self {
+            source,
+            types: request.type_list(),
+            agent,
+            lens,
+            last: 0,
+            pending: VecDeque::new(),
+        };
+
+        match last_event_id {
+            Some(sequence) => {
+                tail.last = sequence;
+                tail.pending = tail.backlog().await?;
+            }
+            None => tail.last = tail.head().await?,
+        }
+
+        Ok(tail)
+    }
+
+    /// The next event that passes the filters, or `None` once the feed
+    /// has closed.
+    pub(crate) async fn next(&mut self) -> Result<Option<StoredEvent>, EventStreamError> {
+        loop {
+            let stored = match self.pending.pop_front() {
+                Some(stored) => stored,
+                None => match self.recv().await {
+                    // Writers publish in the order they finish, not the
+                    // order the log stored them; a gap means something
+                    // before this event hasn't been heard yet.
+                    Ok(stored) if stored.sequence > self.last + 1 => {
+                        self.pending = self.backlog().await?;
+                        continue;
+                    }
+                    Ok(stored) => stored,
+                    Err(RecvError::Lagged(_)) => {
+                        self.pending = self.backlog().await?;
+                        continue;
+                    }
+                    Err(RecvError::Closed) => return Ok(None),
+                },
+            };
+
+            if stored.sequence <= self.last {
+                continue;
+            }
+            self.last = stored.sequence;
+
+            if self.admits(&stored).await? {
+                return Ok(Some(stored));
+            }
+        }
+    }
+
+    /// The log after the last event handed out.
+    async fn backlog(&self) -> Result<VecDeque<StoredEvent>, EventStreamError> {
+        let events = match &self.source {
+            TailSource::Project { scope, .. } => {
+                let events_db = EventsDb::open(scope.as_ref()).await?;
+                let log = EventLog::new(&events_db);
+                log.init()?;
+                log.load_after(self.last)?
+            }
+            TailSource::Host { scope, .. } => {
+                let host_db = HostDb::open(scope.as_ref()).await?;
+                EventLog::new(&host_db).load_after(self.last)?
+            }
+        };
+        Ok(events.into())
+    }
+
+    /// The sequence of the latest event in the followed log.
+    async fn head(&self) -> Result<i64, EventStreamError> {
+        let sequence = match &self.source {
+            TailSource::Project { scope, .. } => {
+                let events_db = EventsDb::open(scope.as_ref()).await?;
+                let log = EventLog::new(&events_db);
+                log.init()?;
+                log.last_sequence()?
+            }
+            TailSource::Host { scope, .. } => {
+                let host_db = HostDb::open(scope.as_ref()).await?;
+                EventLog::new(&host_db).last_sequence()?
+            }
+        };
+        Ok(sequence)
+    }
+
+    /// The next event written to the followed log.
+    async fn recv(&mut self) -> Result<StoredEvent, RecvError> {
+        match &mut self.source {
+            TailSource::Project { scope, live, .. } => loop {
+                let appended = live.recv().await?;
+                if appended.project == scope.project().name {
+                    return Ok(*appended.stored);
+                }
+            },
+            TailSource::Host { live, .. } => Ok(*live.recv().await?),
+        }
+    }
+
+    async fn admits(&self, stored: &StoredEvent) -> Result<bool, EventStreamError> {
+        if !self.types.is_empty() && !self.types.contains(&stored.data.event_type()) {
+            return Ok(false);
+        }
+
+        let Event::Known(events) = &stored.data else {
+            return Ok(self.agent.is_none() && self.lens.is_none());
+        };
+
+        if let Some(agent) = &self.agent
+            && !agent.concerns(events)?
+        {
+            return Ok(false);
+        }
+
+        match (&self.lens, &self.source) {
+            (Some(lens), TailSource::Project { scope, canons, .. }) => {
+                Self::in_lens(scope, canons, lens, stored).await
+            }
+            _ => Ok(true),
+        }
+    }
+
+    /// Whether the lens selects the event, as a slice would match it:
+    /// `events_for(lens)` holds the event exactly when the lens selects
+    /// an entity the event touched. So only the entities are looked up,
+    /// not the events of every entity the lens selects. Waits for the
+    /// trail projection to record the event first, since the feed can
+    /// run ahead of it.
+    async fn in_lens(
+        scope: &Scope<AtBookmark>,
+        canons: &CanonIndex,
+        lens: &Ir,
+        stored: &StoredEvent,
+    ) -> Result<bool, EventStreamError> {
+        let trail = TrailRepo::new(scope);
+        if !trail.settled(stored).await? {
+            return Ok(false);
+        }
+
+        let touched = trail.refs_from(stored.id).await?;
+        let selected = LensService::execute(scope, canons, lens)
+            .await?
+            .entity_refs();
+        Ok(touched
+            .iter()
+            .any(|entity| selected.contains(entity.inner())))
+    }
+}
+
+impl AgentFilter {
+    fn concerns(&self, events: &Events) -> Result<bool, serde_json::Error> {
+        let value = serde_json::to_value(events)?;
+        let data = &value["data"];
+
+        // Agent events carry the agent itself, flattened.
+        if let Events::Agent(_) = events {
+            return Ok(data["name"] == self.name);
+        }
+
+        Ok(self.mentioned_in(data))
+    }
+
+    fn mentioned_in(&self, value: &serde_json::Value) -> bool {
+        match value {
+            serde_json::Value::Object(fields) => fields.iter().any(|(key, field)| {
+                (key == "agent_id" && Some(field) == self.id.as_ref())
+                    || (key == "agent" && *field == self.name)
+                    || self.mentioned_in(field)
+            }),
+            serde_json::Value::Array(items) => items.iter().any(|item| self.mentioned_in(item)),
+            _ => false,
+        }
+    }
+}
@@ -4,6 +4,7 @@ pub(crate) enum ExperienceDocs {
     Create,
     List,
     Show,
+    Amend,
 }
 
 impl ExperienceDocs {
@@ -35,6 +36,12 @@ impl ExperienceDocs {
                 .summary("Get an experience")
                 .description("Retrieve the full record of a specific marked moment by ID.")
                 .build(),
+            Self::Amend => ResourceDocs::builder()
+                .tag(tag)
+                .nickname("amend-experience")
+                .summary("Amend an experience")
+                .description("Replace an experience with a revised description or sensation. The original stays in the event log but is hidden from listings, search and dreams.")
+                .build(),
         }
     }
 }
@@ -17,6 +17,8 @@ pub(crate) enum ExperienceCommands {
         #[arg(long)]
         sensation: Option<String>,
     },
+    /// Replace an experience with a revised one, hiding the original.
+    Amend(AmendExperience),
 }
 
 impl ExperienceCommands {
@@ -39,6 +41,10 @@ impl ExperienceCommands {
                 listing.execute_request(&client).await?,
                 ExperienceRequest::ListExperiences(listing.clone()),
             ),
+            Self::Amend(amendment) => (
+                amendment.execute_request(&client).await?,
+                ExperienceRequest::AmendExperience(amendment.clone()),
+            ),
             Self::Update {
                 id,
                 description,
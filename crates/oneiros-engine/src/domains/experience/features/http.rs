@@ -4,6 +4,7 @@ use axum::{
     extract::{Path, Query},
     http::StatusCode,
 };
+use schemars::JsonSchema;
 use serde::Deserialize;
 
 use crate::*;
@@ -37,6 +38,15 @@ impl ExperienceRouter {
                             .response::<200, Json<ExperienceDetailsResponse>>()
                     }),
                 )
+                .api_route(
+                    "/{id}/amend",
+                    routing::post_with(amend, |op| {
+                        resource_op!(op, ExperienceDocs::Amend)
+                            .security_requirement("BearerToken")
+                            .input::<IdPathParam<ExperienceId>>()
+                            .response::<201, Json<ExperienceAmendedResponse>>()
+                    }),
+                )
                 // Local body structs (UpdateDescriptionBody, UpdateSensationBody) don't
                 // implement OperationInput — use plain route() to skip schema generation.
                 .route("/{id}/description", axum::routing::put(update_description))
@@ -55,6 +65,12 @@ struct UpdateSensationBody {
     sensation: SensationName,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+struct AmendBody {
+    description: Option<Description>,
+    sensation: Option<SensationName>,
+}
+
 async fn create(
     scope: Scope<AtBookmark>,
     mailbox: Mailbox,
@@ -129,3 +145,19 @@ async fn update_sensation(
         .await?,
     ))
 }
+
+async fn amend(
+    scope: Scope<AtBookmark>,
+    mailbox: Mailbox,
+    Path(id): Path<ExperienceId>,
+    Json(body): Json<AmendBody>,
+) -> Result<(StatusCode, Json<ExperienceResponse>), ExperienceError> {
+    let request = AmendExperience::builder_v1()
+        .id(id)
+        .maybe_description(body.description)
+        .maybe_sensation(body.sensation)
+        .build()
+        .into();
+    let response = ExperienceService::amend(&scope, &mailbox, &request).await?;
+    Ok((StatusCode::CREATED, Json(response)))
+}
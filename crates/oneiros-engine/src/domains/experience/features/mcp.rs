@@ -40,6 +40,13 @@ mod experience_mcp {
             Tool::<CreateExperience>::new(
                 ExperienceRequestType::CreateExperience,
                 "Mark a meaningful moment",
+                PermissionOp::ProjectWrite,
+            )
+            .def(),
+            Tool::<AmendExperience>::new(
+                ExperienceRequestType::AmendExperience,
+                "Replace an experience with a revised one",
+                PermissionOp::ProjectWrite,
             )
             .def(),
         ]
@@ -67,6 +74,15 @@ mod experience_mcp {
                     .map_err(Error::from)?;
                 Ok(ExperienceView::new(response, &request).mcp())
             }
+            ExperienceRequestType::AmendExperience => {
+                let amendment: AmendExperience = serde_json::from_value(params.clone())?;
+                let request = ExperienceRequest::AmendExperience(amendment.clone());
+                let scope = context.scope().map_err(Error::from)?;
+                let response = ExperienceService::amend(scope, mailbox, &amendment)
+                    .await
+                    .map_err(Error::from)?;
+                Ok(ExperienceView::new(response, &request).mcp())
+            }
             ExperienceRequestType::GetExperience
             | ExperienceRequestType::ListExperiences
             | ExperienceRequestType::UpdateExperienceDescription
@@ -101,7 +117,8 @@ mod experience_mcp {
             }
             ExperienceRequest::CreateExperience(_)
             | ExperienceRequest::UpdateExperienceDescription(_)
-            | ExperienceRequest::UpdateExperienceSensation(_) => {
+            | ExperienceRequest::UpdateExperienceSensation(_)
+            | ExperienceRequest::AmendExperience(_) => {
                 return Err(ToolError::NotAResource(
                     "Mutations are tools, not resources".to_string(),
                 ));
@@ -9,6 +9,7 @@ impl ExperienceSkills {
             Skill::new("experience-show", include_str!("skills/show.md")),
             Skill::new("experience-list", include_str!("skills/list.md")),
             Skill::new("experience-update", include_str!("skills/update.md")),
+            Skill::new("experience-amend", include_str!("skills/amend.md")),
         ]
     }
 }
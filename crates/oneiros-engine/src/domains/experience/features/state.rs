@@ -25,6 +25,14 @@ impl ExperienceState {
                         experience.sensation = current.sensation;
                     }
                 }
+                // The canon holds the live picture; an amended experience
+                // gives way to its replacement.
+                ExperienceEvents::ExperienceAmended(amended) => {
+                    if let Ok(current) = amended.current() {
+                        canon.experiences.remove(&current.id);
+                        canon.experiences.set(&current.replacement);
+                    }
+                }
             };
         }
 
@@ -15,12 +15,20 @@ pub(crate) struct Experience {
     pub(crate) description: Description,
     #[builder(default = Timestamp::now())]
     pub(crate) created_at: Timestamp,
+    /// The experience that amended this one. Amended experiences stay in
+    /// the log but are hidden from dreams, lists and search by default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) amended_by: Option<ExperienceId>,
 }
 
 impl Experience {
     pub(crate) fn ref_token(&self) -> RefToken {
         RefToken::from(Ref::experience(self.id))
     }
+
+    pub(crate) fn is_amended(&self) -> bool {
+        self.amended_by.is_some()
+    }
 }
 
 impl Indexable<ExperienceId> for Experience {
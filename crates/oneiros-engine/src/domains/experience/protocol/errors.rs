@@ -12,6 +12,9 @@ pub(crate) enum ExperienceError {
     #[error("Experience not found: {0}")]
     NotFound(crate::ExperienceId),
 
+    #[error("Experience already amended: {0}")]
+    AlreadyAmended(crate::ExperienceId),
+
     #[error("Invalid ID: {0}")]
     InvalidId(#[from] crate::IdParseError),
 
@@ -47,6 +50,7 @@ impl IntoResponse for ExperienceError {
         let (status, message) = match &self {
             ExperienceError::AgentNotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
             ExperienceError::NotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
+            ExperienceError::AlreadyAmended(_) => (StatusCode::CONFLICT, self.to_string()),
             ExperienceError::InvalidId(_) => (StatusCode::UNPROCESSABLE_ENTITY, self.to_string()),
             ExperienceError::Resolve(_) => (StatusCode::UNPROCESSABLE_ENTITY, self.to_string()),
             ExperienceError::Database(_) | ExperienceError::Event(_) => {
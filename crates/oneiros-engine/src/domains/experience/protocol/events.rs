@@ -23,6 +23,7 @@ pub(crate) enum ExperienceEvents {
     ExperienceCreated(ExperienceCreated),
     ExperienceDescriptionUpdated(ExperienceDescriptionUpdated),
     ExperienceSensationUpdated(ExperienceSensationUpdated),
+    ExperienceAmended(ExperienceAmended),
 }
 
 versioned! {
@@ -51,6 +52,17 @@ versioned! {
     }
 }
 
+versioned! {
+    pub(crate) enum ExperienceAmended {
+        V1 => {
+            /// The experience being amended.
+            pub(crate) id: ExperienceId,
+            /// The experience as it now stands.
+            pub(crate) replacement: Experience,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -78,6 +90,10 @@ mod tests {
                 ExperienceEventsType::ExperienceSensationUpdated,
                 "experience-sensation-updated",
             ),
+            (
+                ExperienceEventsType::ExperienceAmended,
+                "experience-amended",
+            ),
         ];
         for (event_type, expectation) in cases {
             assert_eq!(&event_type.to_string(), expectation);
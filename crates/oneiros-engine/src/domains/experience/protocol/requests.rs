@@ -47,6 +47,11 @@ versioned! {
             #[arg(long)]
             #[builder(into)]
             pub(crate) lens: Option<String>,
+            /// Include experiences that have been amended.
+            #[arg(long)]
+            #[serde(default, deserialize_with = "query_flag")]
+            #[builder(default)]
+            pub(crate) include_retracted: bool,
             #[command(flatten)]
             #[serde(flatten)]
             #[builder(default)]
@@ -77,6 +82,22 @@ versioned! {
     }
 }
 
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum AmendExperience {
+        #[derive(clap::Args)]
+        V1 => {
+            #[builder(into)] pub(crate) id: ExperienceId,
+            /// Revised description. Defaults to the original's.
+            #[arg(long)]
+            pub(crate) description: Option<Description>,
+            /// Revised sensation. Defaults to the original's.
+            #[arg(long)]
+            pub(crate) sensation: Option<SensationName>,
+        }
+    }
+}
+
 resource_requests! {
     CreateExperience => |this, client| {
         client.post("/experiences", this).await
@@ -105,6 +126,10 @@ resource_requests! {
             params.push(("lens", lens.clone()));
         }
 
+        if listing.include_retracted {
+            params.push(("include_retracted", "true".to_string()));
+        }
+
         params.push(("limit", listing.filters.limit.to_string()));
         params.push(("offset", listing.filters.offset.to_string()));
 
@@ -134,6 +159,18 @@ resource_requests! {
             )
             .await
     },
+    AmendExperience => |this, client| {
+        let AmendExperience::V1(amendment) = this;
+        client
+            .post(
+                &format!("/experiences/{}/amend", amendment.id),
+                &serde_json::json!({
+                    "description": amendment.description,
+                    "sensation": amendment.sensation,
+                }),
+            )
+            .await
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Kinded)]
@@ -145,6 +182,7 @@ pub(crate) enum ExperienceRequest {
     ListExperiences(ListExperiences),
     UpdateExperienceDescription(UpdateExperienceDescription),
     UpdateExperienceSensation(UpdateExperienceSensation),
+    AmendExperience(AmendExperience),
 }
 
 #[cfg(test)]
@@ -165,6 +203,7 @@ mod tests {
                 ExperienceRequestType::UpdateExperienceSensation,
                 "update-experience-sensation",
             ),
+            (ExperienceRequestType::AmendExperience, "amend-experience"),
         ];
 
         for (request_type, expectation) in cases {
@@ -13,6 +13,7 @@ pub(crate) enum ExperienceResponse {
     Experiences(ExperiencesResponse),
     NoExperiences,
     ExperienceUpdated(ExperienceUpdatedResponse),
+    ExperienceAmended(ExperienceAmendedResponse),
 }
 
 versioned! {
@@ -45,3 +46,13 @@ versioned! {
         V1 => { #[serde(flatten)] pub(crate) experience: Experience }
     }
 }
+
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum ExperienceAmendedResponse {
+        V1 => {
+            pub(crate) amended: ExperienceId,
+            pub(crate) experience: Experience,
+        }
+    }
+}
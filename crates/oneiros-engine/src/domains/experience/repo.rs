@@ -25,7 +25,7 @@ impl<'a> ExperienceRepo<'a> {
     pub(crate) async fn get(&self, id: &ExperienceId) -> Result<Option<Experience>, EventError> {
         let db = BookmarkDb::open(self.scope).await?;
         let mut stmt = db.prepare(
-            "SELECT id, agent_id, sensation, description, created_at
+            "SELECT id, agent_id, sensation, description, created_at, amended_by
              FROM experiences WHERE id = ?1",
         )?;
 
@@ -37,17 +37,19 @@ impl<'a> ExperienceRepo<'a> {
                 row.get::<_, String>(2)?,
                 row.get::<_, String>(3)?,
                 row.get::<_, String>(4)?,
+                row.get::<_, Option<String>>(5)?,
             ))
         });
 
         match result {
-            Ok((id, agent_id, sensation, description, created_at)) => Ok(Some(
+            Ok((id, agent_id, sensation, description, created_at, amended_by)) => Ok(Some(
                 Experience::builder()
                     .id(id.parse()?)
                     .agent_id(agent_id.parse()?)
                     .sensation(sensation)
                     .description(description)
                     .created_at(Timestamp::parse_str(&created_at)?)
+                    .maybe_amended_by(amended_by.as_deref().map(str::parse).transpose()?)
                     .build(),
             )),
             Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
@@ -70,7 +72,7 @@ impl<'a> ExperienceRepo<'a> {
             .collect::<Vec<_>>()
             .join(", ");
         let sql = format!(
-            "SELECT id, agent_id, sensation, description, created_at
+            "SELECT id, agent_id, sensation, description, created_at, amended_by
              FROM experiences WHERE id IN ({placeholders})"
         );
         let id_strs: Vec<String> = ids.iter().map(ToString::to_string).collect();
@@ -83,18 +85,20 @@ impl<'a> ExperienceRepo<'a> {
                     row.get::<_, String>(2)?,
                     row.get::<_, String>(3)?,
                     row.get::<_, String>(4)?,
+                    row.get::<_, Option<String>>(5)?,
                 ))
             })?
             .collect::<Result<Vec<_>, _>>()?;
 
         let mut by_id: HashMap<ExperienceId, Experience> = HashMap::with_capacity(rows.len());
-        for (id, agent_id, sensation, description, created_at) in rows {
+        for (id, agent_id, sensation, description, created_at, amended_by) in rows {
             let experience = Experience::builder()
                 .id(id.parse()?)
                 .agent_id(agent_id.parse()?)
                 .sensation(sensation)
                 .description(description)
                 .created_at(Timestamp::parse_str(&created_at)?)
+                .maybe_amended_by(amended_by.as_deref().map(str::parse).transpose()?)
                 .build();
             by_id.insert(experience.id, experience);
         }
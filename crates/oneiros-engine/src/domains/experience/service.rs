@@ -88,6 +88,7 @@ impl ExperienceService {
             .kind(SearchKind::Experience)
             .maybe_sensation(listing.sensation.clone())
             .maybe_query(listing.query.clone())
+            .include_retracted(listing.include_retracted)
             .filters(listing.filters)
             .build();
 
@@ -220,4 +221,76 @@ impl ExperienceService {
                 .into(),
         ))
     }
+
+    /// Replace an experience with a revised one, carrying over whatever
+    /// the amendment leaves unspecified. The original stays in the event
+    /// log but drops out of listings, search and dreams.
+    pub(crate) async fn amend(
+        scope: &Scope<AtBookmark>,
+        mailbox: &Mailbox,
+        request: &AmendExperience,
+    ) -> Result<ExperienceResponse, ExperienceError> {
+        let AmendExperience::V1(amendment) = request;
+        if amendment.description.is_none() && amendment.sensation.is_none() {
+            return Err(ExperienceError::InvalidRequest(
+                "amend requires --description or --sensation".into(),
+            ));
+        }
+
+        let original = ExperienceRepo::new(scope)
+            .fetch(&amendment.id)
+            .await?
+            .ok_or(ExperienceError::NotFound(amendment.id))?;
+
+        if original.is_amended() {
+            return Err(ExperienceError::AlreadyAmended(original.id));
+        }
+
+        let replacement = Experience::builder()
+            .agent_id(original.agent_id)
+            .sensation(
+                amendment
+                    .sensation
+                    .clone()
+                    .unwrap_or_else(|| original.sensation.clone()),
+            )
+            .description(
+                amendment
+                    .description
+                    .clone()
+                    .unwrap_or_else(|| original.description.clone()),
+            )
+            .build();
+        let id = replacement.id;
+
+        let new_event = NewEvent::builder()
+            .data(Events::Experience(ExperienceEvents::ExperienceAmended(
+                ExperienceAmended::builder_v1()
+                    .id(original.id)
+                    .replacement(replacement)
+                    .build()
+                    .into(),
+            )))
+            .build();
+
+        mailbox.tell(ProjectMessage::from(
+            AppendProjectLog::builder()
+                .scope(scope.clone())
+                .event(new_event)
+                .build(),
+        ));
+
+        let stored = ExperienceRepo::new(scope)
+            .fetch(&id)
+            .await?
+            .ok_or(ExperienceError::NotFound(id))?;
+
+        Ok(ExperienceResponse::ExperienceAmended(
+            ExperienceAmendedResponse::builder_v1()
+                .amended(original.id)
+                .experience(stored)
+                .build()
+                .into(),
+        ))
+    }
 }
@@ -30,6 +30,18 @@ impl<'a> ExperienceStore<'a> {
                         search.index_entry(&IndexEntry::experience(&exp))?;
                     }
                 }
+                ExperienceEvents::ExperienceAmended(amended) => {
+                    let current = amended.current()?;
+                    let replacement = current.replacement;
+                    self.write_experience(&replacement)?;
+                    self.conn.execute(
+                        "UPDATE experiences SET amended_by = ?2 WHERE id = ?1",
+                        params![current.id.to_string(), replacement.id.to_string()],
+                    )?;
+                    let search = SearchStore::new(self.conn);
+                    search.index_entry(&IndexEntry::experience(&replacement))?;
+                    search.retract(&Ref::experience(current.id))?;
+                }
                 ExperienceEvents::ExperienceSensationUpdated(updated) => {
                     let current = updated.current()?;
                     self.update_sensation(&current.id, &current.sensation)?;
@@ -59,12 +71,16 @@ impl<'a> ExperienceStore<'a> {
                 created_at TEXT NOT NULL DEFAULT ''
             )",
         )?;
+        // Added with amendments; older projections lack it.
+        self.conn
+            .execute("ALTER TABLE experiences ADD COLUMN amended_by TEXT", [])
+            .ok();
         Ok(())
     }
 
     pub(crate) fn get(&self, id: &ExperienceId) -> Result<Option<Experience>, EventError> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, agent_id, sensation, description, created_at
+            "SELECT id, agent_id, sensation, description, created_at, amended_by
              FROM experiences WHERE id = ?1",
         )?;
 
@@ -75,17 +91,19 @@ impl<'a> ExperienceStore<'a> {
                 row.get::<_, String>(2)?,
                 row.get::<_, String>(3)?,
                 row.get::<_, String>(4)?,
+                row.get::<_, Option<String>>(5)?,
             ))
         });
 
         match result {
-            Ok((id, agent_id, sensation, description, created_at)) => Ok(Some(
+            Ok((id, agent_id, sensation, description, created_at, amended_by)) => Ok(Some(
                 Experience::builder()
                     .id(id.parse()?)
                     .agent_id(agent_id.parse()?)
                     .sensation(sensation)
                     .description(description)
                     .created_at(Timestamp::parse_str(&created_at)?)
+                    .maybe_amended_by(amended_by.as_deref().map(str::parse).transpose()?)
                     .build(),
             )),
             Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
@@ -96,11 +114,11 @@ impl<'a> ExperienceStore<'a> {
     pub(crate) fn list(&self, agent: Option<&str>) -> Result<Vec<Experience>, EventError> {
         let mut stmt = match agent {
             Some(_) => self.conn.prepare(
-                "SELECT id, agent_id, sensation, description, created_at
+                "SELECT id, agent_id, sensation, description, created_at, amended_by
                  FROM experiences WHERE agent_id = ?1 ORDER BY created_at",
             )?,
             None => self.conn.prepare(
-                "SELECT id, agent_id, sensation, description, created_at
+                "SELECT id, agent_id, sensation, description, created_at, amended_by
                  FROM experiences ORDER BY created_at",
             )?,
         };
@@ -112,6 +130,7 @@ impl<'a> ExperienceStore<'a> {
                 row.get::<_, String>(2)?,
                 row.get::<_, String>(3)?,
                 row.get::<_, String>(4)?,
+                row.get::<_, Option<String>>(5)?,
             ))
         };
 
@@ -122,7 +141,7 @@ impl<'a> ExperienceStore<'a> {
         .collect::<Result<Vec<_>, _>>()?;
 
         let mut experiences = vec![];
-        for (id, agent_id, sensation, description, created_at) in raw {
+        for (id, agent_id, sensation, description, created_at, amended_by) in raw {
             experiences.push(
                 Experience::builder()
                     .id(id.parse()?)
@@ -130,6 +149,7 @@ impl<'a> ExperienceStore<'a> {
                     .sensation(sensation)
                     .description(description)
                     .created_at(Timestamp::parse_str(&created_at)?)
+                    .maybe_amended_by(amended_by.as_deref().map(str::parse).transpose()?)
                     .build(),
             );
         }
@@ -137,16 +157,16 @@ impl<'a> ExperienceStore<'a> {
         Ok(experiences)
     }
 
-    /// Most recent experiences for an agent, ordered newest-first.
+    /// Most recent unamended experiences for an agent, ordered newest-first.
     pub(crate) fn list_recent(
         &self,
         agent_id: &str,
         limit: usize,
     ) -> Result<Vec<Experience>, EventError> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, agent_id, sensation, description, created_at
+            "SELECT id, agent_id, sensation, description, created_at, amended_by
              FROM experiences
-             WHERE agent_id = ?1
+             WHERE agent_id = ?1 AND amended_by IS NULL
              ORDER BY created_at DESC
              LIMIT ?2",
         )?;
@@ -158,6 +178,7 @@ impl<'a> ExperienceStore<'a> {
                 row.get::<_, String>(2)?,
                 row.get::<_, String>(3)?,
                 row.get::<_, String>(4)?,
+                row.get::<_, Option<String>>(5)?,
             ))
         };
 
@@ -166,7 +187,7 @@ impl<'a> ExperienceStore<'a> {
             .collect::<Result<Vec<_>, _>>()?;
 
         let mut experiences = vec![];
-        for (id, agent_id, sensation, description, created_at) in raw {
+        for (id, agent_id, sensation, description, created_at, amended_by) in raw {
             experiences.push(
                 Experience::builder()
                     .id(id.parse()?)
@@ -174,6 +195,7 @@ impl<'a> ExperienceStore<'a> {
                     .sensation(sensation)
                     .description(description)
                     .created_at(Timestamp::parse_str(&created_at)?)
+                    .maybe_amended_by(amended_by.as_deref().map(str::parse).transpose()?)
                     .build(),
             );
         }
@@ -183,14 +205,15 @@ impl<'a> ExperienceStore<'a> {
 
     fn write_experience(&self, experience: &Experience) -> Result<(), EventError> {
         self.conn.execute(
-            "INSERT OR REPLACE INTO experiences (id, agent_id, sensation, description, created_at)
-             VALUES (?1, ?2, ?3, ?4, ?5)",
+            "INSERT OR REPLACE INTO experiences (id, agent_id, sensation, description, created_at, amended_by)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
             params![
                 experience.id.to_string(),
                 experience.agent_id.to_string(),
                 experience.sensation.to_string(),
                 experience.description.to_string(),
                 experience.created_at.as_string(),
+                experience.amended_by.map(|id| id.to_string()),
             ],
         )?;
         Ok(())
@@ -26,8 +26,15 @@ impl<'a> ExperienceView<'a> {
             }
             ExperienceResponse::ExperienceDetails(ExperienceDetailsResponse::V1(details)) => {
                 let ref_token = RefToken::from(Ref::experience(details.experience.id));
+                let status = match details.experience.amended_by {
+                    Some(replacement) => format!(
+                        "**amended by:** {}\n",
+                        RefToken::from(Ref::experience(replacement))
+                    ),
+                    None => String::new(),
+                };
                 McpResponse::new(format!(
-                    "# Experience\n\n**sensation:** {}\n**agent:** {}\n**created:** {}\n\n{}\n",
+                    "# Experience\n\n**sensation:** {}\n**agent:** {}\n**created:** {}\n{status}\n{}\n",
                     details.experience.sensation,
                     details.experience.agent_id,
                     details.experience.created_at,
@@ -86,6 +93,19 @@ impl<'a> ExperienceView<'a> {
                     MutationHints::builder().ref_token(ref_token).build(),
                 ))
             }
+            ExperienceResponse::ExperienceAmended(ExperienceAmendedResponse::V1(amended)) => {
+                let ref_token = RefToken::from(Ref::experience(amended.experience.id));
+                McpResponse::new(format!(
+                    "Experience amended ({}).\n\n**sensation:** {}\n**replaces:** {}\n**ref:** {}",
+                    amended.experience.sensation,
+                    amended.experience.sensation,
+                    RefToken::from(Ref::experience(amended.amended)),
+                    ref_token
+                ))
+                .hint_set(HintSet::mutation(
+                    MutationHints::builder().ref_token(ref_token).build(),
+                ))
+            }
         }
     }
 
@@ -108,9 +128,15 @@ impl<'a> ExperienceView<'a> {
                 .with_hints(hints)
             }
             ExperienceResponse::ExperienceDetails(ExperienceDetailsResponse::V1(details)) => {
-                let prompt = Detail::new(details.experience.sensation.to_string())
-                    .field("description:", details.experience.description.to_string())
-                    .to_string();
+                let mut detail = Detail::new(details.experience.sensation.to_string())
+                    .field("description:", details.experience.description.to_string());
+                if let Some(replacement) = details.experience.amended_by {
+                    detail = detail.field(
+                        "amended by:",
+                        RefToken::from(Ref::experience(replacement)).to_string(),
+                    );
+                }
+                let prompt = detail.to_string();
                 let ref_token = RefToken::from(Ref::experience(details.experience.id));
                 let hints =
                     HintSet::mutation(MutationHints::builder().ref_token(ref_token).build());
@@ -166,6 +192,23 @@ impl<'a> ExperienceView<'a> {
                 )
                 .with_hints(hints)
             }
+            ExperienceResponse::ExperienceAmended(ExperienceAmendedResponse::V1(amended)) => {
+                let ref_token = RefToken::from(Ref::experience(amended.experience.id));
+                let subject = format!(
+                    "{} Experience amended: {} → {}",
+                    "✓".success(),
+                    RefToken::from(Ref::experience(amended.amended)).muted(),
+                    ref_token.clone().muted()
+                );
+                let hints =
+                    HintSet::mutation(MutationHints::builder().ref_token(ref_token).build());
+                Rendered::new(
+                    ExperienceResponse::ExperienceAmended(ExperienceAmendedResponse::V1(amended)),
+                    subject,
+                    String::new(),
+                )
+                .with_hints(hints)
+            }
         }
     }
 }
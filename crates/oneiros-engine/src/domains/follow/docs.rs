@@ -22,13 +22,17 @@ impl FollowDocs {
                 .tag(tag)
                 .nickname("get-follow")
                 .summary("Get a follow")
-                .description("Retrieve a single follow record by its identifier.")
+                .description(
+                    "Retrieve a single follow record by its identifier, with its background sync status.",
+                )
                 .build(),
             Self::List => ResourceDocs::builder()
                 .tag(tag)
                 .nickname("list-follows")
                 .summary("List follows")
-                .description("List all follow records on this host.")
+                .description(
+                    "List all follow records on this host, with the background sync status of each peer follow.",
+                )
                 .build(),
         }
     }
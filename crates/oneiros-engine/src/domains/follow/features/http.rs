@@ -1,5 +1,5 @@
 use aide::axum::{ApiRouter, routing};
-use axum::{Json, extract::Path, extract::Query};
+use axum::{Json, extract::Path, extract::Query, extract::State};
 
 use crate::*;
 
@@ -33,17 +33,20 @@ impl FollowRouter {
 
 async fn list(
     scope: Scope<AtHost>,
+    State(state): State<ServerState>,
     Query(params): Query<ListFollows>,
 ) -> Result<Json<Response<FollowResponse>>, FollowError> {
     let ListFollows::V1(listing) = &params;
     let listed = FollowService::list(&scope, &listing.filters).await?;
+    let statuses = state.follow_statuses();
 
     let items: Vec<Response<FollowFoundResponse>> = listed
         .items
         .into_iter()
         .map(|follow| {
             Response::new(
-                FollowFoundResponse::builder_v1()
+                FollowFoundResponse::builder_v2()
+                    .maybe_sync(statuses.get(follow.id))
                     .follow(follow)
                     .build()
                     .into(),
@@ -59,12 +62,14 @@ async fn list(
 
 async fn show(
     scope: Scope<AtHost>,
+    State(state): State<ServerState>,
     Path(key): Path<ResourceKey<FollowId>>,
 ) -> Result<Json<Response<FollowResponse>>, FollowError> {
     let id = key.resolve()?;
     let follow = FollowService::get(&scope, id).await?;
 
-    let inner = FollowFoundResponse::builder_v1()
+    let inner = FollowFoundResponse::builder_v2()
+        .maybe_sync(state.follow_statuses().get(id))
         .follow(follow)
         .build()
         .into();
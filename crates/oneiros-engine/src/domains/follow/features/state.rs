@@ -24,7 +24,24 @@ impl FollowState {
                         canon.follows.remove(&current.follow_id);
                     }
                 }
-                BookmarkEvents::BookmarkCreated(_)
+                BookmarkEvents::BookmarkRenamed(renamed) => {
+                    if let Ok(current) = renamed.current() {
+                        let moved: Vec<Follow> = canon
+                            .follows
+                            .values()
+                            .filter(|follow| {
+                                follow.project == current.project && follow.bookmark == current.from
+                            })
+                            .cloned()
+                            .collect();
+                        for mut follow in moved {
+                            follow.bookmark = current.to.clone();
+                            canon.follows.set(&follow);
+                        }
+                    }
+                }
+                BookmarkEvents::BookmarkDeleted(_)
+                | BookmarkEvents::BookmarkCreated(_)
                 | BookmarkEvents::BookmarkForked(_)
                 | BookmarkEvents::BookmarkSwitched(_)
                 | BookmarkEvents::BookmarkMerged(_)
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use bon::Builder;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -35,6 +37,98 @@ impl Indexable<FollowId> for Follow {
     }
 }
 
+/// How background sync has gone for one peer follow since the host
+/// started. Runtime state, not history: the durable part of a collect is
+/// the checkpoint `BookmarkCollected` records on the follow itself.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub(crate) struct FollowSyncStatus {
+    /// When a collect was last attempted.
+    pub(crate) last_attempt_at: Option<Timestamp>,
+    /// When a collect last succeeded.
+    pub(crate) last_success_at: Option<Timestamp>,
+    /// The error from the most recent attempt, cleared by a success.
+    pub(crate) last_error: Option<String>,
+    /// Events received across every successful collect.
+    pub(crate) events_received: u64,
+    /// Attempts that have failed in a row.
+    pub(crate) failures: u32,
+}
+
+impl FollowSyncStatus {
+    pub(crate) fn succeeded(&mut self, events_received: u64) {
+        let now = Timestamp::now();
+        self.last_attempt_at = Some(now);
+        self.last_success_at = Some(now);
+        self.last_error = None;
+        self.events_received += events_received;
+        self.failures = 0;
+    }
+
+    pub(crate) fn failed(&mut self, error: impl ToString) {
+        self.last_attempt_at = Some(Timestamp::now());
+        self.last_error = Some(error.to_string());
+        self.failures = self.failures.saturating_add(1);
+    }
+
+    /// How long to wait after the last attempt before the next one. A
+    /// healthy follow waits `interval`; each failure in a row doubles
+    /// the wait, up to `max_backoff`.
+    pub(crate) fn delay(&self, interval: Duration, max_backoff: Duration) -> Duration {
+        let factor = 2u32.saturating_pow(self.failures.min(16));
+        interval
+            .saturating_mul(factor)
+            .min(max_backoff.max(interval))
+    }
+
+    /// Whether `delay` has passed since the last attempt.
+    pub(crate) fn due(&self, now: Timestamp, delay: Duration) -> bool {
+        self.last_attempt_at
+            .is_none_or(|attempted| now.duration_since(attempted) >= delay)
+    }
+}
+
 pub(crate) type Follows = EntityIndex<FollowId, Follow>;
 
 resource_id!(FollowId);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINUTE: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn failures_back_off_up_to_the_cap() {
+        let mut status = FollowSyncStatus::default();
+        assert_eq!(status.delay(MINUTE, 10 * MINUTE), MINUTE);
+
+        status.failed("unreachable");
+        assert_eq!(status.delay(MINUTE, 10 * MINUTE), 2 * MINUTE);
+
+        status.failed("unreachable");
+        status.failed("unreachable");
+        assert_eq!(status.delay(MINUTE, 10 * MINUTE), 8 * MINUTE);
+
+        status.failed("unreachable");
+        assert_eq!(status.delay(MINUTE, 10 * MINUTE), 10 * MINUTE);
+
+        status.succeeded(3);
+        assert_eq!(status.failures, 0);
+        assert_eq!(status.last_error, None);
+        assert_eq!(status.events_received, 3);
+        assert_eq!(status.delay(MINUTE, 10 * MINUTE), MINUTE);
+    }
+
+    #[test]
+    fn due_once_the_delay_has_passed() {
+        let mut status = FollowSyncStatus::default();
+        assert!(status.due(Timestamp::now(), MINUTE));
+
+        status.succeeded(0);
+        let attempted = status.last_attempt_at.expect("attempted");
+        assert!(!status.due(attempted, MINUTE));
+
+        let later = Timestamp::from(chrono::Utc::now() + chrono::Duration::minutes(2));
+        assert!(status.due(later, MINUTE));
+    }
+}
@@ -19,9 +19,33 @@ pub(crate) enum FollowResponse {
 versioned! {
     #[derive(JsonSchema)]
     pub(crate) enum FollowFoundResponse {
+        V2 => {
+            #[serde(flatten)] pub(crate) follow: Follow,
+            /// Background sync status. Absent until a collect has been
+            /// attempted since the host started.
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            pub(crate) sync: Option<FollowSyncStatus>,
+        },
+        #[derive(schemars::JsonSchema)]
         V1 => {
             #[serde(flatten)] pub(crate) follow: Follow,
-        }
+        },
+    }
+}
+
+impl TryFrom<FollowFoundResponseV1> for FollowFoundResponseV2 {
+    type Error = UpcastError;
+    fn try_from(v1: FollowFoundResponseV1) -> Result<Self, Self::Error> {
+        Ok(FollowFoundResponseV2 {
+            follow: v1.follow,
+            sync: None,
+        })
+    }
+}
+
+impl From<FollowFoundResponseV1> for FollowFoundResponse {
+    fn from(v: FollowFoundResponseV1) -> Self {
+        Self::V1(v)
     }
 }
 
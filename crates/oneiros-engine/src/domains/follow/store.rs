@@ -30,7 +30,12 @@ impl<'a> FollowStore<'a> {
                     let current = unfollowed.current()?;
                     self.delete_record(current.follow_id)?;
                 }
-                BookmarkEvents::BookmarkCreated(_)
+                BookmarkEvents::BookmarkRenamed(renamed) => {
+                    let current = renamed.current()?;
+                    self.rename_bookmark(&current)?;
+                }
+                BookmarkEvents::BookmarkDeleted(_)
+                | BookmarkEvents::BookmarkCreated(_)
                 | BookmarkEvents::BookmarkForked(_)
                 | BookmarkEvents::BookmarkSwitched(_)
                 | BookmarkEvents::BookmarkMerged(_)
@@ -95,4 +100,17 @@ impl<'a> FollowStore<'a> {
             .execute("delete from follows where id = ?1", params![id.to_string()])?;
         Ok(())
     }
+
+    /// Follows name their local bookmark, so a rename carries them along.
+    fn rename_bookmark(&self, renamed: &BookmarkRenamedV1) -> Result<(), EventError> {
+        self.conn.execute(
+            "update follows set bookmark = ?1 where project = ?2 and bookmark = ?3",
+            params![
+                renamed.to.to_string(),
+                renamed.project.to_string(),
+                renamed.from.to_string(),
+            ],
+        )?;
+        Ok(())
+    }
 }
@@ -13,13 +13,10 @@ impl FollowView {
 
     pub(crate) fn render(self) -> Rendered<FollowResponse> {
         match self.response {
-            FollowResponse::Found(FollowFoundResponse::V1(found)) => {
-                let prompt = Self::detail(&found.follow).to_string();
-                Rendered::new(
-                    FollowResponse::Found(FollowFoundResponse::V1(found)),
-                    prompt,
-                    String::new(),
-                )
+            FollowResponse::Found(found) => {
+                let (follow, sync) = Self::parts(&found);
+                let prompt = Self::detail(follow, sync).to_string();
+                Rendered::new(FollowResponse::Found(found), prompt, String::new())
             }
             FollowResponse::Listed(FollowsResponse::V1(listed)) => {
                 let prompt = format!(
@@ -41,25 +38,34 @@ impl FollowView {
         }
     }
 
+    fn parts(found: &FollowFoundResponse) -> (&Follow, Option<&FollowSyncStatus>) {
+        match found {
+            FollowFoundResponse::V2(found) => (&found.follow, found.sync.as_ref()),
+            FollowFoundResponse::V1(found) => (&found.follow, None),
+        }
+    }
+
     fn table(follows: &Listed<Response<FollowFoundResponse>>) -> Table {
         let mut table = Table::new(vec![
             Column::new("Bookmark"),
             Column::new("Project"),
             Column::new("Source"),
+            Column::new("Last sync"),
+            Column::new("Received"),
+            Column::new("Status"),
             Column::new("ID"),
         ]);
 
         for wrapped in &follows.items {
-            let FollowFoundResponse::V1(found) = &wrapped.data;
-            let follow = &found.follow;
-            let source_label = match &follow.source {
-                FollowSource::Local(_) => "local",
-                FollowSource::Peer(_) => "peer",
-            };
+            let (follow, sync) = Self::parts(&wrapped.data);
             table.push_row(vec![
                 follow.bookmark.to_string(),
                 follow.project.to_string(),
-                source_label.to_string(),
+                Self::source_label(follow).to_string(),
+                Self::last_sync(follow, sync),
+                sync.map(|status| status.events_received.to_string())
+                    .unwrap_or_default(),
+                Self::status(follow, sync),
                 follow.id.to_string(),
             ]);
         }
@@ -67,19 +73,52 @@ impl FollowView {
         table
     }
 
-    fn detail(follow: &Follow) -> Detail {
-        let source_label = match &follow.source {
-            FollowSource::Local(_) => "local",
-            FollowSource::Peer(_) => "peer",
-        };
-        Detail::new(follow.bookmark.to_string())
+    fn detail(follow: &Follow, sync: Option<&FollowSyncStatus>) -> Detail {
+        let detail = Detail::new(follow.bookmark.to_string())
             .field("id:", follow.id.to_string())
             .field("project:", follow.project.to_string())
-            .field("source:", source_label.to_string())
+            .field("source:", Self::source_label(follow).to_string())
             .field(
                 "checkpoint.sequence:",
                 follow.checkpoint.sequence.to_string(),
             )
-            .field("created_at:", follow.created_at.as_string())
+            .field("created_at:", follow.created_at.as_string());
+
+        match sync {
+            Some(status) => detail
+                .field("sync.last_success:", Self::last_sync(follow, sync))
+                .field("sync.events_received:", status.events_received.to_string())
+                .field("sync.status:", Self::status(follow, sync)),
+            None => detail,
+        }
+    }
+
+    fn source_label(follow: &Follow) -> &'static str {
+        match &follow.source {
+            FollowSource::Local(_) => "local",
+            FollowSource::Peer(_) => "peer",
+        }
+    }
+
+    fn last_sync(follow: &Follow, sync: Option<&FollowSyncStatus>) -> String {
+        match (
+            &follow.source,
+            sync.and_then(|status| status.last_success_at),
+        ) {
+            (FollowSource::Local(_), _) => String::new(),
+            (FollowSource::Peer(_), Some(at)) => at.elapsed(),
+            (FollowSource::Peer(_), None) => "never".to_string(),
+        }
+    }
+
+    fn status(follow: &Follow, sync: Option<&FollowSyncStatus>) -> String {
+        match (&follow.source, sync) {
+            (FollowSource::Local(_), _) => String::new(),
+            (FollowSource::Peer(_), None) => "pending".to_string(),
+            (FollowSource::Peer(_), Some(status)) => match &status.last_error {
+                Some(error) => format!("failing ({}x): {error}", status.failures),
+                None => "ok".to_string(),
+            },
+        }
     }
 }
@@ -0,0 +1,308 @@
+//! `FollowSyncActor` — singleton that keeps peer follows collected.
+//!
+//! Sweeps every follow with a `FollowSource::Peer` once per
+//! `sync.interval`, skipping follows still backing off from a failure.
+//! A `FollowSync` message sweeps one project's follows soon after
+//! when `sync.on_change` is set; nudges that arrive within
+//! [`NUDGE_QUIET`] of each other share one sweep, so a burst of local
+//! writes collects once. Outcomes land in the shared
+//! [`FollowStatusIndex`]; a follow's checkpoint only advances when a
+//! collect brought something in, so idle sweeps leave the host log alone.
+//!
+//! With `sync.push` set, the actor also holds a push subscription open
+//! to each peer follow — one task per follow, importing events as the
+//! peer streams them. `FollowSubscribe` and `FollowUnsubscribe` open and
+//! close one as follows come and go; every sweep reopens any that have
+//! dropped. Pushed events don't advance the checkpoint;
+//! the next sweep's diff finds nothing missing and leaves it be.
+//!
+//! Receives the full `HostMessage`; handles the sync variants and
+//! no-ops the rest.
+
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio::time::{Instant, MissedTickBehavior};
+
+use crate::*;
+
+/// How long the actor waits after the latest nudge before it sweeps.
+const NUDGE_QUIET: Duration = Duration::from_millis(500);
+
+#[derive(Clone)]
+pub(crate) struct FollowSyncMailbox {
+    tx: mpsc::UnboundedSender<HostMessage>,
+}
+
+impl FollowSyncMailbox {
+    pub(crate) fn open() -> (Self, FollowSyncInbox) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        (Self { tx }, FollowSyncInbox { rx })
+    }
+
+    pub(crate) fn tell(&self, message: HostMessage) {
+        if let Err(error) = self.tx.send(message) {
+            tracing::warn!(error = %error, "follow sync: receiver closed; message dropped");
+        }
+    }
+}
+
+pub(crate) struct FollowSyncInbox {
+    rx: mpsc::UnboundedReceiver<HostMessage>,
+}
+
+impl FollowSyncInbox {
+    pub(crate) async fn recv(&mut self) -> Option<HostMessage> {
+        self.rx.recv().await
+    }
+}
+
+pub(crate) struct FollowSyncActor {
+    config: Config,
+    canons: CanonIndex,
+    bridge: Bridge,
+    mailbox: Mailbox,
+    statuses: FollowStatusIndex,
+    subscriptions: HashMap<FollowId, JoinHandle<()>>,
+}
+
+impl FollowSyncActor {
+    pub(crate) fn spawn(
+        inbox: FollowSyncInbox,
+        config: Config,
+        canons: CanonIndex,
+        bridge: Bridge,
+        mailbox: Mailbox,
+        statuses: FollowStatusIndex,
+    ) {
+        tokio::spawn(
+            Self {
+                config,
+                canons,
+                bridge,
+                mailbox,
+                statuses,
+                subscriptions: HashMap::new(),
+            }
+            .run(inbox),
+        );
+    }
+
+    async fn run(mut self, mut inbox: FollowSyncInbox) {
+        let sync = self.config.sync.clone();
+        let pushing = sync.enabled && sync.push;
+        let mut ticker = tokio::time::interval_at(Instant::now() + sync.interval, sync.interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        if pushing && let Err(error) = self.resubscribe().await {
+            tracing::warn!(?error, "follow sync: opening push subscriptions failed");
+        }
+
+        let mut nudged: HashSet<ProjectName> = HashSet::new();
+        let mut settle = Instant::now();
+
+        loop {
+            tokio::select! {
+                _ = ticker.tick(), if sync.enabled => {
+                    if pushing && let Err(error) = self.resubscribe().await {
+                        tracing::error!(?error, "follow sync: resubscribe failed");
+                    }
+                    if let Err(error) = self.sweep(None).await {
+                        tracing::error!(?error, "follow sync: sweep failed");
+                    }
+                }
+                _ = tokio::time::sleep_until(settle), if !nudged.is_empty() => {
+                    for project in std::mem::take(&mut nudged) {
+                        if let Err(error) = self.sweep(Some(&project)).await {
+                            tracing::error!(?error, "follow sync: sweep failed");
+                        }
+                    }
+                }
+                message = inbox.recv() => match message {
+                    Some(HostMessage::FollowSync(nudge)) if sync.enabled && sync.on_change => {
+                        nudged.insert(nudge.project);
+                        settle = Instant::now() + NUDGE_QUIET;
+                    }
+                    Some(HostMessage::FollowSubscribe(subscribe)) if pushing => {
+                        self.subscribe(subscribe.follow);
+                    }
+                    Some(HostMessage::FollowUnsubscribe(unsubscribe)) => {
+                        if let Some(task) = self.subscriptions.remove(&unsubscribe.follow) {
+                            task.abort();
+                        }
+                    }
+                    Some(_) => {}
+                    None => break,
+                },
+            }
+        }
+
+        for task in self.subscriptions.into_values() {
+            task.abort();
+        }
+    }
+
+    /// Bring push subscriptions in line with the peer follows: open
+    /// any that are missing or have dropped, and close those whose
+    /// follow is gone.
+    async fn resubscribe(&mut self) -> Result<(), FollowError> {
+        let scope = ComposeScope::new(self.config.clone()).host()?;
+        let all = SearchFilters {
+            limit: Limit(usize::MAX),
+            offset: Offset(0),
+        };
+        let follows = FollowService::list(&scope, &all).await?.items;
+
+        self.subscriptions.retain(|id, task| {
+            let live = follows.iter().any(|follow| follow.id == *id);
+            if !live {
+                task.abort();
+            }
+            live
+        });
+
+        for follow in follows {
+            self.subscribe(follow);
+        }
+
+        Ok(())
+    }
+
+    /// Open a push subscription for a peer follow, unless one is
+    /// already running.
+    fn subscribe(&mut self, follow: Follow) {
+        let FollowSource::Peer(peer_link) = follow.source.clone() else {
+            return;
+        };
+        if self
+            .subscriptions
+            .get(&follow.id)
+            .is_some_and(|task| !task.is_finished())
+        {
+            return;
+        }
+
+        let id = follow.id;
+        let task = tokio::spawn(Self::push(
+            self.bridge.clone(),
+            self.mailbox.clone(),
+            self.config.clone(),
+            self.statuses.clone(),
+            follow,
+            peer_link,
+        ));
+        self.subscriptions.insert(id, task);
+    }
+
+    /// Hold one follow's push subscription open, importing whatever the
+    /// peer streams until it hangs up. A failure is recorded against the
+    /// follow; the next sweep tries again.
+    async fn push(
+        bridge: Bridge,
+        mailbox: Mailbox,
+        config: Config,
+        statuses: FollowStatusIndex,
+        follow: Follow,
+        peer_link: PeerLink,
+    ) {
+        let received = async {
+            let mut subscription = bridge
+                .subscribe(&peer_link.host, &peer_link.link)
+                .await
+                .map_err(|e| BookmarkError::InvalidUri(e.to_string()))?;
+
+            while let Some(events) = subscription
+                .next()
+                .await
+                .map_err(|e| BookmarkError::InvalidUri(e.to_string()))?
+            {
+                let count = BookmarkService::import_pushed(
+                    &mailbox, &bridge, &config, &follow, &peer_link, events,
+                )
+                .await?;
+                statuses.succeeded(follow.id, count);
+            }
+
+            Ok::<_, BookmarkError>(())
+        };
+
+        if let Err(error) = received.await {
+            tracing::warn!(%error, follow = %follow.id, "follow sync: push subscription failed");
+            statuses.failed(follow.id, error);
+        }
+    }
+
+    /// Collect every due peer follow — all of them on a scheduled
+    /// sweep, or one project's when nudged. A nudged sweep ignores the
+    /// interval but still respects a failing follow's backoff.
+    async fn sweep(&self, project: Option<&ProjectName>) -> Result<(), FollowError> {
+        let scope = ComposeScope::new(self.config.clone()).host()?;
+        let all = SearchFilters {
+            limit: Limit(usize::MAX),
+            offset: Offset(0),
+        };
+        let follows = FollowService::list(&scope, &all).await?.items;
+        let live: Vec<FollowId> = follows.iter().map(|follow| follow.id).collect();
+        self.statuses.retain(&live);
+
+        let now = Timestamp::now();
+        for follow in follows {
+            let FollowSource::Peer(peer_link) = follow.source.clone() else {
+                continue;
+            };
+            if project.is_some_and(|project| *project != follow.project) {
+                continue;
+            }
+
+            let status = self.statuses.get(follow.id).unwrap_or_default();
+            let delay = match project {
+                Some(_) if status.failures == 0 => Duration::ZERO,
+                _ => status.delay(self.config.sync.interval, self.config.sync.max_backoff),
+            };
+            if !status.due(now, delay) {
+                continue;
+            }
+
+            self.collect(&scope, &follow, peer_link).await;
+        }
+
+        Ok(())
+    }
+
+    async fn collect(&self, scope: &Scope<AtHost>, follow: &Follow, peer_link: PeerLink) {
+        let pulled = BookmarkService::pull_follow(
+            &self.mailbox,
+            &self.bridge,
+            &self.canons,
+            &self.config,
+            follow,
+            peer_link,
+        )
+        .await;
+
+        match pulled {
+            Ok((events_received, checkpoint)) => {
+                self.statuses.succeeded(follow.id, events_received);
+                let resumed = follow.checkpoint.pending.is_some();
+                if (events_received > 0 || resumed)
+                    && let Err(error) = FollowService::advance(
+                        scope,
+                        &self.mailbox,
+                        follow.id,
+                        checkpoint,
+                        events_received,
+                    )
+                    .await
+                {
+                    tracing::error!(?error, follow = %follow.id, "follow sync: advance failed");
+                }
+            }
+            Err(error) => {
+                tracing::warn!(%error, follow = %follow.id, "follow sync: collect failed");
+                self.statuses.failed(follow.id, error);
+            }
+        }
+    }
+}
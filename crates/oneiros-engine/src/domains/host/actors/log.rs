@@ -1,6 +1,7 @@
 //! `HostLogActor` — singleton that appends events to the host event
 //! log, then emits a projection apply message addressed to the system
-//! projection actor.
+//! projection actor and publishes the stored event on the host's
+//! [`EventFeed`].
 //!
 //! Stateless across messages: the scope on each `LogAppend` opens the
 //! host DB. Receives the full `HostMessage`; handles the log variants
@@ -40,11 +41,25 @@ impl HostLogInbox {
 
 pub(crate) struct HostLogActor {
     mailbox: Mailbox,
+    feed: EventFeed,
+    secret: iroh::SecretKey,
 }
 
 impl HostLogActor {
-    pub(crate) fn spawn(inbox: HostLogInbox, mailbox: Mailbox) {
-        tokio::spawn(Self { mailbox }.run(inbox));
+    pub(crate) fn spawn(
+        inbox: HostLogInbox,
+        mailbox: Mailbox,
+        feed: EventFeed,
+        secret: iroh::SecretKey,
+    ) {
+        tokio::spawn(
+            Self {
+                mailbox,
+                feed,
+                secret,
+            }
+            .run(inbox),
+        );
     }
 
     async fn run(self, mut inbox: HostLogInbox) {
@@ -60,13 +75,16 @@ impl HostLogActor {
     async fn append(&self, append: AppendHostLog) -> Result<(), EventError> {
         let AppendHostLog { scope, event } = append;
         let host_db = HostDb::open(&scope).await?;
-        let stored = EventLog::new(&host_db).append(&event)?;
+        let stored = EventLog::new(&host_db)
+            .signed_by(&self.secret)
+            .append(&event)?;
         self.mailbox.tell(HostMessage::from(
             ApplyHostProjection::builder()
                 .scope(scope)
-                .stored(stored)
+                .stored(stored.clone())
                 .build(),
         ));
+        self.feed.publish_host(Box::new(stored));
         Ok(())
     }
 }
@@ -1,5 +1,9 @@
+mod follow_sync;
 mod log;
 mod projection;
+mod retention;
 
+pub(crate) use follow_sync::*;
 pub(crate) use log::*;
 pub(crate) use projection::*;
+pub(crate) use retention::*;
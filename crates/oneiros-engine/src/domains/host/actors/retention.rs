@@ -0,0 +1,77 @@
+//! `RetentionActor` — singleton that enforces level retention policies.
+//!
+//! Once per `retention.interval`, walks every bookmark of every project
+//! the host knows and runs [`MemoryService::retain`] against it. Expired
+//! memories are demoted or archived through the ordinary project log, so
+//! the sweep is replayable like anything else an agent records.
+//!
+//! Takes no messages; it runs on its ticker alone and does nothing when
+//! `retention.enabled` is off.
+
+use std::collections::HashSet;
+
+use tokio::time::{Instant, MissedTickBehavior};
+
+use crate::*;
+
+pub(crate) struct RetentionActor {
+    config: Config,
+    mailbox: Mailbox,
+}
+
+impl RetentionActor {
+    pub(crate) fn spawn(config: Config, mailbox: Mailbox) {
+        if !config.retention.enabled {
+            return;
+        }
+        tokio::spawn(Self { config, mailbox }.run());
+    }
+
+    async fn run(self) {
+        let interval = self.config.retention.interval;
+        let mut ticker = tokio::time::interval_at(Instant::now() + interval, interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            if let Err(error) = self.sweep().await {
+                tracing::error!(?error, "retention: sweep failed");
+            }
+        }
+    }
+
+    /// Apply retention to each bookmark in turn, sharing what each
+    /// moves with the rest of its project's. One bookmark failing
+    /// doesn't hold up the rest.
+    async fn sweep(&self) -> Result<(), ComposeError> {
+        let compose = ComposeScope::new(self.config.clone());
+        let projects = ProjectStore::new(&self.config.host_db()?).list()?;
+        let now = Timestamp::now();
+
+        for project in projects {
+            let project_scope = compose.project(project.clone())?;
+            let bookmarks: Vec<BookmarkName> =
+                project_scope.project().bookmarks.keys().cloned().collect();
+            let mut moved = HashSet::new();
+
+            for bookmark in bookmarks {
+                let scope = project_scope.clone().verify_bookmark(bookmark.clone())?;
+                match MemoryService::retain(&scope, &self.mailbox, now, &mut moved).await {
+                    Ok(sweep) if sweep != RetentionSweep::default() => tracing::info!(
+                        %project,
+                        %bookmark,
+                        demoted = sweep.demoted,
+                        archived = sweep.archived,
+                        "retention: swept"
+                    ),
+                    Ok(_) => {}
+                    Err(error) => {
+                        tracing::warn!(%error, %project, %bookmark, "retention: bookmark failed");
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
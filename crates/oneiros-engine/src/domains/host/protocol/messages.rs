@@ -30,6 +30,29 @@ pub(crate) struct ResetHostProjection {
     pub(crate) scope: Scope<AtHost>,
 }
 
+/// Collect a project's peer follows now, ahead of the next scheduled
+/// sweep. Sent when the project changes locally; the follow sync actor
+/// ignores it unless `sync.on_change` is set.
+#[derive(Builder, Clone)]
+pub(crate) struct SyncFollows {
+    #[builder(into)]
+    pub(crate) project: ProjectName,
+}
+
+/// Open a push subscription to a newly followed peer. The follow
+/// sync actor ignores it unless `sync.push` is set, and for follows
+/// that aren't peer follows.
+#[derive(Builder, Clone)]
+pub(crate) struct SubscribeFollow {
+    pub(crate) follow: Follow,
+}
+
+/// Close the push subscription for a follow that has been removed.
+#[derive(Builder, Clone)]
+pub(crate) struct UnsubscribeFollow {
+    pub(crate) follow: FollowId,
+}
+
 /// All host-tier messages, flat. Each variant addresses a specific
 /// actor + action; the router dispatches by variant. Actors handle
 /// the variants they own and treat the rest as no-ops.
@@ -39,6 +62,9 @@ pub(crate) enum HostMessage {
     ProjectionApply(ApplyHostProjection),
     ProjectionMigrate(MigrateHostProjection),
     ProjectionReset(ResetHostProjection),
+    FollowSync(SyncFollows),
+    FollowSubscribe(SubscribeFollow),
+    FollowUnsubscribe(UnsubscribeFollow),
 }
 
 collects_enum!(
@@ -46,4 +72,7 @@ collects_enum!(
     HostMessage::ProjectionApply => ApplyHostProjection,
     HostMessage::ProjectionMigrate => MigrateHostProjection,
     HostMessage::ProjectionReset => ResetHostProjection,
+    HostMessage::FollowSync => SyncFollows,
+    HostMessage::FollowSubscribe => SubscribeFollow,
+    HostMessage::FollowUnsubscribe => UnsubscribeFollow,
 );
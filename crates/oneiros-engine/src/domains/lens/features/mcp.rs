@@ -0,0 +1,73 @@
+use crate::*;
+
+pub(crate) struct LensMcp;
+
+impl LensMcp {
+    pub(crate) fn defs(&self) -> Vec<ToolDef> {
+        lens_mcp::tool_defs()
+    }
+
+    #[expect(deprecated)]
+    pub(crate) async fn dispatch(
+        &self,
+        context: &ProjectLog,
+        tool_name: &ToolName,
+        params: &serde_json::Value,
+    ) -> Result<McpResponse, ToolError> {
+        lens_mcp::dispatch(context, tool_name, params).await
+    }
+}
+
+mod lens_mcp {
+    use crate::*;
+
+    pub(crate) fn tool_defs() -> Vec<ToolDef> {
+        vec![
+            Tool::<ExplainLens>::new(
+                LensRequestType::ExplainLens,
+                "Parse and validate a lens, and show the plan it compiles to",
+                PermissionOp::ProjectRead,
+            )
+            .def(),
+            Tool::<QueryLens>::new(
+                LensRequestType::QueryLens,
+                "Run a lens and list the entities, events and names it selects",
+                PermissionOp::ProjectRead,
+            )
+            .def(),
+        ]
+    }
+
+    #[expect(deprecated)]
+    pub(crate) async fn dispatch(
+        context: &ProjectLog,
+        tool_name: &ToolName,
+        params: &serde_json::Value,
+    ) -> Result<McpResponse, ToolError> {
+        let request_type: LensRequestType = tool_name
+            .as_str()
+            .parse()
+            .map_err(|_| ToolError::UnknownTool(tool_name.to_string()))?;
+
+        let scope = context.scope().map_err(Error::from)?;
+        let response = match request_type {
+            LensRequestType::ExplainLens => {
+                let explaining: ExplainLens = serde_json::from_value(params.clone())?;
+                LensService::explain(scope, &explaining)
+                    .await
+                    .map_err(Error::from)?
+            }
+            LensRequestType::QueryLens => {
+                let querying: QueryLens = serde_json::from_value(params.clone())?;
+                LensService::query(scope, context.canons(), &querying)
+                    .await
+                    .map_err(Error::from)?
+            }
+            LensRequestType::ParseLens => {
+                return Err(ToolError::UnknownTool(tool_name.to_string()));
+            }
+        };
+
+        Ok(LensView::new(response).mcp())
+    }
+}
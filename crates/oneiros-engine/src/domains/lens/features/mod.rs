@@ -1,7 +1,9 @@
 mod cli;
 mod http;
+mod mcp;
 mod skills;
 
 pub(crate) use cli::*;
 pub(crate) use http::*;
+pub(crate) use mcp::*;
 pub(crate) use skills::*;
@@ -1,4 +1,6 @@
+use kinded::Kinded;
 use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 use crate::*;
 
@@ -46,3 +48,43 @@ resource_requests! {
         client.post("/lens/query", this).await
     },
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Kinded)]
+#[serde(tag = "type", content = "data", rename_all = "kebab-case")]
+#[kinded(
+    kind = LensRequestType,
+    display = "kebab-case",
+    attrs(
+        expect(
+            clippy::enum_variant_names,
+            reason = "We use these for `type` notation in serde"
+        )
+    )
+)]
+#[expect(
+    clippy::enum_variant_names,
+    reason = "We use these for `type` notation in serde"
+)]
+pub(crate) enum LensRequest {
+    ParseLens(ParseLens),
+    ExplainLens(ExplainLens),
+    QueryLens(QueryLens),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn request_types_are_kebab_cased() {
+        let cases = [
+            (LensRequestType::ParseLens, "parse-lens"),
+            (LensRequestType::ExplainLens, "explain-lens"),
+            (LensRequestType::QueryLens, "query-lens"),
+        ];
+
+        for (request_type, expectation) in cases {
+            assert_eq!(&request_type.to_string(), expectation)
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use crate::*;
 
 pub(crate) struct LensService;
@@ -54,35 +56,7 @@ impl LensService {
         request: &QueryLens,
     ) -> Result<LensResponse, LensError> {
         let QueryLens::V1(req) = request;
-        let parsed = Lens::parse(&req.source)?;
-        let resolver = AliasResolver::new(&scope.config().lens.aliases)?;
-        let lens = resolver.expand(parsed)?;
-        let registry = Registry::seed_default();
-        lens.validate(&registry)?;
-
-        let names = NameResolver::fetch(scope).await?;
-        lens.check_names(&registry, &names)?;
-
-        let compiler = Compiler::new(registry);
-        let ir = compiler.compile(&lens)?;
-
-        let db = BookmarkDb::open(scope).await?;
-        let host_db = HostDb::open(scope).await?;
-        let search_reader = SearchIndexReader::new(&db);
-        let trail_reader = TrailLensReader::new(&db);
-        let connection_reader = ConnectionLensReader::new(&db);
-        let chronicle_reader =
-            ChronicleLensReader::new(&host_db, canons, scope.project().name.clone());
-        let readers: Vec<&dyn Reader> = vec![
-            &search_reader,
-            &trail_reader,
-            &connection_reader,
-            &chronicle_reader,
-        ];
-        let executor = Executor::new(&readers);
-        let selection = executor.run(&ir)?;
-
-        let hits = selection.sorted_by_timestamp_desc();
+        let hits = Self::run(scope, canons, &req.source, &scope.config().lens.aliases).await?;
         let total = hits.len();
 
         Ok(LensResponse::Queried(
@@ -103,21 +77,89 @@ impl LensService {
         canons: &CanonIndex,
         source: &str,
     ) -> Result<Selection, LensError> {
-        let request = QueryLens::builder_v1()
-            .source(source.to_string())
-            .build()
-            .into();
-        let LensResponse::Queried(QueriedLensResponse::V1(queried)) =
-            Self::query(scope, canons, &request).await?
-        else {
-            return Ok(Selection::new());
-        };
+        let hits = Self::run(scope, canons, source, &scope.config().lens.aliases).await?;
+        let mut selection = Selection::new();
+        for hit in hits {
+            selection.insert(hit);
+        }
+        Ok(selection)
+    }
+
+    /// Like [`select`], with the symbol `self` bound to `agent` — how a
+    /// dream recipe refers to the agent that is dreaming.
+    ///
+    /// [`select`]: LensService::select
+    pub(crate) async fn select_as(
+        scope: &Scope<AtBookmark>,
+        canons: &CanonIndex,
+        source: &str,
+        agent: &AgentName,
+    ) -> Result<Selection, LensError> {
+        let mut aliases = scope.config().lens.aliases.clone();
+        aliases.insert("self".to_string(), agent.to_string());
+        let hits = Self::run(scope, canons, source, &aliases).await?;
         let mut selection = Selection::new();
-        for hit in &queried.hits {
-            selection.insert(hit.clone());
+        for hit in hits {
+            selection.insert(hit);
         }
         Ok(selection)
     }
+
+    async fn run(
+        scope: &Scope<AtBookmark>,
+        canons: &CanonIndex,
+        source: &str,
+        aliases: &HashMap<String, String>,
+    ) -> Result<Vec<Hit>, LensError> {
+        let ir = Self::compile(scope, source, aliases).await?;
+        let selection = Self::execute(scope, canons, &ir).await?;
+
+        Ok(selection.sorted_by_timestamp_desc())
+    }
+
+    /// Parse, alias-expand, validate, name-resolve and compile a lens
+    /// without running it — for callers that evaluate the same lens
+    /// many times.
+    pub(crate) async fn compile(
+        scope: &Scope<AtBookmark>,
+        source: &str,
+        aliases: &HashMap<String, String>,
+    ) -> Result<Ir, LensError> {
+        let parsed = Lens::parse(source)?;
+        let resolver = AliasResolver::new(aliases)?;
+        let lens = resolver.expand(parsed)?;
+        let registry = Registry::seed_default();
+        lens.validate(&registry)?;
+
+        let names = NameResolver::fetch(scope).await?;
+        lens.check_names(&registry, &names)?;
+
+        let compiler = Compiler::new(registry);
+        Ok(compiler.compile(&lens)?)
+    }
+
+    /// Run a compiled lens against the scope's bookmark.
+    pub(crate) async fn execute(
+        scope: &Scope<AtBookmark>,
+        canons: &CanonIndex,
+        ir: &Ir,
+    ) -> Result<Selection, LensError> {
+        let db = BookmarkDb::open(scope).await?;
+        let host_db = HostDb::open(scope).await?;
+        let search_reader = SearchIndexReader::new(&db);
+        let trail_reader = TrailLensReader::new(&db);
+        let connection_reader = ConnectionLensReader::new(&db);
+        let chronicle_reader =
+            ChronicleLensReader::new(&host_db, canons, scope.project().name.clone());
+        let readers: Vec<&dyn Reader> = vec![
+            &search_reader,
+            &trail_reader,
+            &connection_reader,
+            &chronicle_reader,
+        ];
+        let executor = Executor::new(&readers);
+        Ok(executor.run(ir)?)
+    }
 }
 
 #[cfg(test)]
@@ -9,6 +9,56 @@ impl LensView {
         Self { response }
     }
 
+    pub(crate) fn mcp(&self) -> McpResponse {
+        match &self.response {
+            LensResponse::Parsed(ParsedLensResponse::V1(parsed)) => McpResponse::new(format!(
+                "# Lens\n\n`{}`\n\n{}\n",
+                parsed.source, parsed.display
+            ))
+            .hint(Hint::suggest("query-lens", "Run the lens")),
+            LensResponse::Explained(ExplainedLensResponse::V1(explained)) => {
+                McpResponse::new(format!(
+                    "# Lens — {}\n\n{}\n\n## Plan\n\n```\n{}\n```\n",
+                    explained.source, explained.display, explained.plan
+                ))
+                .hint(Hint::suggest("query-lens", "Run the lens"))
+            }
+            LensResponse::Queried(QueriedLensResponse::V1(queried)) => {
+                if queried.hits.is_empty() {
+                    return McpResponse::new(format!("No results for `{}`.", queried.source))
+                        .hint(Hint::suggest("explain-lens", "See how the lens is planned"));
+                }
+
+                let mut md = format!(
+                    "# Lens — {}\n\n{} of {} total\n\n",
+                    queried.source,
+                    queried.hits.len(),
+                    queried.total
+                );
+                for hit in &queried.hits {
+                    match hit {
+                        Hit::Entity(e) => md.push_str(&format!(
+                            "- entity {} — {}\n",
+                            RefToken::new(e.entity_ref.clone()),
+                            e.timestamp
+                        )),
+                        Hit::Event(e) => {
+                            md.push_str(&format!("- event {} — {}\n", e.event_id, e.timestamp))
+                        }
+                        Hit::Name(n) => md.push_str(&format!(
+                            "- {} {} — {}\n",
+                            n.kind.describe(),
+                            n.name,
+                            n.timestamp
+                        )),
+                    }
+                }
+                McpResponse::new(md)
+                    .hint(Hint::suggest("trail-of", "See the events behind an entity"))
+            }
+        }
+    }
+
     pub(crate) fn render(self) -> Rendered<LensResponse> {
         let prompt = match &self.response {
             LensResponse::Parsed(ParsedLensResponse::V1(parsed)) => {
@@ -8,7 +8,7 @@ impl LevelState {
             match level_event {
                 LevelEvents::LevelSet(setting) => {
                     if let Ok(current) = setting.current() {
-                        canon.levels.set(&current.level);
+                        canon.levels.set(&current.level.ranked());
                     }
                 }
                 LevelEvents::LevelRemoved(removal) => {
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use bon::Builder;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -12,6 +14,40 @@ pub(crate) struct Level {
     pub(crate) description: Description,
     #[builder(into)]
     pub(crate) prompt: Prompt,
+    /// Where the level sits relative to the others; higher ranks are
+    /// kept closer. Dreams recall memories at or above a threshold rank.
+    #[serde(default)]
+    #[builder(default)]
+    pub(crate) rank: u32,
+    /// How long the level keeps its memories, if not forever.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) retention: Option<RetentionPolicy>,
+}
+
+impl Level {
+    /// The rank `seed core` gives each level it creates. Levels set
+    /// before ranks existed replay at rank zero; these names get their
+    /// seeded rank back.
+    pub(crate) fn seeded_rank(name: &LevelName) -> Option<u32> {
+        match name.as_str() {
+            "core" => Some(5),
+            "working" => Some(4),
+            "session" => Some(3),
+            "project" => Some(2),
+            "archival" => Some(1),
+            _ => None,
+        }
+    }
+
+    /// This level with its seeded rank restored, if it was set unranked.
+    pub(crate) fn ranked(mut self) -> Self {
+        if self.rank == 0
+            && let Some(rank) = Self::seeded_rank(&self.name)
+        {
+            self.rank = rank;
+        }
+        self
+    }
 }
 
 impl Indexable<LevelName> for Level {
@@ -23,3 +59,73 @@ impl Indexable<LevelName> for Level {
 pub(crate) type Levels = EntityIndex<LevelName, Level>;
 
 resource_name!(LevelName);
+
+/// Each level's configured rank. Levels that aren't defined rank zero.
+#[derive(Debug, Default)]
+pub(crate) struct LevelRanks(HashMap<LevelName, u32>);
+
+impl LevelRanks {
+    pub(crate) fn new<'a>(levels: impl IntoIterator<Item = &'a Level>) -> Self {
+        Self(
+            levels
+                .into_iter()
+                .map(|level| (level.name.clone(), level.rank))
+                .collect(),
+        )
+    }
+
+    pub(crate) fn of(&self, name: &LevelName) -> u32 {
+        self.0.get(name).copied().unwrap_or_default()
+    }
+
+    /// The highest rank; recall scores normalize against it.
+    pub(crate) fn highest(&self) -> u32 {
+        self.0.values().copied().max().unwrap_or_default().max(1)
+    }
+
+    /// Whether memories at `name` are core — kept whole in every dream.
+    /// Core is whichever level ranks highest; with nothing ranked,
+    /// nothing is.
+    pub(crate) fn is_core(&self, name: &LevelName) -> bool {
+        let top = self.0.values().copied().max().unwrap_or_default();
+        top > 0 && self.of(name) == top
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(name: &str, rank: u32) -> Level {
+        Level::builder()
+            .name(name)
+            .description("")
+            .prompt("")
+            .rank(rank)
+            .build()
+    }
+
+    #[test]
+    fn core_is_the_top_rank() {
+        let levels = [level("identity", 9), level("working", 4)];
+        let ranks = LevelRanks::new(&levels);
+
+        assert!(ranks.is_core(&LevelName::new("identity")));
+        assert!(!ranks.is_core(&LevelName::new("working")));
+        assert!(!ranks.is_core(&LevelName::new("core")));
+    }
+
+    #[test]
+    fn nothing_is_core_when_nothing_is_ranked() {
+        let levels = [level("core", 0), level("working", 0)];
+
+        assert!(!LevelRanks::new(&levels).is_core(&LevelName::new("core")));
+    }
+
+    #[test]
+    fn unranked_seeded_levels_get_their_rank_back() {
+        assert_eq!(level("core", 0).ranked().rank, 5);
+        assert_eq!(level("core", 7).ranked().rank, 7);
+        assert_eq!(level("custom", 0).ranked().rank, 0);
+    }
+}
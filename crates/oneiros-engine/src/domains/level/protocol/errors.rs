@@ -11,6 +11,12 @@ pub(crate) enum LevelError {
     #[error("Level not found: {0}")]
     NotFound(LevelName),
 
+    #[error("Level {0} can't demote to itself")]
+    SelfDemotion(LevelName),
+
+    #[error("Level {0} can't demote to {1}: {1} already demotes back to {0}")]
+    DemotionCycle(LevelName, LevelName),
+
     #[error(transparent)]
     Resolve(#[from] crate::ResolveError),
 
@@ -36,7 +42,9 @@ impl IntoResponse for LevelError {
     fn into_response(self) -> Response {
         let (status, message) = match &self {
             LevelError::NotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
-            LevelError::Resolve(_) => (StatusCode::UNPROCESSABLE_ENTITY, self.to_string()),
+            LevelError::SelfDemotion(_)
+            | LevelError::DemotionCycle(..)
+            | LevelError::Resolve(_) => (StatusCode::UNPROCESSABLE_ENTITY, self.to_string()),
             LevelError::Database(_) | LevelError::Event(_) => {
                 (StatusCode::INTERNAL_SERVER_ERROR, self.to_string())
             }
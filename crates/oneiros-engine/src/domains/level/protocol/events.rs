@@ -89,4 +89,21 @@ mod tests {
             _ => panic!("wrong variant"),
         }
     }
+
+    #[test]
+    fn level_set_without_rank_decodes_from_older_events() {
+        // Events written before levels carried a rank or retention policy
+        // still replay; both fall back to their defaults.
+        let json = r#"{"type":"level-set","data":{"name":"working","description":"Short-term","prompt":""}}"#;
+        let decoded: LevelEvents = serde_json::from_str(json).unwrap();
+
+        match decoded {
+            LevelEvents::LevelSet(set) => {
+                let v1 = set.current().unwrap();
+                assert_eq!(v1.level.rank, 0);
+                assert!(v1.level.retention.is_none());
+            }
+            _ => panic!("wrong variant"),
+        }
+    }
 }
@@ -16,6 +16,24 @@ versioned! {
             #[arg(long, default_value = "")]
             #[builder(default, into)]
             pub(crate) prompt: Prompt,
+            /// Where the level sits among the others; higher is kept closer.
+            #[arg(long, default_value_t = 0)]
+            #[serde(default)]
+            #[builder(default)]
+            pub(crate) rank: u32,
+            /// Expire memories older than this, e.g. `7d`.
+            #[arg(long, value_parser = humantime::parse_duration)]
+            #[serde(default, skip_serializing_if = "Option::is_none", with = "humantime_serde")]
+            #[schemars(with = "Option<String>")]
+            pub(crate) ttl: Option<std::time::Duration>,
+            /// Expire an agent's memories beyond this many, oldest first.
+            #[arg(long)]
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            pub(crate) max_per_agent: Option<usize>,
+            /// Move expired memories to this level instead of archiving them.
+            #[arg(long)]
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            pub(crate) demote_to: Option<LevelName>,
         }
     }
 }
@@ -21,23 +21,28 @@ impl<'a> LevelRepo<'a> {
 
     pub(crate) async fn get(&self, name: &LevelName) -> Result<Option<Level>, EventError> {
         let db = BookmarkDb::open(self.scope).await?;
-        let mut stmt =
-            db.prepare("SELECT name, description, prompt FROM levels WHERE name = ?1")?;
+        let mut stmt = db.prepare(
+            "SELECT name, description, prompt, rank, retention FROM levels WHERE name = ?1",
+        )?;
 
         let result = stmt.query_row(params![name.to_string()], |row| {
             Ok((
                 row.get::<_, String>(0)?,
                 row.get::<_, String>(1)?,
                 row.get::<_, String>(2)?,
+                row.get::<_, u32>(3)?,
+                row.get::<_, Option<String>>(4)?,
             ))
         });
 
         match result {
-            Ok((name, description, prompt)) => Ok(Some(
+            Ok((name, description, prompt, rank, retention)) => Ok(Some(
                 Level::builder()
                     .name(name)
                     .description(description)
                     .prompt(prompt)
+                    .rank(rank)
+                    .maybe_retention(retention.map(|r| serde_json::from_str(&r)).transpose()?)
                     .build(),
             )),
             Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
@@ -54,27 +59,34 @@ impl<'a> LevelRepo<'a> {
         };
 
         let mut stmt = db.prepare(
-            "SELECT name, description, prompt FROM levels ORDER BY name LIMIT ?1 OFFSET ?2",
+            "SELECT name, description, prompt, rank, retention FROM levels
+             ORDER BY rank DESC, name LIMIT ?1 OFFSET ?2",
         )?;
 
-        let items = stmt
+        let rows = stmt
             .query_map(rusqlite::params![filters.limit, filters.offset], |row| {
                 Ok((
                     row.get::<_, String>(0)?,
                     row.get::<_, String>(1)?,
                     row.get::<_, String>(2)?,
+                    row.get::<_, u32>(3)?,
+                    row.get::<_, Option<String>>(4)?,
                 ))
             })?
-            .collect::<Result<Vec<(String, String, String)>, _>>()?
-            .into_iter()
-            .map(|(name, description, prompt)| {
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut items = Vec::with_capacity(rows.len());
+        for (name, description, prompt, rank, retention) in rows {
+            items.push(
                 Level::builder()
                     .name(name)
                     .description(description)
                     .prompt(prompt)
-                    .build()
-            })
-            .collect();
+                    .rank(rank)
+                    .maybe_retention(retention.map(|r| serde_json::from_str(&r)).transpose()?)
+                    .build(),
+            );
+        }
 
         Ok(Listed::new(items, total))
     }
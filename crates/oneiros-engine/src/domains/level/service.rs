@@ -9,10 +9,43 @@ impl LevelService {
         request: &SetLevel,
     ) -> Result<LevelResponse, LevelError> {
         let SetLevel::V1(set) = request;
+        let retention = RetentionPolicy {
+            ttl: set.ttl,
+            max_per_agent: set.max_per_agent,
+            demote_to: set.demote_to.clone(),
+        };
+        if let Some(target) = &retention.demote_to {
+            if *target == set.name {
+                return Err(LevelError::SelfDemotion(target.clone()));
+            }
+            let mut next = LevelRepo::new(scope)
+                .get(target)
+                .await?
+                .ok_or_else(|| LevelError::NotFound(target.clone()))?
+                .retention
+                .and_then(|retention| retention.demote_to);
+            let mut visited = std::collections::HashSet::from([target.clone()]);
+            while let Some(step) = next {
+                if step == set.name {
+                    return Err(LevelError::DemotionCycle(set.name.clone(), target.clone()));
+                }
+                if !visited.insert(step.clone()) {
+                    break;
+                }
+                next = LevelRepo::new(scope)
+                    .get(&step)
+                    .await?
+                    .and_then(|level| level.retention)
+                    .and_then(|retention| retention.demote_to);
+            }
+        }
+
         let level = Level::builder()
             .name(set.name.clone())
             .description(set.description.clone())
             .prompt(set.prompt.clone())
+            .rank(set.rank)
+            .maybe_retention((!retention.is_empty()).then_some(retention))
             .build();
         let name = level.name.clone();
 
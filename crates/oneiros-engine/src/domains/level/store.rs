@@ -34,38 +34,51 @@ impl<'a> LevelStore<'a> {
                 prompt TEXT NOT NULL DEFAULT ''
             )",
         )?;
+        // Migration: add columns if missing from older schemas.
+        self.conn
+            .execute_batch("ALTER TABLE levels ADD COLUMN rank INTEGER NOT NULL DEFAULT 0")
+            .ok();
+        self.conn
+            .execute_batch("ALTER TABLE levels ADD COLUMN retention TEXT")
+            .ok();
         Ok(())
     }
 
     pub(crate) fn list(&self) -> Result<Vec<Level>, EventError> {
-        let mut stmt = self
-            .conn
-            .prepare("SELECT name, description, prompt FROM levels ORDER BY name")?;
+        let mut stmt = self.conn.prepare(
+            "SELECT name, description, prompt, rank, retention FROM levels ORDER BY name",
+        )?;
 
-        let levels = stmt
+        let rows = stmt
             .query_map([], |row| {
                 Ok((
                     row.get::<_, String>(0)?,
                     row.get::<_, String>(1)?,
                     row.get::<_, String>(2)?,
+                    row.get::<_, u32>(3)?,
+                    row.get::<_, Option<String>>(4)?,
                 ))
             })?
-            .collect::<Result<Vec<(String, String, String)>, _>>()?
-            .into_iter()
-            .map(|(name, description, prompt)| {
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut levels = Vec::with_capacity(rows.len());
+        for (name, description, prompt, rank, retention) in rows {
+            levels.push(
                 Level::builder()
                     .name(name)
                     .description(description)
                     .prompt(prompt)
-                    .build()
-            })
-            .collect();
+                    .rank(rank)
+                    .maybe_retention(retention.map(|r| serde_json::from_str(&r)).transpose()?)
+                    .build(),
+            );
+        }
 
         Ok(levels)
     }
 
     fn set(&self, setting: &LevelSet) -> Result<(), EventError> {
-        let level = setting.current()?.level;
+        let level = setting.current()?.level.ranked();
         self.write_level(&level)
     }
 
@@ -80,11 +93,18 @@ impl<'a> LevelStore<'a> {
 
     fn write_level(&self, level: &Level) -> Result<(), EventError> {
         self.conn.execute(
-            "INSERT OR REPLACE INTO levels (name, description, prompt) VALUES (?1, ?2, ?3)",
+            "INSERT OR REPLACE INTO levels (name, description, prompt, rank, retention)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
             params![
                 level.name.to_string(),
                 level.description.to_string(),
-                level.prompt.to_string()
+                level.prompt.to_string(),
+                level.rank,
+                level
+                    .retention
+                    .as_ref()
+                    .map(serde_json::to_string)
+                    .transpose()?
             ],
         )?;
         Ok(())
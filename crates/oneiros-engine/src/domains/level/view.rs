@@ -66,10 +66,14 @@ impl LevelView {
                 .with_hints(hints)
             }
             LevelResponse::LevelDetails(LevelDetailsResponse::V1(details)) => {
-                let prompt = Detail::new(details.level.name.to_string())
+                let mut detail = Detail::new(details.level.name.to_string())
                     .field("description:", details.level.description.to_string())
                     .field("prompt:", details.level.prompt.to_string())
-                    .to_string();
+                    .field("rank:", details.level.rank.to_string());
+                if let Some(retention) = &details.level.retention {
+                    detail.push_field("retention:", retention.to_string());
+                }
+                let prompt = detail.to_string();
                 Rendered::new(
                     LevelResponse::LevelDetails(LevelDetailsResponse::V1(details)),
                     prompt,
@@ -79,10 +83,15 @@ impl LevelView {
             LevelResponse::Levels(LevelsResponse::V1(listed)) => {
                 let mut table = Table::new(vec![
                     Column::new("Name"),
+                    Column::new("Rank"),
                     Column::new("Description").max(60),
                 ]);
                 for item in &listed.items {
-                    table.push_row(vec![item.name.to_string(), item.description.to_string()]);
+                    table.push_row(vec![
+                        item.name.to_string(),
+                        item.rank.to_string(),
+                        item.description.to_string(),
+                    ]);
                 }
                 let prompt = format!(
                     "{}\n\n{table}",
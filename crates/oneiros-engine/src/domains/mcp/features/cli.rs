@@ -6,10 +6,16 @@ use crate::*;
 pub(crate) enum McpCommands {
     /// Write .mcp.json for Claude Code MCP integration.
     Init(InitMcp),
+    /// Serve MCP over stdin and stdout, for harnesses that can't reach
+    /// the running service.
+    Serve(ServeMcp),
 }
 
 impl McpCommands {
-    pub(crate) fn execute(&self, config: &Config) -> Result<Rendered<Responses>, McpConfigError> {
+    pub(crate) async fn execute(
+        &self,
+        config: &Config,
+    ) -> Result<Rendered<Responses>, McpConfigError> {
         let response = match self {
             McpCommands::Init(initialization) => {
                 let InitMcp::V1(init) = initialization;
@@ -43,6 +49,7 @@ impl McpCommands {
                     result
                 }
             }
+            McpCommands::Serve(serving) => McpConfigService::serve(config, serving).await?,
         };
 
         Ok(McpView::new(response).render().map(Into::into))
@@ -4,6 +4,9 @@ pub(crate) struct McpConfigSkills;
 
 impl McpConfigSkills {
     pub(crate) fn all() -> Vec<Skill> {
-        vec![Skill::new("mcp-init", include_str!("skills/mcp-init.md"))]
+        vec![
+            Skill::new("mcp-init", include_str!("skills/mcp-init.md")),
+            Skill::new("mcp-serve", include_str!("skills/mcp-serve.md")),
+        ]
     }
 }
@@ -11,12 +11,35 @@ pub(crate) enum McpConfigError {
     #[error("No token available. Run `oneiros project create` first, or pass --token.")]
     NoToken,
 
+    #[error(
+        "Only stdio is served from the CLI; HTTP is served by the running service at /mcp. Pass --stdio."
+    )]
+    TransportRequired,
+
+    #[error("The `{0}` table in the existing config isn't a table of servers.")]
+    MalformedServers(&'static str),
+
+    #[error(transparent)]
+    State(#[from] ServerStateError),
+
+    #[error(transparent)]
+    Initialize(Box<rmcp::service::ServerInitializeError>),
+
+    #[error(transparent)]
+    Join(#[from] tokio::task::JoinError),
+
     #[error(transparent)]
     Io(#[from] std::io::Error),
 
     #[error(transparent)]
     Json(#[from] serde_json::Error),
 
+    #[error(transparent)]
+    TomlRead(#[from] toml::de::Error),
+
+    #[error(transparent)]
+    TomlWrite(#[from] toml::ser::Error),
+
     #[error(transparent)]
     Upcast(#[from] UpcastError),
 }
@@ -25,9 +48,17 @@ impl IntoResponse for McpConfigError {
     fn into_response(self) -> Response {
         let (status, message) = match &self {
             McpConfigError::NoToken => (StatusCode::PRECONDITION_FAILED, self.to_string()),
-            McpConfigError::Io(_) | McpConfigError::Json(_) | McpConfigError::Upcast(_) => {
-                (StatusCode::INTERNAL_SERVER_ERROR, self.to_string())
+            McpConfigError::TransportRequired => (StatusCode::BAD_REQUEST, self.to_string()),
+            McpConfigError::MalformedServers(_) | McpConfigError::TomlRead(_) => {
+                (StatusCode::UNPROCESSABLE_ENTITY, self.to_string())
             }
+            McpConfigError::State(_)
+            | McpConfigError::Initialize(_)
+            | McpConfigError::Join(_)
+            | McpConfigError::Io(_)
+            | McpConfigError::Json(_)
+            | McpConfigError::TomlWrite(_)
+            | McpConfigError::Upcast(_) => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string()),
         };
 
         (status, Json(ErrorResponse::new(message))).into_response()
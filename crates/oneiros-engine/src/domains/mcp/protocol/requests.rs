@@ -16,6 +16,16 @@ versioned! {
             /// Service address. Uses config default if not provided.
             #[arg(long)]
             pub(crate) address: Option<SocketAddr>,
+            /// How the MCP client should reach oneiros.
+            #[arg(long, value_enum, default_value_t)]
+            #[serde(default)]
+            #[builder(default)]
+            pub(crate) transport: McpTransport,
+            /// Which agent harness to configure.
+            #[arg(long, value_enum, default_value_t)]
+            #[serde(default)]
+            #[builder(default)]
+            pub(crate) harness: Harness,
             /// Skip confirmation prompts.
             #[arg(long, short)]
             #[serde(default)]
@@ -24,3 +34,17 @@ versioned! {
         }
     }
 }
+
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum ServeMcp {
+        #[derive(clap::Args)]
+        V1 => {
+            /// Speak MCP over stdin and stdout, opening the project directly.
+            #[arg(long)]
+            #[serde(default)]
+            #[builder(default)]
+            pub(crate) stdio: bool,
+        }
+    }
+}
@@ -12,6 +12,7 @@ use crate::*;
 pub(crate) enum McpResponses {
     McpConfigWritten(McpConfigWrittenResponse),
     McpConfigExists(McpConfigExistsResponse),
+    ServerStopped,
 }
 
 versioned! {
@@ -1,38 +1,34 @@
 use std::path::PathBuf;
 
+use rmcp::ServiceExt;
+
 use crate::*;
 
+/// The name oneiros registers itself under in every harness config.
+const SERVER_NAME: &str = "oneiros-local";
+
 pub(crate) struct McpConfigService;
 
 impl McpConfigService {
     pub(crate) fn init(config: &Config, request: &InitMcp) -> Result<McpResponses, McpConfigError> {
         let details = request.current()?;
-        let token = details
-            .token
-            .clone()
-            .or_else(|| config.token())
-            .ok_or(McpConfigError::NoToken)?;
-
-        let address = details.address.unwrap_or(config.service_addr());
-        let scheme = &config.service.scheme;
-
-        let mcp_json = serde_json::json!({
-            "mcpServers": {
-                "oneiros-local": {
-                    "type": "http",
-                    "url": format!("{scheme}://{address}/mcp"),
-                    "headers": {
-                        "Authorization": format!("Bearer {token}")
-                    }
-                }
-            }
-        });
+        let server = Self::server_entry(config, &details)?;
+        let path = Self::config_path(details.harness);
 
-        let path = Self::mcp_json_path();
+        let existing = if path.exists() {
+            Some(config.platform().read_to_string(&path)?)
+        } else {
+            None
+        };
 
-        if path.exists() && !details.yes {
+        let already_configured = existing
+            .as_deref()
+            .and_then(|content| Self::read_entry(details.harness, content))
+            .is_some();
+
+        if already_configured && !details.yes {
             // In non-interactive contexts (like setup), the caller handles
-            // the prompt. Here we just report the file exists.
+            // the prompt. Here we just report the server is already there.
             return Ok(McpResponses::McpConfigExists(
                 McpConfigExistsResponse::builder_v1()
                     .path(path)
@@ -41,7 +37,11 @@ impl McpConfigService {
             ));
         }
 
-        let content = serde_json::to_string_pretty(&mcp_json)?;
+        let content = Self::merge(details.harness, existing.as_deref(), server)?;
+
+        if let Some(parent) = path.parent() {
+            config.platform().ensure_dir(parent)?;
+        }
         config.platform().write(&path, content)?;
 
         Ok(McpResponses::McpConfigWritten(
@@ -52,7 +52,136 @@ impl McpConfigService {
         ))
     }
 
-    /// Write the .mcp.json regardless of whether it exists.
+    /// The server entry for the requested transport, in the shape the
+    /// target harness expects.
+    fn server_entry(
+        config: &Config,
+        details: &InitMcpV1,
+    ) -> Result<serde_json::Value, McpConfigError> {
+        let harness = details.harness;
+
+        Ok(match details.transport {
+            McpTransport::Http => {
+                let token = details
+                    .token
+                    .clone()
+                    .or_else(|| config.token())
+                    .ok_or(McpConfigError::NoToken)?;
+
+                let address = details.address.unwrap_or(config.service_addr());
+                let scheme = &config.service.scheme;
+                let url = format!("{scheme}://{address}/mcp");
+                let headers = serde_json::json!({
+                    "Authorization": format!("Bearer {token}")
+                });
+
+                match harness {
+                    Harness::Claude => serde_json::json!({
+                        "type": "http",
+                        "url": url,
+                        "headers": headers,
+                    }),
+                    Harness::Codex => serde_json::json!({
+                        "url": url,
+                        "http_headers": headers,
+                    }),
+                    Harness::Cursor => serde_json::json!({
+                        "url": url,
+                        "headers": headers,
+                    }),
+                    Harness::Gemini => serde_json::json!({
+                        "httpUrl": url,
+                        "headers": headers,
+                    }),
+                }
+            }
+            // The child process opens the project itself, so it needs no
+            // token — only enough to find the same project on disk.
+            McpTransport::Stdio => {
+                let args = serde_json::json!([
+                    "mcp",
+                    "serve",
+                    "--stdio",
+                    "--project",
+                    config.project.to_string(),
+                    "--data-dir",
+                    config.data_dir.display().to_string(),
+                ]);
+
+                match harness {
+                    Harness::Claude => serde_json::json!({
+                        "type": "stdio",
+                        "command": "oneiros",
+                        "args": args,
+                    }),
+                    Harness::Codex | Harness::Cursor | Harness::Gemini => serde_json::json!({
+                        "command": "oneiros",
+                        "args": args,
+                    }),
+                }
+            }
+        })
+    }
+
+    /// Fold our server entry into a harness config, leaving every other
+    /// server and setting in the file as it was.
+    fn merge(
+        harness: Harness,
+        existing: Option<&str>,
+        server: serde_json::Value,
+    ) -> Result<String, McpConfigError> {
+        let existing = existing.filter(|content| !content.trim().is_empty());
+        let key = harness.servers_key();
+
+        match harness.format() {
+            HarnessFormat::Json => {
+                let mut document: serde_json::Map<String, serde_json::Value> = match existing {
+                    Some(content) => serde_json::from_str(content)?,
+                    None => serde_json::Map::new(),
+                };
+                document
+                    .entry(key)
+                    .or_insert_with(|| serde_json::json!({}))
+                    .as_object_mut()
+                    .ok_or(McpConfigError::MalformedServers(key))?
+                    .insert(SERVER_NAME.to_string(), server);
+
+                Ok(serde_json::to_string_pretty(&document)?)
+            }
+            HarnessFormat::Toml => {
+                let mut document: toml::Table = match existing {
+                    Some(content) => content.parse()?,
+                    None => toml::Table::new(),
+                };
+                document
+                    .entry(key)
+                    .or_insert_with(|| toml::Value::Table(toml::Table::new()))
+                    .as_table_mut()
+                    .ok_or(McpConfigError::MalformedServers(key))?
+                    .insert(SERVER_NAME.to_string(), toml::Value::try_from(server)?);
+
+                Ok(toml::to_string_pretty(&document)?)
+            }
+        }
+    }
+
+    /// Our server entry from a harness config's contents, as JSON whatever
+    /// the file format.
+    fn read_entry(harness: Harness, content: &str) -> Option<serde_json::Value> {
+        let document: serde_json::Value = match harness.format() {
+            HarnessFormat::Json => serde_json::from_str(content).ok()?,
+            HarnessFormat::Toml => {
+                serde_json::to_value(content.parse::<toml::Table>().ok()?).ok()?
+            }
+        };
+
+        document
+            .get(harness.servers_key())?
+            .get(SERVER_NAME)
+            .cloned()
+    }
+
+    /// Write the harness config regardless of whether it's configured.
     /// Used by setup after the user confirms.
     pub(crate) fn write(
         config: &Config,
@@ -62,17 +191,58 @@ impl McpConfigService {
         let forced: InitMcp = InitMcp::builder_v1()
             .maybe_token(details.token.clone())
             .maybe_address(details.address)
+            .transport(details.transport)
+            .harness(details.harness)
             .yes(true)
             .build()
             .into();
         Self::init(config, &forced)
     }
 
-    /// The path to .mcp.json in the current working directory.
-    pub(crate) fn mcp_json_path() -> PathBuf {
+    /// Serve MCP over stdin and stdout until the client hangs up.
+    ///
+    /// Opens the project's state in this process rather than reaching
+    /// for the running service, so it works where loopback networking
+    /// doesn't. Only the project is opened: following peers, retention
+    /// and the sync bridge stay with the service.
+    pub(crate) async fn serve(
+        config: &Config,
+        request: &ServeMcp,
+    ) -> Result<McpResponses, McpConfigError> {
+        let details = request.current()?;
+        if !details.stdio {
+            return Err(McpConfigError::TransportRequired);
+        }
+
+        let state = ServerState::open(config.clone())?;
+        state.hydrate();
+
+        // The session runs on its own task: rmcp's service future nests
+        // too deeply to inline into the CLI's.
+        let toolbox = EngineToolBox::local(state);
+        tokio::spawn(async move {
+            let service = toolbox
+                .serve(rmcp::transport::stdio())
+                .await
+                .map_err(|error| McpConfigError::Initialize(Box::new(error)))?;
+            service.waiting().await?;
+            Ok::<_, McpConfigError>(())
+        })
+        .await??;
+
+        Ok(McpResponses::ServerStopped)
+    }
+
+    /// The path to a harness's MCP config in the current working directory.
+    pub(crate) fn config_path(harness: Harness) -> PathBuf {
         std::env::current_dir()
             .unwrap_or_default()
-            .join(".mcp.json")
+            .join(harness.config_path())
+    }
+
+    /// The path to .mcp.json in the current working directory.
+    pub(crate) fn mcp_json_path() -> PathBuf {
+        Self::config_path(Harness::Claude)
     }
 
     /// Check whether .mcp.json exists.
@@ -80,14 +250,24 @@ impl McpConfigService {
         Self::mcp_json_path().exists()
     }
 
+    /// Where a harness stands: `None` if it has no config file here at
+    /// all, otherwise whether that file carries our server.
+    pub(crate) fn harness_status(config: &Config, harness: Harness) -> Option<bool> {
+        let content = config
+            .platform()
+            .read_to_string(Self::config_path(harness))
+            .ok()?;
+
+        Some(Self::read_entry(harness, &content).is_some())
+    }
+
     /// Read the MCP URL and Bearer token from `.mcp.json`. Returns
     /// `None` if the file does not exist, is not valid JSON, or does
     /// not contain the expected structure.
     pub(crate) fn read_config(config: &Config) -> Option<McpLiveConfig> {
         let path = Self::mcp_json_path();
         let content = config.platform().read_to_string(&path).ok()?;
-        let parsed: serde_json::Value = serde_json::from_str(&content).ok()?;
-        let server = parsed.get("mcpServers")?.get("oneiros-local")?;
+        let server = Self::read_entry(Harness::Claude, &content)?;
         let url = server.get("url")?.as_str()?.to_string();
         let token = server
             .get("headers")?
@@ -105,3 +285,112 @@ pub(crate) struct McpLiveConfig {
     pub(crate) url: String,
     pub(crate) token: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stdio_entry_runs_the_cli_against_this_project() {
+        let config = Config::builder()
+            .data_dir(PathBuf::from("/tmp/oneiros-data"))
+            .project(ProjectName::new("dreamer"))
+            .build();
+        let InitMcp::V1(details) = InitMcp::builder_v1()
+            .transport(McpTransport::Stdio)
+            .build()
+            .into();
+
+        let entry = McpConfigService::server_entry(&config, &details).unwrap();
+
+        assert_eq!(entry["type"], "stdio");
+        assert_eq!(entry["command"], "oneiros");
+        assert_eq!(
+            entry["args"],
+            serde_json::json!([
+                "mcp",
+                "serve",
+                "--stdio",
+                "--project",
+                "dreamer",
+                "--data-dir",
+                "/tmp/oneiros-data"
+            ])
+        );
+        assert!(entry.get("headers").is_none());
+    }
+
+    fn http_details(harness: Harness) -> InitMcpV1 {
+        let InitMcp::V1(details) = InitMcp::builder_v1()
+            .token(Token::from("secret"))
+            .harness(harness)
+            .build()
+            .into();
+        details
+    }
+
+    #[test]
+    fn json_merge_keeps_other_servers() {
+        let config = Config::default();
+        let existing = r#"{ "theme": "dark", "mcpServers": { "other": { "url": "http://x" } } }"#;
+        let entry =
+            McpConfigService::server_entry(&config, &http_details(Harness::Cursor)).unwrap();
+
+        let merged = McpConfigService::merge(Harness::Cursor, Some(existing), entry).unwrap();
+        let merged: serde_json::Value = serde_json::from_str(&merged).unwrap();
+
+        assert_eq!(merged["theme"], "dark");
+        assert_eq!(merged["mcpServers"]["other"]["url"], "http://x");
+        assert_eq!(
+            merged["mcpServers"]["oneiros-local"]["headers"]["Authorization"],
+            "Bearer secret"
+        );
+    }
+
+    #[test]
+    fn toml_merge_keeps_other_servers() {
+        let config = Config::default();
+        let existing = "model = \"o3\"\n\n[mcp_servers.other]\ncommand = \"other\"\n";
+        let entry = McpConfigService::server_entry(&config, &http_details(Harness::Codex)).unwrap();
+
+        let merged = McpConfigService::merge(Harness::Codex, Some(existing), entry).unwrap();
+        let parsed: toml::Table = merged.parse().unwrap();
+
+        assert_eq!(parsed["model"].as_str(), Some("o3"));
+        assert_eq!(
+            parsed["mcp_servers"]["other"]["command"].as_str(),
+            Some("other")
+        );
+        assert_eq!(
+            parsed["mcp_servers"]["oneiros-local"]["http_headers"]["Authorization"].as_str(),
+            Some("Bearer secret")
+        );
+
+        let entry = McpConfigService::read_entry(Harness::Codex, &merged).unwrap();
+        assert!(entry["url"].as_str().unwrap().ends_with("/mcp"));
+    }
+
+    #[test]
+    fn gemini_names_its_http_url_differently() {
+        let config = Config::default();
+        let entry =
+            McpConfigService::server_entry(&config, &http_details(Harness::Gemini)).unwrap();
+
+        assert!(entry["httpUrl"].as_str().unwrap().ends_with("/mcp"));
+        assert!(entry.get("url").is_none());
+    }
+
+    #[test]
+    fn merge_rejects_a_servers_key_that_isnt_a_table() {
+        let result = McpConfigService::merge(
+            Harness::Claude,
+            Some(r#"{ "mcpServers": [] }"#),
+            serde_json::json!({}),
+        );
+
+        assert!(matches!(
+            result,
+            Err(McpConfigError::MalformedServers("mcpServers"))
+        ));
+    }
+}
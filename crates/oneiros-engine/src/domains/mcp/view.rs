@@ -51,6 +51,9 @@ impl McpView {
                     String::new(),
                 )
             }
+            // Stdout was the transport — anything written now would
+            // land in the client's stream.
+            McpResponses::ServerStopped => Rendered::silent(McpResponses::ServerStopped),
         }
     }
 }
@@ -4,6 +4,7 @@ pub(crate) enum MemoryDocs {
     Add,
     List,
     Show,
+    Supersede,
 }
 
 impl MemoryDocs {
@@ -35,6 +36,12 @@ impl MemoryDocs {
                 .summary("Get a memory")
                 .description("Retrieve the full content of a specific memory by ID.")
                 .build(),
+            Self::Supersede => ResourceDocs::builder()
+                .tag(tag)
+                .nickname("supersede-memory")
+                .summary("Supersede a memory")
+                .description("Replace a memory with revised content. The original stays in the event log but is hidden from listings, search and dreams.")
+                .build(),
         }
     }
 }
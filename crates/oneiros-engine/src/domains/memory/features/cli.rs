@@ -7,6 +7,8 @@ pub(crate) enum MemoryCommands {
     Add(AddMemory),
     Show(GetMemory),
     List(ListMemories),
+    /// Replace a memory with revised content, hiding the original.
+    Supersede(SupersedeMemory),
 }
 
 impl MemoryCommands {
@@ -29,6 +31,10 @@ impl MemoryCommands {
                 listing.execute_request(&client).await?,
                 MemoryRequest::ListMemories(listing.clone()),
             ),
+            Self::Supersede(supersession) => (
+                supersession.execute_request(&client).await?,
+                MemoryRequest::SupersedeMemory(supersession.clone()),
+            ),
         };
 
         let response: MemoryResponse = serde_json::from_slice(&bytes)?;
@@ -4,6 +4,8 @@ use axum::{
     extract::{Path, Query},
     http::StatusCode,
 };
+use schemars::JsonSchema;
+use serde::Deserialize;
 
 use crate::*;
 
@@ -35,11 +37,26 @@ impl MemoryRouter {
                             .input::<IdPathParam<MemoryId>>()
                             .response::<200, Json<MemoryDetailsResponse>>()
                     }),
+                )
+                .api_route(
+                    "/{id}/supersede",
+                    routing::post_with(supersede, |op| {
+                        resource_op!(op, MemoryDocs::Supersede)
+                            .security_requirement("BearerToken")
+                            .input::<IdPathParam<MemoryId>>()
+                            .response::<201, Json<MemorySupersededResponse>>()
+                    }),
                 ),
         )
     }
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+struct SupersedeBody {
+    content: Content,
+    level: Option<LevelName>,
+}
+
 async fn add(
     scope: Scope<AtBookmark>,
     mailbox: Mailbox,
@@ -73,3 +90,19 @@ async fn show(
         MemoryService::get(&scope, &GetMemory::builder_v1().key(key).build().into()).await?,
     ))
 }
+
+async fn supersede(
+    scope: Scope<AtBookmark>,
+    mailbox: Mailbox,
+    Path(id): Path<MemoryId>,
+    Json(body): Json<SupersedeBody>,
+) -> Result<(StatusCode, Json<MemoryResponse>), MemoryError> {
+    let request = SupersedeMemory::builder_v1()
+        .id(id)
+        .content(body.content)
+        .maybe_level(body.level)
+        .build()
+        .into();
+    let response = MemoryService::supersede(&scope, &mailbox, &request).await?;
+    Ok((StatusCode::CREATED, Json(response)))
+}
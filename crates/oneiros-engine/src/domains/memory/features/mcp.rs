@@ -40,6 +40,13 @@ mod memory_mcp {
             Tool::<AddMemory>::new(
                 MemoryRequestType::AddMemory,
                 "Consolidate something you've learned",
+                PermissionOp::MemoryWrite,
+            )
+            .def(),
+            Tool::<SupersedeMemory>::new(
+                MemoryRequestType::SupersedeMemory,
+                "Replace a memory with a revised one",
+                PermissionOp::MemoryWrite,
             )
             .def(),
         ]
@@ -67,6 +74,15 @@ mod memory_mcp {
                     .map_err(Error::from)?;
                 Ok(MemoryView::new(response, &request).mcp())
             }
+            MemoryRequestType::SupersedeMemory => {
+                let supersession: SupersedeMemory = serde_json::from_value(params.clone())?;
+                let request = MemoryRequest::SupersedeMemory(supersession.clone());
+                let scope = context.scope().map_err(Error::from)?;
+                let response = MemoryService::supersede(scope, mailbox, &supersession)
+                    .await
+                    .map_err(Error::from)?;
+                Ok(MemoryView::new(response, &request).mcp())
+            }
             MemoryRequestType::GetMemory | MemoryRequestType::ListMemories => {
                 Err(ToolError::UnknownTool(tool_name.to_string()))
             }
@@ -96,9 +112,9 @@ mod memory_mcp {
                         .map_err(Error::from)?
                 }
             }
-            MemoryRequest::AddMemory(_) => {
+            MemoryRequest::AddMemory(_) | MemoryRequest::SupersedeMemory(_) => {
                 return Err(ToolError::NotAResource(
-                    "Add is a tool, not a resource".to_string(),
+                    "Mutations are tools, not resources".to_string(),
                 ));
             }
         };
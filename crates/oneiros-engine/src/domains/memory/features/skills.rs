@@ -8,6 +8,7 @@ impl MemorySkills {
             Skill::new("memory-add", include_str!("skills/add.md")),
             Skill::new("memory-show", include_str!("skills/show.md")),
             Skill::new("memory-list", include_str!("skills/list.md")),
+            Skill::new("memory-supersede", include_str!("skills/supersede.md")),
         ]
     }
 }
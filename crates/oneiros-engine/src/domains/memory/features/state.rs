@@ -4,10 +4,35 @@ pub(crate) struct MemoryState;
 
 impl MemoryState {
     pub(crate) fn reduce(mut canon: ProjectCanon, event: &Events) -> ProjectCanon {
-        if let Events::Memory(MemoryEvents::MemoryAdded(added)) = event
-            && let Ok(current) = added.current()
-        {
-            canon.memories.set(&current.memory);
+        match event {
+            Events::Memory(MemoryEvents::MemoryAdded(added)) => {
+                if let Ok(current) = added.current() {
+                    canon.memories.set(&current.memory);
+                }
+            }
+            Events::Memory(MemoryEvents::MemoryDemoted(demoted)) => {
+                if let Ok(current) = demoted.current()
+                    && let Some(memory) = canon.memories.get_mut(&current.id)
+                {
+                    memory.level = current.to;
+                }
+            }
+            Events::Memory(MemoryEvents::MemoryArchived(archived)) => {
+                if let Ok(current) = archived.current()
+                    && let Some(memory) = canon.memories.get_mut(&current.id)
+                {
+                    memory.archived_at = Some(current.archived_at);
+                }
+            }
+            // The canon holds the live picture; a superseded memory
+            // gives way to its replacement.
+            Events::Memory(MemoryEvents::MemorySuperseded(superseded)) => {
+                if let Ok(current) = superseded.current() {
+                    canon.memories.remove(&current.id);
+                    canon.memories.set(&current.replacement);
+                }
+            }
+            _ => {}
         }
 
         canon
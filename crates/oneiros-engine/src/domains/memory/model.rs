@@ -15,6 +15,17 @@ pub(crate) struct Memory {
     pub(crate) content: Content,
     #[builder(default = Timestamp::now())]
     pub(crate) created_at: Timestamp,
+    /// Set once a retention policy has archived the memory; archived
+    /// memories stay addressable but no longer surface in dreams.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) archived_at: Option<Timestamp>,
+    /// The memory that replaced this one. Superseded memories stay in
+    /// the log but are hidden from dreams, lists and search by default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) superseded_by: Option<MemoryId>,
+    /// When a retention policy last moved the memory to its level.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) demoted_at: Option<Timestamp>,
 }
 
 impl Memory {
@@ -22,6 +33,20 @@ impl Memory {
     pub(crate) fn ref_token(&self) -> RefToken {
         RefToken::from(Ref::memory(self.id))
     }
+
+    pub(crate) fn is_archived(&self) -> bool {
+        self.archived_at.is_some()
+    }
+
+    pub(crate) fn is_superseded(&self) -> bool {
+        self.superseded_by.is_some()
+    }
+
+    /// When the memory entered its current level: its last demotion, or
+    /// its creation if it has never been demoted.
+    pub(crate) fn leveled_at(&self) -> Timestamp {
+        self.demoted_at.unwrap_or(self.created_at)
+    }
 }
 
 impl Indexable<MemoryId> for Memory {
@@ -12,6 +12,9 @@ pub(crate) enum MemoryError {
     #[error("Memory not found: {0}")]
     NotFound(crate::MemoryId),
 
+    #[error("Memory already superseded: {0}")]
+    AlreadySuperseded(crate::MemoryId),
+
     #[error("Invalid ID: {0}")]
     InvalidId(#[from] crate::IdParseError),
 
@@ -44,6 +47,7 @@ impl IntoResponse for MemoryError {
         let (status, message) = match &self {
             MemoryError::AgentNotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
             MemoryError::NotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
+            MemoryError::AlreadySuperseded(_) => (StatusCode::CONFLICT, self.to_string()),
             MemoryError::InvalidId(_) => (StatusCode::UNPROCESSABLE_ENTITY, self.to_string()),
             MemoryError::Resolve(_) => (StatusCode::UNPROCESSABLE_ENTITY, self.to_string()),
             MemoryError::Database(_) | MemoryError::Event(_) => {
@@ -5,9 +5,25 @@ use crate::*;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Kinded)]
 #[serde(rename_all = "kebab-case", tag = "type", content = "data")]
-#[kinded(kind = MemoryEventsType, display = "kebab-case")]
+#[kinded(
+    kind = MemoryEventsType,
+    display = "kebab-case",
+    attrs(
+        expect(
+            clippy::enum_variant_names,
+            reason = "We use these for `type` notation in serde"
+        )
+    )
+)]
+#[expect(
+    clippy::enum_variant_names,
+    reason = "We use these for `type` notation in serde"
+)]
 pub(crate) enum MemoryEvents {
     MemoryAdded(MemoryAdded),
+    MemoryDemoted(MemoryDemoted),
+    MemoryArchived(MemoryArchived),
+    MemorySuperseded(MemorySuperseded),
 }
 
 versioned! {
@@ -18,6 +34,40 @@ versioned! {
     }
 }
 
+versioned! {
+    pub(crate) enum MemoryDemoted {
+        V1 => {
+            pub(crate) id: MemoryId,
+            #[builder(into)] pub(crate) from: LevelName,
+            #[builder(into)] pub(crate) to: LevelName,
+            #[builder(default = Timestamp::now())]
+            pub(crate) demoted_at: Timestamp,
+        }
+    }
+}
+
+versioned! {
+    pub(crate) enum MemoryArchived {
+        V1 => {
+            pub(crate) id: MemoryId,
+            #[builder(into)] pub(crate) level: LevelName,
+            #[builder(default = Timestamp::now())]
+            pub(crate) archived_at: Timestamp,
+        }
+    }
+}
+
+versioned! {
+    pub(crate) enum MemorySuperseded {
+        V1 => {
+            /// The memory being replaced.
+            pub(crate) id: MemoryId,
+            /// The memory that takes its place.
+            pub(crate) replacement: Memory,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -32,7 +82,15 @@ mod tests {
 
     #[test]
     fn event_types_are_kebab_cased() {
-        assert_eq!(&MemoryEventsType::MemoryAdded.to_string(), "memory-added");
+        let cases = [
+            (MemoryEventsType::MemoryAdded, "memory-added"),
+            (MemoryEventsType::MemoryDemoted, "memory-demoted"),
+            (MemoryEventsType::MemoryArchived, "memory-archived"),
+            (MemoryEventsType::MemorySuperseded, "memory-superseded"),
+        ];
+        for (event_type, expectation) in cases {
+            assert_eq!(&event_type.to_string(), expectation);
+        }
     }
 
     #[test]
@@ -55,4 +113,32 @@ mod tests {
             "V1 layer must not appear on the wire"
         );
     }
+
+    #[test]
+    fn memory_demoted_round_trips_through_v1_layer() {
+        let id = MemoryId::new();
+        let original = MemoryEvents::MemoryDemoted(
+            MemoryDemoted::builder_v1()
+                .id(id)
+                .from("working")
+                .to("archival")
+                .build()
+                .into(),
+        );
+
+        let json = serde_json::to_value(&original).unwrap();
+        assert_eq!(json["type"], "memory-demoted");
+        assert_eq!(json["data"]["from"], "working");
+        assert_eq!(json["data"]["to"], "archival");
+
+        let decoded: MemoryEvents = serde_json::from_value(json).unwrap();
+        match decoded {
+            MemoryEvents::MemoryDemoted(demoted) => {
+                let v1 = demoted.current().unwrap();
+                assert_eq!(v1.id, id);
+                assert_eq!(v1.to.as_str(), "archival");
+            }
+            _ => panic!("wrong variant"),
+        }
+    }
 }
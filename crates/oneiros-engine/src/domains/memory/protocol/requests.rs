@@ -46,6 +46,11 @@ versioned! {
             #[arg(long)]
             #[builder(into)]
             pub(crate) lens: Option<String>,
+            /// Include memories that have been superseded.
+            #[arg(long)]
+            #[serde(default, deserialize_with = "query_flag")]
+            #[builder(default)]
+            pub(crate) include_retracted: bool,
             #[command(flatten)]
             #[serde(flatten)]
             #[builder(default)]
@@ -54,6 +59,21 @@ versioned! {
     }
 }
 
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum SupersedeMemory {
+        #[derive(clap::Args)]
+        V1 => {
+            #[builder(into)] pub(crate) id: MemoryId,
+            #[builder(into)] pub(crate) content: Content,
+            /// Level for the replacement. Defaults to the superseded
+            /// memory's level.
+            #[arg(long)]
+            pub(crate) level: Option<LevelName>,
+        }
+    }
+}
+
 resource_requests! {
     AddMemory => |this, client| {
         client.post("/memories", this).await
@@ -82,6 +102,10 @@ resource_requests! {
             params.push(("lens", lens.clone()));
         }
 
+        if listing.include_retracted {
+            params.push(("include_retracted", "true".to_string()));
+        }
+
         params.push(("limit", listing.filters.limit.to_string()));
         params.push(("offset", listing.filters.offset.to_string()));
 
@@ -93,6 +117,18 @@ resource_requests! {
 
         client.get(&format!("/memories?{query}")).await
     },
+    SupersedeMemory => |this, client| {
+        let SupersedeMemory::V1(supersession) = this;
+        client
+            .post(
+                &format!("/memories/{}/supersede", supersession.id),
+                &serde_json::json!({
+                    "content": supersession.content,
+                    "level": supersession.level,
+                }),
+            )
+            .await
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Kinded)]
@@ -102,6 +138,7 @@ pub(crate) enum MemoryRequest {
     AddMemory(AddMemory),
     GetMemory(GetMemory),
     ListMemories(ListMemories),
+    SupersedeMemory(SupersedeMemory),
 }
 
 #[cfg(test)]
@@ -114,6 +151,7 @@ mod tests {
             (MemoryRequestType::AddMemory, "add-memory"),
             (MemoryRequestType::GetMemory, "get-memory"),
             (MemoryRequestType::ListMemories, "list-memories"),
+            (MemoryRequestType::SupersedeMemory, "supersede-memory"),
         ];
 
         for (request_type, expectation) in cases {
@@ -12,6 +12,7 @@ pub(crate) enum MemoryResponse {
     MemoryDetails(MemoryDetailsResponse),
     Memories(MemoriesResponse),
     NoMemories,
+    MemorySuperseded(MemorySupersededResponse),
 }
 
 versioned! {
@@ -37,3 +38,13 @@ versioned! {
         }
     }
 }
+
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum MemorySupersededResponse {
+        V1 => {
+            pub(crate) superseded: MemoryId,
+            pub(crate) memory: Memory,
+        }
+    }
+}
@@ -25,7 +25,7 @@ impl<'a> MemoryRepo<'a> {
     pub(crate) async fn get(&self, id: &MemoryId) -> Result<Option<Memory>, EventError> {
         let db = BookmarkDb::open(self.scope).await?;
         let mut stmt = db.prepare(
-            "SELECT id, agent_id, level, content, created_at
+            "SELECT id, agent_id, level, content, created_at, archived_at, superseded_by, demoted_at
              FROM memories WHERE id = ?1",
         )?;
 
@@ -37,17 +37,42 @@ impl<'a> MemoryRepo<'a> {
                 row.get::<_, String>(2)?,
                 row.get::<_, String>(3)?,
                 row.get::<_, String>(4)?,
+                row.get::<_, Option<String>>(5)?,
+                row.get::<_, Option<String>>(6)?,
+                row.get::<_, Option<String>>(7)?,
             ))
         });
 
         match result {
-            Ok((id, agent_id, level, content, created_at)) => Ok(Some(
+            Ok((
+                id,
+                agent_id,
+                level,
+                content,
+                created_at,
+                archived_at,
+                superseded_by,
+                demoted_at,
+            )) => Ok(Some(
                 Memory::builder()
                     .id(id.parse()?)
                     .agent_id(agent_id.parse()?)
                     .level(level)
                     .content(content)
                     .created_at(Timestamp::parse_str(&created_at)?)
+                    .maybe_superseded_by(superseded_by.as_deref().map(str::parse).transpose()?)
+                    .maybe_archived_at(
+                        archived_at
+                            .as_deref()
+                            .map(Timestamp::parse_str)
+                            .transpose()?,
+                    )
+                    .maybe_demoted_at(
+                        demoted_at
+                            .as_deref()
+                            .map(Timestamp::parse_str)
+                            .transpose()?,
+                    )
                     .build(),
             )),
             Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
@@ -67,7 +92,7 @@ impl<'a> MemoryRepo<'a> {
             .collect::<Vec<_>>()
             .join(", ");
         let sql = format!(
-            "SELECT id, agent_id, level, content, created_at
+            "SELECT id, agent_id, level, content, created_at, archived_at, superseded_by, demoted_at
              FROM memories WHERE id IN ({placeholders})"
         );
         let id_strs: Vec<String> = ids.iter().map(ToString::to_string).collect();
@@ -80,18 +105,36 @@ impl<'a> MemoryRepo<'a> {
                     row.get::<_, String>(2)?,
                     row.get::<_, String>(3)?,
                     row.get::<_, String>(4)?,
+                    row.get::<_, Option<String>>(5)?,
+                    row.get::<_, Option<String>>(6)?,
+                    row.get::<_, Option<String>>(7)?,
                 ))
             })?
             .collect::<Result<Vec<_>, _>>()?;
 
         let mut by_id: HashMap<MemoryId, Memory> = HashMap::with_capacity(rows.len());
-        for (id, agent_id, level, content, created_at) in rows {
+        for (id, agent_id, level, content, created_at, archived_at, superseded_by, demoted_at) in
+            rows
+        {
             let memory = Memory::builder()
                 .id(id.parse()?)
                 .agent_id(agent_id.parse()?)
                 .level(level)
                 .content(content)
                 .created_at(Timestamp::parse_str(&created_at)?)
+                .maybe_superseded_by(superseded_by.as_deref().map(str::parse).transpose()?)
+                .maybe_archived_at(
+                    archived_at
+                        .as_deref()
+                        .map(Timestamp::parse_str)
+                        .transpose()?,
+                )
+                .maybe_demoted_at(
+                    demoted_at
+                        .as_deref()
+                        .map(Timestamp::parse_str)
+                        .transpose()?,
+                )
                 .build();
             by_id.insert(memory.id, memory);
         }
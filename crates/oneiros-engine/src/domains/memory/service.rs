@@ -1,3 +1,5 @@
+use std::collections::{HashMap, HashSet};
+
 use crate::*;
 
 pub(crate) struct MemoryService;
@@ -85,6 +87,7 @@ impl MemoryService {
             .kind(SearchKind::Memory)
             .maybe_level(listing.level.clone())
             .maybe_query(listing.query.clone())
+            .include_retracted(listing.include_retracted)
             .filters(listing.filters)
             .build();
 
@@ -114,4 +117,191 @@ impl MemoryService {
                 .into(),
         ))
     }
+
+    /// Replace a memory with a revised one. The original stays in the
+    /// event log but drops out of listings, search and dreams.
+    pub(crate) async fn supersede(
+        scope: &Scope<AtBookmark>,
+        mailbox: &Mailbox,
+        request: &SupersedeMemory,
+    ) -> Result<MemoryResponse, MemoryError> {
+        let SupersedeMemory::V1(supersession) = request;
+        let original = MemoryRepo::new(scope)
+            .fetch(&supersession.id)
+            .await?
+            .ok_or(MemoryError::NotFound(supersession.id))?;
+
+        if original.is_superseded() {
+            return Err(MemoryError::AlreadySuperseded(original.id));
+        }
+
+        let replacement = Memory::builder()
+            .agent_id(original.agent_id)
+            .level(
+                supersession
+                    .level
+                    .clone()
+                    .unwrap_or_else(|| original.level.clone()),
+            )
+            .content(supersession.content.clone())
+            .build();
+        let id = replacement.id;
+
+        let new_event = NewEvent::builder()
+            .data(Events::Memory(MemoryEvents::MemorySuperseded(
+                MemorySuperseded::builder_v1()
+                    .id(original.id)
+                    .replacement(replacement)
+                    .build()
+                    .into(),
+            )))
+            .build();
+
+        mailbox.tell(ProjectMessage::from(
+            AppendProjectLog::builder()
+                .scope(scope.clone())
+                .event(new_event)
+                .build(),
+        ));
+
+        let stored = MemoryRepo::new(scope)
+            .fetch(&id)
+            .await?
+            .ok_or(MemoryError::NotFound(id))?;
+
+        Ok(MemoryResponse::MemorySuperseded(
+            MemorySupersededResponse::builder_v1()
+                .superseded(original.id)
+                .memory(stored)
+                .build()
+                .into(),
+        ))
+    }
+
+    /// Enforce every level's retention policy against the bookmark's
+    /// memories as of `now`. A memory's age at a level runs from when it
+    /// entered that level, and each agent's memories there count newest
+    /// first; whatever a policy expires is demoted, or archived when the
+    /// policy names no level to demote to.
+    ///
+    /// Every bookmark of a project shares its log, so a memory the log
+    /// already records leaving a level — swept from another bookmark —
+    /// isn't moved again; this bookmark takes in the recorded event
+    /// instead. `moved` carries what one sweep moves across the project's
+    /// bookmarks, before those events land; the rest catch up next sweep.
+    pub(crate) async fn retain(
+        scope: &Scope<AtBookmark>,
+        mailbox: &Mailbox,
+        now: Timestamp,
+        moved: &mut HashSet<(MemoryId, LevelName)>,
+    ) -> Result<RetentionSweep, MemoryError> {
+        let db = BookmarkDb::open(scope).await.map_err(EventError::from)?;
+        let recorded = Self::recorded_moves(&db)?;
+        let levels = LevelStore::new(&db).list()?;
+        let memories = MemoryStore::new(&db).list(None)?;
+
+        let mut sweep = RetentionSweep::default();
+        for level in &levels {
+            let Some(policy) = &level.retention else {
+                continue;
+            };
+            let demote_to = policy
+                .demote_to
+                .as_ref()
+                .filter(|target| levels.iter().any(|l| &l.name == *target));
+
+            let mut by_agent: HashMap<AgentId, Vec<&Memory>> = HashMap::new();
+            for memory in memories
+                .iter()
+                .filter(|m| m.level == level.name && !m.is_archived())
+            {
+                by_agent.entry(memory.agent_id).or_default().push(memory);
+            }
+
+            for mut held in by_agent.into_values() {
+                held.sort_by_key(|m| std::cmp::Reverse(m.leveled_at()));
+                for (newer, memory) in held.into_iter().enumerate() {
+                    if !policy.expires(now.duration_since(memory.leveled_at()), newer) {
+                        continue;
+                    }
+
+                    let key = (memory.id, level.name.clone());
+                    if let Some(stored) = recorded.get(&key) {
+                        mailbox.tell(ProjectMessage::from(
+                            ImportProjectEvent::builder()
+                                .scope(scope.clone())
+                                .stored(stored.clone())
+                                .build(),
+                        ));
+                        continue;
+                    }
+                    if !moved.insert(key) {
+                        continue;
+                    }
+
+                    let event = match demote_to {
+                        Some(target) => {
+                            sweep.demoted += 1;
+                            MemoryEvents::MemoryDemoted(
+                                MemoryDemoted::builder_v1()
+                                    .id(memory.id)
+                                    .from(level.name.clone())
+                                    .to(target.clone())
+                                    .demoted_at(now)
+                                    .build()
+                                    .into(),
+                            )
+                        }
+                        None => {
+                            sweep.archived += 1;
+                            MemoryEvents::MemoryArchived(
+                                MemoryArchived::builder_v1()
+                                    .id(memory.id)
+                                    .level(level.name.clone())
+                                    .archived_at(now)
+                                    .build()
+                                    .into(),
+                            )
+                        }
+                    };
+
+                    mailbox.tell(ProjectMessage::from(
+                        AppendProjectLog::builder()
+                            .scope(scope.clone())
+                            .event(NewEvent::builder().data(Events::Memory(event)).build())
+                            .build(),
+                    ));
+                }
+            }
+        }
+
+        Ok(sweep)
+    }
+
+    /// Every memory the project log records leaving a level, with the
+    /// event that moved it.
+    fn recorded_moves(
+        db: &rusqlite::Connection,
+    ) -> Result<HashMap<(MemoryId, LevelName), StoredEvent>, EventError> {
+        let mut moved = HashMap::new();
+        let kinds = [
+            MemoryEventsType::MemoryDemoted.to_string(),
+            MemoryEventsType::MemoryArchived.to_string(),
+        ];
+        EventLog::attached(db).each_of_kind(&kinds, |event| {
+            match &event.data {
+                Event::Known(Events::Memory(MemoryEvents::MemoryDemoted(demoted))) => {
+                    let demoted = demoted.current()?;
+                    moved.insert((demoted.id, demoted.from), event.clone());
+                }
+                Event::Known(Events::Memory(MemoryEvents::MemoryArchived(archived))) => {
+                    let archived = archived.current()?;
+                    moved.insert((archived.id, archived.level), event.clone());
+                }
+                _ => {}
+            }
+            Ok::<_, EventError>(())
+        })?;
+        Ok(moved)
+    }
 }
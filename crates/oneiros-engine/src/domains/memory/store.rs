@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use rusqlite::params;
 
 use crate::*;
@@ -13,20 +15,59 @@ impl<'a> MemoryStore<'a> {
     }
 
     pub(crate) fn handle(&self, event: &StoredEvent) -> Result<(), EventError> {
-        if let Event::Known(Events::Memory(memory_event)) = &event.data {
-            match memory_event {
-                MemoryEvents::MemoryAdded(added) => {
-                    let memory = added.current()?.memory;
-                    self.write_memory(&memory)?;
+        match &event.data {
+            Event::Known(Events::Memory(MemoryEvents::MemoryAdded(added))) => {
+                let memory = added.current()?.memory;
+                self.write_memory(&memory)?;
+                SearchStore::new(self.conn).index_entry(&IndexEntry::memory(&memory))?;
+            }
+            Event::Known(Events::Memory(MemoryEvents::MemoryDemoted(demoted))) => {
+                let demoted = demoted.current()?;
+                self.conn.execute(
+                    "UPDATE memories SET level = ?2, demoted_at = ?3 WHERE id = ?1",
+                    params![
+                        demoted.id.to_string(),
+                        demoted.to.to_string(),
+                        demoted.demoted_at.as_string(),
+                    ],
+                )?;
+                if let Some(memory) = self.get(&demoted.id)? {
                     SearchStore::new(self.conn).index_entry(&IndexEntry::memory(&memory))?;
                 }
             }
+            Event::Known(Events::Memory(MemoryEvents::MemoryArchived(archived))) => {
+                let archived = archived.current()?;
+                self.conn.execute(
+                    "UPDATE memories SET archived_at = ?2 WHERE id = ?1",
+                    params![archived.id.to_string(), archived.archived_at.as_string()],
+                )?;
+                SearchStore::new(self.conn).retract(&Ref::memory(archived.id))?;
+            }
+            Event::Known(Events::Memory(MemoryEvents::MemorySuperseded(superseded))) => {
+                let superseded = superseded.current()?;
+                let replacement = superseded.replacement;
+                self.write_memory(&replacement)?;
+                self.conn.execute(
+                    "UPDATE memories SET superseded_by = ?2 WHERE id = ?1",
+                    params![superseded.id.to_string(), replacement.id.to_string()],
+                )?;
+                let search = SearchStore::new(self.conn);
+                search.index_entry(&IndexEntry::memory(&replacement))?;
+                search.retract(&Ref::memory(superseded.id))?;
+            }
+            Event::Known(Events::Continuity(ContinuityEvents::Dreamed(dreamed))) => {
+                for id in &dreamed.current()?.memories {
+                    self.record_surfaced(id)?;
+                }
+            }
+            _ => {}
         }
         Ok(())
     }
 
     pub(crate) fn reset(&self) -> Result<(), EventError> {
         self.conn.execute("DELETE FROM memories", [])?;
+        self.conn.execute("DELETE FROM memory_recalls", [])?;
         Ok(())
     }
 
@@ -38,19 +79,105 @@ impl<'a> MemoryStore<'a> {
                 level TEXT NOT NULL DEFAULT '',
                 content TEXT NOT NULL DEFAULT '',
                 created_at TEXT NOT NULL DEFAULT ''
+            );
+            CREATE TABLE IF NOT EXISTS memory_recalls (
+                memory_id TEXT PRIMARY KEY,
+                surfaced INTEGER NOT NULL DEFAULT 0
             )",
         )?;
+        // Added after the table shipped; older projections lack them.
+        for column in ["archived_at TEXT", "superseded_by TEXT", "demoted_at TEXT"] {
+            self.conn
+                .execute(&format!("ALTER TABLE memories ADD COLUMN {column}"), [])
+                .ok();
+        }
         Ok(())
     }
 
+    /// How many dreams have surfaced each memory, for all of them at
+    /// once. Memories never surfaced are absent.
+    pub(crate) fn surfaced_counts(&self) -> Result<HashMap<MemoryId, usize>, EventError> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT memory_id, surfaced FROM memory_recalls")?;
+
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, usize>(1)?))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut counts = HashMap::with_capacity(rows.len());
+        for (id, surfaced) in rows {
+            counts.insert(id.parse()?, surfaced);
+        }
+        Ok(counts)
+    }
+
+    pub(crate) fn get(&self, id: &MemoryId) -> Result<Option<Memory>, EventError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, agent_id, level, content, created_at, archived_at, superseded_by, demoted_at
+             FROM memories WHERE id = ?1",
+        )?;
+
+        let result = stmt.query_row(params![id.to_string()], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, String>(4)?,
+                row.get::<_, Option<String>>(5)?,
+                row.get::<_, Option<String>>(6)?,
+                row.get::<_, Option<String>>(7)?,
+            ))
+        });
+
+        match result {
+            Ok((
+                id,
+                agent_id,
+                level,
+                content,
+                created_at,
+                archived_at,
+                superseded_by,
+                demoted_at,
+            )) => Ok(Some(
+                Memory::builder()
+                    .id(id.parse()?)
+                    .agent_id(agent_id.parse()?)
+                    .level(level)
+                    .content(content)
+                    .created_at(Timestamp::parse_str(&created_at)?)
+                    .maybe_superseded_by(superseded_by.as_deref().map(str::parse).transpose()?)
+                    .maybe_archived_at(
+                        archived_at
+                            .as_deref()
+                            .map(Timestamp::parse_str)
+                            .transpose()?,
+                    )
+                    .maybe_demoted_at(
+                        demoted_at
+                            .as_deref()
+                            .map(Timestamp::parse_str)
+                            .transpose()?,
+                    )
+                    .build(),
+            )),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     pub(crate) fn list(&self, agent: Option<&str>) -> Result<Vec<Memory>, EventError> {
         let mut stmt = match agent {
             Some(_) => self.conn.prepare(
-                "SELECT id, agent_id, level, content, created_at
+                "SELECT id, agent_id, level, content, created_at, archived_at, superseded_by, demoted_at
                  FROM memories WHERE agent_id = ?1 ORDER BY created_at",
             )?,
             None => self.conn.prepare(
-                "SELECT id, agent_id, level, content, created_at
+                "SELECT id, agent_id, level, content, created_at, archived_at, superseded_by, demoted_at
                  FROM memories ORDER BY created_at",
             )?,
         };
@@ -62,6 +189,9 @@ impl<'a> MemoryStore<'a> {
                 row.get::<_, String>(2)?,
                 row.get::<_, String>(3)?,
                 row.get::<_, String>(4)?,
+                row.get::<_, Option<String>>(5)?,
+                row.get::<_, Option<String>>(6)?,
+                row.get::<_, Option<String>>(7)?,
             ))
         };
 
@@ -72,7 +202,9 @@ impl<'a> MemoryStore<'a> {
         .collect::<Result<Vec<_>, _>>()?;
 
         let mut memories = vec![];
-        for (id, agent_id, level, content, created_at) in raw {
+        for (id, agent_id, level, content, created_at, archived_at, superseded_by, demoted_at) in
+            raw
+        {
             memories.push(
                 Memory::builder()
                     .id(id.parse()?)
@@ -80,6 +212,19 @@ impl<'a> MemoryStore<'a> {
                     .level(level)
                     .content(content)
                     .created_at(Timestamp::parse_str(&created_at)?)
+                    .maybe_superseded_by(superseded_by.as_deref().map(str::parse).transpose()?)
+                    .maybe_archived_at(
+                        archived_at
+                            .as_deref()
+                            .map(Timestamp::parse_str)
+                            .transpose()?,
+                    )
+                    .maybe_demoted_at(
+                        demoted_at
+                            .as_deref()
+                            .map(Timestamp::parse_str)
+                            .transpose()?,
+                    )
                     .build(),
             );
         }
@@ -87,16 +232,28 @@ impl<'a> MemoryStore<'a> {
         Ok(memories)
     }
 
+    fn record_surfaced(&self, id: &MemoryId) -> Result<(), EventError> {
+        self.conn.execute(
+            "INSERT INTO memory_recalls (memory_id, surfaced) VALUES (?1, 1)
+             ON CONFLICT(memory_id) DO UPDATE SET surfaced = surfaced + 1",
+            params![id.to_string()],
+        )?;
+        Ok(())
+    }
+
     fn write_memory(&self, memory: &Memory) -> Result<(), EventError> {
         self.conn.execute(
-            "INSERT OR REPLACE INTO memories (id, agent_id, level, content, created_at)
-             VALUES (?1, ?2, ?3, ?4, ?5)",
+            "INSERT OR REPLACE INTO memories (id, agent_id, level, content, created_at, archived_at, superseded_by, demoted_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
             params![
                 memory.id.to_string(),
                 memory.agent_id.to_string(),
                 memory.level.to_string(),
                 memory.content.to_string(),
                 memory.created_at.as_string(),
+                memory.archived_at.as_ref().map(Timestamp::as_string),
+                memory.superseded_by.map(|id| id.to_string()),
+                memory.demoted_at.as_ref().map(Timestamp::as_string),
             ],
         )?;
         Ok(())
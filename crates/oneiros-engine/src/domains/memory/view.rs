@@ -27,10 +27,30 @@ impl<'a> MemoryView<'a> {
                     MutationHints::builder().ref_token(ref_token).build(),
                 ))
             }
+            MemoryResponse::MemorySuperseded(MemorySupersededResponse::V1(superseded)) => {
+                let ref_token = RefToken::new(Ref::memory(superseded.memory.id));
+                McpResponse::new(format!(
+                    "Memory superseded ({}).\n\n**level:** {}\n**replaces:** {}\n**ref:** {}",
+                    superseded.memory.level,
+                    superseded.memory.level,
+                    RefToken::new(Ref::memory(superseded.superseded)),
+                    ref_token
+                ))
+                .hint_set(HintSet::mutation(
+                    MutationHints::builder().ref_token(ref_token).build(),
+                ))
+            }
             MemoryResponse::MemoryDetails(MemoryDetailsResponse::V1(details)) => {
                 let ref_token = RefToken::new(Ref::memory(details.memory.id));
+                let status = match details.memory.superseded_by {
+                    Some(replacement) => format!(
+                        "**superseded by:** {}\n",
+                        RefToken::new(Ref::memory(replacement))
+                    ),
+                    None => String::new(),
+                };
                 McpResponse::new(format!(
-                    "# Memory\n\n**level:** {}\n**agent:** {}\n**created:** {}\n\n{}\n",
+                    "# Memory\n\n**level:** {}\n**agent:** {}\n**created:** {}\n{status}\n{}\n",
                     details.memory.level,
                     details.memory.agent_id,
                     details.memory.created_at,
@@ -99,10 +119,34 @@ impl<'a> MemoryView<'a> {
                 )
                 .with_hints(hints)
             }
+            (MemoryResponse::MemorySuperseded(MemorySupersededResponse::V1(superseded)), _) => {
+                let ref_token = RefToken::new(Ref::memory(superseded.memory.id));
+                let subject = format!(
+                    "{} Memory superseded: {} → {}",
+                    "✓".success(),
+                    RefToken::new(Ref::memory(superseded.superseded)).muted(),
+                    ref_token.clone().muted()
+                );
+                let hints =
+                    HintSet::mutation(MutationHints::builder().ref_token(ref_token).build());
+
+                Rendered::new(
+                    MemoryResponse::MemorySuperseded(MemorySupersededResponse::V1(superseded)),
+                    subject,
+                    String::new(),
+                )
+                .with_hints(hints)
+            }
             (MemoryResponse::MemoryDetails(MemoryDetailsResponse::V1(details)), _) => {
-                let prompt = Detail::new(details.memory.level.to_string())
-                    .field("content:", details.memory.content.to_string())
-                    .to_string();
+                let mut detail = Detail::new(details.memory.level.to_string())
+                    .field("content:", details.memory.content.to_string());
+                if let Some(replacement) = details.memory.superseded_by {
+                    detail = detail.field(
+                        "superseded by:",
+                        RefToken::new(Ref::memory(replacement)).to_string(),
+                    );
+                }
+                let prompt = detail.to_string();
                 let ref_token = RefToken::new(Ref::memory(details.memory.id));
                 let hints =
                     HintSet::mutation(MutationHints::builder().ref_token(ref_token).build());
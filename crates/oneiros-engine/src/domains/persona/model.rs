@@ -12,6 +12,9 @@ pub(crate) struct Persona {
     pub(crate) description: Description,
     #[builder(into)]
     pub(crate) prompt: Prompt,
+    /// How this persona's agents dream; the default recipe when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) recipe: Option<DreamRecipe>,
 }
 
 impl Indexable<PersonaName> for Persona {
@@ -16,6 +16,13 @@ versioned! {
             #[arg(long, default_value = "")]
             #[builder(default, into)]
             pub(crate) prompt: Prompt,
+            /// A dream recipe section as `NAME[:CAP]=LENS`; repeat for
+            /// more, in priority order. Agents of a persona without
+            /// sections dream with the default recipe.
+            #[arg(long = "section")]
+            #[serde(default, skip_serializing_if = "Vec::is_empty")]
+            #[builder(default)]
+            pub(crate) sections: Vec<DreamSection>,
         }
     }
 }
@@ -22,22 +22,24 @@ impl<'a> PersonaRepo<'a> {
     pub(crate) async fn get(&self, name: &PersonaName) -> Result<Option<Persona>, EventError> {
         let db = BookmarkDb::open(self.scope).await?;
         let mut stmt =
-            db.prepare("SELECT name, description, prompt FROM personas WHERE name = ?1")?;
+            db.prepare("SELECT name, description, prompt, recipe FROM personas WHERE name = ?1")?;
 
         let result = stmt.query_row(params![name.to_string()], |row| {
             Ok((
                 row.get::<_, String>(0)?,
                 row.get::<_, String>(1)?,
                 row.get::<_, String>(2)?,
+                row.get::<_, Option<String>>(3)?,
             ))
         });
 
         match result {
-            Ok((name, description, prompt)) => Ok(Some(
+            Ok((name, description, prompt, recipe)) => Ok(Some(
                 Persona::builder()
                     .name(name)
                     .description(description)
                     .prompt(prompt)
+                    .maybe_recipe(recipe.map(|r| serde_json::from_str(&r)).transpose()?)
                     .build(),
             )),
             Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
@@ -57,27 +59,32 @@ impl<'a> PersonaRepo<'a> {
         };
 
         let mut stmt = db.prepare(
-            "SELECT name, description, prompt FROM personas ORDER BY name LIMIT ?1 OFFSET ?2",
+            "SELECT name, description, prompt, recipe FROM personas
+             ORDER BY name LIMIT ?1 OFFSET ?2",
         )?;
 
-        let items = stmt
+        let rows = stmt
             .query_map(rusqlite::params![filters.limit, filters.offset], |row| {
                 Ok((
                     row.get::<_, String>(0)?,
                     row.get::<_, String>(1)?,
                     row.get::<_, String>(2)?,
+                    row.get::<_, Option<String>>(3)?,
                 ))
             })?
-            .collect::<Result<Vec<(String, String, String)>, _>>()?
-            .into_iter()
-            .map(|(name, description, prompt)| {
+            .collect::<Result<Vec<(String, String, String, Option<String>)>, _>>()?;
+
+        let mut items = Vec::with_capacity(rows.len());
+        for (name, description, prompt, recipe) in rows {
+            items.push(
                 Persona::builder()
                     .name(name)
                     .description(description)
                     .prompt(prompt)
-                    .build()
-            })
-            .collect();
+                    .maybe_recipe(recipe.map(|r| serde_json::from_str(&r)).transpose()?)
+                    .build(),
+            );
+        }
 
         Ok(Listed::new(items, total))
     }
@@ -13,6 +13,9 @@ impl PersonaService {
             .name(set.name.clone())
             .description(set.description.clone())
             .prompt(set.prompt.clone())
+            .maybe_recipe(
+                (!set.sections.is_empty()).then(|| DreamRecipe::new(set.sections.clone())),
+            )
             .build();
         let name = persona.name.clone();
 
@@ -32,31 +32,38 @@ impl<'a> PersonaStore<'a> {
             "CREATE TABLE IF NOT EXISTS personas (
                 name TEXT PRIMARY KEY,
                 description TEXT NOT NULL DEFAULT '',
-                prompt TEXT NOT NULL DEFAULT ''
+                prompt TEXT NOT NULL DEFAULT '',
+                recipe TEXT
             )",
         )?;
+        // Migration: add column if missing from older schemas.
+        self.conn
+            .execute_batch("ALTER TABLE personas ADD COLUMN recipe TEXT")
+            .ok();
         Ok(())
     }
 
     pub(crate) fn get(&self, name: &PersonaName) -> Result<Option<Persona>, EventError> {
         let mut stmt = self
             .conn
-            .prepare("SELECT name, description, prompt FROM personas WHERE name = ?1")?;
+            .prepare("SELECT name, description, prompt, recipe FROM personas WHERE name = ?1")?;
 
         let result = stmt.query_row(params![name.to_string()], |row| {
             Ok((
                 row.get::<_, String>(0)?,
                 row.get::<_, String>(1)?,
                 row.get::<_, String>(2)?,
+                row.get::<_, Option<String>>(3)?,
             ))
         });
 
         match result {
-            Ok((name, description, prompt)) => Ok(Some(
+            Ok((name, description, prompt, recipe)) => Ok(Some(
                 Persona::builder()
                     .name(name)
                     .description(description)
                     .prompt(prompt)
+                    .maybe_recipe(recipe.map(|r| serde_json::from_str(&r)).transpose()?)
                     .build(),
             )),
             Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
@@ -80,11 +87,17 @@ impl<'a> PersonaStore<'a> {
 
     fn write_persona(&self, persona: &Persona) -> Result<(), EventError> {
         self.conn.execute(
-            "INSERT OR REPLACE INTO personas (name, description, prompt) VALUES (?1, ?2, ?3)",
+            "INSERT OR REPLACE INTO personas (name, description, prompt, recipe)
+             VALUES (?1, ?2, ?3, ?4)",
             params![
                 persona.name.to_string(),
                 persona.description.to_string(),
-                persona.prompt.to_string()
+                persona.prompt.to_string(),
+                persona
+                    .recipe
+                    .as_ref()
+                    .map(serde_json::to_string)
+                    .transpose()?
             ],
         )?;
         Ok(())
@@ -68,10 +68,15 @@ impl PersonaView {
                 .with_hints(hints)
             }
             PersonaResponse::PersonaDetails(PersonaDetailsResponse::V1(details)) => {
-                let prompt = Detail::new(details.persona.name.to_string())
+                let mut detail = Detail::new(details.persona.name.to_string())
                     .field("description:", details.persona.description.to_string())
-                    .field("prompt:", details.persona.prompt.to_string())
-                    .to_string();
+                    .field("prompt:", details.persona.prompt.to_string());
+                if let Some(recipe) = &details.persona.recipe {
+                    for section in &recipe.sections {
+                        detail.push_field("section:", section.to_string());
+                    }
+                }
+                let prompt = detail.to_string();
                 Rendered::new(
                     PersonaResponse::PersonaDetails(PersonaDetailsResponse::V1(details)),
                     prompt,
@@ -3,6 +3,7 @@ use crate::*;
 pub(crate) enum PressureDocs {
     List,
     Get,
+    Explain,
 }
 
 impl PressureDocs {
@@ -30,6 +31,14 @@ impl PressureDocs {
                     "Retrieve the current cognitive pressure level for a specific context.",
                 )
                 .build(),
+            Self::Explain => ResourceDocs::builder()
+                .tag(tag)
+                .nickname("explain-pressure")
+                .summary("Explain pressure")
+                .description(
+                    "Show which gauge configuration and tunings produced each of an agent's urgencies.",
+                )
+                .build(),
         }
     }
 }
@@ -1,11 +1,23 @@
-use clap::Args;
+use clap::{Args, Subcommand};
 
 use crate::*;
 
+/// `oneiros pressure <agent>` reads an agent's pressure; the subcommands
+/// look deeper into it.
 #[derive(Debug, Args)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 pub(crate) struct PressureCommands {
-    #[command(flatten)]
-    pub(crate) command: GetPressure,
+    #[command(subcommand)]
+    pub(crate) subcommand: Option<PressureSubcommands>,
+    /// The agent whose pressure to read.
+    #[arg(required = true)]
+    pub(crate) agent: Option<AgentName>,
+}
+
+#[derive(Debug, Subcommand)]
+pub(crate) enum PressureSubcommands {
+    /// Show the gauge configuration and tunings behind each urgency
+    Explain(ExplainPressure),
 }
 
 impl PressureCommands {
@@ -15,9 +27,22 @@ impl PressureCommands {
     ) -> Result<Rendered<Responses>, PressureError> {
         let client = Client::from_config(config)?;
 
-        let bytes = self.command.execute_request(&client).await?;
+        let (request, bytes) = match (&self.subcommand, &self.agent) {
+            (Some(PressureSubcommands::Explain(explain)), _) => (
+                PressureRequest::ExplainPressure(explain.clone()),
+                explain.execute_request(&client).await?,
+            ),
+            (None, Some(agent)) => {
+                let get: GetPressure = GetPressure::builder_v1()
+                    .agent(agent.clone())
+                    .build()
+                    .into();
+                let bytes = get.execute_request(&client).await?;
+                (PressureRequest::GetPressure(get), bytes)
+            }
+            (None, None) => unreachable!("clap requires an agent without a subcommand"),
+        };
         let response: PressureResponse = serde_json::from_slice(&bytes)?;
-        let request = PressureRequest::GetPressure(self.command.clone());
         Ok(PressureView::new(response, &request)
             .render()
             .map(Into::into))
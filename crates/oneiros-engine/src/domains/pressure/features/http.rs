@@ -26,6 +26,15 @@ impl PressureRouter {
                             .input::<AgentPathParam<AgentName>>()
                             .response::<200, Json<ReadingsResponse>>()
                     }),
+                )
+                .api_route(
+                    "/{agent}/explain",
+                    routing::get_with(explain, |op| {
+                        resource_op!(op, PressureDocs::Explain)
+                            .security_requirement("BearerToken")
+                            .input::<AgentPathParam<AgentName>>()
+                            .response::<200, Json<ExplainedResponse>>()
+                    }),
                 ),
         )
     }
@@ -45,6 +54,20 @@ async fn get(
     ))
 }
 
+#[expect(deprecated)]
+async fn explain(
+    context: ProjectLog,
+    Path(agent): Path<AgentName>,
+) -> Result<Json<PressureResponse>, PressureError> {
+    Ok(Json(
+        PressureService::explain(
+            &context,
+            &ExplainPressure::builder_v1().agent(agent).build().into(),
+        )
+        .await?,
+    ))
+}
+
 #[expect(deprecated)]
 async fn list(context: ProjectLog) -> Result<Json<PressureResponse>, PressureError> {
     Ok(Json(PressureService::list(&context).await?))
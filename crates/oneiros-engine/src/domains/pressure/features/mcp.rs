@@ -3,6 +3,20 @@ use crate::*;
 pub(crate) struct PressureMcp;
 
 impl PressureMcp {
+    pub(crate) fn defs(&self) -> Vec<ToolDef> {
+        pressure_mcp::tool_defs()
+    }
+
+    #[expect(deprecated)]
+    pub(crate) async fn dispatch(
+        &self,
+        context: &ProjectLog,
+        tool_name: &ToolName,
+        params: &serde_json::Value,
+    ) -> Result<McpResponse, ToolError> {
+        pressure_mcp::dispatch(context, tool_name, params).await
+    }
+
     pub(crate) fn resources(&self) -> Vec<ResourceDef> {
         vec![ResourcePathKind::Pressure.resource_def("All agents' pressure readings")]
     }
@@ -20,6 +34,50 @@ impl PressureMcp {
 mod pressure_mcp {
     use crate::*;
 
+    pub(crate) fn tool_defs() -> Vec<ToolDef> {
+        vec![
+            Tool::<GetPressure>::new(
+                PressureRequestType::GetPressure,
+                "Read how strongly each urge is pulling on an agent",
+                PermissionOp::ProjectRead,
+            )
+            .def(),
+            Tool::<ExplainPressure>::new(
+                PressureRequestType::ExplainPressure,
+                "See which gauge configuration and tunings produced each urgency",
+                PermissionOp::ProjectRead,
+            )
+            .def(),
+        ]
+    }
+
+    #[expect(deprecated)]
+    pub(crate) async fn dispatch(
+        context: &ProjectLog,
+        tool_name: &ToolName,
+        params: &serde_json::Value,
+    ) -> Result<McpResponse, ToolError> {
+        let request_type: PressureRequestType = tool_name
+            .as_str()
+            .parse()
+            .map_err(|_| ToolError::UnknownTool(tool_name.to_string()))?;
+
+        match request_type {
+            PressureRequestType::GetPressure => {
+                let request = PressureRequest::GetPressure(serde_json::from_value(params.clone())?);
+                resource(context, &request).await
+            }
+            PressureRequestType::ExplainPressure => {
+                let request =
+                    PressureRequest::ExplainPressure(serde_json::from_value(params.clone())?);
+                resource(context, &request).await
+            }
+            PressureRequestType::ListPressures => {
+                Err(ToolError::UnknownTool(tool_name.to_string()))
+            }
+        }
+    }
+
     #[expect(deprecated)]
     pub(crate) async fn resource(
         context: &ProjectLog,
@@ -32,6 +90,9 @@ mod pressure_mcp {
             PressureRequest::ListPressures => {
                 PressureService::list(context).await.map_err(Error::from)?
             }
+            PressureRequest::ExplainPressure(explain) => PressureService::explain(context, explain)
+                .await
+                .map_err(Error::from)?,
         };
 
         Ok(PressureView::new(response, request)
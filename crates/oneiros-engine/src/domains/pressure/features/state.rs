@@ -27,27 +27,27 @@ impl PressureState {
 
         let now = Timestamp::now();
         let referenced = Self::referenced_ids(&canon);
-        let urge_names: Vec<UrgeName> = canon.urges.values().map(|u| u.name.clone()).collect();
+        let urges: Vec<Urge> = canon.urges.values().cloned().collect();
 
         // Compute all gauges, then apply them
         let mut updates: Vec<Pressure> = vec![];
 
         for (agent_id, _agent_name) in &agents {
             if let Some(agent) = canon.agents.get(agent_id) {
-                for urge_name in &urge_names {
-                    let gauge = Self::compute_gauge(&canon, &referenced, urge_name, agent);
+                for urge in &urges {
+                    let gauge = Self::compute_gauge(&canon, &referenced, urge, agent);
 
                     let id = canon
                         .pressures
                         .values()
-                        .find(|p| p.agent_id == *agent_id && p.urge == *urge_name)
+                        .find(|p| p.agent_id == *agent_id && p.urge == urge.name)
                         .map(|p| p.id)
                         .unwrap_or_else(PressureId::new);
 
                     updates.push(Pressure {
                         id,
                         agent_id: *agent_id,
-                        urge: urge_name.clone(),
+                        urge: urge.name.clone(),
                         data: gauge,
                         updated_at: now,
                     });
@@ -81,11 +81,13 @@ impl PressureState {
                     .get_or_default(&e.agent)
                     .last_reflect = Some(e.created_at);
             }
-            Events::Continuity(ContinuityEvents::Dreamed(Dreamed::V1(e))) => {
-                canon
-                    .continuity_timestamps
-                    .get_or_default(&e.agent)
-                    .last_dream = Some(e.created_at);
+            Events::Continuity(ContinuityEvents::Dreamed(
+                Dreamed::V2(DreamedV2 {
+                    agent, created_at, ..
+                })
+                | Dreamed::V1(DreamedV1 { agent, created_at }),
+            )) => {
+                canon.continuity_timestamps.get_or_default(agent).last_dream = Some(*created_at);
             }
             Events::Continuity(ContinuityEvents::Slept(Slept::V1(e))) => {
                 canon
@@ -112,6 +114,38 @@ impl PressureState {
                 .get(&addition.memory.agent_id)
                 .into_iter()
                 .collect(),
+            Events::Memory(MemoryEvents::MemoryDemoted(MemoryDemoted::V1(demotion))) => canon
+                .memories
+                .get(&demotion.id)
+                .and_then(|memory| canon.agents.get(&memory.agent_id))
+                .into_iter()
+                .collect(),
+            Events::Memory(MemoryEvents::MemorySuperseded(MemorySuperseded::V1(supersession))) => {
+                canon
+                    .agents
+                    .get(&supersession.replacement.agent_id)
+                    .into_iter()
+                    .collect()
+            }
+            Events::Experience(ExperienceEvents::ExperienceAmended(ExperienceAmended::V1(
+                amendment,
+            ))) => canon
+                .agents
+                .get(&amendment.replacement.agent_id)
+                .into_iter()
+                .collect(),
+            // The retracted cognition has already left the canon, so its
+            // agent can't be looked up; recompute everyone, as connection
+            // changes do.
+            Events::Cognition(CognitionEvents::CognitionRetracted(_)) => {
+                canon.agents.values().collect()
+            }
+            Events::Memory(MemoryEvents::MemoryArchived(MemoryArchived::V1(archival))) => canon
+                .memories
+                .get(&archival.id)
+                .and_then(|memory| canon.agents.get(&memory.agent_id))
+                .into_iter()
+                .collect(),
             Events::Experience(ExperienceEvents::ExperienceCreated(ExperienceCreated::V1(
                 creation,
             ))) => canon
@@ -125,9 +159,11 @@ impl PressureState {
             Events::Continuity(ContinuityEvents::Reflected(Reflected::V1(e))) => {
                 canon.agents.find_by_name(&e.agent).into_iter().collect()
             }
-            Events::Continuity(ContinuityEvents::Dreamed(Dreamed::V1(e))) => {
-                canon.agents.find_by_name(&e.agent).into_iter().collect()
-            }
+            Events::Continuity(continuity @ ContinuityEvents::Dreamed(_)) => canon
+                .agents
+                .find_by_name(continuity.agent())
+                .into_iter()
+                .collect(),
             Events::Continuity(ContinuityEvents::Slept(Slept::V1(e))) => {
                 canon.agents.find_by_name(&e.agent).into_iter().collect()
             }
@@ -136,6 +172,8 @@ impl PressureState {
             }
             // Connection events may affect orphaned/unconnected counts for any agent
             Events::Connection(_) => canon.agents.values().collect(),
+            // A tuning can change any agent's reading of the urge
+            Events::Urge(UrgeEvents::UrgeTuned(_)) => canon.agents.values().collect(),
             _ => vec![],
         }
     }
@@ -163,39 +201,116 @@ impl PressureState {
         ids.insert(id);
     }
 
+    /// An urge's own gauge definition wins; otherwise the built-ins are
+    /// gauged by name, and any other urge builds no pressure. Either way
+    /// the configuration is tuned for the agent's persona first.
     fn compute_gauge(
         canon: &ProjectCanon,
         referenced: &HashSet<String>,
-        urge: &UrgeName,
+        urge: &Urge,
         agent: &Agent,
     ) -> Gauge {
-        match urge.as_str() {
-            "introspect" => {
-                Gauge::Introspect(Self::compute_introspect(canon, &agent.id, &agent.name))
-            }
+        let tunings = &canon.tunings;
+        let persona = &agent.persona;
+
+        if let Some(definition) = &urge.gauge {
+            let definition = tunings.tune(&urge.name, persona, definition.clone());
+            return Gauge::Declared(Self::compute_declared(
+                canon,
+                referenced,
+                &definition,
+                agent,
+            ));
+        }
+
+        match urge.name.as_str() {
+            "introspect" => Gauge::Introspect(Self::compute_introspect(
+                canon,
+                &agent.id,
+                &agent.name,
+                tunings.tune(&urge.name, persona, IntrospectConfig::default()),
+            )),
             "catharsis" => Gauge::Catharsis(Self::compute_catharsis(
                 canon,
                 referenced,
                 &agent.id,
                 &agent.name,
+                tunings.tune(&urge.name, persona, CatharsisConfig::default()),
+            )),
+            "recollect" => Gauge::Recollect(Self::compute_recollect(
+                canon,
+                referenced,
+                &agent.id,
+                tunings.tune(&urge.name, persona, RecollectConfig::default()),
             )),
-            "recollect" => Gauge::Recollect(Self::compute_recollect(canon, referenced, &agent.id)),
-            "retrospect" => Gauge::Retrospect(Self::compute_retrospect(canon, &agent.id)),
-            _ => Gauge::Introspect(IntrospectGauge::from_inputs(IntrospectInputs {
-                hours_since_last_introspect: 0.0,
-                total_cognitions: 0,
-                working_cognitions: 0,
-                cognitions_since_introspect: 0,
-                memories_since_introspect: 0,
-                session_cognition_count: 0,
-            })),
+            "retrospect" => Gauge::Retrospect(Self::compute_retrospect(
+                canon,
+                &agent.id,
+                tunings.tune(&urge.name, persona, RetrospectConfig::default()),
+            )),
+            _ => Gauge::Declared(DeclaredGauge::from_inputs([])),
         }
     }
 
+    fn compute_declared(
+        canon: &ProjectCanon,
+        referenced: &HashSet<String>,
+        definition: &GaugeDefinition,
+        agent: &Agent,
+    ) -> DeclaredGauge {
+        let agent_cognitions = || canon.cognitions.values().filter(|c| c.agent_id == agent.id);
+
+        let inputs = definition.factors.iter().map(|factor| {
+            let input = match &factor.measure {
+                Measure::HoursSince(moment) => Self::hours_since(
+                    canon
+                        .continuity_timestamps
+                        .get(&agent.name)
+                        .and_then(|t| moment.of(t))
+                        .as_ref(),
+                ),
+                Measure::Texture(texture) => {
+                    agent_cognitions().filter(|c| c.texture == *texture).count() as f64
+                }
+                Measure::OrphanRatio => {
+                    let total = agent_cognitions().count();
+                    let orphaned = agent_cognitions()
+                        .filter(|c| !referenced.contains(&c.id.to_string()))
+                        .count();
+                    if total > 0 {
+                        orphaned as f64 / total as f64
+                    } else {
+                        0.0
+                    }
+                }
+                Measure::Level(level) => canon
+                    .memories
+                    .values()
+                    .filter(|m| m.agent_id == agent.id && m.level == *level)
+                    .count() as f64,
+                // A lens that didn't compile was reported when its urge
+                // was set, and reads as nothing selected.
+                Measure::Lens(source) => match canon.gauge_lenses.bind(source, &agent.name) {
+                    Some(ir) => CanonLensReader::new(canon)
+                        .count(&ir)
+                        .unwrap_or_else(|error| {
+                            tracing::warn!(%error, lens = %source, "gauge lens failed to read");
+                            0
+                        }) as f64,
+                    None => 0.0,
+                },
+            };
+            (factor.clone(), input)
+        });
+
+        DeclaredGauge::from_inputs(inputs)
+    }
+
     fn compute_introspect(
         canon: &ProjectCanon,
         agent_id: &AgentId,
         agent_name: &AgentName,
+        config: IntrospectConfig,
     ) -> IntrospectGauge {
         let agent_cognitions: Vec<&Cognition> = canon
             .cognitions
@@ -241,14 +356,17 @@ impl PressureState {
             None => total_cognitions,
         };
 
-        IntrospectGauge::from_inputs(IntrospectInputs {
-            hours_since_last_introspect: hours_since,
-            total_cognitions,
-            working_cognitions,
-            cognitions_since_introspect: cognitions_since,
-            memories_since_introspect: memories_since,
-            session_cognition_count,
-        })
+        IntrospectGauge::from_inputs_with_config(
+            IntrospectInputs {
+                hours_since_last_introspect: hours_since,
+                total_cognitions,
+                working_cognitions,
+                cognitions_since_introspect: cognitions_since,
+                memories_since_introspect: memories_since,
+                session_cognition_count,
+            },
+            config,
+        )
     }
 
     fn compute_catharsis(
@@ -256,6 +374,7 @@ impl PressureState {
         referenced: &HashSet<String>,
         agent_id: &AgentId,
         agent_name: &AgentName,
+        config: CatharsisConfig,
     ) -> CatharsisGauge {
         let agent_cognitions: Vec<&Cognition> = canon
             .cognitions
@@ -288,19 +407,23 @@ impl PressureState {
             .filter(|c| !referenced.contains(&c.id.to_string()))
             .count() as u64;
 
-        CatharsisGauge::from_inputs(CatharsisInputs {
-            tensions_experience_count: tensions_count,
-            total_cognitions,
-            working_cognitions,
-            hours_since_last_reflect: hours_since_reflect,
-            orphaned_cognitions: orphaned,
-        })
+        CatharsisGauge::from_inputs_with_config(
+            CatharsisInputs {
+                tensions_experience_count: tensions_count,
+                total_cognitions,
+                working_cognitions,
+                hours_since_last_reflect: hours_since_reflect,
+                orphaned_cognitions: orphaned,
+            },
+            config,
+        )
     }
 
     fn compute_recollect(
         canon: &ProjectCanon,
         referenced: &HashSet<String>,
         agent_id: &AgentId,
+        config: RecollectConfig,
     ) -> RecollectGauge {
         let agent_memories: Vec<&Memory> = canon
             .memories
@@ -335,16 +458,23 @@ impl PressureState {
         let last_memory_at = agent_memories.iter().map(|m| &m.created_at).max().copied();
         let hours_since_memory = Self::hours_since(last_memory_at.as_ref());
 
-        RecollectGauge::from_inputs(RecollectInputs {
-            session_memory_count: session_memories,
-            total_experiences,
-            unconnected_experiences: unconnected,
-            hours_since_last_memory: hours_since_memory,
-            working_memory_count: working_memories,
-        })
+        RecollectGauge::from_inputs_with_config(
+            RecollectInputs {
+                session_memory_count: session_memories,
+                total_experiences,
+                unconnected_experiences: unconnected,
+                hours_since_last_memory: hours_since_memory,
+                working_memory_count: working_memories,
+            },
+            config,
+        )
     }
 
-    fn compute_retrospect(canon: &ProjectCanon, agent_id: &AgentId) -> RetrospectGauge {
+    fn compute_retrospect(
+        canon: &ProjectCanon,
+        agent_id: &AgentId,
+        config: RetrospectConfig,
+    ) -> RetrospectGauge {
         let agent_memories: Vec<&Memory> = canon
             .memories
             .values()
@@ -371,12 +501,15 @@ impl PressureState {
             .filter(|e| e.agent_id == *agent_id)
             .count() as u64;
 
-        RetrospectGauge::from_inputs(RetrospectInputs {
-            hours_since_last_archival: Self::hours_since(last_archival_at.as_ref()),
-            hours_since_last_project_memory: Self::hours_since(last_project_at.as_ref()),
-            sessions_since_retrospect: 0,
-            total_experience_count: total_experiences,
-        })
+        RetrospectGauge::from_inputs_with_config(
+            RetrospectInputs {
+                hours_since_last_archival: Self::hours_since(last_archival_at.as_ref()),
+                hours_since_last_project_memory: Self::hours_since(last_project_at.as_ref()),
+                sessions_since_retrospect: 0,
+                total_experience_count: total_experiences,
+            },
+            config,
+        )
     }
 
     fn hours_since(timestamp: Option<&Timestamp>) -> f64 {
@@ -604,4 +737,115 @@ mod tests {
             .expect("should have timestamps");
         assert!(timestamps.last_introspect.is_some());
     }
+
+    #[test]
+    fn declared_gauges_drive_custom_urges() {
+        let mut canon = seeded_canon();
+        let agent_id = canon
+            .agents
+            .find_by_name(&AgentName::new("gov.process"))
+            .unwrap()
+            .id;
+
+        canon.urges.set(
+            &Urge::builder()
+                .name("curiosity")
+                .description("Open questions")
+                .prompt("")
+                .gauge(GaugeDefinition::new([
+                    "texture:1:0.5=question".parse().unwrap(),
+                    "orphan-ratio:1:0.5".parse().unwrap(),
+                ]))
+                .build(),
+        );
+        canon.urges.set(
+            &Urge::builder()
+                .name("wanderlust")
+                .description("Undefined")
+                .prompt("")
+                .build(),
+        );
+
+        let question = Cognition::builder()
+            .agent_id(agent_id)
+            .texture("question")
+            .content("Why?")
+            .build();
+        canon.cognitions.set(&question);
+
+        let added: CognitionAdded = CognitionAdded::builder_v1()
+            .cognition(question)
+            .build()
+            .into();
+        let event = Events::Cognition(CognitionEvents::CognitionAdded(added));
+        let next = PressureState::reduce(canon, &event);
+
+        let curiosity = next
+            .pressures
+            .values()
+            .find(|p| p.urge.as_str() == "curiosity")
+            .expect("curiosity");
+        let Gauge::Declared(gauge) = &curiosity.data else {
+            panic!("expected declared gauge");
+        };
+        // texture: saturate(1, 1) = 0.5; orphan ratio: saturate(1.0, 1) = 0.5
+        assert!((gauge.urgency() - 0.5).abs() < f64::EPSILON);
+
+        let wanderlust = next
+            .pressures
+            .values()
+            .find(|p| p.urge.as_str() == "wanderlust")
+            .expect("wanderlust");
+        assert!(wanderlust.data.urgency().abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn persona_tunings_layer_over_project_tunings() {
+        let mut canon = seeded_canon();
+        let urge = UrgeName::new("introspect");
+        let tune = |param: &str| GaugeTuning::new([param.parse().unwrap()]);
+
+        canon.tunings.set(
+            &urge,
+            TuningLayer::Project,
+            GaugeTuning::new([
+                "time_midpoint=6".parse().unwrap(),
+                "session_midpoint=10".parse().unwrap(),
+            ]),
+        );
+        canon.tunings.set(
+            &urge,
+            TuningLayer::Persona(PersonaName::new("process")),
+            tune("time_midpoint=3"),
+        );
+        canon.tunings.set(
+            &urge,
+            TuningLayer::Persona(PersonaName::new("expression")),
+            tune("time_midpoint=100"),
+        );
+
+        let tuned: UrgeTuned = UrgeTuned::builder_v1()
+            .urge(urge.clone())
+            .layer(TuningLayer::Persona(PersonaName::new("process")))
+            .tuning(tune("time_midpoint=3"))
+            .build()
+            .into();
+        let event = Events::Urge(UrgeEvents::UrgeTuned(tuned));
+        let next = PressureState::reduce(canon, &event);
+
+        let introspect = next
+            .pressures
+            .values()
+            .find(|p| p.urge == urge)
+            .expect("introspect");
+        let Gauge::Introspect(gauge) = &introspect.data else {
+            panic!("expected introspect gauge");
+        };
+        assert!((gauge.config.time_midpoint - 3.0).abs() < f64::EPSILON);
+        assert!((gauge.config.session_midpoint - 10.0).abs() < f64::EPSILON);
+        assert!(
+            (gauge.config.time_weight - IntrospectConfig::default().time_weight).abs()
+                < f64::EPSILON
+        );
+    }
 }
@@ -0,0 +1,210 @@
+use crate::*;
+
+/// A lens reader over the in-memory canon, so the pressure reducer can
+/// count what a gauge's lens selects without touching SQLite.
+///
+/// Answers text search, the search facets, and single connection hops.
+/// Deeper graph walks and the event-backed steps select nothing here.
+pub(crate) struct CanonLensReader<'a> {
+    canon: &'a ProjectCanon,
+}
+
+impl<'a> CanonLensReader<'a> {
+    pub(crate) fn new(canon: &'a ProjectCanon) -> Self {
+        Self { canon }
+    }
+
+    /// How many hits a compiled lens selects.
+    pub(crate) fn count(&self, ir: &Ir) -> Result<usize, LensError> {
+        let readers: Vec<&dyn Reader> = vec![self];
+        Ok(Executor::new(&readers).run(ir)?.len())
+    }
+
+    /// Every searchable entity in the canon, with the facets the search
+    /// index would hold for it.
+    fn entries(&self) -> impl Iterator<Item = SearchableEntry> + '_ {
+        let cognitions = self.canon.cognitions.values().map(|c| SearchableEntry {
+            entity_ref: Ref::cognition(c.id),
+            kind: SearchKind::Cognition,
+            agent_id: c.agent_id,
+            texture: Some(c.texture.to_string()),
+            level: None,
+            text: c.content.to_string(),
+            created_at: c.created_at,
+        });
+        let memories = self.canon.memories.values().map(|m| SearchableEntry {
+            entity_ref: Ref::memory(m.id),
+            kind: SearchKind::Memory,
+            agent_id: m.agent_id,
+            texture: None,
+            level: Some(m.level.to_string()),
+            text: m.content.to_string(),
+            created_at: m.created_at,
+        });
+        let experiences = self.canon.experiences.values().map(|e| SearchableEntry {
+            entity_ref: Ref::experience(e.id),
+            kind: SearchKind::Experience,
+            agent_id: e.agent_id,
+            texture: None,
+            level: None,
+            text: e.description.to_string(),
+            created_at: e.created_at,
+        });
+        let agents = self.canon.agents.values().map(|a| SearchableEntry {
+            entity_ref: Ref::agent(a.id),
+            kind: SearchKind::Agent,
+            agent_id: a.id,
+            texture: None,
+            level: None,
+            text: format!("{} {}", a.name, a.description),
+            created_at: Timestamp::now(),
+        });
+
+        cognitions.chain(memories).chain(experiences).chain(agents)
+    }
+
+    fn select(&self, predicate: impl Fn(&SearchableEntry) -> bool) -> Selection {
+        let mut selection = Selection::new();
+        for entry in self.entries().filter(|entry| predicate(entry)) {
+            selection.insert(Hit::Entity(EntityHit {
+                entity_ref: entry.entity_ref,
+                timestamp: entry.created_at,
+                relevance: Relevance::Unknown,
+            }));
+        }
+        selection
+    }
+
+    fn read_text(&self, query: &str) -> Selection {
+        let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+        self.select(|entry| {
+            let text = entry.text.to_lowercase();
+            !terms.is_empty() && terms.iter().all(|term| text.contains(term))
+        })
+    }
+
+    fn step_by_facet(&self, kind: &StepKind, input: &Selection) -> Option<Selection> {
+        let selection = match kind {
+            StepKind::SearchByAgent => {
+                let agents: Vec<AgentId> = input
+                    .names_of(NameKind::Agent)
+                    .iter()
+                    .filter_map(|name| self.canon.agents.find_by_name(&AgentName::new(name)))
+                    .map(|agent| agent.id)
+                    .collect();
+                self.select(|entry| agents.contains(&entry.agent_id))
+            }
+            StepKind::SearchByTexture => {
+                let textures = input.names_of(NameKind::Texture);
+                self.select(|entry| entry.texture.as_ref().is_some_and(|t| textures.contains(t)))
+            }
+            StepKind::SearchByLevel => {
+                let levels = input.names_of(NameKind::Level);
+                self.select(|entry| entry.level.as_ref().is_some_and(|l| levels.contains(l)))
+            }
+            StepKind::SearchByKind => {
+                let kinds = input.names_of(NameKind::Kind);
+                self.select(|entry| kinds.iter().any(|k| k == entry.kind.as_str()))
+            }
+            _ => return None,
+        };
+        Some(selection)
+    }
+
+    fn step_connected(&self, input: &Selection, forward: bool) -> Selection {
+        let refs = input.entity_refs();
+        let mut selection = Selection::new();
+        for connection in self.canon.connections.values() {
+            let (near, far) = if forward {
+                (&connection.from_ref, &connection.to_ref)
+            } else {
+                (&connection.to_ref, &connection.from_ref)
+            };
+            if refs.contains(near) {
+                selection.insert(Hit::Entity(EntityHit {
+                    entity_ref: far.clone(),
+                    timestamp: connection.created_at,
+                    relevance: Relevance::Unknown,
+                }));
+            }
+        }
+        selection
+    }
+}
+
+struct SearchableEntry {
+    entity_ref: Ref,
+    kind: SearchKind,
+    agent_id: AgentId,
+    texture: Option<String>,
+    level: Option<String>,
+    text: String,
+    created_at: Timestamp,
+}
+
+impl Reader for CanonLensReader<'_> {
+    fn read(&self, read: &Read) -> Option<Result<Selection, ReaderError>> {
+        match read {
+            Read::SearchText(query) => Some(Ok(self.read_text(query))),
+            Read::ChronicleBetween { .. } => None,
+        }
+    }
+
+    fn step(&self, kind: &StepKind, input: &Selection) -> Option<Result<Selection, ReaderError>> {
+        match kind {
+            StepKind::ConnectedFrom => Some(Ok(self.step_connected(input, true))),
+            StepKind::ConnectedTo => Some(Ok(self.step_connected(input, false))),
+            _ => self.step_by_facet(kind, input).map(Ok),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_an_agents_cognitions_by_texture() {
+        let mut canon = ProjectCanon::default();
+        let agent = Agent::builder()
+            .name("gov.process")
+            .persona("process")
+            .description("Governor")
+            .prompt("")
+            .build();
+        let other = Agent::builder()
+            .name("other.process")
+            .persona("process")
+            .description("Someone else")
+            .prompt("")
+            .build();
+        canon.agents.set(&agent);
+        canon.agents.set(&other);
+        for (agent_id, texture) in [
+            (agent.id, "question"),
+            (agent.id, "question"),
+            (agent.id, "observation"),
+            (other.id, "question"),
+        ] {
+            canon.cognitions.set(
+                &Cognition::builder()
+                    .agent_id(agent_id)
+                    .texture(texture)
+                    .content("why?")
+                    .build(),
+            );
+        }
+
+        let source = "texture(question) & agent(self)";
+        let mut lenses = GaugeLenses::default();
+        lenses.learn(&GaugeDefinition::new([GaugeFactor {
+            measure: Measure::Lens(source.into()),
+            midpoint: 2.0,
+            weight: 1.0,
+        }]));
+        let ir = lenses.bind(source, &agent.name).unwrap();
+        let count = CanonLensReader::new(&canon).count(&ir).unwrap();
+
+        assert_eq!(count, 2);
+    }
+}
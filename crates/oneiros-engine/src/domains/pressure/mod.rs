@@ -1,5 +1,6 @@
 mod docs;
 mod features;
+mod lens_reader;
 mod model;
 mod presenter;
 mod protocol;
@@ -10,6 +11,7 @@ mod view;
 
 pub(crate) use docs::*;
 pub(crate) use features::*;
+pub(crate) use lens_reader::*;
 pub(crate) use model::*;
 pub(crate) use presenter::*;
 pub(crate) use protocol::*;
@@ -44,3 +44,21 @@ impl From<&Pressure> for PressureSummary {
         }
     }
 }
+
+/// Why an urge reads as it does for one agent: the gauge that produced
+/// its urgency, and the tunings that configured that gauge.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub(crate) struct PressureExplanation {
+    pub(crate) urge: UrgeName,
+    pub(crate) urgency: f64,
+    pub(crate) gauge: Gauge,
+    /// The tunings layered over the gauge's defaults, in order. Empty
+    /// when the defaults stand.
+    pub(crate) tunings: Vec<AppliedTuning>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub(crate) struct AppliedTuning {
+    pub(crate) layer: TuningLayer,
+    pub(crate) tuning: GaugeTuning,
+}
@@ -42,9 +42,64 @@ impl<'a> PressurePresenter<'a> {
                 }
                 McpResponse::new(md)
             }
+            PressureResponse::Explained(ExplainedResponse::V1(result)) => {
+                let mut md = format!(
+                    "# Pressure explained — {} ({})\n\n",
+                    result.agent, result.persona
+                );
+                for explanation in &result.explanations {
+                    md.push_str(&format!(
+                        "## {} — {:.0}%\n\n**configured by:** {}\n\n",
+                        explanation.urge,
+                        explanation.urgency * 100.0,
+                        Self::configured_by(explanation)
+                    ));
+                    for (name, value, source) in Self::params(explanation) {
+                        match source {
+                            Some(layer) => {
+                                md.push_str(&format!("- `{name}` = {value} ({layer})\n"))
+                            }
+                            None => md.push_str(&format!("- `{name}` = {value}\n")),
+                        }
+                    }
+                    md.push('\n');
+                }
+                McpResponse::new(md)
+            }
         }
     }
 
+    /// Which layers configured an explanation's gauge.
+    fn configured_by(explanation: &PressureExplanation) -> String {
+        if explanation.tunings.is_empty() {
+            "defaults".to_string()
+        } else {
+            let layers: Vec<String> = explanation
+                .tunings
+                .iter()
+                .map(|applied| applied.layer.to_string())
+                .collect();
+            format!("defaults, tuned by {}", layers.join(" then "))
+        }
+    }
+
+    /// Every parameter the gauge ran with, and the layer that set it if
+    /// a tuning did. Later layers win, as they do when tuning.
+    fn params(explanation: &PressureExplanation) -> Vec<(String, f64, Option<&TuningLayer>)> {
+        GaugeParams::of(&explanation.gauge)
+            .into_iter()
+            .map(|(name, value)| {
+                let source = explanation
+                    .tunings
+                    .iter()
+                    .rev()
+                    .find(|applied| applied.tuning.params.contains_key(&name))
+                    .map(|applied| &applied.layer);
+                (name, value, source)
+            })
+            .collect()
+    }
+
     pub(crate) fn render(self) -> Rendered<PressureResponse> {
         let prompt = self.render_prompt();
         let text = self.render_text();
@@ -60,6 +115,27 @@ impl<'a> PressurePresenter<'a> {
             PressureResponse::AllReadings(AllReadingsResponse::V1(result)) => {
                 RelevantPressures::from_pressures(result.pressures.clone()).to_string()
             }
+            PressureResponse::Explained(ExplainedResponse::V1(result)) => result
+                .explanations
+                .iter()
+                .map(|explanation| {
+                    let mut detail = Detail::new(format!(
+                        "{} {:.0}%",
+                        explanation.urge,
+                        explanation.urgency * 100.0
+                    ))
+                    .field("configured by:", Self::configured_by(explanation));
+                    for (name, value, source) in Self::params(explanation) {
+                        let value = match source {
+                            Some(layer) => format!("{value} ({layer})"),
+                            None => value.to_string(),
+                        };
+                        detail.push_field(format!("{name}:"), value);
+                    }
+                    detail.to_string()
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
         }
     }
 
@@ -69,6 +145,10 @@ impl<'a> PressurePresenter<'a> {
                 format!("Pressure readings for {}.", result.agent)
             }
             PressureResponse::AllReadings(_) => "Pressure readings for all agents.".to_string(),
+            PressureResponse::Explained(ExplainedResponse::V1(result)) => format!(
+                "Pressure explained for {} (persona {}).",
+                result.agent, result.persona
+            ),
         }
     }
 }
@@ -6,6 +6,9 @@ use crate::*;
 
 #[derive(Debug, thiserror::Error)]
 pub(crate) enum PressureError {
+    #[error("Agent not found: {0}")]
+    AgentNotFound(AgentName),
+
     #[error("Database error: {0}")]
     Database(#[from] rusqlite::Error),
 
@@ -30,6 +33,7 @@ resource_op_error!(PressureError);
 impl IntoResponse for PressureError {
     fn into_response(self) -> Response {
         let (status, message) = match &self {
+            PressureError::AgentNotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
             PressureError::Database(_) | PressureError::Event(_) | PressureError::Upcast(_) => {
                 (StatusCode::INTERNAL_SERVER_ERROR, self.to_string())
             }
@@ -14,11 +14,25 @@ versioned! {
     }
 }
 
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum ExplainPressure {
+        #[derive(clap::Args)]
+        V1 => {
+            #[builder(into)] pub(crate) agent: AgentName,
+        }
+    }
+}
+
 resource_requests! {
     GetPressure => |this, client| {
         let GetPressure::V1(lookup) = this;
         client.get(&format!("/pressures/{}", lookup.agent)).await
     },
+    ExplainPressure => |this, client| {
+        let ExplainPressure::V1(lookup) = this;
+        client.get(&format!("/pressures/{}/explain", lookup.agent)).await
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Kinded)]
@@ -27,6 +41,7 @@ resource_requests! {
 pub(crate) enum PressureRequest {
     GetPressure(GetPressure),
     ListPressures,
+    ExplainPressure(ExplainPressure),
 }
 
 #[cfg(test)]
@@ -38,6 +53,7 @@ mod tests {
         let cases = [
             (PressureRequestType::GetPressure, "get-pressure"),
             (PressureRequestType::ListPressures, "list-pressures"),
+            (PressureRequestType::ExplainPressure, "explain-pressure"),
         ];
 
         for (request_type, expectation) in cases {
@@ -10,6 +10,7 @@ use crate::*;
 pub(crate) enum PressureResponse {
     Readings(ReadingsResponse),
     AllReadings(AllReadingsResponse),
+    Explained(ExplainedResponse),
 }
 
 versioned! {
@@ -30,3 +31,14 @@ versioned! {
         }
     }
 }
+
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum ExplainedResponse {
+        V1 => {
+            #[builder(into)] pub(crate) agent: AgentName,
+            #[builder(into)] pub(crate) persona: PersonaName,
+            pub(crate) explanations: Vec<PressureExplanation>,
+        }
+    }
+}
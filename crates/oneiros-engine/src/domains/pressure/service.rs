@@ -31,4 +31,47 @@ impl PressureService {
                 .into(),
         ))
     }
+
+    /// Each of an agent's urgencies alongside the gauge configuration
+    /// behind it and the tunings that shaped that configuration.
+    #[expect(deprecated)]
+    pub(crate) async fn explain(
+        context: &ProjectLog,
+        selector: &ExplainPressure,
+    ) -> Result<PressureResponse, PressureError> {
+        let details = selector.current()?;
+        let scope = context.scope()?;
+        let agent = AgentRepo::new(scope)
+            .get(&details.agent)
+            .await?
+            .ok_or_else(|| PressureError::AgentNotFound(details.agent.clone()))?;
+        let persona_layer = TuningLayer::Persona(agent.persona.clone());
+
+        let urges = UrgeRepo::new(scope);
+        let mut explanations = vec![];
+        for pressure in PressureRepo::new(scope).get(&agent.name).await? {
+            let tunings = urges
+                .tunings(&pressure.urge)
+                .await?
+                .into_iter()
+                .filter(|(layer, _)| *layer == TuningLayer::Project || *layer == persona_layer)
+                .map(|(layer, tuning)| AppliedTuning { layer, tuning })
+                .collect();
+            explanations.push(PressureExplanation {
+                urge: pressure.urge.clone(),
+                urgency: pressure.urgency(),
+                gauge: pressure.data,
+                tunings,
+            });
+        }
+
+        Ok(PressureResponse::Explained(
+            ExplainedResponse::builder_v1()
+                .agent(agent.name)
+                .persona(agent.persona)
+                .explanations(explanations)
+                .build()
+                .into(),
+        ))
+    }
 }
@@ -1,6 +1,11 @@
 //! `ProjectImportActor` — singleton that handles foreign event ingestion
 //! for any project. Insert-or-ignore into events.db keyed on event id,
-//! then emit the same downstream messages as a local append.
+//! then emit the same downstream messages as a local append, and
+//! publish the event on the feed if it was new here. A
+//! redaction notice from a peer is handed to the log actor to purge,
+//! once it is known to come from this host or from the author of every
+//! event it names; any other notice is refused, and the refusal is
+//! recorded on the host log.
 //!
 //! Stateless across messages: the scope on each `ImportEvent` opens
 //! the correct events.db. Receives the full `ProjectMessage`; handles
@@ -40,11 +45,25 @@ impl ProjectImportInbox {
 
 pub(crate) struct ProjectImportActor {
     mailbox: Mailbox,
+    feed: EventFeed,
+    host: PeerKey,
 }
 
 impl ProjectImportActor {
-    pub(crate) fn spawn(inbox: ProjectImportInbox, mailbox: Mailbox) {
-        tokio::spawn(Self { mailbox }.run(inbox));
+    pub(crate) fn spawn(
+        inbox: ProjectImportInbox,
+        mailbox: Mailbox,
+        feed: EventFeed,
+        host: PeerKey,
+    ) {
+        tokio::spawn(
+            Self {
+                mailbox,
+                feed,
+                host,
+            }
+            .run(inbox),
+        );
     }
 
     async fn run(self, mut inbox: ProjectImportInbox) {
@@ -58,12 +77,35 @@ impl ProjectImportActor {
     }
 
     async fn import(&self, import: ImportProjectEvent) -> Result<(), EventError> {
-        let ImportProjectEvent { scope, stored } = import;
+        let ImportProjectEvent { scope, mut stored } = import;
+        let redaction = match &stored.data {
+            Event::Known(Events::Project(project)) => project.maybe_redaction(),
+            _ => None,
+        };
+
         let events_db = EventsDb::open(&scope).await?;
-        EventLog::new(&events_db).init()?;
-        EventLog::new(&events_db).import(&stored)?;
+        let log = EventLog::new(&events_db);
+        log.init()?;
+        if let Some(redaction) = &redaction {
+            match log.authorize_redaction(&stored, &redaction.events, Some(&self.host)) {
+                Ok(()) => {}
+                Err(EventError::Provenance(ProvenanceError::UnauthorizedRedaction(notice))) => {
+                    tracing::warn!(%notice, "project import: redaction notice refused");
+                    self.refuse(&scope, notice, redaction);
+                    return Ok(());
+                }
+                Err(error) => return Err(error),
+            }
+        }
+        let sequence = log.import(&stored)?;
         drop(events_db);
 
+        // Projections see the event where it landed in this log, as a
+        // replay would, rather than where it sat in the peer's.
+        if let Some(sequence) = sequence {
+            stored.sequence = sequence;
+        }
+
         self.mailbox.tell(BookmarkMessage::from(
             ApplyBookmarkProjection::builder()
                 .scope(scope.clone())
@@ -72,11 +114,55 @@ impl ProjectImportActor {
         ));
         self.mailbox.tell(BookmarkMessage::from(
             RecordBookmarkChronicle::builder()
-                .scope(scope)
-                .stored(stored)
+                .scope(scope.clone())
+                .stored(stored.clone())
                 .build(),
         ));
 
+        if sequence.is_some() {
+            self.feed.publish(AppendedEvent {
+                project: scope.project().name.clone(),
+                bookmark: scope.bookmark().name.clone(),
+                stored: stored.clone(),
+                imported: true,
+            });
+        }
+
+        if let Some(redaction) = redaction {
+            self.mailbox.tell(ProjectMessage::from(
+                PurgeProjectEvents::builder()
+                    .scope(scope)
+                    .redaction(redaction)
+                    .build(),
+            ));
+        }
+
         Ok(())
     }
+
+    /// Leave a refused notice on the host log. The import goes on
+    /// without it, so this is where an operator finds out.
+    fn refuse(&self, scope: &Scope<AtBookmark>, notice: EventId, redaction: &ContentRedactedV1) {
+        let refusal = NewEvent::builder()
+            .data(Events::Project(ProjectEvents::RedactionRefused(
+                RedactionRefused::builder_v1()
+                    .project(scope.project().name.clone())
+                    .bookmark(scope.bookmark().name.clone())
+                    .notice(notice)
+                    .events(redaction.events.clone())
+                    .refused_at(Timestamp::now())
+                    .build()
+                    .into(),
+            )))
+            .build();
+
+        match ComposeScope::new(scope.config().clone()).host() {
+            Ok(host) => self.mailbox.tell(HostMessage::from(
+                AppendHostLog::builder().scope(host).event(refusal).build(),
+            )),
+            Err(error) => {
+                tracing::error!(?error, "project import: recording a refused notice failed");
+            }
+        }
+    }
 }
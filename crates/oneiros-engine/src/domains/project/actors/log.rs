@@ -1,6 +1,13 @@
 //! `ProjectLogActor` — singleton that owns the events.db append for any
-//! project. After appending, emits two follow-up messages addressed to
-//! the bookmark projections and bookmark chronicle actors.
+//! project. After appending, emits follow-up messages addressed to the
+//! bookmark projections, bookmark chronicle and slice actors, publishes
+//! the stored event on the host's [`EventFeed`], and — when
+//! `sync.on_change` is set — nudges the follow sync actor.
+//!
+//! Also owns redaction: a `Purge` tombstones the named events in
+//! events.db, rewrites every bookmark's chronicle over the tombstones,
+//! sweeps the chronicle nodes that held the originals, and resets
+//! every bookmark's projections.
 //!
 //! Stateless across messages: the scope on each `LogAppend` opens the
 //! correct events.db. Receives the full `ProjectMessage`; handles the
@@ -40,19 +47,44 @@ impl ProjectLogInbox {
 
 pub(crate) struct ProjectLogActor {
     mailbox: Mailbox,
+    feed: EventFeed,
+    secret: iroh::SecretKey,
+    canons: CanonIndex,
 }
 
 impl ProjectLogActor {
-    pub(crate) fn spawn(inbox: ProjectLogInbox, mailbox: Mailbox) {
-        tokio::spawn(Self { mailbox }.run(inbox));
+    pub(crate) fn spawn(
+        inbox: ProjectLogInbox,
+        mailbox: Mailbox,
+        feed: EventFeed,
+        secret: iroh::SecretKey,
+        canons: CanonIndex,
+    ) {
+        tokio::spawn(
+            Self {
+                mailbox,
+                feed,
+                secret,
+                canons,
+            }
+            .run(inbox),
+        );
     }
 
     async fn run(self, mut inbox: ProjectLogInbox) {
         while let Some(message) = inbox.recv().await {
-            if let ProjectMessage::LogAppend(append) = message
-                && let Err(error) = self.append(append).await
-            {
-                tracing::error!(?error, "project log: append failed");
+            match message {
+                ProjectMessage::LogAppend(append) => {
+                    if let Err(error) = self.append(append).await {
+                        tracing::error!(?error, "project log: append failed");
+                    }
+                }
+                ProjectMessage::Purge(purge) => {
+                    if let Err(error) = self.purge(purge).await {
+                        tracing::error!(?error, "project log: purge failed");
+                    }
+                }
+                _ => {}
             }
         }
     }
@@ -61,7 +93,11 @@ impl ProjectLogActor {
         let AppendProjectLog { scope, event } = append;
         let events_db = EventsDb::open(&scope).await?;
         EventLog::new(&events_db).init()?;
-        let stored = Box::new(EventLog::new(&events_db).append(&event)?);
+        let stored = Box::new(
+            EventLog::new(&events_db)
+                .signed_by(&self.secret)
+                .append(&event)?,
+        );
 
         self.mailbox.tell(BookmarkMessage::from(
             ApplyBookmarkProjection::builder()
@@ -76,6 +112,34 @@ impl ProjectLogActor {
                 .build(),
         ));
 
+        self.feed.publish(AppendedEvent {
+            project: scope.project().name.clone(),
+            bookmark: scope.bookmark().name.clone(),
+            stored: stored.clone(),
+            imported: false,
+        });
+
+        // A local change can prompt an early pull from the project's
+        // peer follows.
+        if scope.config().sync.on_change {
+            self.mailbox.tell(HostMessage::from(
+                SyncFollows::builder()
+                    .project(scope.project().name.clone())
+                    .build(),
+            ));
+        }
+
+        if let Event::Known(Events::Project(project)) = &stored.data
+            && let Some(redaction) = project.maybe_redaction()
+        {
+            self.mailbox.tell(ProjectMessage::from(
+                PurgeProjectEvents::builder()
+                    .scope(scope.clone())
+                    .redaction(redaction)
+                    .build(),
+            ));
+        }
+
         // Fan out to the slice actor for prospective lens matching.
         self.mailbox.tell(ProjectMessage::from(
             SliceMatch::builder().scope(scope).stored(stored).build(),
@@ -83,4 +147,53 @@ impl ProjectLogActor {
 
         Ok(())
     }
+
+    async fn purge(&self, purge: PurgeProjectEvents) -> Result<(), EventError> {
+        let PurgeProjectEvents { scope, redaction } = purge;
+        let events_db = EventsDb::open(&scope).await?;
+        let redacted =
+            EventLog::new(&events_db).redact(&redaction.events, redaction.redacted_at)?;
+        drop(events_db);
+
+        if redacted.is_empty() {
+            return Ok(());
+        }
+
+        let host_db = HostDb::open(&scope).await?;
+        let store = ChronicleStore::new(&host_db);
+        store.migrate()?;
+
+        for bookmark in scope.siblings() {
+            let chronicle = self
+                .canons
+                .bookmark_chronicle(&bookmark.project().name, &bookmark.bookmark().name)?;
+            for tombstone in &redacted {
+                chronicle.record(tombstone, &store.resolver(), &store.writer())?;
+            }
+            self.canons
+                .save_head(&store, &bookmark.project().name, &bookmark.bookmark().name)?;
+
+            // The chronicle actor may still hold records read before the
+            // rewrite; resetting after them settles it on the tombstones.
+            self.mailbox.tell(BookmarkMessage::from(
+                ResetBookmarkChronicle::builder()
+                    .scope(bookmark.clone())
+                    .build(),
+            ));
+            self.mailbox.tell(BookmarkMessage::from(
+                ResetBookmarkProjection::builder().scope(bookmark).build(),
+            ));
+        }
+
+        // The originals' hashes are unreachable now; drop their nodes.
+        self.canons.collect_garbage(scope.config())?;
+
+        tracing::info!(
+            target = %redaction.target,
+            redacted = redacted.len(),
+            "project log: purged redacted events"
+        );
+
+        Ok(())
+    }
 }
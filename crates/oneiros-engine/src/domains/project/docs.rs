@@ -6,6 +6,7 @@ pub(crate) enum ProjectDocs {
     Show,
     Share,
     Follow,
+    Redact,
 }
 
 impl ProjectDocs {
@@ -52,6 +53,16 @@ impl ProjectDocs {
                 .summary("Follow a project")
                 .description("Create a repository peer by following a project share URI.")
                 .build(),
+            Self::Redact => ResourceDocs::builder()
+                .tag(tag)
+                .nickname("redact-project")
+                .summary("Redact content from a project")
+                .description(
+                    "Replace the payloads of every event that records or revises a cognition, \
+                     memory or experience with tombstones, rebuild projections and the search \
+                     index, and send a redaction notice followers purge their copies by.",
+                )
+                .build(),
         }
     }
 }
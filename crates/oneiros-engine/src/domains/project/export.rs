@@ -0,0 +1,352 @@
+//! Export archives — the manifest that heads them and the filter that
+//! decides which events go in.
+//!
+//! An archive is JSONL, optionally gzipped: one manifest line, then events
+//! in sequence order, each storage event preceded by its blob the first
+//! time that blob's content appears.
+
+use std::collections::HashSet;
+use std::io::{BufRead, BufReader};
+
+use flate2::read::GzDecoder;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+/// The leading bytes of any gzip stream.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+versioned! {
+    #[derive(schemars::JsonSchema)]
+    pub(crate) enum ExportManifest {
+        V1 => {
+            #[builder(into)] pub(crate) project: ProjectName,
+            #[builder(into)] pub(crate) bookmark: BookmarkName,
+            /// The chronicle root over exactly the exported events.
+            pub(crate) chronicle_root: Option<ContentHash>,
+            pub(crate) events: usize,
+            pub(crate) blobs: usize,
+            /// Whether any filter narrowed the export.
+            pub(crate) filtered: bool,
+            pub(crate) exported_at: Timestamp,
+        }
+    }
+}
+
+/// The first line of an archive. Archives written before manifests
+/// existed start straight in with events, and still import.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ExportHeader {
+    pub(crate) manifest: ExportManifest,
+}
+
+/// An archive's lines, decompressed if the file is gzipped.
+pub(crate) fn archive_reader(file: std::fs::File) -> std::io::Result<Box<dyn BufRead>> {
+    let mut reader = BufReader::new(file);
+
+    if reader.fill_buf()?.starts_with(&GZIP_MAGIC) {
+        Ok(Box::new(BufReader::new(GzDecoder::new(reader))))
+    } else {
+        Ok(Box::new(reader))
+    }
+}
+
+/// Which events an export keeps. Every criterion that's set must hold.
+#[derive(Debug, Default)]
+pub(crate) struct ExportFilter {
+    agent: Option<(AgentName, Option<AgentId>)>,
+    since: Option<Timestamp>,
+    until: Option<Timestamp>,
+    event_types: HashSet<String>,
+    lens: Option<LensMatch>,
+}
+
+/// What a lens selected, in the terms an event can be checked against.
+#[derive(Debug, Default)]
+struct LensMatch {
+    events: HashSet<EventId>,
+    entities: HashSet<Ref>,
+}
+
+impl ExportFilter {
+    pub(crate) fn agent(mut self, name: AgentName, id: Option<AgentId>) -> Self {
+        self.agent = Some((name, id));
+        self
+    }
+
+    pub(crate) fn window(mut self, since: Option<Timestamp>, until: Option<Timestamp>) -> Self {
+        self.since = since;
+        self.until = until;
+        self
+    }
+
+    pub(crate) fn event_types(mut self, types: impl IntoIterator<Item = String>) -> Self {
+        self.event_types = types.into_iter().collect();
+        self
+    }
+
+    pub(crate) fn lens(mut self, selection: &Selection) -> Self {
+        self.lens = Some(LensMatch {
+            events: selection.event_ids().into_iter().collect(),
+            entities: selection.entity_refs().into_iter().collect(),
+        });
+        self
+    }
+
+    /// Whether this filter lets everything through.
+    pub(crate) fn is_open(&self) -> bool {
+        self.agent.is_none()
+            && self.since.is_none()
+            && self.until.is_none()
+            && self.event_types.is_empty()
+            && self.lens.is_none()
+    }
+
+    pub(crate) fn admits(&self, event: &StoredEvent) -> bool {
+        let Event::Known(data) = &event.data else {
+            return false;
+        };
+
+        if self.since.is_some_and(|since| event.created_at < since)
+            || self.until.is_some_and(|until| event.created_at >= until)
+        {
+            return false;
+        }
+
+        if !self.event_types.is_empty() && !self.event_types.contains(&data.event_type()) {
+            return false;
+        }
+
+        if let Some((name, id)) = &self.agent {
+            let about_agent = match ActingAgent::of(data) {
+                Some(ActingAgent::Name(acting)) => &acting == name,
+                Some(ActingAgent::Id(acting)) => Some(acting) == *id,
+                None => false,
+            };
+            if !about_agent {
+                return false;
+            }
+        }
+
+        if let Some(lens) = &self.lens {
+            let selected = lens.events.contains(&event.id)
+                || subject_ref(data).is_some_and(|subject| lens.entities.contains(&subject));
+            if !selected {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// The entity an event records or revises, as a lens would name it.
+pub(crate) fn subject_ref(event: &Events) -> Option<Ref> {
+    match event {
+        Events::Cognition(CognitionEvents::CognitionAdded(added)) => {
+            added.current().ok().map(|v| Ref::cognition(v.cognition.id))
+        }
+        Events::Memory(MemoryEvents::MemoryAdded(added)) => {
+            added.current().ok().map(|v| Ref::memory(v.memory.id))
+        }
+        Events::Cognition(CognitionEvents::CognitionRetracted(retracted)) => {
+            retracted.current().ok().map(|v| Ref::cognition(v.id))
+        }
+        Events::Memory(MemoryEvents::MemorySuperseded(superseded)) => superseded
+            .current()
+            .ok()
+            .map(|v| Ref::memory(v.replacement.id)),
+        Events::Memory(MemoryEvents::MemoryDemoted(demoted)) => {
+            demoted.current().ok().map(|v| Ref::memory(v.id))
+        }
+        Events::Memory(MemoryEvents::MemoryArchived(archived)) => {
+            archived.current().ok().map(|v| Ref::memory(v.id))
+        }
+        Events::Experience(ExperienceEvents::ExperienceCreated(created)) => created
+            .current()
+            .ok()
+            .map(|v| Ref::experience(v.experience.id)),
+        Events::Experience(ExperienceEvents::ExperienceDescriptionUpdated(updated)) => {
+            updated.current().ok().map(|v| Ref::experience(v.id))
+        }
+        Events::Experience(ExperienceEvents::ExperienceSensationUpdated(updated)) => {
+            updated.current().ok().map(|v| Ref::experience(v.id))
+        }
+        Events::Experience(ExperienceEvents::ExperienceAmended(amended)) => amended
+            .current()
+            .ok()
+            .map(|v| Ref::experience(v.replacement.id)),
+        Events::Connection(ConnectionEvents::ConnectionCreated(created)) => created
+            .current()
+            .ok()
+            .map(|v| Ref::connection(v.connection.id)),
+        Events::Agent(AgentEvents::AgentCreated(created)) => {
+            created.current().ok().map(|v| Ref::agent(v.agent.id))
+        }
+        Events::Agent(AgentEvents::AgentUpdated(updated)) => {
+            updated.current().ok().map(|v| Ref::agent(v.agent.id))
+        }
+        _ => None,
+    }
+}
+
+/// The entity an event's subject takes the place of, when it supersedes
+/// or amends one — the other end of the link [`subject_ref`] names.
+pub(crate) fn replaced_ref(event: &Events) -> Option<Ref> {
+    match event {
+        Events::Memory(MemoryEvents::MemorySuperseded(superseded)) => {
+            superseded.current().ok().map(|v| Ref::memory(v.id))
+        }
+        Events::Experience(ExperienceEvents::ExperienceAmended(amended)) => {
+            amended.current().ok().map(|v| Ref::experience(v.id))
+        }
+        _ => None,
+    }
+}
+
+/// Read an archive's manifest, if it has one, leaving the reader at the
+/// first event either way. Returns the line it consumed when that line
+/// turned out to be an event.
+pub(crate) fn read_manifest(
+    reader: &mut dyn BufRead,
+) -> std::io::Result<(Option<ExportManifest>, Option<String>)> {
+    let mut line = String::new();
+    while reader.read_line(&mut line)? > 0 {
+        if line.trim().is_empty() {
+            line.clear();
+            continue;
+        }
+
+        return Ok(match serde_json::from_str::<ExportHeader>(&line) {
+            Ok(header) => (Some(header.manifest), None),
+            Err(_) => (None, Some(line)),
+        });
+    }
+
+    Ok((None, None))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+
+    use flate2::{Compression, write::GzEncoder};
+
+    use super::*;
+
+    fn cognition_event(agent: AgentId, at: &str) -> StoredEvent {
+        let cognition = Cognition::builder()
+            .agent_id(agent)
+            .texture("observation")
+            .content("a thought")
+            .build();
+
+        StoredEvent::builder()
+            .id(EventId::new())
+            .sequence(1)
+            .created_at(at.parse().unwrap())
+            .source(Source::default())
+            .data(Event::Known(Events::Cognition(
+                CognitionEvents::CognitionAdded(
+                    CognitionAdded::builder_v1()
+                        .cognition(cognition)
+                        .build()
+                        .into(),
+                ),
+            )))
+            .build()
+    }
+
+    #[test]
+    fn open_filter_admits_everything_known() {
+        let filter = ExportFilter::default();
+        let event = cognition_event(AgentId::new(), "2026-01-01");
+
+        assert!(filter.is_open());
+        assert!(filter.admits(&event));
+    }
+
+    #[test]
+    fn window_is_inclusive_then_exclusive() {
+        let filter = ExportFilter::default().window(
+            Some("2026-01-01".parse().unwrap()),
+            Some("2026-02-01".parse().unwrap()),
+        );
+
+        assert!(filter.admits(&cognition_event(AgentId::new(), "2026-01-01")));
+        assert!(!filter.admits(&cognition_event(AgentId::new(), "2026-02-01")));
+        assert!(!filter.admits(&cognition_event(AgentId::new(), "2025-12-31")));
+    }
+
+    #[test]
+    fn agent_filter_matches_records_by_id() {
+        let thinker = AgentId::new();
+        let filter =
+            ExportFilter::default().agent(AgentName::new("thinker.process"), Some(thinker));
+
+        assert!(filter.admits(&cognition_event(thinker, "2026-01-01")));
+        assert!(!filter.admits(&cognition_event(AgentId::new(), "2026-01-01")));
+    }
+
+    #[test]
+    fn event_type_filter_matches_kebab_types() {
+        let event = cognition_event(AgentId::new(), "2026-01-01");
+
+        assert!(
+            ExportFilter::default()
+                .event_types(["cognition-added".to_string()])
+                .admits(&event)
+        );
+        assert!(
+            !ExportFilter::default()
+                .event_types(["memory-added".to_string()])
+                .admits(&event)
+        );
+    }
+
+    #[test]
+    fn gzipped_archives_read_like_plain_ones() {
+        let manifest = ExportManifest::builder_v1()
+            .project("dreamer")
+            .bookmark(BookmarkName::main())
+            .events(0)
+            .blobs(0)
+            .filtered(false)
+            .exported_at(Timestamp::now())
+            .build();
+        let header = serde_json::to_string(&ExportHeader {
+            manifest: manifest.into(),
+        })
+        .unwrap();
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("archive.jsonl.gz");
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        writeln!(encoder, "{header}").unwrap();
+        let platform = Platform::default();
+        platform.write(&path, encoder.finish().unwrap()).unwrap();
+
+        let mut reader = archive_reader(platform.open_file(&path).unwrap()).unwrap();
+        let (manifest, pending) = read_manifest(&mut reader).unwrap();
+
+        let ExportManifest::V1(manifest) = manifest.expect("manifest line");
+        assert_eq!(manifest.project.as_str(), "dreamer");
+        assert!(pending.is_none());
+        let mut rest = String::new();
+        reader.read_to_string(&mut rest).unwrap();
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn archives_without_a_manifest_start_with_an_event() {
+        let line = serde_json::to_string(&cognition_event(AgentId::new(), "2026-01-01")).unwrap();
+        let archive = format!("{line}\n");
+        let mut reader = BufReader::new(archive.as_bytes());
+
+        let (manifest, pending) = read_manifest(&mut reader).unwrap();
+
+        assert!(manifest.is_none());
+        assert_eq!(pending.unwrap().trim(), line);
+    }
+}
@@ -9,7 +9,9 @@ pub(crate) enum ProjectCommands {
     List(ListProjects),
     Export(ExportProject),
     Import(ImportProject),
+    Verify(VerifyProject),
     Replay,
+    Redact(RedactProject),
     Share(ShareProject),
     Follow(FollowProject),
 }
@@ -45,7 +47,16 @@ impl ProjectCommands {
                 ProjectService::export(&scope, exporting).await?
             }
             ProjectCommands::Import(importing) => ProjectService::import(config, importing).await?,
+            ProjectCommands::Verify(verifying) => {
+                let scope = ComposeScope::new(config.clone())
+                    .bookmark(config.project.clone(), config.bookmark.clone())?;
+                ProjectService::verify(&scope, verifying).await?
+            }
             ProjectCommands::Replay => ProjectService::replay(config).await?,
+            ProjectCommands::Redact(redaction) => {
+                let bytes = redaction.execute_request(&client).await?;
+                serde_json::from_slice(&bytes)?
+            }
             ProjectCommands::Share(share) => {
                 let bytes = share.execute_request(&client).await?;
                 serde_json::from_slice(&bytes)?
@@ -38,6 +38,12 @@ impl ProjectRouter {
                         .response::<200, Json<ProjectSharedResponse>>()
                 }),
             )
+            .api_route(
+                "/projects/redact",
+                routing::post_with(redact, |op| {
+                    resource_op!(op, ProjectDocs::Redact).response::<200, Json<RedactedResponse>>()
+                }),
+            )
             .api_route(
                 "/projects/follow",
                 routing::post_with(follow, |op| {
@@ -128,6 +134,14 @@ async fn share(
     Ok(Json(ProjectService::share(&state, &body).await?))
 }
 
+async fn redact(
+    scope: Scope<AtBookmark>,
+    mailbox: Mailbox,
+    Json(body): Json<RedactProject>,
+) -> Result<Json<ProjectResponse>, ProjectError> {
+    Ok(Json(ProjectService::redact(&scope, &mailbox, &body).await?))
+}
+
 async fn follow(
     scope: Scope<AtHost>,
     mailbox: Mailbox,
@@ -10,7 +10,9 @@ impl ProjectSkills {
             Skill::new("project-list", include_str!("skills/list.md")),
             Skill::new("project-export", include_str!("skills/export.md")),
             Skill::new("project-import", include_str!("skills/import.md")),
+            Skill::new("project-verify", include_str!("skills/verify.md")),
             Skill::new("project-replay", include_str!("skills/replay.md")),
+            Skill::new("project-redact", include_str!("skills/redact.md")),
             Skill::new("project-share", include_str!("skills/share.md")),
             Skill::new("project-follow", include_str!("skills/follow.md")),
         ]
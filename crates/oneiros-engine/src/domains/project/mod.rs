@@ -1,5 +1,6 @@
 mod actors;
 mod docs;
+mod export;
 mod features;
 mod model;
 mod protocol;
@@ -10,6 +11,7 @@ mod view;
 
 pub(crate) use actors::*;
 pub(crate) use docs::*;
+pub(crate) use export::*;
 pub(crate) use features::*;
 pub(crate) use model::*;
 pub(crate) use protocol::*;
@@ -20,6 +20,29 @@ pub(crate) enum ProjectError {
     #[error("Project not found for id: {0}")]
     NotFoundById(ProjectId),
 
+    #[error("Agent not found: {0}")]
+    AgentNotFound(AgentName),
+
+    #[error("Export is incomplete: its manifest promises {expected} events, found {found}")]
+    IncompleteExport { expected: usize, found: usize },
+
+    #[error("Export is incomplete: its manifest promises {expected} blobs, found {found}")]
+    MissingBlobs { expected: usize, found: usize },
+
+    #[error(
+        "Export doesn't match its manifest: the imported events don't hash to its chronicle root"
+    )]
+    ChronicleMismatch,
+
+    #[error("Only cognitions, memories and experiences can be redacted, not a {0}")]
+    NotRedactable(&'static str),
+
+    #[error("Nothing in the event log records {0}")]
+    NothingToRedact(Ref),
+
+    #[error(transparent)]
+    Lens(#[from] LensError),
+
     #[error(transparent)]
     Resolve(#[from] ResolveError),
 
@@ -44,6 +67,9 @@ pub(crate) enum ProjectError {
     #[error(transparent)]
     Event(#[from] EventError),
 
+    #[error(transparent)]
+    Provenance(#[from] ProvenanceError),
+
     #[error(transparent)]
     Client(#[from] ClientError),
 
@@ -57,11 +83,21 @@ impl IntoResponse for ProjectError {
     fn into_response(self) -> Response {
         let (status, message) = match self {
             ProjectError::Ticket(ticket) => return ticket.into_response(),
-            ProjectError::NotFound(_) | ProjectError::NotFoundById(_) => {
-                (StatusCode::NOT_FOUND, self.to_string())
+            ProjectError::Lens(lens) => return lens.into_response(),
+            ProjectError::NotFound(_)
+            | ProjectError::NotFoundById(_)
+            | ProjectError::AgentNotFound(_)
+            | ProjectError::NothingToRedact(_) => (StatusCode::NOT_FOUND, self.to_string()),
+            ProjectError::Resolve(_)
+            | ProjectError::Provenance(_)
+            | ProjectError::IncompleteExport { .. }
+            | ProjectError::MissingBlobs { .. }
+            | ProjectError::ChronicleMismatch => {
+                (StatusCode::UNPROCESSABLE_ENTITY, self.to_string())
+            }
+            ProjectError::MissingName | ProjectError::NotRedactable(_) => {
+                (StatusCode::BAD_REQUEST, self.to_string())
             }
-            ProjectError::Resolve(_) => (StatusCode::UNPROCESSABLE_ENTITY, self.to_string()),
-            ProjectError::MissingName => (StatusCode::BAD_REQUEST, self.to_string()),
             ProjectError::Missing
             | ProjectError::Database(_)
             | ProjectError::Event(_)
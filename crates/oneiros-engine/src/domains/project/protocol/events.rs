@@ -9,12 +9,34 @@ use crate::*;
 pub(crate) enum ProjectEvents {
     #[serde(alias = "brain-created")]
     ProjectCreated(ProjectCreated),
+    /// A project's content was redacted, recorded on the host log.
+    ProjectRedacted(ProjectRedacted),
+    /// A notice on the project log that its events were redacted —
+    /// every host holding the log purges them when it sees this.
+    ContentRedacted(ContentRedacted),
+    /// A peer's redaction notice this host refused to honor, recorded
+    /// on the host log.
+    RedactionRefused(RedactionRefused),
+    /// What a redacted event's payload is replaced with.
+    Tombstone(Tombstone),
 }
 
 impl ProjectEvents {
     pub(crate) fn maybe_project(&self) -> Option<Project> {
         match self {
             ProjectEvents::ProjectCreated(event) => event.clone().current().ok().map(|v| v.project),
+            ProjectEvents::ProjectRedacted(_)
+            | ProjectEvents::ContentRedacted(_)
+            | ProjectEvents::RedactionRefused(_)
+            | ProjectEvents::Tombstone(_) => None,
+        }
+    }
+
+    /// The redaction this event announces, if it is a redaction notice.
+    pub(crate) fn maybe_redaction(&self) -> Option<ContentRedactedV1> {
+        match self {
+            ProjectEvents::ContentRedacted(notice) => notice.clone().current().ok(),
+            _ => None,
         }
     }
 }
@@ -27,16 +49,67 @@ versioned! {
     }
 }
 
+versioned! {
+    pub(crate) enum ProjectRedacted {
+        V1 => {
+            #[builder(into)] pub(crate) project: ProjectName,
+            pub(crate) target: Ref,
+            pub(crate) events: Vec<EventId>,
+            pub(crate) redacted_at: Timestamp,
+        }
+    }
+}
+
+versioned! {
+    pub(crate) enum ContentRedacted {
+        V1 => {
+            pub(crate) target: Ref,
+            pub(crate) events: Vec<EventId>,
+            pub(crate) redacted_at: Timestamp,
+        }
+    }
+}
+
+versioned! {
+    pub(crate) enum RedactionRefused {
+        V1 => {
+            #[builder(into)] pub(crate) project: ProjectName,
+            #[builder(into)] pub(crate) bookmark: BookmarkName,
+            /// The refused notice.
+            pub(crate) notice: EventId,
+            pub(crate) events: Vec<EventId>,
+            pub(crate) refused_at: Timestamp,
+        }
+    }
+}
+
+versioned! {
+    pub(crate) enum Tombstone {
+        V1 => {
+            /// The type of the event this tombstone replaced.
+            #[builder(into)] pub(crate) event_type: String,
+            pub(crate) redacted_at: Timestamp,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn event_types_are_kebab_cased() {
-        assert_eq!(
-            &ProjectEventsType::ProjectCreated.to_string(),
-            "project-created"
-        );
+        let cases = [
+            (ProjectEventsType::ProjectCreated, "project-created"),
+            (ProjectEventsType::ProjectRedacted, "project-redacted"),
+            (ProjectEventsType::ContentRedacted, "content-redacted"),
+            (ProjectEventsType::RedactionRefused, "redaction-refused"),
+            (ProjectEventsType::Tombstone, "tombstone"),
+        ];
+
+        for (event_type, expectation) in cases {
+            assert_eq!(&event_type.to_string(), expectation)
+        }
     }
 
     #[test]
@@ -52,6 +125,7 @@ mod tests {
         let event: ProjectEvents = serde_json::from_value(json).expect("decode legacy tag");
         let project = match event {
             ProjectEvents::ProjectCreated(inner) => inner.current().unwrap().project,
+            other => panic!("expected ProjectCreated, got {other:?}"),
         };
         assert_eq!(project.name.as_str(), "legacy-project");
     }
@@ -20,6 +20,16 @@ pub(crate) struct ImportProjectEvent {
     pub(crate) stored: Box<StoredEvent>,
 }
 
+/// Purge the events a redaction notice names from the project's
+/// events.db, then rebuild what was derived from them. Bookmark scope is
+/// preserved so the purge knows which project to reach.
+#[derive(Builder, Clone)]
+pub(crate) struct PurgeProjectEvents {
+    pub(crate) scope: Scope<AtBookmark>,
+    #[builder(into)]
+    pub(crate) redaction: Box<ContentRedactedV1>,
+}
+
 /// Fan a newly-stored event to the slice actor for prospective lens
 /// matching. Carries the stored event and its project scope.
 #[derive(Builder, Clone)]
@@ -35,11 +45,13 @@ pub(crate) struct SliceMatch {
 pub(crate) enum ProjectMessage {
     LogAppend(AppendProjectLog),
     ImportEvent(ImportProjectEvent),
+    Purge(PurgeProjectEvents),
     SliceMatch(SliceMatch),
 }
 
 collects_enum!(
     ProjectMessage::LogAppend => AppendProjectLog,
     ProjectMessage::ImportEvent => ImportProjectEvent,
+    ProjectMessage::Purge => PurgeProjectEvents,
     ProjectMessage::SliceMatch => SliceMatch,
 );
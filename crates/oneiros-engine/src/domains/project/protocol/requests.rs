@@ -52,6 +52,30 @@ versioned! {
         V1 => {
             #[arg(long, short)]
             pub(crate) target: PathBuf,
+            /// Gzip the archive.
+            #[arg(long)]
+            #[serde(default)]
+            #[builder(default)]
+            pub(crate) gzip: bool,
+            /// Keep only events a lens expression selects.
+            #[arg(long)]
+            #[builder(into)]
+            pub(crate) lens: Option<String>,
+            /// Keep only events about this agent.
+            #[arg(long)]
+            #[builder(into)]
+            pub(crate) agent: Option<AgentName>,
+            /// Keep only events at or after this time (RFC 3339 or YYYY-MM-DD).
+            #[arg(long)]
+            pub(crate) since: Option<Timestamp>,
+            /// Keep only events before this time (RFC 3339 or YYYY-MM-DD).
+            #[arg(long)]
+            pub(crate) until: Option<Timestamp>,
+            /// Keep only events of these types, e.g. `cognition-added`.
+            #[arg(long = "event-type")]
+            #[serde(default)]
+            #[builder(default)]
+            pub(crate) event_types: Vec<String>,
         }
     }
 }
@@ -66,6 +90,26 @@ versioned! {
     }
 }
 
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum VerifyProject {
+        #[derive(clap::Args)]
+        V1 => {}
+    }
+}
+
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum RedactProject {
+        #[derive(clap::Args)]
+        V1 => {
+            /// The cognition, memory or experience whose content to redact.
+            #[builder(into)]
+            pub(crate) target: RefToken,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Kinded)]
 #[serde(tag = "type", content = "data", rename_all = "kebab-case")]
 #[kinded(kind = ProjectRequestType, display = "kebab-case")]
@@ -75,7 +119,9 @@ pub(crate) enum ProjectRequest {
     ListProjects(ListProjects),
     ExportProject(ExportProject),
     ImportProject(ImportProject),
+    VerifyProject(VerifyProject),
     ReplayProject,
+    RedactProject(RedactProject),
     ShareProject(ShareProject),
     FollowProject(FollowProject),
 }
@@ -127,6 +173,9 @@ resource_requests! {
     FollowProject => |this, client| {
         client.post("/projects/follow", this).await
     },
+    RedactProject => |this, client| {
+        client.post("/projects/redact", this).await
+    },
 }
 
 #[cfg(test)]
@@ -141,7 +190,9 @@ mod tests {
             (ProjectRequestType::ListProjects, "list-projects"),
             (ProjectRequestType::ExportProject, "export-project"),
             (ProjectRequestType::ImportProject, "import-project"),
+            (ProjectRequestType::VerifyProject, "verify-project"),
             (ProjectRequestType::ReplayProject, "replay-project"),
+            (ProjectRequestType::RedactProject, "redact-project"),
         ];
 
         for (request_type, expectation) in cases {
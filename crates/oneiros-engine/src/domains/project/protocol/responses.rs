@@ -16,7 +16,9 @@ pub(crate) enum ProjectResponse {
     ProjectAlreadyExists(ProjectAlreadyExistsResponse),
     WroteExport(WroteExportResponse),
     Imported(ImportedResponse),
+    Verified(VerifiedResponse),
     Replayed(ReplayedResponse),
+    Redacted(RedactedResponse),
     Shared(ProjectSharedResponse),
     Followed(ProjectFollowedResponse),
 }
@@ -62,6 +64,12 @@ versioned! {
     pub(crate) enum WroteExportResponse {
         V1 => {
             pub(crate) path: PathBuf,
+            #[serde(default)]
+            #[builder(default)]
+            pub(crate) events: usize,
+            #[serde(default)]
+            #[builder(default)]
+            pub(crate) blobs: usize,
         }
     }
 }
@@ -76,6 +84,52 @@ versioned! {
     }
 }
 
+/// Why an event failed verification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum ProvenanceProblem {
+    /// The event carries no signature.
+    Unsigned,
+    /// The event's content no longer matches its signature.
+    Tampered,
+    /// The signature is valid, but the trust policy denies its signer.
+    Untrusted,
+}
+
+impl std::fmt::Display for ProvenanceProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unsigned => write!(f, "unsigned"),
+            Self::Tampered => write!(f, "tampered"),
+            Self::Untrusted => write!(f, "untrusted"),
+        }
+    }
+}
+
+/// One event that failed verification.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub(crate) struct ProvenanceIssue {
+    pub(crate) id: EventId,
+    pub(crate) event_type: String,
+    pub(crate) problem: ProvenanceProblem,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) signer: Option<PeerKey>,
+}
+
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum VerifiedResponse {
+        V1 => {
+            pub(crate) checked: i64,
+            pub(crate) signed: i64,
+            #[serde(default)]
+            #[builder(default)]
+            pub(crate) redacted: i64,
+            pub(crate) issues: Vec<ProvenanceIssue>,
+        }
+    }
+}
+
 versioned! {
     #[derive(JsonSchema)]
     pub(crate) enum ReplayedResponse {
@@ -85,6 +139,18 @@ versioned! {
     }
 }
 
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum RedactedResponse {
+        V1 => {
+            pub(crate) target: Ref,
+            /// The events whose payloads were replaced with tombstones.
+            pub(crate) events: Vec<EventId>,
+            pub(crate) redacted_at: Timestamp,
+        }
+    }
+}
+
 /// Response to `project share` — contains the issued ticket and URI.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub(crate) struct ProjectShareResult {
@@ -1,4 +1,8 @@
-use std::io::BufRead;
+use std::collections::HashSet;
+use std::io::{BufRead, BufWriter, Write};
+use std::path::PathBuf;
+
+use flate2::{Compression, write::GzEncoder};
 
 use crate::*;
 
@@ -102,6 +106,7 @@ impl ProjectService {
                 &CreateTicket::builder_v1()
                     .actor_id(actor.id)
                     .project_name(project_name.clone())
+                    .permissions(PermissionOp::ALL.to_vec())
                     .build()
                     .into(),
             )
@@ -181,66 +186,200 @@ impl ProjectService {
         ))
     }
 
-    /// Export all events to a JSONL file in the target directory.
+    /// Export the bookmark's events to a JSONL archive in the target
+    /// directory, writing as it reads. Filters narrow which events go in;
+    /// each blob is written once, just ahead of the first event that
+    /// stores it. The archive opens with a manifest `import` checks
+    /// itself against.
     pub(crate) async fn export(
         scope: &Scope<AtBookmark>,
         request: &ExportProject,
     ) -> Result<ProjectResponse, ProjectError> {
         let details = request.current()?;
-        let target_dir = &details.target;
-        let project_name = &scope.config().project;
+        let filter = Self::export_filter(scope, &details).await?;
         let db = BookmarkDb::open(scope).await?;
-        let events = EventLog::attached(&db).load_all()?;
-        let storage = StorageStore::new(&db);
-
-        let mut buffer = String::new();
-        for event in &events {
-            if let Event::Known(Events::Storage(StorageEvents::StorageSet(set))) = &event.data
-                && let Ok(current) = set.current()
-                && let Ok(Some(blob)) = storage.get_blob(&current.entry.hash)
-            {
-                let synthetic = StoredEvent::builder()
-                    .id(EventId::new())
-                    .sequence(0)
-                    .created_at(event.created_at)
-                    .source(event.source)
-                    .data(Event::Ephemeral(EphemeralEvents::BlobStored(blob)))
-                    .build();
 
-                buffer.push_str(&serde_json::to_string(&synthetic)?);
-                buffer.push('\n');
+        // Both passes read one snapshot, so the manifest describes
+        // exactly what gets written even while new events arrive.
+        db.execute_batch("BEGIN")?;
+        let (path, manifest) = match Self::write_export(scope, &db, &filter, &details) {
+            Ok(written) => {
+                db.execute_batch("COMMIT")?;
+                written
+            }
+            Err(e) => {
+                let _ = db.execute_batch("ROLLBACK");
+                return Err(e);
             }
+        };
+
+        Ok(ProjectResponse::WroteExport(
+            WroteExportResponse::builder_v1()
+                .path(path)
+                .events(manifest.events)
+                .blobs(manifest.blobs)
+                .build()
+                .into(),
+        ))
+    }
+
+    async fn export_filter(
+        scope: &Scope<AtBookmark>,
+        details: &ExportProjectV1,
+    ) -> Result<ExportFilter, ProjectError> {
+        let mut filter = ExportFilter::default()
+            .window(details.since, details.until)
+            .event_types(details.event_types.iter().cloned());
+
+        if let Some(name) = &details.agent {
+            let agent = AgentRepo::new(scope)
+                .get(name)
+                .await?
+                .ok_or_else(|| ProjectError::AgentNotFound(name.clone()))?;
+            filter = filter.agent(agent.name, Some(agent.id));
+        }
 
-            buffer.push_str(&serde_json::to_string(event)?);
-            buffer.push('\n');
+        if let Some(source) = &details.lens {
+            // Exports run without the service, so the chronicle reader
+            // gets a canon index of its own.
+            let canons = CanonIndex::new();
+            canons.hydrate_project(scope.config(), &scope.project().name)?;
+            let selection = LensService::select(scope, &canons, source).await?;
+            filter = filter.lens(&selection);
         }
 
+        Ok(filter)
+    }
+
+    fn write_export(
+        scope: &Scope<AtBookmark>,
+        db: &rusqlite::Connection,
+        filter: &ExportFilter,
+        details: &ExportProjectV1,
+    ) -> Result<(PathBuf, ExportManifestV1), ProjectError> {
+        let log = EventLog::attached(db);
+        let storage = StorageStore::new(db);
+
+        // First pass: count what the filter keeps and chronicle it,
+        // without holding any of it. The chronicle only yields the
+        // manifest's root, so its nodes stay in memory.
+        let scratch = rusqlite::Connection::open_in_memory()?;
+        let nodes = ChronicleStore::new(&scratch);
+        nodes.migrate()?;
+        let chronicle = Chronicle::new();
+        let mut events = 0usize;
+        let mut blobs = HashSet::new();
+        log.each_after(0, |event| {
+            if filter.admits(&event) {
+                chronicle.record(&event, &nodes.resolver(), &nodes.writer())?;
+                events += 1;
+                if let Some(hash) = stored_blob(&event)
+                    && storage.has_blob(&hash)?
+                {
+                    blobs.insert(hash);
+                }
+            }
+            Ok::<_, ProjectError>(())
+        })?;
+
+        let manifest = ExportManifest::builder_v1()
+            .project(scope.project().name.clone())
+            .bookmark(scope.bookmark().name.clone())
+            .maybe_chronicle_root(chronicle.root()?)
+            .events(events)
+            .blobs(blobs.len())
+            .filtered(!filter.is_open())
+            .exported_at(Timestamp::now())
+            .build();
+
         let platform = scope.config().platform();
+        let target_dir = &details.target;
         platform.ensure_dir(target_dir)?;
 
         let date = chrono::Utc::now().format("%Y-%m-%d");
-        let file_name = format!("{project_name}-{date}-export.jsonl");
+        let extension = if details.gzip { "jsonl.gz" } else { "jsonl" };
+        let file_name = format!("{}-{date}-export.{extension}", scope.project().name);
         let file_path = target_dir.join(file_name);
+        let file = platform.open_with(
+            &file_path,
+            std::fs::OpenOptions::new()
+                .write(true)
+                .create(true)
+                .truncate(true),
+        )?;
+        let file = BufWriter::new(file);
+
+        // Second pass: write the manifest, then the events.
+        if details.gzip {
+            let mut out = GzEncoder::new(file, Compression::default());
+            Self::write_archive(&mut out, &log, &storage, filter, &manifest)?;
+            out.finish()?.flush()?;
+        } else {
+            let mut out = file;
+            Self::write_archive(&mut out, &log, &storage, filter, &manifest)?;
+            out.flush()?;
+        }
 
-        platform.write(&file_path, buffer)?;
+        Ok((file_path, manifest))
+    }
 
-        Ok(ProjectResponse::WroteExport(
-            WroteExportResponse::builder_v1()
-                .path(file_path)
-                .build()
-                .into(),
-        ))
+    fn write_archive(
+        out: &mut impl Write,
+        log: &EventLog<'_>,
+        storage: &StorageStore<'_>,
+        filter: &ExportFilter,
+        manifest: &ExportManifestV1,
+    ) -> Result<(), ProjectError> {
+        let header = ExportHeader {
+            manifest: manifest.clone().into(),
+        };
+        serde_json::to_writer(&mut *out, &header)?;
+        out.write_all(b"\n")?;
+
+        let mut written = HashSet::new();
+        log.each_after(0, |event| {
+            if !filter.admits(&event) {
+                return Ok(());
+            }
+
+            if let Some(hash) = stored_blob(&event)
+                && !written.contains(&hash)
+                && let Some(blob) = storage.get_blob(&hash)?
+            {
+                let synthetic = StoredEvent::builder()
+                    .id(EventId::new())
+                    .sequence(0)
+                    .created_at(event.created_at)
+                    .source(event.source)
+                    .data(Event::Ephemeral(EphemeralEvents::BlobStored(blob)))
+                    .build();
+
+                serde_json::to_writer(&mut *out, &synthetic)?;
+                out.write_all(b"\n")?;
+                written.insert(hash);
+            }
+
+            serde_json::to_writer(&mut *out, &event)?;
+            out.write_all(b"\n")?;
+            Ok::<_, ProjectError>(())
+        })
     }
 
-    /// Import events from a JSONL file and replay projections.
+    /// Import events from an export archive and replay projections. The
+    /// import is refused as a whole if any event fails the trust policy,
+    /// or if the archive's events or blobs fall short of what its
+    /// manifest promises.
+    /// Redaction notices in the archive are honored before the replay,
+    /// provided each comes from this host or from the author of every
+    /// event it names.
     pub(crate) async fn import(
         config: &Config,
         request: &ImportProject,
     ) -> Result<ProjectResponse, ProjectError> {
         let details = request.current()?;
         let platform = config.platform();
-        let file = platform.open_file(&details.file)?;
-        let reader = std::io::BufReader::new(file);
+        let mut reader = archive_reader(platform.open_file(&details.file)?)?;
+        let (manifest, first) = read_manifest(&mut reader)?;
         let mut imported = 0usize;
 
         let db = BookmarkDb::open_with(
@@ -256,10 +395,24 @@ impl ProjectService {
         log.init()?;
         projections.migrate(&db)?;
 
+        // The chronicle only checks the archive against its manifest, so
+        // its nodes stay in memory: a refused import leaves nothing behind.
+        let scratch = rusqlite::Connection::open_in_memory()?;
+        let nodes = ChronicleStore::new(&scratch);
+        nodes.migrate()?;
+        let chronicle = Chronicle::new();
+        let mut blobs = HashSet::new();
+        let mut redactions = Vec::new();
+        let host = HostKey::new(platform.clone())
+            .load()
+            .ok()
+            .flatten()
+            .map(|secret| PeerKey::from_bytes(*secret.public().as_bytes()));
+
         db.execute_batch("BEGIN")?;
 
         let result = (|| -> Result<(), ProjectError> {
-            for line in reader.lines() {
+            for line in first.into_iter().map(Ok).chain(reader.lines()) {
                 let line = line?;
 
                 if line.trim().is_empty() {
@@ -272,13 +425,48 @@ impl ProjectService {
                     match ephemeral {
                         EphemeralEvents::BlobStored(content) => {
                             StorageStore::new(&db).put_blob(content)?;
+                            blobs.insert(content.hash.clone());
                         }
                     }
                 } else {
+                    config.trust.admit(&event, None)?;
+                    if let Event::Known(Events::Project(project)) = &event.data
+                        && let Some(redaction) = project.maybe_redaction()
+                    {
+                        log.authorize_redaction(&event, &redaction.events, host.as_ref())?;
+                        redactions.push(redaction);
+                    }
                     log.import(&event)?;
+                    if manifest.is_some() {
+                        chronicle.record(&event, &nodes.resolver(), &nodes.writer())?;
+                    }
                     imported += 1;
                 }
             }
+
+            if let Some(ExportManifest::V1(manifest)) = &manifest {
+                if imported != manifest.events {
+                    return Err(ProjectError::IncompleteExport {
+                        expected: manifest.events,
+                        found: imported,
+                    });
+                }
+                if blobs.len() != manifest.blobs {
+                    return Err(ProjectError::MissingBlobs {
+                        expected: manifest.blobs,
+                        found: blobs.len(),
+                    });
+                }
+                if chronicle.root()? != manifest.chronicle_root {
+                    return Err(ProjectError::ChronicleMismatch);
+                }
+            }
+
+            // An authorized redaction reaches events this log already
+            // held before the import, too — their author asked for it.
+            for redaction in &redactions {
+                log.redact(&redaction.events, redaction.redacted_at)?;
+            }
             Ok(())
         })();
 
@@ -290,8 +478,10 @@ impl ProjectService {
             }
         }
 
+        let superseded = BookmarkHistory::load(&config.host_db()?, &config.project)?
+            .superseded(&config.bookmark);
         let log = EventLog::attached(&db);
-        let replayed = projections.replay_project(&db, &log)?;
+        let replayed = projections.replay_project_except(&db, &log, &superseded)?;
 
         Ok(ProjectResponse::Imported(
             ImportedResponse::builder_v1()
@@ -302,6 +492,57 @@ impl ProjectService {
         ))
     }
 
+    /// Walk the project's event log and check every event's signature,
+    /// reporting those that are unsigned, tampered, or signed by a peer
+    /// the trust policy denies. Tombstones left by redaction are counted
+    /// apart; they have no content left to sign.
+    pub(crate) async fn verify(
+        scope: &Scope<AtBookmark>,
+        request: &VerifyProject,
+    ) -> Result<ProjectResponse, ProjectError> {
+        let VerifyProject::V1(_) = request;
+        let trust = &scope.config().trust;
+        let db = BookmarkDb::open(scope).await?;
+        let events = EventLog::attached(&db).load_all()?;
+
+        let mut signed = 0i64;
+        let mut redacted = 0i64;
+        let mut issues = Vec::new();
+        for event in &events {
+            let (problem, signer) = match event.provenance() {
+                Provenance::Redacted => {
+                    redacted += 1;
+                    continue;
+                }
+                Provenance::Unsigned => (ProvenanceProblem::Unsigned, None),
+                Provenance::Tampered(signer) => (ProvenanceProblem::Tampered, Some(signer)),
+                Provenance::Signed(signer) if trust.level(&signer) == TrustLevel::Deny => {
+                    (ProvenanceProblem::Untrusted, Some(signer))
+                }
+                Provenance::Signed(_) => {
+                    signed += 1;
+                    continue;
+                }
+            };
+            issues.push(ProvenanceIssue {
+                id: event.id,
+                event_type: event.data.event_type(),
+                problem,
+                signer,
+            });
+        }
+
+        Ok(ProjectResponse::Verified(
+            VerifiedResponse::builder_v1()
+                .checked(events.len() as i64)
+                .signed(signed)
+                .redacted(redacted)
+                .issues(issues)
+                .build()
+                .into(),
+        ))
+    }
+
     /// Replay all events through projections, rebuilding read models.
     pub(crate) async fn replay(config: &Config) -> Result<ProjectResponse, ProjectError> {
         let platform = config.platform();
@@ -321,8 +562,10 @@ impl ProjectService {
         .await?;
         let projections = Projections::<ProjectCanon>::project();
         projections.migrate(&db)?;
+        let superseded = BookmarkHistory::load(&config.host_db()?, &config.project)?
+            .superseded(&config.bookmark);
         let log = EventLog::attached(&db);
-        let replayed = projections.replay_project(&db, &log)?;
+        let replayed = projections.replay_project_except(&db, &log, &superseded)?;
 
         Ok(ProjectResponse::Replayed(
             ReplayedResponse::builder_v1()
@@ -332,6 +575,142 @@ impl ProjectService {
         ))
     }
 
+    /// Redact an entity's content from the project's event log, along
+    /// with every entity it superseded or was superseded by. The
+    /// events that record or revise them are named in a notice on the
+    /// project log; each host holding the log — this one, and every
+    /// follower that pulls the notice — replaces their payloads with
+    /// tombstones and rebuilds what was derived from them. The host log
+    /// keeps an audit of the redaction.
+    pub(crate) async fn redact(
+        scope: &Scope<AtBookmark>,
+        mailbox: &Mailbox,
+        request: &RedactProject,
+    ) -> Result<ProjectResponse, ProjectError> {
+        let details = request.current()?;
+        let target = details.target.into_inner();
+        let Ref::V0(resource) = target.clone();
+        if !matches!(
+            resource,
+            Resource::Cognition(_) | Resource::Memory(_) | Resource::Experience(_)
+        ) {
+            return Err(ProjectError::NotRedactable(resource.label()));
+        }
+
+        // A supersede or amend links two entities, and its event is about
+        // the replacement. Redacting only one end would tombstone the link
+        // and bring the other end back on replay, so the whole chain goes.
+        let mut links = Vec::new();
+        let mut subjects = Vec::new();
+        {
+            let db = BookmarkDb::open(scope).await?;
+            EventLog::attached(&db).each_after(0, |event| {
+                if let Event::Known(data) = &event.data
+                    && let Some(subject) = subject_ref(data)
+                {
+                    if let Some(replaced) = replaced_ref(data) {
+                        links.push((replaced, subject.clone()));
+                    }
+                    subjects.push((event.id, subject));
+                }
+                Ok::<_, ProjectError>(())
+            })?;
+        }
+
+        let mut chain = HashSet::from([target.clone()]);
+        while let Some(next) = links
+            .iter()
+            .filter(|(replaced, replacement)| {
+                chain.contains(replaced) != chain.contains(replacement)
+            })
+            .map(|(replaced, replacement)| {
+                if chain.contains(replaced) {
+                    replacement.clone()
+                } else {
+                    replaced.clone()
+                }
+            })
+            .next()
+        {
+            chain.insert(next);
+        }
+
+        let events: Vec<EventId> = subjects
+            .into_iter()
+            .filter(|(_, subject)| chain.contains(subject))
+            .map(|(id, _)| id)
+            .collect();
+
+        if events.is_empty() {
+            return Err(ProjectError::NothingToRedact(target));
+        }
+
+        let redacted_at = Timestamp::now();
+
+        let notice = NewEvent::builder()
+            .data(Events::Project(ProjectEvents::ContentRedacted(
+                ContentRedacted::builder_v1()
+                    .target(target.clone())
+                    .events(events.clone())
+                    .redacted_at(redacted_at)
+                    .build()
+                    .into(),
+            )))
+            .build();
+
+        mailbox.tell(ProjectMessage::from(
+            AppendProjectLog::builder()
+                .scope(scope.clone())
+                .event(notice)
+                .build(),
+        ));
+
+        let audit = NewEvent::builder()
+            .data(Events::Project(ProjectEvents::ProjectRedacted(
+                ProjectRedacted::builder_v1()
+                    .project(scope.project().name.clone())
+                    .target(target.clone())
+                    .events(events.clone())
+                    .redacted_at(redacted_at)
+                    .build()
+                    .into(),
+            )))
+            .build();
+
+        mailbox.tell(HostMessage::from(
+            AppendHostLog::builder()
+                .scope(ComposeScope::new(scope.config().clone()).host()?)
+                .event(audit)
+                .build(),
+        ));
+
+        // Every event about the target is gone once the projections
+        // rebuild, and the target with them.
+        scope
+            .config()
+            .fetch
+            .until_absent(|| async {
+                let db = BookmarkDb::open(scope).await?;
+                let present = match &resource {
+                    Resource::Cognition(id) => CognitionStore::new(&db).get(id)?.map(|_| ()),
+                    Resource::Memory(id) => MemoryStore::new(&db).get(id)?.map(|_| ()),
+                    Resource::Experience(id) => ExperienceStore::new(&db).get(id)?.map(|_| ()),
+                    _ => None,
+                };
+                Ok::<_, ProjectError>(present)
+            })
+            .await?;
+
+        Ok(ProjectResponse::Redacted(
+            RedactedResponse::builder_v1()
+                .target(target)
+                .events(events)
+                .redacted_at(redacted_at)
+                .build()
+                .into(),
+        ))
+    }
+
     /// Share a project by issuing a project-scoped ticket.
     pub(crate) async fn share(
         state: &ServerState,
@@ -377,6 +756,10 @@ impl ProjectService {
                     .operation(PermissionOp::BookmarkList)
                     .build()
                     .into(),
+                Permission::builder_v1()
+                    .operation(PermissionOp::BookmarkCollect)
+                    .build()
+                    .into(),
             ])
             .build()
             .into();
@@ -465,3 +848,13 @@ impl ProjectService {
         )))
     }
 }
+
+/// The blob a storage event points at, if it is one.
+fn stored_blob(event: &StoredEvent) -> Option<ContentHash> {
+    match &event.data {
+        Event::Known(Events::Storage(StorageEvents::StorageSet(set))) => {
+            set.current().ok().map(|current| current.entry.hash)
+        }
+        _ => None,
+    }
+}
@@ -63,14 +63,18 @@ impl ProjectView {
             }
             ProjectResponse::WroteExport(WroteExportResponse::V1(details)) => {
                 let prompt = format!(
-                    "{} Export written to '{}'.",
+                    "{} Export written to '{}' ({} events, {} blobs).",
                     "✓".success(),
-                    details.path.display()
+                    details.path.display(),
+                    details.events,
+                    details.blobs,
                 );
                 Rendered::new(
                     ProjectResponse::WroteExport(
                         WroteExportResponse::builder_v1()
                             .path(details.path)
+                            .events(details.events)
+                            .blobs(details.blobs)
                             .build()
                             .into(),
                     ),
@@ -97,6 +101,40 @@ impl ProjectView {
                     String::new(),
                 )
             }
+            ProjectResponse::Verified(VerifiedResponse::V1(verified)) => {
+                let mut summary = format!(
+                    "{} events checked, {} signed",
+                    verified.checked, verified.signed
+                );
+                if verified.redacted > 0 {
+                    summary.push_str(&format!(", {} redacted", verified.redacted));
+                }
+                summary.push_str(&format!(", {} flagged", verified.issues.len()));
+                let prompt = if verified.issues.is_empty() {
+                    format!("{} {summary}.", "✓".success())
+                } else {
+                    let mut table = Table::new(vec![
+                        Column::new("Event"),
+                        Column::new("Type"),
+                        Column::new("Problem"),
+                        Column::new("Signer"),
+                    ]);
+                    for issue in &verified.issues {
+                        table.push_row(vec![
+                            issue.id.to_string(),
+                            issue.event_type.clone(),
+                            issue.problem.to_string(),
+                            issue.signer.map(|key| key.to_string()).unwrap_or_default(),
+                        ]);
+                    }
+                    format!("{}\n\n{table}", summary.muted())
+                };
+                Rendered::new(
+                    ProjectResponse::Verified(VerifiedResponse::V1(verified)),
+                    prompt,
+                    String::new(),
+                )
+            }
             ProjectResponse::Replayed(ReplayedResponse::V1(details)) => {
                 let prompt = format!("{} Replayed {} events.", "✓".success(), details.replayed);
                 Rendered::new(
@@ -110,6 +148,19 @@ impl ProjectView {
                     String::new(),
                 )
             }
+            ProjectResponse::Redacted(RedactedResponse::V1(redacted)) => {
+                let prompt = format!(
+                    "{} Redacted {} events about {}.",
+                    "✓".success(),
+                    redacted.events.len(),
+                    RefToken::new(redacted.target.clone()),
+                );
+                Rendered::new(
+                    ProjectResponse::Redacted(RedactedResponse::V1(redacted)),
+                    prompt,
+                    String::new(),
+                )
+            }
             ProjectResponse::Shared(ProjectSharedResponse::V1(shared)) => {
                 let prompt = shared.result.uri.clone();
                 Rendered::new(
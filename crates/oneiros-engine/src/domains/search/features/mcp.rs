@@ -26,6 +26,7 @@ mod search_mcp {
             Tool::<SearchQuery>::new(
                 SearchRequestType::SearchQuery,
                 "Search across everything in the project",
+                PermissionOp::Search,
             )
             .def(),
         ]
@@ -12,7 +12,7 @@ impl<'a> SearchIndexReader<'a> {
     }
 
     fn read_text(&self, query: &str) -> Result<Selection, ReaderError> {
-        let sql = "select resource_ref, created_at, rank from search_index where search_index match ?1 order by rank";
+        let sql = "select resource_ref, created_at, rank from search_index where search_index match ?1 and resource_ref not in (select resource_ref from search_retracted) order by rank";
         let mut stmt = self
             .db
             .prepare(sql)
@@ -72,7 +72,7 @@ impl<'a> SearchIndexReader<'a> {
             .collect::<Vec<_>>()
             .join(", ");
         let sql = format!(
-            "select resource_ref, created_at from search_index where {column} in ({placeholders}) order by created_at desc"
+            "select resource_ref, created_at from search_index where {column} in ({placeholders}) and resource_ref not in (select resource_ref from search_retracted) order by created_at desc"
         );
         let mut stmt = self
             .db
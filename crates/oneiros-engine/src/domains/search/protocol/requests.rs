@@ -16,7 +16,14 @@ versioned! {
             #[arg(long)] pub(crate) kind: Option<SearchKind>,
             #[arg(long)] pub(crate) texture: Option<TextureName>,
             #[arg(long)] pub(crate) level: Option<LevelName>,
+            /// Only memories at levels ranked at or above this one.
+            #[arg(long)] pub(crate) min_level: Option<LevelName>,
             #[arg(long)] pub(crate) sensation: Option<SensationName>,
+            /// Include superseded, retracted and amended entries.
+            #[arg(long)]
+            #[serde(default, deserialize_with = "query_flag")]
+            #[builder(default)]
+            pub(crate) include_retracted: bool,
             #[command(flatten)]
             #[serde(flatten)]
             #[builder(default)]
@@ -61,9 +68,15 @@ resource_requests! {
         if let Some(l) = &query.level {
             parts.push(format!("level={l}"));
         }
+        if let Some(l) = &query.min_level {
+            parts.push(format!("min_level={l}"));
+        }
         if let Some(s) = &query.sensation {
             parts.push(format!("sensation={s}"));
         }
+        if query.include_retracted {
+            parts.push("include_retracted=true".to_string());
+        }
         parts.push(format!("limit={}", query.filters.limit));
         parts.push(format!("offset={}", query.filters.offset));
 
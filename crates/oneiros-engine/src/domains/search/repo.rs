@@ -174,10 +174,24 @@ impl SearchQueryV1 {
         if let Some(level) = &self.level {
             push("level = ?N".into(), Box::new(level.to_string()));
         }
+        if let Some(min_level) = &self.min_level {
+            push(
+                "level in (select levels.name from levels
+                     where levels.rank >= (select floor.rank from levels floor where floor.name = ?N))"
+                    .into(),
+                Box::new(min_level.to_string()),
+            );
+        }
         if let Some(sensation) = &self.sensation {
             push("sensation = ?N".into(), Box::new(sensation.to_string()));
         }
 
+        if !self.include_retracted {
+            conditions.push(
+                "resource_ref not in (select resource_ref from search_retracted)".to_string(),
+            );
+        }
+
         let sql = if conditions.is_empty() {
             String::new()
         } else {
@@ -6,8 +6,10 @@ use crate::*;
 ///
 /// Content-bearing domains (cognition, memory, experience, agent) call
 /// [`SearchStore::index_entry`] and [`SearchStore::remove_by_ref`] from
-/// their own event handlers. The search projection's `apply` is a no-op —
-/// search owns the substrate, domains own the meaning of their events.
+/// their own event handlers, and [`SearchStore::retract`] when one of
+/// their entities is marked obsolete. The search projection's `apply` is
+/// a no-op — search owns the substrate, domains own the meaning of their
+/// events.
 pub(crate) struct SearchStore<'a> {
     conn: &'a rusqlite::Connection,
 }
@@ -30,6 +32,9 @@ impl<'a> SearchStore<'a> {
                  sensation unindexed,
                  persona unindexed,
                  created_at unindexed
+             );
+             create table if not exists search_retracted (
+                 resource_ref text primary key
              )",
         )?;
         Ok(())
@@ -37,6 +42,7 @@ impl<'a> SearchStore<'a> {
 
     pub(crate) fn reset(&self) -> Result<(), EventError> {
         self.conn.execute("delete from search_index", [])?;
+        self.conn.execute("delete from search_retracted", [])?;
         Ok(())
     }
 
@@ -103,4 +109,16 @@ impl<'a> SearchStore<'a> {
         )?;
         Ok(())
     }
+
+    /// Hide an entry from default searches without dropping it from the
+    /// index — superseded, retracted and amended entities stay findable
+    /// when a search asks to include them.
+    pub(crate) fn retract(&self, resource_ref: &Ref) -> Result<(), EventError> {
+        let ref_json = serde_json::to_string(resource_ref)?;
+        self.conn.execute(
+            "insert or ignore into search_retracted (resource_ref) values (?1)",
+            params![ref_json],
+        )?;
+        Ok(())
+    }
 }
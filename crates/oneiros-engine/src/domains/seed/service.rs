@@ -41,6 +41,7 @@ impl SeedService {
                     .name(LevelName::new(name))
                     .description(Description::from(description))
                     .prompt(Prompt::from(prompt))
+                    .maybe_rank(Level::seeded_rank(&LevelName::new(name)))
                     .build()
                     .into(),
             )
@@ -24,6 +24,7 @@ impl SetupCli {
                 name: details.name.clone(),
                 install_host,
                 init_mcp,
+                harness: details.harness,
                 accept_all: false,
             });
         }
@@ -45,16 +46,18 @@ impl SetupCli {
         }
 
         if !init_mcp {
-            init_mcp = inquire::Confirm::new("Set up MCP config for Claude Code?")
-                .with_default(true)
-                .prompt()
-                .unwrap_or(false);
+            init_mcp =
+                inquire::Confirm::new(&format!("Set up MCP config for {}?", details.harness))
+                    .with_default(true)
+                    .prompt()
+                    .unwrap_or(false);
         }
 
         SetupRequest::V1(SetupRequestV1 {
             name: details.name.clone(),
             install_host,
             init_mcp,
+            harness: details.harness,
             accept_all: false,
         })
     }
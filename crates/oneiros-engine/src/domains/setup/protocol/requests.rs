@@ -16,11 +16,16 @@ versioned! {
             #[serde(default)]
             #[builder(default)]
             pub(crate) install_host: bool,
-            /// Write .mcp.json for Claude Code integration.
+            /// Write the harness's MCP config (`.mcp.json` for Claude Code).
             #[arg(long)]
             #[serde(default)]
             #[builder(default)]
             pub(crate) init_mcp: bool,
+            /// Which agent harness to wire oneiros into.
+            #[arg(long, value_enum, default_value_t)]
+            #[serde(default)]
+            #[builder(default)]
+            pub(crate) harness: Harness,
             /// Accept all optional steps without prompting.
             #[arg(long, short = 'y')]
             #[serde(default)]
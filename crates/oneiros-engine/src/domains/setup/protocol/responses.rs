@@ -17,6 +17,7 @@ pub(crate) enum SetupStep {
     AgentsSeeded,
     McpConfigured,
     McpSkipped,
+    SkillsInstalled(Harness),
     ServiceInstalled,
     ServiceStarted,
     ServiceSkipped,
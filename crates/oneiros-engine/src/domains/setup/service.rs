@@ -208,13 +208,26 @@ impl SetupService {
 
         // 6. MCP config — gated by `init_mcp`.
         if details.init_mcp {
-            let mcp_request: InitMcp = InitMcp::builder_v1().yes(true).build().into();
+            let mcp_request: InitMcp = InitMcp::builder_v1()
+                .harness(details.harness)
+                .yes(true)
+                .build()
+                .into();
             match McpConfigService::init(config, &mcp_request) {
-                Ok(McpResponses::McpConfigWritten(_)) => {
-                    steps.push(SetupStep::McpConfigured);
-                }
-                Ok(McpResponses::McpConfigExists(_)) => {
+                Ok(_) => {
                     steps.push(SetupStep::McpConfigured);
+
+                    let root = std::env::current_dir().unwrap_or_default();
+                    match SkillPackage::install(&config.platform(), &root, details.harness) {
+                        Ok(true) => steps.push(SetupStep::SkillsInstalled(details.harness)),
+                        Ok(false) => {}
+                        Err(e) => {
+                            steps.push(SetupStep::StepFailed {
+                                step: "skills install".into(),
+                                reason: e.to_string(),
+                            });
+                        }
+                    }
                 }
                 Err(e) => {
                     steps.push(SetupStep::StepFailed {
@@ -55,6 +55,9 @@ impl SetupView {
                 SetupStep::McpConfigured => {
                     format!("  {} MCP config written", "✓".success())
                 }
+                SetupStep::SkillsInstalled(harness) => {
+                    format!("  {} Skills installed for {}", "✓".success(), harness)
+                }
                 SetupStep::McpSkipped => {
                     format!("  {} MCP config skipped", "−".muted())
                 }
@@ -5,6 +5,7 @@ pub(crate) enum StorageDocs {
     List,
     Show,
     Remove,
+    Fsck,
 }
 
 impl StorageDocs {
@@ -42,6 +43,12 @@ impl StorageDocs {
                 .summary("Remove a file")
                 .description("Delete a blob from the project's archive.")
                 .build(),
+            Self::Fsck => ResourceDocs::builder()
+                .tag(tag)
+                .nickname("check-blobs")
+                .summary("Check for missing files")
+                .description("List archive entries whose blob isn't stored on this host.")
+                .build(),
         }
     }
 }
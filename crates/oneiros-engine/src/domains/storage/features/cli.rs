@@ -24,6 +24,8 @@ pub(crate) enum StorageCommands {
     },
     List(ListStorage),
     Remove(RemoveStorage),
+    /// List storage entries whose blob is missing on this host.
+    Fsck(CheckStorage),
 }
 
 impl StorageCommands {
@@ -72,6 +74,7 @@ impl StorageCommands {
             }
             Self::List(listing) => listing.execute_request(&client).await?,
             Self::Remove(removal) => removal.execute_request(&client).await?,
+            Self::Fsck(checking) => checking.execute_request(&client).await?,
         };
 
         let response: StorageResponse = serde_json::from_slice(&bytes)?;
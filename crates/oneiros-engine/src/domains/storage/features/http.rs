@@ -28,6 +28,14 @@ impl StorageRouter {
                             .response::<201, Json<StorageSetResponse>>()
                     }),
                 )
+                .api_route(
+                    "/fsck",
+                    routing::get_with(fsck, |op| {
+                        resource_op!(op, StorageDocs::Fsck)
+                            .security_requirement("BearerToken")
+                            .response::<200, Json<StorageCheckedResponse>>()
+                    }),
+                )
                 .api_route(
                     "/{ref_key}",
                     routing::get_with(show, |op| {
@@ -66,6 +74,12 @@ async fn list(
     Ok(Json(StorageService::list(&scope, &params).await?))
 }
 
+async fn fsck(scope: Scope<AtBookmark>) -> Result<Json<StorageResponse>, StorageError> {
+    Ok(Json(
+        StorageService::fsck(&scope, &CheckStorage::builder_v1().build().into()).await?,
+    ))
+}
+
 async fn show(
     scope: Scope<AtBookmark>,
     Path(ref_key): Path<String>,
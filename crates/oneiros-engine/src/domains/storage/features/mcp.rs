@@ -0,0 +1,117 @@
+use crate::*;
+
+pub(crate) struct StorageMcp;
+
+impl StorageMcp {
+    pub(crate) fn defs(&self) -> Vec<ToolDef> {
+        storage_mcp::tool_defs()
+    }
+
+    #[expect(deprecated)]
+    pub(crate) async fn dispatch(
+        &self,
+        context: &ProjectLog,
+        mailbox: &Mailbox,
+        tool_name: &ToolName,
+        params: &serde_json::Value,
+    ) -> Result<McpResponse, ToolError> {
+        storage_mcp::dispatch(context, mailbox, tool_name, params).await
+    }
+}
+
+mod storage_mcp {
+    use data_encoding::BASE64;
+    use schemars::JsonSchema;
+    use serde::Deserialize;
+
+    use crate::*;
+
+    /// The most an upload may carry over MCP, decoded.
+    const UPLOAD_LIMIT: usize = 4 * 1024 * 1024;
+
+    /// An upload as MCP takes it — the bytes base64 encoded, rather than
+    /// the array of integers [`UploadStorage`] would serialize them as.
+    #[derive(Deserialize, JsonSchema)]
+    struct UploadStorageParams {
+        key: StorageKey,
+        #[serde(default)]
+        description: Description,
+        /// The file's bytes, base64 encoded.
+        data: String,
+    }
+
+    impl UploadStorageParams {
+        fn into_request(self) -> Result<UploadStorage, ToolError> {
+            let encoded = self.data.trim();
+            if encoded.len() > BASE64.encode_len(UPLOAD_LIMIT) {
+                return Err(ToolError::TooLarge(UPLOAD_LIMIT));
+            }
+
+            let data = BASE64.decode(encoded.as_bytes())?;
+            if data.len() > UPLOAD_LIMIT {
+                return Err(ToolError::TooLarge(UPLOAD_LIMIT));
+            }
+
+            Ok(UploadStorage::builder_v1()
+                .key(self.key)
+                .description(self.description)
+                .data(data)
+                .build()
+                .into())
+        }
+    }
+
+    pub(crate) fn tool_defs() -> Vec<ToolDef> {
+        vec![
+            Tool::<UploadStorageParams>::new(
+                StorageRequestType::UploadStorage,
+                "Archive a file's bytes, base64 encoded, under a key",
+                PermissionOp::StorageWrite,
+            )
+            .def(),
+            Tool::<GetStorage>::new(
+                StorageRequestType::GetStorage,
+                "Show a stored entry by key or ref",
+                PermissionOp::StorageRead,
+            )
+            .def(),
+        ]
+    }
+
+    #[expect(deprecated)]
+    pub(crate) async fn dispatch(
+        context: &ProjectLog,
+        mailbox: &Mailbox,
+        tool_name: &ToolName,
+        params: &serde_json::Value,
+    ) -> Result<McpResponse, ToolError> {
+        let request_type: StorageRequestType = tool_name
+            .as_str()
+            .parse()
+            .map_err(|_| ToolError::UnknownTool(tool_name.to_string()))?;
+
+        let scope = context.scope().map_err(Error::from)?;
+        let response = match request_type {
+            StorageRequestType::UploadStorage => {
+                let upload = serde_json::from_value::<UploadStorageParams>(params.clone())?
+                    .into_request()?;
+                StorageService::upload(scope, mailbox, &upload)
+                    .await
+                    .map_err(Error::from)?
+            }
+            StorageRequestType::GetStorage => {
+                let lookup: GetStorage = serde_json::from_value(params.clone())?;
+                StorageService::show(scope, &lookup)
+                    .await
+                    .map_err(Error::from)?
+            }
+            StorageRequestType::ListStorage
+            | StorageRequestType::RemoveStorage
+            | StorageRequestType::CheckStorage => {
+                return Err(ToolError::UnknownTool(tool_name.to_string()));
+            }
+        };
+
+        Ok(StorageView::new(response).mcp())
+    }
+}
@@ -10,6 +10,7 @@ impl StorageSkills {
             Skill::new("storage-get", include_str!("skills/get.md")),
             Skill::new("storage-list", include_str!("skills/list.md")),
             Skill::new("storage-remove", include_str!("skills/remove.md")),
+            Skill::new("storage-fsck", include_str!("skills/fsck.md")),
         ]
     }
 }
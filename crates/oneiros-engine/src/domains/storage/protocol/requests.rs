@@ -52,6 +52,14 @@ versioned! {
     }
 }
 
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum CheckStorage {
+        #[derive(clap::Args)]
+        V1 => {}
+    }
+}
+
 resource_requests! {
     GetStorage => |this, client| {
         let GetStorage::V1(lookup) = this;
@@ -77,6 +85,9 @@ resource_requests! {
         );
         client.get(&format!("/storage?{query}")).await
     },
+    CheckStorage => |_this, client| {
+        client.get("/storage/fsck").await
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Kinded)]
@@ -100,6 +111,7 @@ pub(crate) enum StorageRequest {
     GetStorage(GetStorage),
     ListStorage(ListStorage),
     RemoveStorage(RemoveStorage),
+    CheckStorage(CheckStorage),
 }
 
 #[cfg(test)]
@@ -113,6 +125,7 @@ mod tests {
             (StorageRequestType::GetStorage, "get-storage"),
             (StorageRequestType::ListStorage, "list-storage"),
             (StorageRequestType::RemoveStorage, "remove-storage"),
+            (StorageRequestType::CheckStorage, "check-storage"),
         ];
 
         for (request_type, expectation) in cases {
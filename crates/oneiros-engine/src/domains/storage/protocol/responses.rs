@@ -13,6 +13,7 @@ pub(crate) enum StorageResponse {
     Entries(StorageEntriesResponse),
     NoEntries,
     StorageRemoved(StorageRemovedResponse),
+    Checked(StorageCheckedResponse),
 }
 
 versioned! {
@@ -39,6 +40,16 @@ versioned! {
     }
 }
 
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum StorageCheckedResponse {
+        V1 => {
+            /// Entries set in the event log whose blob isn't here.
+            pub(crate) dangling: Vec<StorageEntry>,
+        }
+    }
+}
+
 versioned! {
     #[derive(JsonSchema)]
     pub(crate) enum StorageRemovedResponse {
@@ -124,4 +124,10 @@ impl<'a> StorageRepo<'a> {
             Err(e) => Err(e.into()),
         }
     }
+
+    /// Entries whose blob this bookmark doesn't hold.
+    pub(crate) async fn dangling(&self) -> Result<Vec<StorageEntry>, EventError> {
+        let db = BookmarkDb::open(self.scope).await?;
+        StorageStore::new(&db).dangling()
+    }
 }
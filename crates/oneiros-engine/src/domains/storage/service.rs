@@ -98,6 +98,21 @@ impl StorageService {
         }
     }
 
+    /// Find storage entries whose blob is missing — typically ones
+    /// collected from a peer that couldn't supply the blob.
+    pub(crate) async fn fsck(
+        scope: &Scope<AtBookmark>,
+        _request: &CheckStorage,
+    ) -> Result<StorageResponse, StorageError> {
+        let dangling = StorageRepo::new(scope).dangling().await?;
+        Ok(StorageResponse::Checked(
+            StorageCheckedResponse::builder_v1()
+                .dangling(dangling)
+                .build()
+                .into(),
+        ))
+    }
+
     /// Remove storage metadata by key. The blob is NOT deleted (dedup preservation).
     pub(crate) async fn remove(
         scope: &Scope<AtBookmark>,
@@ -7,6 +7,12 @@ use crate::*;
 /// Two-tier design (inspired by Fossil):
 /// - `blob` table: content-addressed by SHA256 hash, stores compressed binary
 /// - `storage` table: maps human-readable keys to blob hashes
+///
+/// A third table, `blob_chunk`, holds the chunks of a blob still being
+/// fetched from a peer, so an interrupted transfer resumes rather than
+/// restarting. A fourth, `storage_reference`, projects every entry the
+/// log sets whether or not its blob is here; an entry joins `storage`
+/// only once it is, and the rest are dangling.
 pub(crate) struct StorageStore<'a> {
     conn: &'a rusqlite::Connection,
 }
@@ -21,7 +27,10 @@ impl<'a> StorageStore<'a> {
             match storage_event {
                 StorageEvents::StorageSet(set) => {
                     let entry = set.current()?.entry;
-                    self.set_storage(&entry)?
+                    self.set_reference(&entry)?;
+                    if self.has_blob(&entry.hash)? {
+                        self.set_storage(&entry)?
+                    }
                 }
                 StorageEvents::StorageRemoved(removed) => {
                     let current = removed.current()?;
@@ -33,7 +42,8 @@ impl<'a> StorageStore<'a> {
     }
 
     pub(crate) fn reset_storage(&self) -> Result<(), EventError> {
-        self.conn.execute_batch("DELETE FROM storage")?;
+        self.conn
+            .execute_batch("DELETE FROM storage; DELETE FROM storage_reference")?;
         Ok(())
     }
 
@@ -48,6 +58,17 @@ impl<'a> StorageStore<'a> {
                 key TEXT PRIMARY KEY NOT NULL,
                 description TEXT NOT NULL DEFAULT '',
                 hash TEXT NOT NULL REFERENCES blob(hash)
+            );
+            CREATE TABLE IF NOT EXISTS blob_chunk (
+                hash TEXT NOT NULL,
+                position INTEGER NOT NULL,
+                data BLOB NOT NULL,
+                PRIMARY KEY (hash, position)
+            );
+            CREATE TABLE IF NOT EXISTS storage_reference (
+                key TEXT PRIMARY KEY NOT NULL,
+                description TEXT NOT NULL DEFAULT '',
+                hash TEXT NOT NULL
             )",
         )?;
         Ok(())
@@ -87,6 +108,116 @@ impl<'a> StorageStore<'a> {
         }
     }
 
+    pub(crate) fn has_blob(&self, hash: &ContentHash) -> Result<bool, EventError> {
+        let held = self.conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM blob WHERE hash = ?1)",
+            params![hash.as_str()],
+            |row| row.get(0),
+        )?;
+        Ok(held)
+    }
+
+    /// Storage entries whose blob isn't here — set by an event that
+    /// arrived without its blob. Ordered by key.
+    pub(crate) fn dangling(&self) -> Result<Vec<StorageEntry>, EventError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT key, description, hash FROM storage_reference
+             WHERE NOT EXISTS (SELECT 1 FROM blob WHERE blob.hash = storage_reference.hash)
+             ORDER BY key",
+        )?;
+        let entries = stmt
+            .query_map([], |row| {
+                Ok(StorageEntry {
+                    key: StorageKey::new(row.get::<_, String>(0)?),
+                    description: Description::new(row.get::<_, String>(1)?),
+                    hash: ContentHash::new(row.get::<_, String>(2)?),
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(entries)
+    }
+
+    /// Up to `len` of a blob's stored bytes from `offset`, with the
+    /// stored length. `None` if the blob isn't here.
+    pub(crate) fn blob_chunk(
+        &self,
+        hash: &ContentHash,
+        offset: u64,
+        len: usize,
+    ) -> Result<Option<(Vec<u8>, u64)>, EventError> {
+        let result = self.conn.query_row(
+            "SELECT substr(data, ?2, ?3), length(data) FROM blob WHERE hash = ?1",
+            params![hash.as_str(), offset as i64 + 1, len as i64],
+            |row| {
+                let bytes: Option<Vec<u8>> = row.get(0)?;
+                let total: i64 = row.get(1)?;
+                Ok((bytes.unwrap_or_default(), total as u64))
+            },
+        );
+
+        match result {
+            Ok(chunk) => Ok(Some(chunk)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// How many bytes of a partly fetched blob are already held.
+    pub(crate) fn chunks_received(&self, hash: &ContentHash) -> Result<u64, EventError> {
+        let received: i64 = self.conn.query_row(
+            "SELECT coalesce(sum(length(data)), 0) FROM blob_chunk WHERE hash = ?1",
+            params![hash.as_str()],
+            |row| row.get(0),
+        )?;
+        Ok(received as u64)
+    }
+
+    pub(crate) fn put_chunk(
+        &self,
+        hash: &ContentHash,
+        position: u64,
+        data: &[u8],
+    ) -> Result<(), EventError> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO blob_chunk (hash, position, data) VALUES (?1, ?2, ?3)",
+            params![hash.as_str(), position as i64, data],
+        )?;
+        Ok(())
+    }
+
+    /// Join a fetched blob's chunks into a [`BlobContent`] and check it
+    /// against its hash. The chunks are cleared either way — a blob
+    /// that fails the check is fetched again from scratch.
+    pub(crate) fn assemble_chunks(
+        &self,
+        hash: &ContentHash,
+    ) -> Result<Option<BlobContent>, EventError> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT data FROM blob_chunk WHERE hash = ?1 ORDER BY position")?;
+        let chunks = stmt
+            .query_map(params![hash.as_str()], |row| row.get::<_, Vec<u8>>(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        self.conn.execute(
+            "DELETE FROM blob_chunk WHERE hash = ?1",
+            params![hash.as_str()],
+        )?;
+
+        let data = Blob::encode(&chunks.concat());
+        let Ok(raw) = data.decompressed() else {
+            return Ok(None);
+        };
+        if ContentHash::compute(&raw) != *hash {
+            return Ok(None);
+        }
+
+        Ok(Some(BlobContent {
+            hash: hash.clone(),
+            size: Size::new(raw.len()),
+            data,
+        }))
+    }
+
     pub(crate) fn set_storage(&self, entry: &StorageEntry) -> Result<(), EventError> {
         self.conn.execute(
             "INSERT INTO storage (key, description, hash) VALUES (?1, ?2, ?3)
@@ -103,6 +234,135 @@ impl<'a> StorageStore<'a> {
     pub(crate) fn remove_storage(&self, key: &StorageKey) -> Result<(), EventError> {
         self.conn
             .execute("DELETE FROM storage WHERE key = ?1", params![key.as_str()])?;
+        self.conn.execute(
+            "DELETE FROM storage_reference WHERE key = ?1",
+            params![key.as_str()],
+        )?;
+        Ok(())
+    }
+
+    fn set_reference(&self, entry: &StorageEntry) -> Result<(), EventError> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO storage_reference (key, description, hash) VALUES (?1, ?2, ?3)",
+            params![
+                entry.key.as_str(),
+                entry.description.as_str(),
+                entry.hash.as_str(),
+            ],
+        )?;
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store(conn: &rusqlite::Connection) -> StorageStore<'_> {
+        let store = StorageStore::new(conn);
+        store.migrate().unwrap();
+        store
+    }
+
+    #[test]
+    fn chunks_resume_and_assemble_into_the_blob() {
+        let source_conn = rusqlite::Connection::open_in_memory().unwrap();
+        let source = store(&source_conn);
+        let content = BlobContent::create(&b"chunked content ".repeat(64)).unwrap();
+        source.put_blob(&content).unwrap();
+
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        let target = store(&conn);
+        loop {
+            let offset = target.chunks_received(&content.hash).unwrap();
+            let (bytes, total) = source
+                .blob_chunk(&content.hash, offset, 10)
+                .unwrap()
+                .unwrap();
+            target.put_chunk(&content.hash, offset, &bytes).unwrap();
+            if offset + bytes.len() as u64 >= total {
+                break;
+            }
+        }
+
+        let assembled = target
+            .assemble_chunks(&content.hash)
+            .unwrap()
+            .expect("chunks should verify against the hash");
+        assert_eq!(assembled.data, content.data);
+        assert_eq!(target.chunks_received(&content.hash).unwrap(), 0);
+    }
+
+    #[test]
+    fn chunks_that_fail_the_hash_are_discarded() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        let target = store(&conn);
+        let expected = ContentHash::compute(b"expected");
+        let other = BlobContent::create(b"something else").unwrap();
+
+        target
+            .put_chunk(&expected, 0, &other.data.decode().unwrap())
+            .unwrap();
+
+        assert!(target.assemble_chunks(&expected).unwrap().is_none());
+        assert_eq!(target.chunks_received(&expected).unwrap(), 0);
+    }
+
+    #[test]
+    fn dangling_entries_are_live_entries_without_blobs() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        let storage = store(&conn);
+
+        let held = BlobContent::create(b"held").unwrap();
+        storage.put_blob(&held).unwrap();
+
+        let stored = |event: StorageEvents| {
+            StoredEvent::builder()
+                .id(EventId::new())
+                .sequence(0)
+                .created_at(Timestamp::now())
+                .source(Source::default())
+                .data(Event::Known(Events::Storage(event)))
+                .build()
+        };
+        let set = |key: &str, hash: &ContentHash| {
+            stored(StorageEvents::StorageSet(
+                StorageSet::builder_v1()
+                    .entry(StorageEntry {
+                        key: StorageKey::new(key),
+                        description: Description::new(""),
+                        hash: hash.clone(),
+                    })
+                    .build()
+                    .into(),
+            ))
+        };
+        let missing = ContentHash::compute(b"missing");
+        storage.handle(&set("held", &held.hash)).unwrap();
+        storage.handle(&set("missing", &missing)).unwrap();
+        storage.handle(&set("removed", &missing)).unwrap();
+        storage
+            .handle(&stored(StorageEvents::StorageRemoved(
+                StorageRemoved::builder_v1()
+                    .key(StorageKey::new("removed"))
+                    .build()
+                    .into(),
+            )))
+            .unwrap();
+
+        let dangling = storage.dangling().unwrap();
+        assert_eq!(dangling.len(), 1);
+        assert_eq!(dangling[0].key, StorageKey::new("missing"));
+    }
+
+    #[test]
+    fn missing_blobs_have_no_chunks() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        let source = store(&conn);
+
+        let chunk = source
+            .blob_chunk(&ContentHash::compute(b"absent"), 0, 10)
+            .unwrap();
+        assert!(chunk.is_none());
+    }
+}
@@ -11,6 +11,63 @@ impl StorageView {
         Self { response }
     }
 
+    pub(crate) fn mcp(&self) -> McpResponse {
+        match &self.response {
+            StorageResponse::StorageSet(StorageSetResponse::V1(set)) => {
+                let ref_token = RefToken::new(Ref::storage(set.entry.key.clone()));
+                McpResponse::new(format!(
+                    "Stored {}.\n\n**hash:** {}\n**ref:** {}",
+                    set.entry.key, set.entry.hash, ref_token
+                ))
+                .hint_set(HintSet::mutation(
+                    MutationHints::builder().ref_token(ref_token).build(),
+                ))
+            }
+            StorageResponse::StorageDetails(StorageDetailsResponse::V1(details)) => {
+                McpResponse::new(format!(
+                    "# Storage — {}\n\n**description:** {}\n**hash:** {}\n",
+                    details.entry.key, details.entry.description, details.entry.hash
+                ))
+                .hint(Hint::suggest(
+                    format!(
+                        "trail-of {}",
+                        RefToken::new(Ref::storage(details.entry.key.clone()))
+                    ),
+                    "See the events behind this entry",
+                ))
+            }
+            StorageResponse::Entries(StorageEntriesResponse::V1(listed)) => {
+                let mut md = format!(
+                    "# Storage\n\n{} of {} total\n\n",
+                    listed.items.len(),
+                    listed.total
+                );
+                for entry in &listed.items {
+                    md.push_str(&format!("- **{}** — {}\n", entry.key, entry.description));
+                }
+                McpResponse::new(md)
+            }
+            StorageResponse::NoEntries => McpResponse::new("No storage entries.")
+                .hint(Hint::suggest("upload-storage", "Archive a file")),
+            StorageResponse::Checked(StorageCheckedResponse::V1(checked)) => {
+                if checked.dangling.is_empty() {
+                    return McpResponse::new("Every storage entry has its blob.");
+                }
+                let mut md = format!(
+                    "# Storage\n\n{} entries missing their blob\n\n",
+                    checked.dangling.len()
+                );
+                for entry in &checked.dangling {
+                    md.push_str(&format!("- **{}** — {}\n", entry.key, entry.hash));
+                }
+                McpResponse::new(md)
+            }
+            StorageResponse::StorageRemoved(StorageRemovedResponse::V1(removed)) => {
+                McpResponse::new(format!("Removed {}.", removed.key))
+            }
+        }
+    }
+
     pub(crate) fn render(self) -> Rendered<StorageResponse> {
         match self.response {
             StorageResponse::StorageSet(StorageSetResponse::V1(set)) => {
@@ -65,6 +122,26 @@ impl StorageView {
                 format!("{}", "No storage entries.".muted()),
                 String::new(),
             ),
+            StorageResponse::Checked(StorageCheckedResponse::V1(checked)) => {
+                let prompt = if checked.dangling.is_empty() {
+                    format!("{} Every storage entry has its blob.", "✓".success())
+                } else {
+                    let mut table = Table::new(vec![Column::new("Key"), Column::new("Hash")]);
+                    for entry in &checked.dangling {
+                        table.push_row(vec![entry.key.to_string(), entry.hash.to_string()]);
+                    }
+                    format!(
+                        "{}\n\n{table}",
+                        format_args!("{} entries missing their blob", checked.dangling.len())
+                            .muted(),
+                    )
+                };
+                Rendered::new(
+                    StorageResponse::Checked(StorageCheckedResponse::V1(checked)),
+                    prompt,
+                    String::new(),
+                )
+            }
             StorageResponse::StorageRemoved(StorageRemovedResponse::V1(removed)) => {
                 let prompt =
                     Confirmation::new("Storage", removed.key.to_string(), "removed").to_string();
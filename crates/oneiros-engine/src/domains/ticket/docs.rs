@@ -5,6 +5,9 @@ pub(crate) enum TicketDocs {
     List,
     Show,
     Validate,
+    Revoke,
+    Rotate,
+    Audit,
 }
 
 impl TicketDocs {
@@ -42,6 +45,30 @@ impl TicketDocs {
                 .summary("Validate a ticket")
                 .description("Verify that a ticket is still valid and not revoked.")
                 .build(),
+            Self::Revoke => ResourceDocs::builder()
+                .tag(tag)
+                .nickname("revoke-ticket")
+                .summary("Revoke a ticket")
+                .description(
+                    "Withdraw a ticket so it no longer authorizes HTTP or peer sync access.",
+                )
+                .build(),
+            Self::Rotate => ResourceDocs::builder()
+                .tag(tag)
+                .nickname("rotate-ticket")
+                .summary("Rotate a ticket")
+                .description(
+                    "Issue a successor ticket for the same target and revoke the original.",
+                )
+                .build(),
+            Self::Audit => ResourceDocs::builder()
+                .tag(tag)
+                .nickname("audit-ticket")
+                .summary("Audit ticket uses")
+                .description(
+                    "List each recorded use of a ticket: which peer, which request, and when.",
+                )
+                .build(),
         }
     }
 }
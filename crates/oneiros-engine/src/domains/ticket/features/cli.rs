@@ -10,6 +10,9 @@ pub(crate) enum TicketCommands {
     Issue(CreateTicket),
     Validate(ValidateTicket),
     List(ListTickets),
+    Revoke(RevokeTicket),
+    Rotate(RotateTicket),
+    Audit(AuditTicket),
 }
 
 impl TicketCommands {
@@ -23,6 +26,9 @@ impl TicketCommands {
             Self::Issue(issuance) => issuance.execute_request(&client).await?,
             Self::Validate(validation) => validation.execute_request(&client).await?,
             Self::List(listing) => listing.execute_request(&client).await?,
+            Self::Revoke(revocation) => revocation.execute_request(&client).await?,
+            Self::Rotate(rotation) => rotation.execute_request(&client).await?,
+            Self::Audit(audit) => audit.execute_request(&client).await?,
         };
 
         let response: TicketResponse = serde_json::from_slice(&bytes)?;
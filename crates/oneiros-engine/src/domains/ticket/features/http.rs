@@ -1,7 +1,7 @@
 use aide::axum::{ApiRouter, routing};
 use axum::{
     Json,
-    extract::{Path, Query},
+    extract::{Path, Query, State},
     http::StatusCode,
 };
 
@@ -32,6 +32,30 @@ impl TicketRouter {
                             .response::<200, Json<TicketFoundResponse>>()
                     }),
                 )
+                .api_route(
+                    "/{id}/revoke",
+                    routing::post_with(revoke, |op| {
+                        resource_op!(op, TicketDocs::Revoke)
+                            .input::<IdPathParam<TicketId>>()
+                            .response::<200, Json<TicketRevokedResponse>>()
+                    }),
+                )
+                .api_route(
+                    "/{id}/rotate",
+                    routing::post_with(rotate, |op| {
+                        resource_op!(op, TicketDocs::Rotate)
+                            .input::<IdPathParam<TicketId>>()
+                            .response::<200, Json<TicketRotatedResponse>>()
+                    }),
+                )
+                .api_route(
+                    "/{id}/uses",
+                    routing::get_with(audit, |op| {
+                        resource_op!(op, TicketDocs::Audit)
+                            .input::<IdPathParam<TicketId>>()
+                            .response::<200, Json<TicketAuditResponse>>()
+                    }),
+                )
                 .api_route(
                     "/validate",
                     routing::post_with(validate, |op| {
@@ -74,3 +98,42 @@ async fn validate(
 ) -> Result<Json<TicketResponse>, TicketError> {
     Ok(Json(TicketService::validate(&scope, &body).await?))
 }
+
+async fn revoke(
+    scope: Scope<AtHost>,
+    mailbox: Mailbox,
+    Path(key): Path<ResourceKey<TicketId>>,
+) -> Result<Json<TicketResponse>, TicketError> {
+    Ok(Json(
+        TicketService::revoke(
+            &scope,
+            &mailbox,
+            &RevokeTicket::builder_v1().key(key).build().into(),
+        )
+        .await?,
+    ))
+}
+
+async fn rotate(
+    scope: Scope<AtHost>,
+    State(state): State<ServerState>,
+    Path(key): Path<ResourceKey<TicketId>>,
+) -> Result<Json<TicketResponse>, TicketError> {
+    Ok(Json(
+        TicketService::rotate(
+            &scope,
+            &state,
+            &RotateTicket::builder_v1().key(key).build().into(),
+        )
+        .await?,
+    ))
+}
+
+async fn audit(
+    scope: Scope<AtHost>,
+    Path(key): Path<ResourceKey<TicketId>>,
+) -> Result<Json<TicketResponse>, TicketError> {
+    Ok(Json(
+        TicketService::audit(&scope, &AuditTicket::builder_v1().key(key).build().into()).await?,
+    ))
+}
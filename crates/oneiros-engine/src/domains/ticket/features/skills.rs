@@ -8,6 +8,9 @@ impl TicketSkills {
             Skill::new("ticket-issue", include_str!("skills/issue.md")),
             Skill::new("ticket-validate", include_str!("skills/validate.md")),
             Skill::new("ticket-list", include_str!("skills/list.md")),
+            Skill::new("ticket-revoke", include_str!("skills/revoke.md")),
+            Skill::new("ticket-rotate", include_str!("skills/rotate.md")),
+            Skill::new("ticket-audit", include_str!("skills/audit.md")),
         ]
     }
 }
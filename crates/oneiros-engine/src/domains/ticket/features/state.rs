@@ -4,12 +4,32 @@ pub(crate) struct TicketState;
 
 impl TicketState {
     pub(crate) fn reduce(mut canon: HostCanon, event: &Events) -> HostCanon {
-        if let Events::Ticket(ticket_event) = event
-            && let Some(ticket) = ticket_event.maybe_ticket()
-        {
+        let Events::Ticket(ticket_event) = event else {
+            return canon;
+        };
+
+        if let Some(ticket) = ticket_event.maybe_ticket() {
             canon.tickets.set(&ticket);
         }
 
+        match ticket_event {
+            TicketEvents::TicketUsed(used) => {
+                if let Ok(used) = used.current()
+                    && let Some(ticket) = canon.tickets.get_mut(&used.ticket_id)
+                {
+                    ticket.uses += 1;
+                }
+            }
+            TicketEvents::TicketRevoked(revoked) => {
+                if let Ok(revoked) = revoked.current()
+                    && let Some(ticket) = canon.tickets.get_mut(&revoked.ticket_id)
+                {
+                    ticket.revoked_at.get_or_insert(revoked.revoked_at);
+                }
+            }
+            TicketEvents::TicketIssued(_) | TicketEvents::TicketRejected(_) => {}
+        }
+
         canon
     }
 
@@ -29,11 +29,17 @@ pub(crate) struct Ticket {
     #[builder(default = Timestamp::now())]
     pub(crate) created_at: Timestamp,
 
-    /// Capabilities granted by this ticket. Empty vec = implicit read access
-    /// (V0 behavior for backward compatibility).
+    /// Capabilities granted by this ticket. Authoritative when `scoped`.
     #[builder(default)]
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub(crate) permissions: Vec<Permission>,
+
+    /// Whether `permissions` is the complete grant. Tickets issued before
+    /// scoped permissions deserialize as unscoped and keep the access
+    /// they always had.
+    #[builder(default = true)]
+    #[serde(default)]
+    pub(crate) scoped: bool,
 }
 
 impl Indexable<TicketId> for Ticket {
@@ -56,6 +62,19 @@ impl Ticket {
     /// Check whether this ticket is still valid for use. Returns `Ok(())` if
     /// the ticket is neither revoked, expired, nor exhausted.
     pub(crate) fn check_validity(&self) -> Result<(), TicketInvalid> {
+        self.check_standing()?;
+        if let Some(max_uses) = self.max_uses
+            && self.uses >= max_uses
+        {
+            return Err(TicketInvalid::Exhausted);
+        }
+        Ok(())
+    }
+
+    /// Check that this ticket is neither revoked nor expired, without
+    /// regard to its use limit — for requests that continue a session
+    /// whose use was already counted.
+    pub(crate) fn check_standing(&self) -> Result<(), TicketInvalid> {
         if self.revoked_at.is_some() {
             return Err(TicketInvalid::Revoked);
         }
@@ -64,26 +83,106 @@ impl Ticket {
         {
             return Err(TicketInvalid::Expired);
         }
-        if let Some(max_uses) = self.max_uses
-            && self.uses >= max_uses
-        {
-            return Err(TicketInvalid::Exhausted);
-        }
         Ok(())
     }
 
     /// Check whether this ticket grants a specific capability.
+    ///
+    /// Scoped tickets grant exactly what they list. Unscoped tickets
+    /// predate the capability model: they always needed an explicit grant
+    /// for `BookmarkSubmit` and `BookmarkList`, and were never checked for
+    /// anything else, so they still pass every other operation.
     pub(crate) fn can(&self, required: PermissionOp) -> bool {
-        if self.permissions.is_empty() {
-            return false;
-        }
-        self.permissions.iter().any(|p| match p {
+        let listed = self.permissions.iter().any(|p| match p {
             Permission::V1(v1) => v1.operation == required,
             Permission::V0(_) => false,
-        })
+        });
+
+        match required {
+            _ if self.scoped => listed,
+            PermissionOp::BookmarkSubmit | PermissionOp::BookmarkList => listed,
+            _ => true,
+        }
+    }
+
+    /// The operations this ticket effectively grants.
+    pub(crate) fn grants(&self) -> Vec<PermissionOp> {
+        PermissionOp::ALL
+            .iter()
+            .copied()
+            .filter(|op| self.can(*op))
+            .collect()
     }
 }
 
+/// One recorded presentation of a ticket — who used it, for what, and when.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub(crate) struct TicketUse {
+    pub(crate) ticket_id: TicketId,
+    pub(crate) peer: Option<PeerKey>,
+    pub(crate) operation: Option<BridgeOp>,
+    pub(crate) used_at: Timestamp,
+}
+
 pub(crate) type Tickets = EntityIndex<TicketId, Ticket>;
 
 resource_id!(TicketId);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ticket(scoped: bool, ops: &[PermissionOp]) -> Ticket {
+        let permissions = ops
+            .iter()
+            .map(|op| Permission::V1(PermissionV1::builder().operation(*op).build()))
+            .collect();
+
+        Ticket::builder()
+            .actor_id(ActorId::new())
+            .project_name(ProjectName::new("test"))
+            .project_id(ProjectId::new())
+            .link(Link::new(Ref::project(ProjectId::new()), Token::from("t")))
+            .granted_by(ActorId::new())
+            .permissions(permissions)
+            .scoped(scoped)
+            .build()
+    }
+
+    #[test]
+    fn scoped_tickets_grant_only_listed_operations() {
+        let ticket = ticket(true, &[PermissionOp::ProjectRead, PermissionOp::Search]);
+
+        assert!(ticket.can(PermissionOp::ProjectRead));
+        assert!(ticket.can(PermissionOp::Search));
+        assert!(!ticket.can(PermissionOp::ProjectWrite));
+        assert!(!ticket.can(PermissionOp::BookmarkCollect));
+        assert_eq!(
+            ticket.grants(),
+            vec![PermissionOp::Search, PermissionOp::ProjectRead]
+        );
+    }
+
+    #[test]
+    fn unscoped_tickets_keep_legacy_access() {
+        let ticket = ticket(false, &[PermissionOp::BookmarkList]);
+
+        assert!(ticket.can(PermissionOp::BookmarkList));
+        assert!(ticket.can(PermissionOp::BookmarkCollect));
+        assert!(ticket.can(PermissionOp::ProjectWrite));
+        assert!(!ticket.can(PermissionOp::BookmarkSubmit));
+    }
+
+    #[test]
+    fn exhausted_tickets_keep_their_standing() {
+        let mut ticket = ticket(true, &[PermissionOp::BookmarkCollect]);
+        ticket.max_uses = Some(1);
+        ticket.uses = 1;
+
+        assert_eq!(ticket.check_validity(), Err(TicketInvalid::Exhausted));
+        assert_eq!(ticket.check_standing(), Ok(()));
+
+        ticket.revoked_at = Some(Timestamp::now());
+        assert_eq!(ticket.check_standing(), Err(TicketInvalid::Revoked));
+    }
+}
@@ -14,6 +14,9 @@ pub(crate) enum TicketError {
     #[error("Invalid token")]
     InvalidToken,
 
+    #[error("Ticket already revoked: {0}")]
+    AlreadyRevoked(TicketId),
+
     #[error("Project not found: {0}")]
     ProjectNotFound(ProjectName),
 
@@ -46,6 +49,7 @@ impl IntoResponse for TicketError {
         let (status, message) = match &self {
             TicketError::NotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
             TicketError::InvalidToken => (StatusCode::UNAUTHORIZED, self.to_string()),
+            TicketError::AlreadyRevoked(_) => (StatusCode::CONFLICT, self.to_string()),
             TicketError::ProjectNotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
             TicketError::ActorNotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
             TicketError::Resolve(_) => (StatusCode::UNPROCESSABLE_ENTITY, self.to_string()),
@@ -23,13 +23,16 @@ pub(crate) enum TicketEvents {
     TicketIssued(TicketIssued),
     TicketUsed(TicketUsed),
     TicketRejected(TicketRejected),
+    TicketRevoked(TicketRevoked),
 }
 
 impl TicketEvents {
     pub(crate) fn maybe_ticket(&self) -> Option<Ticket> {
         match self {
             TicketEvents::TicketIssued(event) => event.clone().current().ok().map(|v| v.ticket),
-            TicketEvents::TicketUsed(_) | TicketEvents::TicketRejected(_) => None,
+            TicketEvents::TicketUsed(_)
+            | TicketEvents::TicketRejected(_)
+            | TicketEvents::TicketRevoked(_) => None,
         }
     }
 }
@@ -44,10 +47,36 @@ versioned! {
 
 versioned! {
     pub(crate) enum TicketUsed {
+        V2 => {
+            pub(crate) ticket_id: TicketId,
+            pub(crate) used_at: Timestamp,
+            /// The peer that presented the ticket. Absent on V1 uses.
+            pub(crate) peer: Option<PeerKey>,
+            /// The bridge request it was presented with. Absent on V1 uses.
+            pub(crate) operation: Option<BridgeOp>,
+        },
         V1 => {
             pub(crate) ticket_id: TicketId,
             pub(crate) used_at: Timestamp,
-        }
+        },
+    }
+}
+
+impl TryFrom<TicketUsedV1> for TicketUsedV2 {
+    type Error = UpcastError;
+    fn try_from(v1: TicketUsedV1) -> Result<Self, Self::Error> {
+        Ok(TicketUsedV2 {
+            ticket_id: v1.ticket_id,
+            used_at: v1.used_at,
+            peer: None,
+            operation: None,
+        })
+    }
+}
+
+impl From<TicketUsedV1> for TicketUsed {
+    fn from(v: TicketUsedV1) -> Self {
+        Self::V1(v)
     }
 }
 
@@ -61,6 +90,17 @@ versioned! {
     }
 }
 
+versioned! {
+    pub(crate) enum TicketRevoked {
+        V1 => {
+            pub(crate) ticket_id: TicketId,
+            pub(crate) revoked_at: Timestamp,
+            /// The ticket issued in its place, when revoked by rotation.
+            pub(crate) replaced_by: Option<TicketId>,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -87,6 +127,27 @@ mod tests {
             &TicketEventsType::TicketRejected.to_string(),
             "ticket-rejected"
         );
+        assert_eq!(
+            &TicketEventsType::TicketRevoked.to_string(),
+            "ticket-revoked"
+        );
+    }
+
+    #[test]
+    fn ticket_used_v1_upcasts_without_a_peer() {
+        let ticket_id = TicketId::new();
+        let json = serde_json::json!({
+            "type": "ticket-used",
+            "data": { "ticket_id": ticket_id, "used_at": Timestamp::now() }
+        });
+        let event: TicketEvents = serde_json::from_value(json).expect("v1 decode");
+        let used = match event {
+            TicketEvents::TicketUsed(inner) => inner.current().unwrap(),
+            other => panic!("expected TicketUsed, got {other:?}"),
+        };
+        assert_eq!(used.ticket_id, ticket_id);
+        assert!(used.peer.is_none());
+        assert!(used.operation.is_none());
     }
 
     #[test]
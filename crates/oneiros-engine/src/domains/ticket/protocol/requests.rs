@@ -13,7 +13,8 @@ versioned! {
             #[builder(into)] pub(crate) actor_id: ActorId,
             #[arg(long)]
             #[builder(into)] pub(crate) project_name: ProjectName,
-            /// Operations this ticket grants. Repeatable. Empty = full access.
+            /// Operations this ticket grants. Repeatable. Empty = read-only
+            /// (`project-read`).
             #[arg(long = "permission", value_enum)]
             #[serde(default, skip_serializing_if = "Vec::is_empty")]
             #[builder(default)]
@@ -55,6 +56,36 @@ versioned! {
     }
 }
 
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum RevokeTicket {
+        #[derive(clap::Args)]
+        V1 => {
+            #[builder(into)] pub(crate) key: ResourceKey<TicketId>,
+        }
+    }
+}
+
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum RotateTicket {
+        #[derive(clap::Args)]
+        V1 => {
+            #[builder(into)] pub(crate) key: ResourceKey<TicketId>,
+        }
+    }
+}
+
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum AuditTicket {
+        #[derive(clap::Args)]
+        V1 => {
+            #[builder(into)] pub(crate) key: ResourceKey<TicketId>,
+        }
+    }
+}
+
 resource_requests! {
     CreateTicket => |this, client| { client.post("/tickets", this).await },
     GetTicket => |this, client| {
@@ -70,6 +101,18 @@ resource_requests! {
         client.get(&format!("/tickets?{query}")).await
     },
     ValidateTicket => |this, client| { client.post("/tickets/validate", this).await },
+    RevokeTicket => |this, client| {
+        let RevokeTicket::V1(revocation) = this;
+        client.post(&format!("/tickets/{}/revoke", revocation.key), this).await
+    },
+    RotateTicket => |this, client| {
+        let RotateTicket::V1(rotation) = this;
+        client.post(&format!("/tickets/{}/rotate", rotation.key), this).await
+    },
+    AuditTicket => |this, client| {
+        let AuditTicket::V1(audit) = this;
+        client.get(&format!("/tickets/{}/uses", audit.key)).await
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Kinded)]
@@ -80,6 +123,9 @@ pub(crate) enum TicketRequest {
     GetTicket(GetTicket),
     ListTickets(ListTickets),
     ValidateTicket(ValidateTicket),
+    RevokeTicket(RevokeTicket),
+    RotateTicket(RotateTicket),
+    AuditTicket(AuditTicket),
 }
 
 // Internal service request for issuing a ticket. Not an HTTP/CLI endpoint —
@@ -109,6 +155,9 @@ mod tests {
             (TicketRequestType::GetTicket, "get-ticket"),
             (TicketRequestType::ListTickets, "list-tickets"),
             (TicketRequestType::ValidateTicket, "validate-ticket"),
+            (TicketRequestType::RevokeTicket, "revoke-ticket"),
+            (TicketRequestType::RotateTicket, "rotate-ticket"),
+            (TicketRequestType::AuditTicket, "audit-ticket"),
         ];
 
         for (request_type, expectation) in cases {
@@ -12,6 +12,9 @@ pub(crate) enum TicketResponse {
     Found(TicketFoundResponse),
     Listed(TicketsResponse),
     Validated(TicketValidatedResponse),
+    Revoked(TicketRevokedResponse),
+    Rotated(TicketRotatedResponse),
+    Audited(TicketAuditResponse),
 }
 
 versioned! {
@@ -44,3 +47,34 @@ versioned! {
         V1 => { #[serde(flatten)] pub(crate) ticket: Ticket }
     }
 }
+
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum TicketRevokedResponse {
+        V1 => { #[serde(flatten)] pub(crate) ticket: Ticket }
+    }
+}
+
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum TicketRotatedResponse {
+        V1 => {
+            /// The ticket that was retired.
+            pub(crate) revoked: TicketId,
+            /// Its successor, bound to the same target.
+            pub(crate) ticket: Ticket,
+            /// A shareable peer URI for the successor.
+            pub(crate) uri: String,
+        }
+    }
+}
+
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum TicketAuditResponse {
+        V1 => {
+            pub(crate) ticket_id: TicketId,
+            pub(crate) uses: Vec<TicketUse>,
+        }
+    }
+}
@@ -22,10 +22,12 @@ type TicketRow = (
     i64,            // uses
     String,         // created_at
     String,         // permissions (JSON)
+    bool,           // scoped
 );
 
 const SELECT_COLUMNS: &str = "id, actor_id, project_name, project_id, token, target, granted_by, \
-                              expires_at, revoked_at, max_uses, uses, created_at, permissions";
+                              expires_at, revoked_at, max_uses, uses, created_at, permissions, \
+                              scoped";
 
 fn ticket_from_row(row: TicketRow) -> Result<Ticket, EventError> {
     let (
@@ -42,6 +44,7 @@ fn ticket_from_row(row: TicketRow) -> Result<Ticket, EventError> {
         uses,
         created_at,
         permissions_json,
+        scoped,
     ) = row;
 
     let target_ref: RefToken = target
@@ -74,6 +77,7 @@ fn ticket_from_row(row: TicketRow) -> Result<Ticket, EventError> {
         uses: uses as u64,
         created_at: Timestamp::parse_str(created_at)?,
         permissions,
+        scoped,
     })
 }
 
@@ -92,6 +96,7 @@ fn read_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<TicketRow> {
         row.get(10)?,
         row.get(11)?,
         row.get(12)?,
+        row.get(13)?,
     ))
 }
 
@@ -151,6 +156,37 @@ impl<'a> TicketRepo<'a> {
         Ok(Listed::new(tickets, total))
     }
 
+    /// Every recorded use of a ticket, oldest first.
+    pub(crate) async fn uses(&self, id: &TicketId) -> Result<Vec<TicketUse>, EventError> {
+        let db = HostDb::open(self.scope).await?;
+        let mut stmt = db.prepare(
+            "SELECT ticket_id, peer, operation, used_at
+             FROM ticket_uses
+             WHERE ticket_id = ?1
+             ORDER BY used_at",
+        )?;
+
+        let raw: Vec<(String, Option<String>, Option<String>, String)> = stmt
+            .query_map(params![id.to_string()], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut uses = vec![];
+
+        for (ticket_id, peer, operation, used_at) in raw {
+            uses.push(TicketUse {
+                ticket_id: ticket_id.parse()?,
+                peer: peer.and_then(|peer| peer.parse().ok()),
+                operation: operation
+                    .and_then(|op| serde_json::from_value(serde_json::Value::String(op)).ok()),
+                used_at: Timestamp::parse_str(used_at)?,
+            });
+        }
+
+        Ok(uses)
+    }
+
     pub(crate) async fn get_by_token(&self, token: &str) -> Result<Option<Ticket>, EventError> {
         let db = HostDb::open(self.scope).await?;
         let sql = format!("SELECT {SELECT_COLUMNS} FROM tickets WHERE token = ?1");
@@ -15,19 +15,16 @@ impl TicketService {
             .ok_or_else(|| TicketError::ProjectNotFound(create.project_name.clone()))?;
 
         let target = Ref::project(project.id);
-        let permissions: Vec<Permission> = if create.permissions.is_empty() {
-            // Default to full access when no permissions specified.
-            vec![PermissionOp::BookmarkSubmit, PermissionOp::BookmarkList]
-                .into_iter()
-                .map(|op| Permission::builder_v1().operation(op).build().into())
-                .collect()
+        // A ticket that names nothing may only read the project.
+        let operations: &[PermissionOp] = if create.permissions.is_empty() {
+            &[PermissionOp::ProjectRead]
         } else {
-            create
-                .permissions
-                .iter()
-                .map(|op| Permission::builder_v1().operation(*op).build().into())
-                .collect()
+            create.permissions.as_slice()
         };
+        let permissions: Vec<Permission> = operations
+            .iter()
+            .map(|op| Permission::builder_v1().operation(*op).build().into())
+            .collect();
         let request = IssueTicket::builder_v1()
             .project_name(create.project_name.clone())
             .project(project)
@@ -65,10 +62,12 @@ impl TicketService {
             .actor_id(request.actor_id)
             .build();
 
-        let token = Token::issue(claims);
+        let id = TicketId::new();
+        let token = Token::issue(id, claims);
         let link = Link::new(request.target.clone(), token);
 
         let ticket = Ticket::builder()
+            .id(id)
             .actor_id(request.actor_id)
             .project_name(request.project_name.clone())
             .project_id(request.project.id)
@@ -76,7 +75,6 @@ impl TicketService {
             .granted_by(request.actor_id)
             .permissions(request.permissions.clone())
             .build();
-        let id = ticket.id;
 
         let new_event = NewEvent::builder()
             .data(Events::Ticket(TicketEvents::TicketIssued(
@@ -153,4 +151,138 @@ impl TicketService {
                 .into(),
         ))
     }
+
+    /// Revoke a ticket. The projection is awaited before responding, so
+    /// HTTP auth and bridge requests refuse the ticket from then on.
+    pub(crate) async fn revoke(
+        scope: &Scope<AtHost>,
+        mailbox: &Mailbox,
+        request: &RevokeTicket,
+    ) -> Result<TicketResponse, TicketError> {
+        let RevokeTicket::V1(revocation) = request;
+        let id = revocation.key.resolve()?;
+        let ticket = Self::retire(scope, mailbox, id, None).await?;
+        Ok(TicketResponse::Revoked(
+            TicketRevokedResponse::builder_v1()
+                .ticket(ticket)
+                .build()
+                .into(),
+        ))
+    }
+
+    /// Replace a ticket with a freshly-tokened successor bound to the same
+    /// target and permissions, then revoke the original.
+    pub(crate) async fn rotate(
+        scope: &Scope<AtHost>,
+        state: &ServerState,
+        request: &RotateTicket,
+    ) -> Result<TicketResponse, TicketError> {
+        let RotateTicket::V1(rotation) = request;
+        let id = rotation.key.resolve()?;
+        let old = TicketRepo::new(scope)
+            .fetch(&id)
+            .await?
+            .ok_or(TicketError::NotFound(id))?;
+
+        if old.revoked_at.is_some() {
+            return Err(TicketError::AlreadyRevoked(id));
+        }
+
+        let project = ProjectRepo::new(scope)
+            .get(&old.project_name)
+            .await?
+            .ok_or_else(|| TicketError::ProjectNotFound(old.project_name.clone()))?;
+
+        let request = IssueTicket::builder_v1()
+            .project_name(old.project_name.clone())
+            .project(project)
+            .actor_id(old.actor_id)
+            .target(old.link.target.clone())
+            .permissions(
+                old.grants()
+                    .into_iter()
+                    .map(|op| Permission::builder_v1().operation(op).build().into())
+                    .collect(),
+            )
+            .build()
+            .into();
+        let ticket = Self::issue(scope, state.mailbox(), &request).await?;
+
+        Self::retire(scope, state.mailbox(), id, Some(ticket.id)).await?;
+
+        let peer_link = PeerLink::new(state.host_identity().address, ticket.link.clone());
+        let uri = OneirosUri::Peer(peer_link).to_string();
+
+        Ok(TicketResponse::Rotated(
+            TicketRotatedResponse::builder_v1()
+                .revoked(id)
+                .ticket(ticket)
+                .uri(uri)
+                .build()
+                .into(),
+        ))
+    }
+
+    /// List every recorded use of a ticket.
+    pub(crate) async fn audit(
+        scope: &Scope<AtHost>,
+        request: &AuditTicket,
+    ) -> Result<TicketResponse, TicketError> {
+        let AuditTicket::V1(audit) = request;
+        let id = audit.key.resolve()?;
+        let repo = TicketRepo::new(scope);
+        repo.get(&id).await?.ok_or(TicketError::NotFound(id))?;
+        let uses = repo.uses(&id).await?;
+        Ok(TicketResponse::Audited(
+            TicketAuditResponse::builder_v1()
+                .ticket_id(id)
+                .uses(uses)
+                .build()
+                .into(),
+        ))
+    }
+
+    /// Emit the revocation and wait until the projection reflects it.
+    async fn retire(
+        scope: &Scope<AtHost>,
+        mailbox: &Mailbox,
+        id: TicketId,
+        replaced_by: Option<TicketId>,
+    ) -> Result<Ticket, TicketError> {
+        let repo = TicketRepo::new(scope);
+        let ticket = repo.get(&id).await?.ok_or(TicketError::NotFound(id))?;
+
+        if ticket.revoked_at.is_some() {
+            return Err(TicketError::AlreadyRevoked(id));
+        }
+
+        let new_event = NewEvent::builder()
+            .data(Events::Ticket(TicketEvents::TicketRevoked(
+                TicketRevoked::builder_v1()
+                    .ticket_id(id)
+                    .revoked_at(Timestamp::now())
+                    .maybe_replaced_by(replaced_by)
+                    .build()
+                    .into(),
+            )))
+            .build();
+
+        mailbox.tell(HostMessage::from(
+            AppendHostLog::builder()
+                .scope(scope.clone())
+                .event(new_event)
+                .build(),
+        ));
+
+        let revoked = scope
+            .config()
+            .fetch
+            .eventual(|| async {
+                Ok::<_, TicketError>(repo.get(&id).await?.filter(|t| t.revoked_at.is_some()))
+            })
+            .await?
+            .ok_or(TicketError::NotFound(id))?;
+
+        Ok(revoked)
+    }
 }
@@ -12,15 +12,34 @@ impl<'a> TicketStore<'a> {
     }
 
     pub(crate) fn handle(&self, event: &StoredEvent) -> Result<(), EventError> {
-        if let Event::Known(Events::Ticket(TicketEvents::TicketIssued(issued))) = &event.data {
-            let ticket = issued.current()?.ticket;
-            self.write_ticket(&ticket)?;
+        let Event::Known(Events::Ticket(ticket_event)) = &event.data else {
+            return Ok(());
+        };
+
+        match ticket_event {
+            TicketEvents::TicketIssued(issued) => {
+                let ticket = issued.current()?.ticket;
+                self.write_ticket(&ticket)?;
+            }
+            TicketEvents::TicketUsed(used) => self.record_use(&used.current()?)?,
+            TicketEvents::TicketRevoked(revoked) => {
+                let revoked = revoked.current()?;
+                self.conn.execute(
+                    "UPDATE tickets SET revoked_at = ?1 WHERE id = ?2 AND revoked_at IS NULL",
+                    params![
+                        revoked.revoked_at.as_string(),
+                        revoked.ticket_id.to_string()
+                    ],
+                )?;
+            }
+            TicketEvents::TicketRejected(_) => {}
         }
         Ok(())
     }
 
     pub(crate) fn reset(&self) -> Result<(), EventError> {
         self.conn.execute("DELETE FROM tickets", [])?;
+        self.conn.execute("DELETE FROM ticket_uses", [])?;
         Ok(())
     }
 
@@ -39,13 +58,45 @@ impl<'a> TicketStore<'a> {
                 max_uses INTEGER,
                 uses INTEGER NOT NULL DEFAULT 0,
                 created_at TEXT NOT NULL DEFAULT '',
-                permissions TEXT NOT NULL DEFAULT '[]'
+                permissions TEXT NOT NULL DEFAULT '[]',
+                scoped INTEGER NOT NULL DEFAULT 0
             )",
         )?;
         // Migration: add column if missing from older schemas.
         self.conn
             .execute_batch("ALTER TABLE tickets ADD COLUMN permissions TEXT NOT NULL DEFAULT '[]'")
             .ok();
+        // Rows projected before scoped permissions are unscoped.
+        self.conn
+            .execute_batch("ALTER TABLE tickets ADD COLUMN scoped INTEGER NOT NULL DEFAULT 0")
+            .ok();
+        self.conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS ticket_uses (
+                ticket_id TEXT NOT NULL,
+                peer TEXT,
+                operation TEXT,
+                used_at TEXT NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS ticket_uses_by_ticket ON ticket_uses (ticket_id, used_at)",
+        )?;
+        Ok(())
+    }
+
+    fn record_use(&self, used: &TicketUsedV2) -> Result<(), EventError> {
+        self.conn.execute(
+            "INSERT INTO ticket_uses (ticket_id, peer, operation, used_at)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![
+                used.ticket_id.to_string(),
+                used.peer.map(|peer| peer.to_string()),
+                used.operation.map(|op| op.to_string()),
+                used.used_at.as_string(),
+            ],
+        )?;
+        self.conn.execute(
+            "UPDATE tickets SET uses = uses + 1 WHERE id = ?1",
+            params![used.ticket_id.to_string()],
+        )?;
         Ok(())
     }
 
@@ -56,9 +107,9 @@ impl<'a> TicketStore<'a> {
         self.conn.execute(
             "insert or replace into tickets (
                 id, actor_id, project_name, project_id, token, target, granted_by,
-                expires_at, revoked_at, max_uses, uses, created_at, permissions
+                expires_at, revoked_at, max_uses, uses, created_at, permissions, scoped
              )
-             values (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+             values (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
             params![
                 ticket.id.to_string(),
                 ticket.actor_id.to_string(),
@@ -73,8 +124,82 @@ impl<'a> TicketStore<'a> {
                 ticket.uses,
                 ticket.created_at.as_string(),
                 permissions_json,
+                ticket.scoped,
             ],
         )?;
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stored(event: TicketEvents) -> StoredEvent {
+        StoredEvent::builder()
+            .id(EventId::new())
+            .sequence(0)
+            .data(Event::Known(Events::Ticket(event)))
+            .source(Source::default())
+            .created_at(Timestamp::now())
+            .build()
+    }
+
+    #[test]
+    fn uses_and_revocation_project_onto_the_ticket() -> Result<(), Box<dyn core::error::Error>> {
+        let db = rusqlite::Connection::open_in_memory()?;
+        let store = TicketStore::new(&db);
+        store.migrate()?;
+
+        let actor_id = ActorId::new();
+        let ticket = Ticket::builder()
+            .actor_id(actor_id)
+            .project_name(ProjectName::new("test-project"))
+            .project_id(ProjectId::new())
+            .link(Link::new(
+                Ref::project(ProjectId::new()),
+                Token::from("token"),
+            ))
+            .granted_by(actor_id)
+            .build();
+        let id = ticket.id;
+
+        store.handle(&stored(TicketEvents::TicketIssued(
+            TicketIssued::builder_v1().ticket(ticket).build().into(),
+        )))?;
+        store.handle(&stored(TicketEvents::TicketUsed(
+            TicketUsed::builder_v2()
+                .ticket_id(id)
+                .used_at(Timestamp::now())
+                .peer(PeerKey::from_bytes([7; 32]))
+                .operation(BridgeOp::Diff)
+                .build()
+                .into(),
+        )))?;
+        store.handle(&stored(TicketEvents::TicketRevoked(
+            TicketRevoked::builder_v1()
+                .ticket_id(id)
+                .revoked_at(Timestamp::now())
+                .build()
+                .into(),
+        )))?;
+
+        let (uses, revoked_at): (i64, Option<String>) = db.query_row(
+            "SELECT uses, revoked_at FROM tickets WHERE id = ?1",
+            params![id.to_string()],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+        assert_eq!(uses, 1);
+        assert!(revoked_at.is_some());
+
+        let (peer, operation): (String, String) = db.query_row(
+            "SELECT peer, operation FROM ticket_uses WHERE ticket_id = ?1",
+            params![id.to_string()],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+        assert_eq!(peer, PeerKey::from_bytes([7; 32]).to_string());
+        assert_eq!(operation, "bridge-diff");
+
+        Ok(())
+    }
+}
@@ -62,6 +62,54 @@ impl TicketView {
                     String::new(),
                 )
             }
+            TicketResponse::Revoked(TicketRevokedResponse::V1(revoked)) => {
+                let prompt = Confirmation::new("Ticket", revoked.ticket.id.to_string(), "revoked")
+                    .to_string();
+                Rendered::new(
+                    TicketResponse::Revoked(TicketRevokedResponse::V1(revoked)),
+                    prompt,
+                    String::new(),
+                )
+            }
+            TicketResponse::Rotated(TicketRotatedResponse::V1(rotated)) => {
+                let prompt = format!(
+                    "{}\n{}",
+                    Confirmation::new(
+                        "Ticket",
+                        rotated.revoked.to_string(),
+                        format!("rotated to {}", rotated.ticket.id),
+                    ),
+                    rotated.uri,
+                );
+                Rendered::new(
+                    TicketResponse::Rotated(TicketRotatedResponse::V1(rotated)),
+                    prompt,
+                    String::new(),
+                )
+            }
+            TicketResponse::Audited(TicketAuditResponse::V1(audit)) => {
+                let mut table = Table::new(vec![
+                    Column::new("Used at"),
+                    Column::new("Peer"),
+                    Column::new("Operation"),
+                ]);
+                for used in &audit.uses {
+                    table.push_row(vec![
+                        used.used_at.as_string(),
+                        used.peer.map(|peer| peer.to_string()).unwrap_or_default(),
+                        used.operation.map(|op| op.to_string()).unwrap_or_default(),
+                    ]);
+                }
+                let prompt = format!(
+                    "{}\n\n{table}",
+                    format_args!("{} uses of ticket {}", audit.uses.len(), audit.ticket_id).muted(),
+                );
+                Rendered::new(
+                    TicketResponse::Audited(TicketAuditResponse::V1(audit)),
+                    prompt,
+                    String::new(),
+                )
+            }
         }
     }
 }
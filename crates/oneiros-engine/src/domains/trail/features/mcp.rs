@@ -0,0 +1,70 @@
+use crate::*;
+
+pub(crate) struct TrailMcp;
+
+impl TrailMcp {
+    pub(crate) fn defs(&self) -> Vec<ToolDef> {
+        trail_mcp::tool_defs()
+    }
+
+    #[expect(deprecated)]
+    pub(crate) async fn dispatch(
+        &self,
+        context: &ProjectLog,
+        tool_name: &ToolName,
+        params: &serde_json::Value,
+    ) -> Result<McpResponse, ToolError> {
+        trail_mcp::dispatch(context, tool_name, params).await
+    }
+}
+
+mod trail_mcp {
+    use crate::*;
+
+    pub(crate) fn tool_defs() -> Vec<ToolDef> {
+        vec![
+            Tool::<TrailOf>::new(
+                TrailRequestType::TrailOf,
+                "Walk back from an entity to the events that touched it",
+                PermissionOp::ProjectRead,
+            )
+            .def(),
+            Tool::<TrailFrom>::new(
+                TrailRequestType::TrailFrom,
+                "Walk forward from an event to the entities it emitted",
+                PermissionOp::ProjectRead,
+            )
+            .def(),
+        ]
+    }
+
+    #[expect(deprecated)]
+    pub(crate) async fn dispatch(
+        context: &ProjectLog,
+        tool_name: &ToolName,
+        params: &serde_json::Value,
+    ) -> Result<McpResponse, ToolError> {
+        let request_type: TrailRequestType = tool_name
+            .as_str()
+            .parse()
+            .map_err(|_| ToolError::UnknownTool(tool_name.to_string()))?;
+
+        let scope = context.scope().map_err(Error::from)?;
+        let response = match request_type {
+            TrailRequestType::TrailOf => {
+                let TrailOf::V1(of) = serde_json::from_value(params.clone())?;
+                TrailService::of(scope, &of.r#ref)
+                    .await
+                    .map_err(Error::from)?
+            }
+            TrailRequestType::TrailFrom => {
+                let TrailFrom::V1(from) = serde_json::from_value(params.clone())?;
+                TrailService::from(scope, from.event)
+                    .await
+                    .map_err(Error::from)?
+            }
+        };
+
+        Ok(TrailView::new(response).mcp())
+    }
+}
@@ -1,9 +1,11 @@
 mod cli;
 mod http;
+mod mcp;
 mod projections;
 mod skills;
 
 pub(crate) use cli::*;
 pub(crate) use http::*;
+pub(crate) use mcp::*;
 pub(crate) use projections::*;
 pub(crate) use skills::*;
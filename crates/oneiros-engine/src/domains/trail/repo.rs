@@ -52,6 +52,31 @@ impl<'a> TrailRepo<'a> {
         }
         Ok(refs)
     }
+
+    /// Wait, within the fetch window, for the trail to record a freshly
+    /// appended event — how readers that hear of an append from the
+    /// event feed know the bookmark's projections have caught up to it.
+    /// Returns `false` for events that touch no entity, which the trail
+    /// never records, and when the window runs out.
+    pub(crate) async fn settled(&self, stored: &StoredEvent) -> Result<bool, EventError> {
+        let Event::Known(events) = &stored.data else {
+            return Ok(false);
+        };
+        if derive_refs(events).is_empty() {
+            return Ok(false);
+        }
+
+        let recorded = self
+            .scope
+            .config()
+            .fetch
+            .eventual(|| async {
+                let refs = self.refs_from(stored.id).await?;
+                Ok::<_, EventError>((!refs.is_empty()).then_some(()))
+            })
+            .await?;
+        Ok(recorded.is_some())
+    }
 }
 
 type TrailRow = (String, String, String, String);
@@ -82,7 +82,7 @@ impl<'a> TrailStore<'a> {
 ///   events) emit nothing — Ref takes typed IDs, and we would need a
 ///   name→id resolution at projection time that doesn't belong here. When
 ///   in doubt, emit nothing rather than over-couple.
-fn derive_refs(event: &Events) -> Vec<Ref> {
+pub(crate) fn derive_refs(event: &Events) -> Vec<Ref> {
     match event {
         // Vocabulary — set carries the entity (named), remove carries the name.
         Events::Level(LevelEvents::LevelSet(set)) => {
@@ -121,6 +121,9 @@ fn derive_refs(event: &Events) -> Vec<Ref> {
         Events::Urge(UrgeEvents::UrgeRemoved(removed)) => {
             one(removed.clone().current(), |v| Ref::urge(v.name))
         }
+        Events::Urge(UrgeEvents::UrgeTuned(tuned)) => {
+            one(tuned.clone().current(), |v| Ref::urge(v.urge))
+        }
 
         // Agents — name-keyed remove doesn't emit (no name→id resolution at
         // projection time).
@@ -136,9 +139,25 @@ fn derive_refs(event: &Events) -> Vec<Ref> {
         Events::Cognition(CognitionEvents::CognitionAdded(added)) => {
             one(added.clone().current(), |v| Ref::cognition(v.cognition.id))
         }
+        Events::Cognition(CognitionEvents::CognitionRetracted(retracted)) => {
+            one(retracted.clone().current(), |v| Ref::cognition(v.id))
+        }
         Events::Memory(MemoryEvents::MemoryAdded(added)) => {
             one(added.clone().current(), |v| Ref::memory(v.memory.id))
         }
+        // Supersession and amendment touch both the original and its
+        // replacement.
+        Events::Memory(MemoryEvents::MemorySuperseded(superseded)) => superseded
+            .clone()
+            .current()
+            .map(|v| vec![Ref::memory(v.id), Ref::memory(v.replacement.id)])
+            .unwrap_or_default(),
+        Events::Memory(MemoryEvents::MemoryDemoted(demoted)) => {
+            one(demoted.clone().current(), |v| Ref::memory(v.id))
+        }
+        Events::Memory(MemoryEvents::MemoryArchived(archived)) => {
+            one(archived.clone().current(), |v| Ref::memory(v.id))
+        }
         Events::Experience(ExperienceEvents::ExperienceCreated(created)) => {
             one(created.clone().current(), |v| {
                 Ref::experience(v.experience.id)
@@ -147,6 +166,11 @@ fn derive_refs(event: &Events) -> Vec<Ref> {
         Events::Experience(ExperienceEvents::ExperienceDescriptionUpdated(updated)) => {
             one(updated.clone().current(), |v| Ref::experience(v.id))
         }
+        Events::Experience(ExperienceEvents::ExperienceAmended(amended)) => amended
+            .clone()
+            .current()
+            .map(|v| vec![Ref::experience(v.id), Ref::experience(v.replacement.id)])
+            .unwrap_or_default(),
         Events::Experience(ExperienceEvents::ExperienceSensationUpdated(updated)) => {
             one(updated.clone().current(), |v| Ref::experience(v.id))
         }
@@ -180,12 +204,23 @@ fn derive_refs(event: &Events) -> Vec<Ref> {
         Events::Project(ProjectEvents::ProjectCreated(created)) => {
             one(created.clone().current(), |v| Ref::project(v.project.id))
         }
+        // A redaction notice touches what it redacted; the host-log audits
+        // are name-keyed, and a tombstone has nothing left to point at.
+        Events::Project(ProjectEvents::ContentRedacted(redacted)) => {
+            one(redacted.clone().current(), |v| v.target)
+        }
+        Events::Project(ProjectEvents::ProjectRedacted(_))
+        | Events::Project(ProjectEvents::RedactionRefused(_))
+        | Events::Project(ProjectEvents::Tombstone(_)) => Vec::new(),
         Events::Ticket(TicketEvents::TicketIssued(issued)) => {
             one(issued.clone().current(), |v| Ref::ticket(v.ticket.id))
         }
         Events::Ticket(TicketEvents::TicketUsed(used)) => {
             one(used.clone().current(), |v| Ref::ticket(v.ticket_id))
         }
+        Events::Ticket(TicketEvents::TicketRevoked(revoked)) => {
+            one(revoked.clone().current(), |v| Ref::ticket(v.ticket_id))
+        }
         Events::Ticket(TicketEvents::TicketRejected(rejected)) => rejected
             .clone()
             .current()
@@ -212,6 +247,12 @@ fn derive_refs(event: &Events) -> Vec<Ref> {
         Events::Bookmark(BookmarkEvents::BookmarkUnfollowed(unfollowed)) => {
             one(unfollowed.clone().current(), |v| Ref::follow(v.follow_id))
         }
+        Events::Bookmark(BookmarkEvents::BookmarkDeleted(deleted)) => {
+            one(deleted.clone().current(), |v| Ref::bookmark(v.id))
+        }
+        Events::Bookmark(BookmarkEvents::BookmarkRenamed(renamed)) => {
+            one(renamed.clone().current(), |v| Ref::bookmark(v.id))
+        }
 
         // Slices — metadata events, no entity refs to emit.
         Events::Slice(_) => Vec::new(),
@@ -11,6 +11,44 @@ impl TrailView {
         Self { response }
     }
 
+    pub(crate) fn mcp(&self) -> McpResponse {
+        match &self.response {
+            TrailResponse::TrailEvents(TrailEventsResponse::V1(events)) => {
+                let mut md = format!(
+                    "# Trail\n\n{} event(s) touched this entity\n\n",
+                    events.items.len()
+                );
+                for entry in &events.items {
+                    md.push_str(&format!(
+                        "- {} **{}** — {}\n",
+                        entry.event_id,
+                        entry.event_type,
+                        entry.created_at.as_string()
+                    ));
+                }
+                McpResponse::new(md).hint(Hint::suggest(
+                    "trail-from",
+                    "See what one of these events emitted",
+                ))
+            }
+            TrailResponse::EmittedRefs(EmittedRefsResponse::V1(refs)) => {
+                let mut md = format!(
+                    "# Trail\n\n{} entity(ies) emitted by this event\n\n",
+                    refs.items.len()
+                );
+                for entity_ref in &refs.items {
+                    md.push_str(&format!("- {entity_ref}\n"));
+                }
+                McpResponse::new(md).hint(Hint::suggest(
+                    "trail-of",
+                    "See every event that touched one of these",
+                ))
+            }
+            TrailResponse::NoTrail => McpResponse::new("No trail.")
+                .hint(Hint::suggest("search-query", "Search for the entity")),
+        }
+    }
+
     pub(crate) fn render(self) -> Rendered<TrailResponse> {
         match self.response {
             TrailResponse::TrailEvents(TrailEventsResponse::V1(events)) => {
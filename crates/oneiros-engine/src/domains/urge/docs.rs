@@ -5,6 +5,7 @@ pub(crate) enum UrgeDocs {
     Set,
     Show,
     Remove,
+    Tune,
 }
 
 impl UrgeDocs {
@@ -42,6 +43,14 @@ impl UrgeDocs {
                 .summary("Remove an urge")
                 .description("Delete a cognitive drive from the project's vocabulary.")
                 .build(),
+            Self::Tune => ResourceDocs::builder()
+                .tag(tag)
+                .nickname("tune-urge")
+                .summary("Tune an urge's gauge")
+                .description(
+                    "Override an urge's gauge weights and midpoints for the project or one persona.",
+                )
+                .build(),
         }
     }
 }
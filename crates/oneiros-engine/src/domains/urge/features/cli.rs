@@ -13,6 +13,7 @@ pub(crate) enum UrgeCommands {
     Show(GetUrge),
     List(ListUrges),
     Remove(RemoveUrge),
+    Tune(TuneUrge),
 }
 
 impl UrgeCommands {
@@ -24,6 +25,7 @@ impl UrgeCommands {
             Self::Show(lookup) => lookup.execute_request(&client).await?,
             Self::List(listing) => listing.execute_request(&client).await?,
             Self::Remove(removal) => removal.execute_request(&client).await?,
+            Self::Tune(tuning) => tuning.execute_request(&client).await?,
         };
 
         let response: UrgeResponse = serde_json::from_slice(&bytes)?;
@@ -42,6 +42,15 @@ impl UrgeRouter {
                             .input::<NamePathParam<UrgeName>>()
                             .response::<200, Json<UrgeRemovedResponse>>()
                     }),
+                )
+                .api_route(
+                    "/{name}/tuning",
+                    routing::put_with(tune, |op| {
+                        resource_op!(op, UrgeDocs::Tune)
+                            .security_requirement("BearerToken")
+                            .response::<200, Json<UrgeTunedResponse>>()
+                            .input::<NamePathParam<UrgeName>>()
+                    }),
                 ),
         )
     }
@@ -92,3 +101,16 @@ async fn remove(
         .await?,
     ))
 }
+
+async fn tune(
+    scope: Scope<AtBookmark>,
+    mailbox: Mailbox,
+    Path(name): Path<UrgeName>,
+    Json(body): Json<TuneUrge>,
+) -> Result<Json<UrgeResponse>, UrgeError> {
+    let TuneUrge::V1(mut tuning) = body;
+    tuning.name = name;
+    Ok(Json(
+        UrgeService::tune(&scope, &mailbox, &TuneUrge::V1(tuning)).await?,
+    ))
+}
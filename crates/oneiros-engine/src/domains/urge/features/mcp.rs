@@ -34,9 +34,9 @@ mod urge_mcp {
                 let response = UrgeService::get(scope, get).await.map_err(Error::from)?;
                 Ok(UrgeView::new(response).mcp())
             }
-            UrgeRequest::SetUrge(_) | UrgeRequest::RemoveUrge(_) => Err(ToolError::NotAResource(
-                "Mutations are tools, not resources".to_string(),
-            )),
+            UrgeRequest::SetUrge(_) | UrgeRequest::RemoveUrge(_) | UrgeRequest::TuneUrge(_) => Err(
+                ToolError::NotAResource("Mutations are tools, not resources".to_string()),
+            ),
         }
     }
 }
@@ -9,6 +9,7 @@ impl UrgeSkills {
             Skill::new("urge-show", include_str!("skills/show.md")),
             Skill::new("urge-list", include_str!("skills/list.md")),
             Skill::new("urge-remove", include_str!("skills/remove.md")),
+            Skill::new("urge-tune", include_str!("skills/tune.md")),
         ]
     }
 }
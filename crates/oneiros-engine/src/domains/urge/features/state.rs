@@ -8,12 +8,23 @@ impl UrgeState {
             match urge_event {
                 UrgeEvents::UrgeSet(setting) => {
                     if let Ok(current) = setting.current() {
+                        if let Some(gauge) = &current.urge.gauge {
+                            canon.gauge_lenses.learn(gauge);
+                        }
                         canon.urges.set(&current.urge);
                     }
                 }
                 UrgeEvents::UrgeRemoved(removal) => {
                     if let Ok(current) = removal.current() {
                         canon.urges.remove(&current.name);
+                        canon.tunings.remove_urge(&current.name);
+                    }
+                }
+                UrgeEvents::UrgeTuned(tuned) => {
+                    if let Ok(current) = tuned.current() {
+                        canon
+                            .tunings
+                            .set(&current.urge, current.layer, current.tuning);
                     }
                 }
             };
@@ -12,6 +12,10 @@ pub(crate) struct Urge {
     pub(crate) description: Description,
     #[builder(into)]
     pub(crate) prompt: Prompt,
+    /// How this urge builds pressure; the built-in urges have their own
+    /// gauges when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) gauge: Option<GaugeDefinition>,
 }
 
 impl Indexable<UrgeName> for Urge {
@@ -11,6 +11,15 @@ pub(crate) enum UrgeError {
     #[error("Urge not found: {0}")]
     NotFound(UrgeName),
 
+    #[error(transparent)]
+    Tuning(#[from] GaugeTuningError),
+
+    #[error("gauge factor has an invalid lens: {0}")]
+    Lens(#[from] LensError),
+
+    #[error("Persona not found: {0}")]
+    PersonaNotFound(PersonaName),
+
     #[error(transparent)]
     Resolve(#[from] crate::ResolveError),
 
@@ -36,7 +45,10 @@ impl IntoResponse for UrgeError {
     fn into_response(self) -> Response {
         let (status, message) = match &self {
             UrgeError::NotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
-            UrgeError::Resolve(_) => (StatusCode::UNPROCESSABLE_ENTITY, self.to_string()),
+            UrgeError::PersonaNotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
+            UrgeError::Tuning(_) | UrgeError::Lens(_) | UrgeError::Resolve(_) => {
+                (StatusCode::UNPROCESSABLE_ENTITY, self.to_string())
+            }
             UrgeError::Database(_) | UrgeError::Event(_) => {
                 (StatusCode::INTERNAL_SERVER_ERROR, self.to_string())
             }
@@ -5,10 +5,24 @@ use crate::*;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Kinded)]
 #[serde(rename_all = "kebab-case", tag = "type", content = "data")]
-#[kinded(kind = UrgeEventsType, display = "kebab-case")]
+#[kinded(
+    kind = UrgeEventsType,
+    display = "kebab-case",
+    attrs(
+        expect(
+            clippy::enum_variant_names,
+            reason = "We use these for `type` notation in serde"
+        )
+    )
+)]
+#[expect(
+    clippy::enum_variant_names,
+    reason = "We use these for `type` notation in serde"
+)]
 pub(crate) enum UrgeEvents {
     UrgeSet(UrgeSet),
     UrgeRemoved(UrgeRemoved),
+    UrgeTuned(UrgeTuned),
 }
 
 versioned! {
@@ -27,6 +41,17 @@ versioned! {
     }
 }
 
+versioned! {
+    pub(crate) enum UrgeTuned {
+        V1 => {
+            #[builder(into)] pub(crate) urge: UrgeName,
+            pub(crate) layer: TuningLayer,
+            /// The layer's whole tuning; empty clears it.
+            pub(crate) tuning: GaugeTuning,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -44,6 +69,7 @@ mod tests {
         let cases = [
             (UrgeEventsType::UrgeSet, "urge-set"),
             (UrgeEventsType::UrgeRemoved, "urge-removed"),
+            (UrgeEventsType::UrgeTuned, "urge-tuned"),
         ];
         for (event_type, expectation) in cases {
             assert_eq!(&event_type.to_string(), expectation);
@@ -16,6 +16,13 @@ versioned! {
             #[arg(long, default_value = "")]
             #[builder(default, into)]
             pub(crate) prompt: Prompt,
+            /// A gauge factor as `MEASURE:MIDPOINT:WEIGHT[=ARG]`; repeat
+            /// for more. Urges without factors use their built-in gauge,
+            /// if they have one.
+            #[arg(long = "factor")]
+            #[serde(default, skip_serializing_if = "Vec::is_empty")]
+            #[builder(default)]
+            pub(crate) factors: Vec<GaugeFactor>,
         }
     }
 }
@@ -53,6 +60,27 @@ versioned! {
     }
 }
 
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum TuneUrge {
+        #[derive(clap::Args)]
+        V1 => {
+            #[builder(into)] pub(crate) name: UrgeName,
+            /// Tune only for agents of this persona, over the project's
+            /// tuning.
+            #[arg(long)]
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            pub(crate) persona: Option<PersonaName>,
+            /// A gauge parameter as `NAME=VALUE`; repeat for more. Tuning
+            /// with no parameters clears the layer.
+            #[arg(long = "param")]
+            #[serde(default, skip_serializing_if = "Vec::is_empty")]
+            #[builder(default)]
+            pub(crate) params: Vec<GaugeParam>,
+        }
+    }
+}
+
 resource_requests! {
     SetUrge => |this, client| {
         let SetUrge::V1(body) = this;
@@ -66,6 +94,10 @@ resource_requests! {
         let RemoveUrge::V1(removal) = this;
         client.delete(&format!("/urges/{}", removal.name)).await
     },
+    TuneUrge => |this, client| {
+        let TuneUrge::V1(tuning) = this;
+        client.put(&format!("/urges/{}/tuning", tuning.name), this).await
+    },
     ListUrges => |this, client| {
         let ListUrges::V1(listing) = this;
         let query = format!(
@@ -84,6 +116,7 @@ pub(crate) enum UrgeRequest {
     GetUrge(GetUrge),
     ListUrges(ListUrges),
     RemoveUrge(RemoveUrge),
+    TuneUrge(TuneUrge),
 }
 
 #[cfg(test)]
@@ -97,6 +130,7 @@ mod tests {
             (UrgeRequestType::GetUrge, "get-urge"),
             (UrgeRequestType::ListUrges, "list-urges"),
             (UrgeRequestType::RemoveUrge, "remove-urge"),
+            (UrgeRequestType::TuneUrge, "tune-urge"),
         ];
 
         for (request_type, expectation) in cases {
@@ -13,6 +13,7 @@ pub(crate) enum UrgeResponse {
     Urges(UrgesResponse),
     NoUrges,
     UrgeRemoved(UrgeRemovedResponse),
+    UrgeTuned(UrgeTunedResponse),
 }
 
 versioned! {
@@ -47,3 +48,14 @@ versioned! {
         }
     }
 }
+
+versioned! {
+    #[derive(JsonSchema)]
+    pub(crate) enum UrgeTunedResponse {
+        V1 => {
+            #[builder(into)] pub(crate) urge: UrgeName,
+            pub(crate) layer: TuningLayer,
+            pub(crate) tuning: GaugeTuning,
+        }
+    }
+}
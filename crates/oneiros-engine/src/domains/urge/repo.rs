@@ -21,22 +21,25 @@ impl<'a> UrgeRepo<'a> {
 
     pub(crate) async fn get(&self, name: &UrgeName) -> Result<Option<Urge>, EventError> {
         let db = BookmarkDb::open(self.scope).await?;
-        let mut stmt = db.prepare("SELECT name, description, prompt FROM urges WHERE name = ?1")?;
+        let mut stmt =
+            db.prepare("SELECT name, description, prompt, gauge FROM urges WHERE name = ?1")?;
 
         let result = stmt.query_row(params![name.to_string()], |row| {
             Ok((
                 row.get::<_, String>(0)?,
                 row.get::<_, String>(1)?,
                 row.get::<_, String>(2)?,
+                row.get::<_, Option<String>>(3)?,
             ))
         });
 
         match result {
-            Ok((name, description, prompt)) => Ok(Some(
+            Ok((name, description, prompt, gauge)) => Ok(Some(
                 Urge::builder()
                     .name(name)
                     .description(description)
                     .prompt(prompt)
+                    .maybe_gauge(gauge.map(|g| serde_json::from_str(&g)).transpose()?)
                     .build(),
             )),
             Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
@@ -53,28 +56,63 @@ impl<'a> UrgeRepo<'a> {
         };
 
         let mut stmt = db.prepare(
-            "SELECT name, description, prompt FROM urges ORDER BY name LIMIT ?1 OFFSET ?2",
+            "SELECT name, description, prompt, gauge FROM urges ORDER BY name LIMIT ?1 OFFSET ?2",
         )?;
 
-        let items = stmt
+        let rows = stmt
             .query_map(rusqlite::params![filters.limit, filters.offset], |row| {
                 Ok((
                     row.get::<_, String>(0)?,
                     row.get::<_, String>(1)?,
                     row.get::<_, String>(2)?,
+                    row.get::<_, Option<String>>(3)?,
                 ))
             })?
-            .collect::<Result<Vec<(String, String, String)>, _>>()?
-            .into_iter()
-            .map(|(name, description, prompt)| {
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut items = Vec::with_capacity(rows.len());
+        for (name, description, prompt, gauge) in rows {
+            items.push(
                 Urge::builder()
                     .name(name)
                     .description(description)
                     .prompt(prompt)
-                    .build()
-            })
-            .collect();
+                    .maybe_gauge(gauge.map(|g| serde_json::from_str(&g)).transpose()?)
+                    .build(),
+            );
+        }
 
         Ok(Listed::new(items, total))
     }
+
+    /// The tunings recorded for an urge, project layer first. Cleared
+    /// layers are left out.
+    pub(crate) async fn tunings(
+        &self,
+        name: &UrgeName,
+    ) -> Result<Vec<(TuningLayer, GaugeTuning)>, EventError> {
+        let db = BookmarkDb::open(self.scope).await?;
+        let mut stmt = db
+            .prepare("SELECT persona, tuning FROM urge_tunings WHERE urge = ?1 ORDER BY persona")?;
+        let rows = stmt
+            .query_map(params![name.to_string()], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut tunings = Vec::with_capacity(rows.len());
+        for (persona, tuning) in rows {
+            let tuning: GaugeTuning = serde_json::from_str(&tuning)?;
+            if tuning.is_empty() {
+                continue;
+            }
+            let layer = if persona.is_empty() {
+                TuningLayer::Project
+            } else {
+                TuningLayer::Persona(PersonaName::new(persona))
+            };
+            tunings.push((layer, tuning));
+        }
+        Ok(tunings)
+    }
 }
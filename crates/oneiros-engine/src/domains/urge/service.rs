@@ -9,10 +9,18 @@ impl UrgeService {
         request: &SetUrge,
     ) -> Result<UrgeResponse, UrgeError> {
         let SetUrge::V1(set) = request;
+        for factor in &set.factors {
+            if let Measure::Lens(source) = &factor.measure {
+                GaugeLenses::compile(source)?;
+            }
+        }
         let urge = Urge::builder()
             .name(set.name.clone())
             .description(set.description.clone())
             .prompt(set.prompt.clone())
+            .maybe_gauge(
+                (!set.factors.is_empty()).then(|| GaugeDefinition::new(set.factors.clone())),
+            )
             .build();
         let name = urge.name.clone();
 
@@ -104,4 +112,73 @@ impl UrgeService {
             UrgeRemovedResponse::builder_v1().name(name).build().into(),
         ))
     }
+
+    pub(crate) async fn tune(
+        scope: &Scope<AtBookmark>,
+        mailbox: &Mailbox,
+        request: &TuneUrge,
+    ) -> Result<UrgeResponse, UrgeError> {
+        let TuneUrge::V1(tune) = request;
+        let urge = UrgeRepo::new(scope)
+            .get(&tune.name)
+            .await?
+            .ok_or_else(|| UrgeError::NotFound(tune.name.clone()))?;
+
+        let tuning = GaugeTuning::new(tune.params.clone());
+        tuning.validate_for(&urge)?;
+
+        let layer = match &tune.persona {
+            Some(persona) => {
+                PersonaRepo::new(scope)
+                    .get(persona)
+                    .await?
+                    .ok_or_else(|| UrgeError::PersonaNotFound(persona.clone()))?;
+                TuningLayer::Persona(persona.clone())
+            }
+            None => TuningLayer::Project,
+        };
+
+        let new_event = NewEvent::builder()
+            .data(Events::Urge(UrgeEvents::UrgeTuned(
+                UrgeTuned::builder_v1()
+                    .urge(urge.name.clone())
+                    .layer(layer.clone())
+                    .tuning(tuning.clone())
+                    .build()
+                    .into(),
+            )))
+            .build();
+
+        mailbox.tell(ProjectMessage::from(
+            AppendProjectLog::builder()
+                .scope(scope.clone())
+                .event(new_event)
+                .build(),
+        ));
+
+        let repo = UrgeRepo::new(scope);
+        let tuned = |tunings: Vec<(TuningLayer, GaugeTuning)>| {
+            let current = tunings.into_iter().find(|(l, _)| *l == layer);
+            match current {
+                Some((_, current)) => current == tuning,
+                None => tuning.is_empty(),
+            }
+        };
+        scope
+            .config()
+            .fetch
+            .eventual(|| async {
+                Ok::<_, EventError>(tuned(repo.tunings(&urge.name).await?).then_some(()))
+            })
+            .await?;
+
+        Ok(UrgeResponse::UrgeTuned(
+            UrgeTunedResponse::builder_v1()
+                .urge(urge.name)
+                .layer(layer)
+                .tuning(tuning)
+                .build()
+                .into(),
+        ))
+    }
 }
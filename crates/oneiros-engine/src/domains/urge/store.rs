@@ -16,6 +16,7 @@ impl<'a> UrgeStore<'a> {
             match urge_event {
                 UrgeEvents::UrgeSet(setting) => self.set(setting)?,
                 UrgeEvents::UrgeRemoved(removal) => self.remove(removal)?,
+                UrgeEvents::UrgeTuned(tuned) => self.tune(tuned)?,
             }
         }
         Ok(())
@@ -23,6 +24,7 @@ impl<'a> UrgeStore<'a> {
 
     pub(crate) fn reset(&self) -> Result<(), EventError> {
         self.conn.execute("DELETE FROM urges", [])?;
+        self.conn.execute("DELETE FROM urge_tunings", [])?;
         Ok(())
     }
 
@@ -31,7 +33,21 @@ impl<'a> UrgeStore<'a> {
             "CREATE TABLE IF NOT EXISTS urges (
                 name TEXT PRIMARY KEY,
                 description TEXT NOT NULL DEFAULT '',
-                prompt TEXT NOT NULL DEFAULT ''
+                prompt TEXT NOT NULL DEFAULT '',
+                gauge TEXT
+            )",
+        )?;
+        // Migration: add column if missing from older schemas.
+        self.conn
+            .execute_batch("ALTER TABLE urges ADD COLUMN gauge TEXT")
+            .ok();
+        // The project layer is stored under the empty persona.
+        self.conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS urge_tunings (
+                urge TEXT NOT NULL,
+                persona TEXT NOT NULL DEFAULT '',
+                tuning TEXT NOT NULL,
+                PRIMARY KEY (urge, persona)
             )",
         )?;
         Ok(())
@@ -40,26 +56,30 @@ impl<'a> UrgeStore<'a> {
     pub(crate) fn list(&self) -> Result<Vec<Urge>, EventError> {
         let mut stmt = self
             .conn
-            .prepare("SELECT name, description, prompt FROM urges ORDER BY name")?;
+            .prepare("SELECT name, description, prompt, gauge FROM urges ORDER BY name")?;
 
-        let urges = stmt
+        let rows = stmt
             .query_map([], |row| {
                 Ok((
                     row.get::<_, String>(0)?,
                     row.get::<_, String>(1)?,
                     row.get::<_, String>(2)?,
+                    row.get::<_, Option<String>>(3)?,
                 ))
             })?
-            .collect::<Result<Vec<(String, String, String)>, _>>()?
-            .into_iter()
-            .map(|(name, description, prompt)| {
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut urges = Vec::with_capacity(rows.len());
+        for (name, description, prompt, gauge) in rows {
+            urges.push(
                 Urge::builder()
                     .name(name)
                     .description(description)
                     .prompt(prompt)
-                    .build()
-            })
-            .collect();
+                    .maybe_gauge(gauge.map(|g| serde_json::from_str(&g)).transpose()?)
+                    .build(),
+            );
+        }
 
         Ok(urges)
     }
@@ -75,16 +95,39 @@ impl<'a> UrgeStore<'a> {
             "DELETE FROM urges WHERE name = ?1",
             params![name.to_string()],
         )?;
+        self.conn.execute(
+            "DELETE FROM urge_tunings WHERE urge = ?1",
+            params![name.to_string()],
+        )?;
+        Ok(())
+    }
+
+    fn tune(&self, tuned: &UrgeTuned) -> Result<(), EventError> {
+        let tuned = tuned.current()?;
+        let persona = match &tuned.layer {
+            TuningLayer::Project => String::new(),
+            TuningLayer::Persona(persona) => persona.to_string(),
+        };
+        self.conn.execute(
+            "INSERT OR REPLACE INTO urge_tunings (urge, persona, tuning) VALUES (?1, ?2, ?3)",
+            params![
+                tuned.urge.to_string(),
+                persona,
+                serde_json::to_string(&tuned.tuning)?
+            ],
+        )?;
         Ok(())
     }
 
     fn write_urge(&self, urge: &Urge) -> Result<(), EventError> {
         self.conn.execute(
-            "INSERT OR REPLACE INTO urges (name, description, prompt) VALUES (?1, ?2, ?3)",
+            "INSERT OR REPLACE INTO urges (name, description, prompt, gauge)
+             VALUES (?1, ?2, ?3, ?4)",
             params![
                 urge.name.to_string(),
                 urge.description.to_string(),
-                urge.prompt.to_string()
+                urge.prompt.to_string(),
+                urge.gauge.as_ref().map(serde_json::to_string).transpose()?
             ],
         )?;
         Ok(())
@@ -33,6 +33,9 @@ impl UrgeView {
             UrgeResponse::UrgeRemoved(UrgeRemovedResponse::V1(removed)) => {
                 McpResponse::new(format!("Urge removed: {}", removed.name))
             }
+            UrgeResponse::UrgeTuned(UrgeTunedResponse::V1(tuned)) => {
+                McpResponse::new(format!("Urge tuned: {} ({})", tuned.urge, tuned.layer))
+            }
         }
     }
 
@@ -66,10 +69,15 @@ impl UrgeView {
                 .with_hints(hints)
             }
             UrgeResponse::UrgeDetails(UrgeDetailsResponse::V1(details)) => {
-                let prompt = Detail::new(details.urge.name.to_string())
+                let mut detail = Detail::new(details.urge.name.to_string())
                     .field("description:", details.urge.description.to_string())
-                    .field("prompt:", details.urge.prompt.to_string())
-                    .to_string();
+                    .field("prompt:", details.urge.prompt.to_string());
+                if let Some(gauge) = &details.urge.gauge {
+                    for factor in &gauge.factors {
+                        detail.push_field("factor:", factor.to_string());
+                    }
+                }
+                let prompt = detail.to_string();
                 Rendered::new(
                     UrgeResponse::UrgeDetails(UrgeDetailsResponse::V1(details)),
                     prompt,
@@ -112,6 +120,24 @@ impl UrgeView {
                 )
                 .with_hints(hints)
             }
+            UrgeResponse::UrgeTuned(UrgeTunedResponse::V1(tuned)) => {
+                let mut detail = Detail::new(format!("{} ({})", tuned.urge, tuned.layer));
+                if tuned.tuning.is_empty() {
+                    detail.push_field("params:", "cleared".to_string());
+                }
+                for (name, value) in &tuned.tuning.params {
+                    detail.push_field("param:", format!("{name}={value}"));
+                }
+                let prompt = format!(
+                    "{}\n\n{detail}",
+                    Confirmation::new("Urge", tuned.urge.to_string(), "tuned")
+                );
+                Rendered::new(
+                    UrgeResponse::UrgeTuned(UrgeTunedResponse::V1(tuned)),
+                    prompt,
+                    String::new(),
+                )
+            }
         }
     }
 }
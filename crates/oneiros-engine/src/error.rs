@@ -80,6 +80,8 @@ pub(crate) enum Error {
     #[error(transparent)]
     Storage(#[from] StorageError),
     #[error(transparent)]
+    EventStream(#[from] EventStreamError),
+    #[error(transparent)]
     Host(#[from] HostError),
     #[error(transparent)]
     Lens(#[from] LensError),
@@ -16,6 +16,10 @@ use crate::*;
 /// message protocols.
 #[derive(Debug, Default, Clone, Deserialize, Serialize)]
 #[serde(untagged)]
+#[expect(
+    clippy::large_enum_variant,
+    reason = "Follow events carry a full checkpoint; box them if this grows further"
+)]
 pub(crate) enum Event {
     Known(Events),
     Ephemeral(EphemeralEvents),
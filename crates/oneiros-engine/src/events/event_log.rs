@@ -1,7 +1,7 @@
 //! EventLog — the append-only event persistence primitive.
 //!
 //! This is infrastructure, not a domain. It owns exactly one thing:
-//! durable event persistence. Append, load, import, delete. It does
+//! durable event persistence. Append, load, import, redact. It does
 //! not run projections. It does not broadcast. It is the lowest layer.
 
 use rusqlite::params;
@@ -14,6 +14,10 @@ use crate::*;
 /// external sources, delete transient entries. No projections,
 /// no broadcasting — those are the bus's concern.
 ///
+/// Events appended through a log built with [`EventLog::signed_by`]
+/// carry the host's signature; imported events keep whatever signature
+/// they arrived with.
+///
 /// Two construction modes:
 /// - `new(conn)` — standalone, events DB is the base connection.
 ///   Table references are unqualified (`events`).
@@ -23,6 +27,7 @@ use crate::*;
 pub(crate) struct EventLog<'a> {
     conn: &'a rusqlite::Connection,
     table: &'static str,
+    signer: Option<&'a iroh::SecretKey>,
 }
 
 impl<'a> EventLog<'a> {
@@ -31,6 +36,7 @@ impl<'a> EventLog<'a> {
         Self {
             conn,
             table: "events",
+            signer: None,
         }
     }
 
@@ -39,6 +45,15 @@ impl<'a> EventLog<'a> {
         Self {
             conn,
             table: "events.events",
+            signer: None,
+        }
+    }
+
+    /// Sign appended events with the host's secret key.
+    pub(crate) fn signed_by(self, secret: &'a iroh::SecretKey) -> Self {
+        Self {
+            signer: Some(secret),
+            ..self
         }
     }
 
@@ -50,7 +65,8 @@ impl<'a> EventLog<'a> {
                 event_type text not null,
                 data       text not null,
                 source     text not null default '',
-                created_at text not null
+                created_at text not null,
+                signature  text
             )",
             self.table,
         ))?;
@@ -61,76 +77,130 @@ impl<'a> EventLog<'a> {
     /// Append a single event. Returns the stored form with sequence.
     #[tracing::instrument(skip_all, fields(event_type = event.data.event_type()), err(Display))]
     pub(crate) fn append(&self, event: &NewEvent) -> Result<StoredEvent, EventError> {
-        let id = EventId::new();
-        let data_json = serde_json::to_string(&event.data)?;
-        let source_json = serde_json::to_string(&event.source)?;
-        let event_type = event.data.event_type();
-        let now = Timestamp::now();
+        let mut stored = StoredEvent::builder()
+            .id(EventId::new())
+            .sequence(0)
+            .data(Event::Known(event.data.clone()))
+            .source(event.source)
+            .created_at(Timestamp::now())
+            .build();
 
-        self.conn.execute(
-            &format!(
-                "insert into {} (id, event_type, data, source, created_at) values (?1, ?2, ?3, ?4, ?5)",
-                self.table,
-            ),
-            params![id.to_string(), event_type, data_json, source_json, now.as_string()],
-        )?;
+        if let Some(secret) = self.signer {
+            stored = stored.signed(secret)?;
+        }
 
-        let sequence = self.conn.last_insert_rowid();
+        self.insert("insert", &stored)?;
+        stored.sequence = self.conn.last_insert_rowid();
 
-        Ok(StoredEvent::builder()
-            .id(id)
-            .sequence(sequence)
-            .data(Event::Known(event.data.clone()))
-            .source(event.source)
-            .created_at(now)
-            .build())
+        Ok(stored)
     }
 
     /// Load all events in sequence order. Rows whose event type is not
     /// recognized are logged at warn and filtered out of the result.
     pub(crate) fn load_all(&self) -> Result<Vec<StoredEvent>, EventError> {
-        let mut stmt = self.conn.prepare(&format!(
-            "SELECT id, rowid, data, source, created_at FROM {} ORDER BY rowid",
-            self.table,
-        ))?;
+        self.load_after(0)
+    }
+
+    /// Load the events after `sequence`, in sequence order — how a
+    /// resumed stream catches up. Filters like [`load_all`].
+    ///
+    /// [`load_all`]: EventLog::load_all
+    pub(crate) fn load_after(&self, sequence: i64) -> Result<Vec<StoredEvent>, EventError> {
+        let mut events = Vec::new();
+        self.each_after(sequence, |event| {
+            events.push(event);
+            Ok::<_, EventError>(())
+        })?;
+        Ok(events)
+    }
+
+    /// Hand the events after `sequence` to `visit` one at a time, in
+    /// sequence order, without holding the log in memory. Filters like
+    /// [`load_all`].
+    ///
+    /// [`load_all`]: EventLog::load_all
+    pub(crate) fn each_after<E: From<EventError>>(
+        &self,
+        sequence: i64,
+        visit: impl FnMut(StoredEvent) -> Result<(), E>,
+    ) -> Result<(), E> {
+        self.each_where("rowid > ?1", [sequence], visit)
+    }
+
+    /// Hand the events of the given kinds to `visit` one at a time, in
+    /// sequence order — a scan that only reads the rows it wants.
+    /// Filters like [`load_all`].
+    ///
+    /// [`load_all`]: EventLog::load_all
+    pub(crate) fn each_of_kind<E: From<EventError>>(
+        &self,
+        kinds: &[String],
+        visit: impl FnMut(StoredEvent) -> Result<(), E>,
+    ) -> Result<(), E> {
+        if kinds.is_empty() {
+            return Ok(());
+        }
+
+        let placeholders: Vec<String> = (1..=kinds.len()).map(|i| format!("?{i}")).collect();
+        self.each_where(
+            &format!("event_type IN ({})", placeholders.join(",")),
+            rusqlite::params_from_iter(kinds),
+            visit,
+        )
+    }
+
+    fn each_where<E: From<EventError>>(
+        &self,
+        clause: &str,
+        params: impl rusqlite::Params,
+        mut visit: impl FnMut(StoredEvent) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let mut stmt = self
+            .conn
+            .prepare(&format!(
+                "SELECT id, rowid, data, source, created_at, signature FROM {} WHERE {clause} ORDER BY rowid",
+                self.table,
+            ))
+            .map_err(EventError::from)?;
 
         let rows = stmt
-            .query_map([], |row| {
+            .query_map(params, |row| {
                 let id_str: String = row.get(0)?;
                 let data_str: String = row.get(2)?;
                 let source_str: String = row.get(3)?;
                 let created_at_str: String = row.get(4)?;
-                Ok((id_str, row.get(1)?, data_str, source_str, created_at_str))
-            })?
+                let signature_str: Option<String> = row.get(5)?;
+                Ok((
+                    id_str,
+                    row.get(1)?,
+                    data_str,
+                    source_str,
+                    created_at_str,
+                    signature_str,
+                ))
+            })
+            .map_err(EventError::from)?
             .map(|result| -> Result<StoredEvent, EventError> {
-                let (id_str, sequence, data_str, source_str, created_at_str) = result?;
-
-                let id = id_str.parse().unwrap_or_default();
-                let record = serde_json::from_str(&data_str)
-                    .inspect_err(|error| {
-                        tracing::warn!(%error, "skipping event row with malformed json");
-                    })
-                    .unwrap_or_default();
-
-                Ok(StoredEvent::builder()
-                    .id(id)
-                    .sequence(sequence)
-                    .data(record)
-                    .source(serde_json::from_str(&source_str).unwrap_or_default())
-                    .created_at(
-                        Timestamp::parse_str(created_at_str).unwrap_or_else(|_| Timestamp::now()),
-                    )
-                    .build())
+                let (id_str, sequence, data_str, source_str, created_at_str, signature_str) =
+                    result?;
+
+                Ok(StoredEvent::read(
+                    id_str.parse().unwrap_or_default(),
+                    sequence,
+                    data_str,
+                    serde_json::from_str(&source_str).unwrap_or_default(),
+                    Timestamp::parse_str(created_at_str).unwrap_or_else(|_| Timestamp::now()),
+                    signature_str.and_then(|signature| serde_json::from_str(&signature).ok()),
+                ))
             });
 
-        let mut events = Vec::new();
         for row in rows {
             let row = row?;
             if let Event::Known(_) = row.data {
-                events.push(row);
+                visit(row)?;
             }
         }
-        Ok(events)
+        Ok(())
     }
 
     /// Fetch events by ID. Returns all found events in sequence order;
@@ -142,7 +212,7 @@ impl<'a> EventLog<'a> {
 
         let placeholders: Vec<String> = (1..=ids.len()).map(|i| format!("?{i}")).collect();
         let query = format!(
-            "select id, rowid, data, source, created_at from {} where id in ({}) order by rowid",
+            "select id, rowid, data, source, created_at, signature from {} where id in ({}) order by rowid",
             self.table,
             placeholders.join(","),
         );
@@ -158,27 +228,28 @@ impl<'a> EventLog<'a> {
                 let data_str: String = row.get(2)?;
                 let source_str: String = row.get(3)?;
                 let created_at_str: String = row.get(4)?;
-                Ok((id_str, row.get(1)?, data_str, source_str, created_at_str))
+                let signature_str: Option<String> = row.get(5)?;
+                Ok((
+                    id_str,
+                    row.get(1)?,
+                    data_str,
+                    source_str,
+                    created_at_str,
+                    signature_str,
+                ))
             })?
             .map(|result| -> Result<StoredEvent, EventError> {
-                let (id_str, sequence, data_str, source_str, created_at_str) = result?;
-
-                let id = id_str.parse().unwrap_or_default();
-                let record = serde_json::from_str(&data_str)
-                    .inspect_err(|error| {
-                        tracing::warn!(%error, "skipping event row with malformed json");
-                    })
-                    .unwrap_or_default();
-
-                Ok(StoredEvent::builder()
-                    .id(id)
-                    .sequence(sequence)
-                    .data(record)
-                    .source(serde_json::from_str(&source_str).unwrap_or_default())
-                    .created_at(
-                        Timestamp::parse_str(created_at_str).unwrap_or_else(|_| Timestamp::now()),
-                    )
-                    .build())
+                let (id_str, sequence, data_str, source_str, created_at_str, signature_str) =
+                    result?;
+
+                Ok(StoredEvent::read(
+                    id_str.parse().unwrap_or_default(),
+                    sequence,
+                    data_str,
+                    serde_json::from_str(&source_str).unwrap_or_default(),
+                    Timestamp::parse_str(created_at_str).unwrap_or_else(|_| Timestamp::now()),
+                    signature_str.and_then(|signature| serde_json::from_str(&signature).ok()),
+                ))
             });
 
         let mut events = Vec::new();
@@ -193,14 +264,99 @@ impl<'a> EventLog<'a> {
     }
 
     /// Import a single event without running projections. Idempotent.
-    pub(crate) fn import(&self, event: &StoredEvent) -> Result<(), EventError> {
+    /// Returns the sequence the event was stored at here, or `None` if
+    /// the log already held it.
+    pub(crate) fn import(&self, event: &StoredEvent) -> Result<Option<i64>, EventError> {
+        self.insert("insert or ignore", event)?;
+        Ok((self.conn.changes() > 0).then(|| self.conn.last_insert_rowid()))
+    }
+
+    /// The sequence of the latest event in the log, or 0 if it is empty.
+    pub(crate) fn last_sequence(&self) -> Result<i64, EventError> {
+        Ok(self.conn.query_row(
+            &format!("SELECT COALESCE(MAX(rowid), 0) FROM {}", self.table),
+            [],
+            |row| row.get(0),
+        )?)
+    }
+
+    /// Check a redaction notice before it is honored. The notice must be
+    /// signed, either by `host` or by the author of every event it names
+    /// that this log still holds content for. Tombstones and events the
+    /// log never saw are passed over; nothing of them is left to erase.
+    pub(crate) fn authorize_redaction(
+        &self,
+        notice: &StoredEvent,
+        events: &[EventId],
+        host: Option<&PeerKey>,
+    ) -> Result<(), EventError> {
+        let Provenance::Signed(signer) = notice.provenance() else {
+            return Err(ProvenanceError::UnauthorizedRedaction(notice.id).into());
+        };
+
+        if host == Some(&signer) {
+            return Ok(());
+        }
+
+        let authored = self
+            .get_batch(events)?
+            .iter()
+            .filter(|event| !event.is_tombstone())
+            .all(|event| event.provenance() == Provenance::Signed(signer));
+
+        if authored {
+            Ok(())
+        } else {
+            Err(ProvenanceError::UnauthorizedRedaction(notice.id).into())
+        }
+    }
+
+    /// Replace the payloads of the given events with tombstones, keeping
+    /// their ids, sequence and times. Events already tombstoned are left
+    /// alone; returns the ones this call redacted.
+    pub(crate) fn redact(
+        &self,
+        ids: &[EventId],
+        redacted_at: Timestamp,
+    ) -> Result<Vec<StoredEvent>, EventError> {
+        let mut redacted = Vec::new();
+
+        for event in self.get_batch(ids)? {
+            if event.is_tombstone() {
+                continue;
+            }
+
+            let tombstone = event.tombstoned(redacted_at);
+            self.conn.execute(
+                &format!(
+                    "update {} set event_type = ?2, data = ?3, signature = null where id = ?1",
+                    self.table,
+                ),
+                params![
+                    tombstone.id.to_string(),
+                    tombstone.data.event_type(),
+                    serde_json::to_string(&tombstone.data)?,
+                ],
+            )?;
+            redacted.push(tombstone);
+        }
+
+        Ok(redacted)
+    }
+
+    fn insert(&self, verb: &str, event: &StoredEvent) -> Result<(), EventError> {
         let event_type = event.data.event_type();
-        let data_json = serde_json::to_string(&event.data)?;
+        let data_json = event.payload()?.get().to_owned();
         let source_json = serde_json::to_string(&event.source)?;
+        let signature_json = event
+            .signature
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()?;
 
         self.conn.execute(
             &format!(
-                "insert or ignore into {} (id, event_type, data, source, created_at) values (?1, ?2, ?3, ?4, ?5)",
+                "{verb} into {} (id, event_type, data, source, created_at, signature) values (?1, ?2, ?3, ?4, ?5, ?6)",
                 self.table,
             ),
             params![
@@ -209,6 +365,7 @@ impl<'a> EventLog<'a> {
                 data_json,
                 source_json,
                 event.created_at.as_string(),
+                signature_json,
             ],
         )?;
 
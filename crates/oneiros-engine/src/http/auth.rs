@@ -1,6 +1,6 @@
 use axum::{
     extract::{Request, State},
-    http::StatusCode,
+    http::{Method, StatusCode},
     middleware::Next,
     response::{IntoResponse, Response},
 };
@@ -13,6 +13,8 @@ pub(crate) enum AuthError {
     NoAuthHeader,
     #[error("Invalid or expired token")]
     InvalidToken,
+    #[error("Ticket does not grant {0}")]
+    Forbidden(PermissionOp),
     #[error(transparent)]
     Compose(#[from] ComposeError),
     #[error(transparent)]
@@ -23,6 +25,7 @@ impl IntoResponse for AuthError {
     fn into_response(self) -> Response {
         let status = match &self {
             AuthError::NoAuthHeader | AuthError::InvalidToken => StatusCode::UNAUTHORIZED,
+            AuthError::Forbidden(_) => StatusCode::FORBIDDEN,
             AuthError::Compose(_) | AuthError::Event(_) => StatusCode::INTERNAL_SERVER_ERROR,
         };
         (status, axum::Json(ErrorResponse::new(self.to_string()))).into_response()
@@ -35,11 +38,21 @@ pub(crate) enum VerifiedSession {
     /// Authenticated with a host-level token. Grants access to host-scoped
     /// routes (dashboard, host management, project CRUD).
     Host,
-    /// Authenticated with a project-scoped project token. Grants access to
-    /// project-scoped routes and (by implication) host-scoped routes. The
-    /// project is resolved from the ticket; callers derive the full config
-    /// from `ServerState`.
-    Project(ProjectName),
+    /// Authenticated with a project-scoped project token. Grants the
+    /// routes its ticket's operations cover. The project is resolved from
+    /// the ticket; callers derive the full config from `ServerState`.
+    Project(ProjectName, Vec<PermissionOp>),
+}
+
+impl VerifiedSession {
+    /// Whether this session may perform the given operation. Host
+    /// sessions may perform anything.
+    pub(crate) fn permits(&self, required: PermissionOp) -> bool {
+        match self {
+            Self::Host => true,
+            Self::Project(_, grants) => grants.contains(&required),
+        }
+    }
 }
 
 /// Unified token verifier for both host and project tokens.
@@ -98,7 +111,34 @@ impl TicketVerifier {
             .check_validity()
             .map_err(|_| AuthError::InvalidToken)?;
 
-        Ok(VerifiedSession::Project(ticket.project_name))
+        let grants = ticket.grants();
+        Ok(VerifiedSession::Project(ticket.project_name, grants))
+    }
+}
+
+/// The operation a project session needs for a route. Host-tier
+/// resources need `HostAdmin`; a few resources have their own operations;
+/// everything else is a project read or write by method. Lens queries,
+/// slice diffs and bookmark previews are POSTs that change nothing.
+pub(crate) fn required_permission(method: &Method, path: &str) -> PermissionOp {
+    let reads = method == Method::GET || method == Method::HEAD;
+    let mut segments = path.trim_start_matches('/').split('/');
+    let resource = segments.next().unwrap_or_default();
+    let action = segments.next_back().unwrap_or_default();
+
+    match resource {
+        "tickets" | "projects" | "tenants" | "actors" | "peers" | "host" => PermissionOp::HostAdmin,
+        "events" => PermissionOp::EventsRead,
+        "search" => PermissionOp::Search,
+        "storage" if reads => PermissionOp::StorageRead,
+        "storage" => PermissionOp::StorageWrite,
+        "cognitions" if !reads => PermissionOp::CognitionWrite,
+        "memories" if !reads => PermissionOp::MemoryWrite,
+        "lens" => PermissionOp::ProjectRead,
+        "slices" if action == "diff" => PermissionOp::ProjectRead,
+        "bookmarks" if action == "preview" => PermissionOp::ProjectRead,
+        _ if reads => PermissionOp::ProjectRead,
+        _ => PermissionOp::ProjectWrite,
     }
 }
 
@@ -111,7 +151,8 @@ const EXCLUDED_PATHS: &[&str] = &["/", "/health", "/docs", "/api.json"];
 const EXCLUDED_PREFIXES: &[&str] = &["/docs/", "/mcp"];
 
 /// Axum middleware that enforces Bearer token authentication on every
-/// request except the public paths listed above.
+/// request except the public paths listed above, and checks that a
+/// project token's ticket grants the operation the route requires.
 ///
 /// Extracts the `Authorization: Bearer <token>` header, verifies it via
 /// [`TicketVerifier`], and injects the resulting [`VerifiedSession`] into
@@ -134,9 +175,15 @@ pub(crate) async fn auth_middleware(
         .and_then(|v| v.strip_prefix("Bearer "))
         .ok_or(AuthError::NoAuthHeader)?;
 
+    let required = required_permission(req.method(), path);
+
     let verifier = state.ticket_verifier();
     let session = verifier.verify(token_str).await?;
 
+    if !session.permits(required) {
+        return Err(AuthError::Forbidden(required));
+    }
+
     req.extensions_mut().insert(session);
     Ok(next.run(req).await)
 }
@@ -203,6 +250,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn routes_map_to_required_permissions() {
+        let cases = [
+            (Method::GET, "/agents", PermissionOp::ProjectRead),
+            (Method::POST, "/agents", PermissionOp::ProjectWrite),
+            (Method::GET, "/cognitions", PermissionOp::ProjectRead),
+            (Method::POST, "/cognitions", PermissionOp::CognitionWrite),
+            (Method::POST, "/memories", PermissionOp::MemoryWrite),
+            (Method::GET, "/storage/notes", PermissionOp::StorageRead),
+            (Method::DELETE, "/storage/notes", PermissionOp::StorageWrite),
+            (Method::GET, "/events", PermissionOp::EventsRead),
+            (Method::GET, "/events/stream", PermissionOp::EventsRead),
+            (Method::GET, "/host/events/stream", PermissionOp::HostAdmin),
+            (Method::GET, "/search", PermissionOp::Search),
+            (Method::POST, "/lens/query", PermissionOp::ProjectRead),
+            (
+                Method::POST,
+                "/bookmarks/merge/preview",
+                PermissionOp::ProjectRead,
+            ),
+            (Method::POST, "/bookmarks/merge", PermissionOp::ProjectWrite),
+            (Method::GET, "/tickets", PermissionOp::HostAdmin),
+            (Method::POST, "/projects", PermissionOp::HostAdmin),
+        ];
+
+        for (method, path, expected) in cases {
+            assert_eq!(
+                required_permission(&method, path),
+                expected,
+                "{method} {path}"
+            );
+        }
+    }
+
+    #[test]
+    fn host_sessions_permit_everything() {
+        let project =
+            VerifiedSession::Project(ProjectName::new("test"), vec![PermissionOp::ProjectRead]);
+
+        assert!(VerifiedSession::Host.permits(PermissionOp::HostAdmin));
+        assert!(project.permits(PermissionOp::ProjectRead));
+        assert!(!project.permits(PermissionOp::ProjectWrite));
+    }
+
     // Project token tests require a running host with tickets.
     // Those are exercised via integration tests in tests/acceptance/.
 }
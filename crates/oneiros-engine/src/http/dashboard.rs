@@ -2,8 +2,9 @@
 //!
 //! Returns everything the web UI needs to render the host view and
 //! authenticate into individual projects, in a single unauthenticated
-//! call. Aggregates from the `HostLog` — tenants, projects, and
-//! tickets — plus the host's iroh identity.
+//! call. Aggregates from the `HostLog` — tenants, projects, tickets,
+//! peers and follows — plus the host's iroh identity and the sync
+//! status of each peer follow.
 
 use axum::{Json, extract::State};
 
@@ -63,6 +64,15 @@ pub(crate) async fn dashboard_config(State(state): State<ServerState>) -> Json<D
         None => Vec::new(),
     };
 
+    let follows = match scope.as_ref() {
+        Some(s) => FollowRepo::new(s)
+            .list(&SearchFilters::default())
+            .await
+            .map(|listed| listed.items)
+            .unwrap_or_default(),
+        None => Vec::new(),
+    };
+
     Json(DashboardBootstrap {
         host: state.host_identity(),
         version: env!("CARGO_PKG_VERSION").to_string(),
@@ -72,5 +82,7 @@ pub(crate) async fn dashboard_config(State(state): State<ServerState>) -> Json<D
         projects,
         tickets,
         peers,
+        follows,
+        follow_status: state.follow_statuses().all(),
     })
 }
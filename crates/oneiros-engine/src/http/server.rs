@@ -105,11 +105,13 @@ impl Server {
         let state = ServerState::bind(self.config.clone()).await?;
 
         // Register the sync handler on the bridge so incoming
-        // `/oneiros/sync/1` connections from peers can serve canon updates.
+        // `/oneiros/sync/2` connections from peers can serve canon updates
+        // and stream pushed events.
         state.bridge().serve(
             self.config.clone(),
             state.canons().clone(),
             state.mailbox().clone(),
+            state.feed().clone(),
         );
 
         let app = Self::router_from_state(state);
@@ -173,6 +175,7 @@ impl Server {
             .merge(CognitionRouter.routes())
             .merge(ConnectionRouter.routes())
             .merge(ContinuityRouter.routes())
+            .merge(EventRouter.routes())
             .merge(ExperienceRouter.routes())
             .merge(FollowRouter.routes())
             .merge(LevelRouter.routes())
@@ -17,6 +17,8 @@ pub(crate) struct ServerState {
     api: Arc<OnceLock<OpenApi>>,
     mailbox: Mailbox,
     host_secret: iroh::SecretKey,
+    follow_statuses: FollowStatusIndex,
+    feed: EventFeed,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -30,13 +32,23 @@ pub(crate) enum ServerStateError {
 impl ServerState {
     /// Construct a server state with a bound iroh bridge. Loads (or
     /// generates) the host secret key from disk, binds a `Bridge`, and
-    /// spawns the host actor that consumes the bus.
+    /// spawns the actors that consume the bus — the follow sync actor
+    /// among them.
     pub(crate) async fn bind(config: Config) -> Result<Self, ServerStateError> {
         let secret = HostKey::new(config.platform()).ensure()?;
         let bridge = Bridge::bind(secret.clone()).await?;
 
         let canons = CanonIndex::new();
-        let mailbox = Mailbox::spawn(canons.clone());
+        let follow_statuses = FollowStatusIndex::new();
+        let feed = EventFeed::new();
+        let mailbox = Mailbox::spawn(
+            config.clone(),
+            canons.clone(),
+            bridge.clone(),
+            follow_statuses.clone(),
+            feed.clone(),
+            secret.clone(),
+        );
 
         Ok(Self {
             config,
@@ -45,6 +57,33 @@ impl ServerState {
             api: Arc::new(OnceLock::new()),
             mailbox,
             host_secret: secret,
+            follow_statuses,
+            feed,
+        })
+    }
+
+    /// Construct a server state for a session of its own beside the
+    /// running service, such as `mcp serve --stdio`. Loads the host key
+    /// but binds no bridge, and spawns only the actors that keep the
+    /// logs and projections: the service stays the one host that syncs
+    /// follows, sweeps retention and answers peers.
+    pub(crate) fn open(config: Config) -> Result<Self, ServerStateError> {
+        let secret = HostKey::new(config.platform()).ensure()?;
+        let bridge = Bridge::offline(&secret);
+
+        let canons = CanonIndex::new();
+        let feed = EventFeed::new();
+        let mailbox = Mailbox::spawn_local(canons.clone(), feed.clone(), secret.clone());
+
+        Ok(Self {
+            config,
+            canons,
+            bridge,
+            api: Arc::new(OnceLock::new()),
+            mailbox,
+            host_secret: secret,
+            follow_statuses: FollowStatusIndex::new(),
+            feed,
         })
     }
 
@@ -86,6 +125,16 @@ impl ServerState {
             .hydrate_project(&self.config, &self.config.project);
     }
 
+    /// Background sync status for peer follows.
+    pub(crate) fn follow_statuses(&self) -> &FollowStatusIndex {
+        &self.follow_statuses
+    }
+
+    /// Events appended on this host, as they happen.
+    pub(crate) fn feed(&self) -> &EventFeed {
+        &self.feed
+    }
+
     /// The server configuration.
     pub(crate) fn config(&self) -> &Config {
         &self.config
@@ -160,7 +209,7 @@ impl axum::response::IntoResponse for ScopeExtractError {
 /// Resolve the bookmark for a request from the `X-Bookmark` header or
 /// `?bookmark=` query parameter. Falls back to the active bookmark for
 /// the given project, or `main` if none is active.
-fn resolve_bookmark(
+pub(crate) fn resolve_bookmark(
     parts: &Parts,
     state: &ServerState,
     project_name: &ProjectName,
@@ -205,7 +254,7 @@ impl FromRequestParts<ServerState> for Scope<AtBookmark> {
 
         let project_name = match session {
             VerifiedSession::Host => return Err(AuthError::InvalidToken),
-            VerifiedSession::Project(project_name) => project_name.clone(),
+            VerifiedSession::Project(project_name, _) => project_name.clone(),
         };
 
         let bookmark = resolve_bookmark(parts, state, &project_name);
@@ -237,7 +286,7 @@ impl FromRequestParts<ServerState> for ProjectLog {
 
         let config = match session {
             VerifiedSession::Host => return Err(AuthError::InvalidToken),
-            VerifiedSession::Project(project_name) => {
+            VerifiedSession::Project(project_name, _) => {
                 let bookmark = resolve_bookmark(parts, state, project_name);
                 let mut c = state.config().clone();
                 c.project = project_name.clone();
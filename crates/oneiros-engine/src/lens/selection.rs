@@ -167,7 +167,6 @@ impl Selection {
         result
     }
 
-    #[cfg(test)]
     pub(crate) fn len(&self) -> usize {
         self.entries.len()
     }
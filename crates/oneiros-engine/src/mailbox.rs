@@ -21,6 +21,7 @@ use crate::*;
 pub(crate) struct Mailbox {
     host_log: HostLogMailbox,
     host_projection: HostProjectionMailbox,
+    follow_sync: FollowSyncMailbox,
     project_log: ProjectLogMailbox,
     project_import: ProjectImportMailbox,
     project_slice: ProjectSliceMailbox,
@@ -33,10 +34,54 @@ impl Mailbox {
     ///
     /// Each per-actor inbox is consumed by a singleton task; each actor
     /// receives a clone of the mailbox so it can emit follow-ups without
-    /// holding handles to siblings.
-    pub(crate) fn spawn(canons: CanonIndex) -> Self {
+    /// holding handles to siblings. The log actors sign every event
+    /// they append with the host's `secret`, and the project log and
+    /// import actors publish what they write on the `feed`; the follow sync actor
+    /// collects through the `bridge` and reports into `follow_statuses`.
+    /// The retention actor has no inbox; it sweeps on its own ticker.
+    pub(crate) fn spawn(
+        config: Config,
+        canons: CanonIndex,
+        bridge: Bridge,
+        follow_statuses: FollowStatusIndex,
+        feed: EventFeed,
+        secret: iroh::SecretKey,
+    ) -> Self {
+        let (mailbox, follow_sync_inbox) = Self::spawn_logs(canons.clone(), feed, secret);
+
+        RetentionActor::spawn(config.clone(), mailbox.clone());
+        FollowSyncActor::spawn(
+            follow_sync_inbox,
+            config,
+            canons,
+            bridge,
+            mailbox.clone(),
+            follow_statuses,
+        );
+
+        mailbox
+    }
+
+    /// Spawn only the actors that keep logs and projections — for a
+    /// session working on local state beside the running service,
+    /// which stays the one host that syncs follows and sweeps retention.
+    /// Messages for the follow sync actor are dropped.
+    pub(crate) fn spawn_local(
+        canons: CanonIndex,
+        feed: EventFeed,
+        secret: iroh::SecretKey,
+    ) -> Self {
+        Self::spawn_logs(canons, feed, secret).0
+    }
+
+    fn spawn_logs(
+        canons: CanonIndex,
+        feed: EventFeed,
+        secret: iroh::SecretKey,
+    ) -> (Self, FollowSyncInbox) {
         let (host_log, host_log_inbox) = HostLogMailbox::open();
         let (host_projection, host_projection_inbox) = HostProjectionMailbox::open();
+        let (follow_sync, follow_sync_inbox) = FollowSyncMailbox::open();
         let (project_log, project_log_inbox) = ProjectLogMailbox::open();
         let (project_import, project_import_inbox) = ProjectImportMailbox::open();
         let (project_slice, project_slice_inbox) = ProjectSliceMailbox::open();
@@ -46,6 +91,7 @@ impl Mailbox {
         let mailbox = Self {
             host_log,
             host_projection,
+            follow_sync,
             project_log,
             project_import,
             project_slice,
@@ -53,15 +99,27 @@ impl Mailbox {
             bookmark_chronicle,
         };
 
-        HostLogActor::spawn(host_log_inbox, mailbox.clone());
+        HostLogActor::spawn(
+            host_log_inbox,
+            mailbox.clone(),
+            feed.clone(),
+            secret.clone(),
+        );
         HostProjectionActor::spawn(host_projection_inbox);
-        ProjectLogActor::spawn(project_log_inbox, mailbox.clone());
-        ProjectImportActor::spawn(project_import_inbox, mailbox.clone());
+        let host = PeerKey::from_bytes(*secret.public().as_bytes());
+        ProjectLogActor::spawn(
+            project_log_inbox,
+            mailbox.clone(),
+            feed.clone(),
+            secret,
+            canons.clone(),
+        );
+        ProjectImportActor::spawn(project_import_inbox, mailbox.clone(), feed, host);
         SliceActor::spawn(project_slice_inbox, mailbox.clone(), canons.clone());
         BookmarkProjectionsActor::spawn(bookmark_projections_inbox, canons.clone());
         BookmarkChronicleActor::spawn(bookmark_chronicle_inbox, canons);
 
-        mailbox
+        (mailbox, follow_sync_inbox)
     }
 
     /// Send a message into the bus. `Into<Message>` lets callers pass
@@ -76,9 +134,12 @@ impl Mailbox {
                 HostMessage::ProjectionApply(_)
                 | HostMessage::ProjectionMigrate(_)
                 | HostMessage::ProjectionReset(_) => self.host_projection.tell(message),
+                HostMessage::FollowSync(_)
+                | HostMessage::FollowSubscribe(_)
+                | HostMessage::FollowUnsubscribe(_) => self.follow_sync.tell(message),
             },
             Message::Project(message) => match message {
-                ProjectMessage::LogAppend(_) => {
+                ProjectMessage::LogAppend(_) | ProjectMessage::Purge(_) => {
                     self.project_log.tell(message);
                 }
                 ProjectMessage::ImportEvent(_) => {
@@ -1,22 +1,44 @@
 //! MCP server — tools, resources, and prompts for agent consumption.
 //!
-//! Tools are write operations (8 total). Resources are read operations
+//! Tools are write operations and the session lifecycle, plus read-only
+//! queries that take arguments a resource URI can't carry — lenses,
+//! trails, storage lookups, bookmark previews. Resources are read operations
 //! rendered as markdown. Prompts assemble identity context.
 //!
+//! Resources can be subscribed to. The first subscription starts a
+//! watcher on the host's event feed that sends
+//! `notifications/resources/updated` for each subscribed resource an
+//! append to the session's bookmark touches. The watcher stops when the
+//! session ends or its last subscription is dropped.
+//!
 //! Session authentication: every MCP session must provide a valid
 //! `Authorization: Bearer <token>` header during the initialize handshake.
 //! The token resolves to the project associated with the project. Sessions
 //! that fail to authenticate receive an informative error directing the
-//! agent to run `oneiros mcp init` in its project directory.
+//! agent to run `oneiros mcp init` in its project directory. Stdio sessions
+//! (`oneiros mcp serve --stdio`) are the exception: the process opened the
+//! project itself, so its session is established before the handshake.
+//! HTTP sessions work in the bookmark their handshake names with an
+//! `X-Bookmark` header, or the project's active bookmark.
+//!
+//! A session keeps the grants of the ticket it authenticated with. Each
+//! tool names the operation it needs, as each HTTP route does; reading or
+//! subscribing to resources needs `project-read`, and the dream prompts
+//! need `project-write`.
 
-use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
 
+use axum::http::request::Parts;
 use rmcp::model::{
     CallToolResult, Content, GetPromptResult, Implementation, ListPromptsResult,
     ListResourceTemplatesResult, ListResourcesResult, ListToolsResult, PaginatedRequestParams,
-    ReadResourceResult, ServerCapabilities, ServerInfo, Tool,
+    ReadResourceResult, ResourceUpdatedNotificationParam, ServerCapabilities, ServerInfo,
+    SubscribeRequestParams, Tool, UnsubscribeRequestParams,
 };
-use rmcp::{ErrorData, ServerHandler};
+use rmcp::{ErrorData, Peer, RoleServer, ServerHandler, ServiceError};
+use tokio::sync::broadcast::error::RecvError;
+use tokio_util::sync::{CancellationToken, DropGuard};
 
 use crate::*;
 
@@ -42,6 +64,18 @@ pub(crate) enum ToolError {
     /// A write operation was requested through the resource (read) path.
     #[error("Not a resource: {0}")]
     NotAResource(String),
+
+    /// A tool's binary input was not valid base64.
+    #[error("Malformed encoding: {0}")]
+    Encoding(#[from] data_encoding::DecodeError),
+
+    /// A tool's input exceeded what it accepts over MCP.
+    #[error("Input exceeds the {0}-byte limit")]
+    TooLarge(usize),
+
+    /// The session's ticket does not grant what the tool needs.
+    #[error("Ticket does not grant {0}")]
+    Forbidden(PermissionOp),
 }
 
 fn all_tools() -> Vec<ToolDef> {
@@ -52,6 +86,12 @@ fn all_tools() -> Vec<ToolDef> {
         ExperienceMcp.defs(),
         ConnectionMcp.defs(),
         SearchMcp.defs(),
+        BookmarkMcp.defs(),
+        LensMcp.defs(),
+        StorageMcp.defs(),
+        TrailMcp.defs(),
+        ContinuityMcp.defs(),
+        PressureMcp.defs(),
     ]
     .into_iter()
     .flatten()
@@ -61,10 +101,18 @@ fn all_tools() -> Vec<ToolDef> {
 #[tracing::instrument(skip_all, fields(tool = %tool_name), err(Display))]
 async fn dispatch(
     state: &ServerState,
-    config: &Config,
+    session: &McpSession,
     tool_name: &ToolName,
     params: &serde_json::Value,
 ) -> Result<McpResponse, ToolError> {
+    let required = all_tools()
+        .into_iter()
+        .find(|tool| &tool.name == tool_name)
+        .map(|tool| tool.permission)
+        .ok_or_else(|| ToolError::UnknownTool(tool_name.to_string()))?;
+    session.require(required)?;
+
+    let config = &session.config;
     let context = state.project_log(config.clone());
 
     let name = tool_name.as_str();
@@ -97,6 +145,28 @@ async fn dispatch(
     if name.parse::<SearchRequestType>().is_ok() {
         return SearchMcp.dispatch(&context, tool_name, params).await;
     }
+    if name.parse::<BookmarkRequestType>().is_ok() {
+        return BookmarkMcp.dispatch(state, config, tool_name, params).await;
+    }
+    if name.parse::<LensRequestType>().is_ok() {
+        return LensMcp.dispatch(&context, tool_name, params).await;
+    }
+    if name.parse::<StorageRequestType>().is_ok() {
+        return StorageMcp
+            .dispatch(&context, state.mailbox(), tool_name, params)
+            .await;
+    }
+    if name.parse::<TrailRequestType>().is_ok() {
+        return TrailMcp.dispatch(&context, tool_name, params).await;
+    }
+    if name.parse::<ContinuityRequestType>().is_ok() {
+        return ContinuityMcp
+            .dispatch(&context, state.mailbox(), tool_name, params)
+            .await;
+    }
+    if name.parse::<PressureRequestType>().is_ok() {
+        return PressureMcp.dispatch(&context, tool_name, params).await;
+    }
 
     Err(ToolError::UnknownTool(tool_name.to_string()))
 }
@@ -116,6 +186,7 @@ fn mcp_error_response(error: &ToolError) -> McpResponse {
             ResourcePath::Agents.uri(),
             "See available agents",
         )],
+        ToolError::Encoding(_) | ToolError::TooLarge(_) | ToolError::Forbidden(_) => vec![],
     };
     McpResponse::new(body).hints(hints)
 }
@@ -175,38 +246,189 @@ async fn read_resource(context: &ProjectLog, uri: &ResourceUri) -> Result<McpRes
     }
 }
 
-async fn resolve_config_from_token(state: &ServerState, token_str: &str) -> Option<Config> {
+/// The resources an appended event touched, once the bookmark's
+/// projections have caught up to it, so a re-read sees the change.
+async fn touched_resources(scope: &Scope<AtBookmark>, stored: &StoredEvent) -> Vec<ResourcePath> {
+    let Event::Known(events) = &stored.data else {
+        return vec![];
+    };
+
+    if let Err(error) = TrailRepo::new(scope).settled(stored).await {
+        tracing::debug!(%error, "mcp: waiting on projections failed");
+    }
+
+    let agent = match ActingAgent::of(events) {
+        Some(ActingAgent::Name(name)) => Some(name),
+        Some(ActingAgent::Id(id)) => AgentRepo::new(scope)
+            .get_by_id(id)
+            .await
+            .ok()
+            .flatten()
+            .map(|agent| agent.name),
+        None => None,
+    };
+
+    ResourcePath::touched_by(events, agent.as_ref())
+}
+
+/// Notify a session's peer whenever an append to its bookmark touches
+/// a subscribed resource. Runs until `closed` is cancelled, the
+/// session's transport closes, or the last subscription is dropped,
+/// when it hands `watching` back for the next subscription to take up.
+async fn watch_resources(
+    state: ServerState,
+    config: Config,
+    subscriptions: ResourceSubscriptions,
+    watching: Arc<AtomicBool>,
+    closed: CancellationToken,
+    peer: Peer<RoleServer>,
+) {
+    let mut feed = state.feed().subscribe();
+    let scope = match ComposeScope::new(config.clone())
+        .bookmark(config.project.clone(), config.bookmark.clone())
+    {
+        Ok(scope) => scope,
+        Err(error) => {
+            tracing::warn!(%error, "mcp: resource watcher could not compose a scope");
+            watching.store(false, Ordering::SeqCst);
+            return;
+        }
+    };
+
+    loop {
+        let received = tokio::select! {
+            () = closed.cancelled() => return,
+            () = subscriptions.emptied() => None,
+            received = feed.recv() => Some(received),
+        };
+
+        if subscriptions.is_empty() {
+            // A subscribe racing this either finds the watch released
+            // and starts its own watcher, or is seen here and kept.
+            watching.store(false, Ordering::SeqCst);
+            if subscriptions.is_empty() || watching.swap(true, Ordering::SeqCst) {
+                return;
+            }
+        }
+
+        let Some(received) = received else {
+            continue;
+        };
+        let uris = match received {
+            Ok(appended)
+                if appended.project == config.project && appended.bookmark == config.bookmark =>
+            {
+                subscriptions.matching(&touched_resources(&scope, &appended.stored).await)
+            }
+            Ok(_) => continue,
+            // Too far behind to know what changed, so anything may have.
+            Err(RecvError::Lagged(_)) => subscriptions.all(),
+            Err(RecvError::Closed) => return,
+        };
+
+        for uri in uris {
+            let notification = ResourceUpdatedNotificationParam::new(uri.to_string());
+            match peer.notify_resource_updated(notification).await {
+                Ok(()) => {}
+                Err(ServiceError::TransportClosed) => return,
+                Err(error) => tracing::debug!(%error, %uri, "mcp: resource notification failed"),
+            }
+        }
+    }
+}
+
+/// The session a token opens, on the bookmark the handshake names — by
+/// `x-bookmark` header or query, as for every HTTP route — or else the
+/// project's active bookmark.
+async fn resolve_session_from_token(
+    state: &ServerState,
+    parts: &Parts,
+    token_str: &str,
+) -> Option<McpSession> {
     let verifier = state.ticket_verifier();
     match verifier.verify(token_str).await {
-        Ok(VerifiedSession::Project(project_name)) => {
+        Ok(VerifiedSession::Project(project_name, grants)) => {
             let mut config = state.config().clone();
-            config.project = project_name;
-            Some(config)
+            config.bookmark = resolve_bookmark(parts, state, &project_name);
+            config.project = project_name.clone();
+            Some(McpSession {
+                config,
+                grants: VerifiedSession::Project(project_name, grants),
+            })
         }
         _ => None,
     }
 }
 
+/// An established MCP session — the project it works in, and what the
+/// ticket it authenticated with grants.
+#[derive(Clone)]
+struct McpSession {
+    config: Config,
+    grants: VerifiedSession,
+}
+
+impl McpSession {
+    fn require(&self, operation: PermissionOp) -> Result<(), ToolError> {
+        if self.grants.permits(operation) {
+            Ok(())
+        } else {
+            Err(ToolError::Forbidden(operation))
+        }
+    }
+
+    /// The session's config, once it may perform `operation`.
+    fn config_for(&self, operation: PermissionOp) -> Result<&Config, ErrorData> {
+        self.require(operation)
+            .map_err(|error| ErrorData::invalid_request(error.to_string(), None))?;
+        Ok(&self.config)
+    }
+}
+
 #[derive(Clone)]
 pub(crate) struct EngineToolBox {
     state: ServerState,
-    session_config: OnceLock<Config>,
+    session: OnceLock<McpSession>,
+    subscriptions: ResourceSubscriptions,
+    watching: Arc<AtomicBool>,
+    /// The session's cancellation token, cancelled once the session is
+    /// done with this toolbox — when its last clone drops.
+    closed: CancellationToken,
+    _closing: Arc<DropGuard>,
 }
 
 impl EngineToolBox {
     pub(crate) fn new(state: ServerState) -> Self {
+        let closed = CancellationToken::new();
         Self {
             state,
-            session_config: OnceLock::new(),
+            session: OnceLock::new(),
+            subscriptions: ResourceSubscriptions::default(),
+            watching: Arc::new(AtomicBool::new(false)),
+            _closing: Arc::new(closed.clone().drop_guard()),
+            closed,
         }
     }
 
-    /// Return the session config if `initialize()` has completed
-    /// successfully. Returns `None` if no session has been established
-    /// — callers should reject the request rather than falling back to
-    /// an unauthenticated server-level config.
-    fn session_config(&self) -> Option<&Config> {
-        self.session_config.get()
+    /// A toolbox whose session is the state's own config, established
+    /// up front. For stdio, where the process that opened the project
+    /// is the only client and there are no headers to authenticate; it
+    /// holds the project with full access.
+    pub(crate) fn local(state: ServerState) -> Self {
+        let toolbox = Self::new(state);
+        let _ = toolbox.session.set(McpSession {
+            config: toolbox.state.config().clone(),
+            grants: VerifiedSession::Host,
+        });
+        toolbox
+    }
+
+    /// Return the session if `initialize()` has completed successfully.
+    /// Returns `None` if no session has been established — callers
+    /// should reject the request rather than falling back to an
+    /// unauthenticated server-level config.
+    fn session(&self) -> Option<&McpSession> {
+        self.session.get()
     }
 }
 
@@ -216,6 +438,7 @@ impl ServerHandler for EngineToolBox {
             ServerCapabilities::builder()
                 .enable_tools()
                 .enable_resources()
+                .enable_resources_subscribe()
                 .enable_prompts()
                 .build(),
         )
@@ -230,38 +453,42 @@ impl ServerHandler for EngineToolBox {
         request: rmcp::model::InitializeRequestParams,
         context: rmcp::service::RequestContext<rmcp::RoleServer>,
     ) -> Result<rmcp::model::InitializeResult, ErrorData> {
-        let parts = context.extensions.get::<axum::http::request::Parts>();
-        let auth_header = parts.and_then(|p| p.headers.get("authorization"));
-
-        let Some(header_value) = auth_header else {
-            return Err(ErrorData::invalid_params(
-                "No authorization token. Run `oneiros mcp init` in your project directory to configure MCP access.",
-                None,
-            ));
-        };
+        // Local sessions arrive already established.
+        if self.session().is_none() {
+            let parts = context.extensions.get::<Parts>();
+            let auth_header = parts.and_then(|p| p.headers.get("authorization"));
+
+            let (Some(parts), Some(header_value)) = (parts, auth_header) else {
+                return Err(ErrorData::invalid_params(
+                    "No authorization token. Run `oneiros mcp init` in your project directory to configure MCP access.",
+                    None,
+                ));
+            };
 
-        let Ok(header_str) = header_value.to_str() else {
-            return Err(ErrorData::invalid_params(
-                "Invalid authorization header encoding. Expected format: `Authorization: Bearer <token>`.",
-                None,
-            ));
-        };
+            let Ok(header_str) = header_value.to_str() else {
+                return Err(ErrorData::invalid_params(
+                    "Invalid authorization header encoding. Expected format: `Authorization: Bearer <token>`.",
+                    None,
+                ));
+            };
 
-        let Some(token_str) = header_str.strip_prefix("Bearer ") else {
-            return Err(ErrorData::invalid_params(
-                "Invalid authorization scheme. Expected `Bearer <token>` — got something else. Run `oneiros mcp init` to generate a valid token.",
-                None,
-            ));
-        };
+            let Some(token_str) = header_str.strip_prefix("Bearer ") else {
+                return Err(ErrorData::invalid_params(
+                    "Invalid authorization scheme. Expected `Bearer <token>` — got something else. Run `oneiros mcp init` to generate a valid token.",
+                    None,
+                ));
+            };
 
-        let Some(config) = resolve_config_from_token(&self.state, token_str).await else {
-            return Err(ErrorData::invalid_params(
-                "Invalid or unrecognized token. It may be expired, from a different server, or for a deleted project. Run `oneiros mcp init` to regenerate it.",
-                None,
-            ));
-        };
+            let Some(session) = resolve_session_from_token(&self.state, parts, token_str).await
+            else {
+                return Err(ErrorData::invalid_params(
+                    "Invalid or unrecognized token. It may be expired, from a different server, or for a deleted project. Run `oneiros mcp init` to regenerate it.",
+                    None,
+                ));
+            };
 
-        let _ = self.session_config.set(config);
+            let _ = self.session.set(session);
+        }
 
         if context.peer.peer_info().is_none() {
             context.peer.set_peer_info(request);
@@ -304,13 +531,13 @@ impl ServerHandler for EngineToolBox {
         let params = serde_json::to_value(request.arguments.unwrap_or_default())
             .unwrap_or_else(|_| serde_json::json!({}));
 
-        let Some(config) = self.session_config() else {
+        let Some(session) = self.session() else {
             return Err(ErrorData::internal_error(
                 "Session not initialized. Call initialize with a valid Bearer token first.",
                 None,
             ));
         };
-        match dispatch(&self.state, config, &tool_name, &params).await {
+        match dispatch(&self.state, session, &tool_name, &params).await {
             Ok(response) => Ok(CallToolResult::success(vec![Content::text(
                 response.into_text(),
             )])),
@@ -380,12 +607,13 @@ impl ServerHandler for EngineToolBox {
         request: rmcp::model::ReadResourceRequestParams,
         _context: rmcp::service::RequestContext<rmcp::RoleServer>,
     ) -> Result<ReadResourceResult, ErrorData> {
-        let Some(config) = self.session_config() else {
+        let Some(session) = self.session() else {
             return Err(ErrorData::internal_error(
                 "Session not initialized. Call initialize with a valid Bearer token first.",
                 None,
             ));
         };
+        let config = session.config_for(PermissionOp::ProjectRead)?;
         let context = self.state.project_log(config.clone());
 
         let uri: ResourceUri = request
@@ -406,20 +634,76 @@ impl ServerHandler for EngineToolBox {
         }
     }
 
+    async fn subscribe(
+        &self,
+        request: SubscribeRequestParams,
+        context: rmcp::service::RequestContext<rmcp::RoleServer>,
+    ) -> Result<(), ErrorData> {
+        let Some(session) = self.session() else {
+            return Err(ErrorData::internal_error(
+                "Session not initialized. Call initialize with a valid Bearer token first.",
+                None,
+            ));
+        };
+        let config = session.config_for(PermissionOp::ProjectRead)?;
+
+        let uri: ResourceUri = request
+            .uri
+            .parse()
+            .map_err(|e: ResourceUriError| ErrorData::invalid_params(e.to_string(), None))?;
+        self.subscriptions.subscribe(uri);
+
+        if !self.watching.swap(true, Ordering::SeqCst) {
+            tokio::spawn(watch_resources(
+                self.state.clone(),
+                config.clone(),
+                self.subscriptions.clone(),
+                self.watching.clone(),
+                self.closed.clone(),
+                context.peer,
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn unsubscribe(
+        &self,
+        request: UnsubscribeRequestParams,
+        _context: rmcp::service::RequestContext<rmcp::RoleServer>,
+    ) -> Result<(), ErrorData> {
+        let uri: ResourceUri = request
+            .uri
+            .parse()
+            .map_err(|e: ResourceUriError| ErrorData::invalid_params(e.to_string(), None))?;
+        self.subscriptions.unsubscribe(&uri);
+        Ok(())
+    }
+
     async fn list_prompts(
         &self,
         _request: Option<PaginatedRequestParams>,
         _context: rmcp::service::RequestContext<rmcp::RoleServer>,
     ) -> Result<ListPromptsResult, ErrorData> {
-        let prompts = vec![rmcp::model::Prompt::new(
-            "dream",
-            Some("Restore an agent's full identity and cognitive context"),
-            Some(vec![
+        let agent_argument = || {
+            vec![
                 rmcp::model::PromptArgument::new("agent")
                     .with_description("The agent name to dream")
                     .with_required(true),
-            ]),
-        )];
+            ]
+        };
+        let prompts = vec![
+            rmcp::model::Prompt::new(
+                "dream",
+                Some("Restore an agent's full identity and cognitive context"),
+                Some(agent_argument()),
+            ),
+            rmcp::model::Prompt::new(
+                "dream-since-last",
+                Some("Restore an agent's context, leading with what changed since it last dreamed"),
+                Some(agent_argument()),
+            ),
+        ];
 
         Ok(ListPromptsResult {
             prompts,
@@ -434,7 +718,7 @@ impl ServerHandler for EngineToolBox {
         _context: rmcp::service::RequestContext<rmcp::RoleServer>,
     ) -> Result<GetPromptResult, ErrorData> {
         match request.name.as_str() {
-            "dream" => {
+            name @ ("dream" | "dream-since-last") => {
                 let agent_name = request
                     .arguments
                     .as_ref()
@@ -444,12 +728,13 @@ impl ServerHandler for EngineToolBox {
                         ErrorData::invalid_params("Missing required argument: agent", None)
                     })?;
 
-                let Some(config) = self.session_config() else {
+                let Some(session) = self.session() else {
                     return Err(ErrorData::internal_error(
                         "Session not initialized. Call initialize with a valid Bearer token first.",
                         None,
                     ));
                 };
+                let config = session.config_for(PermissionOp::ProjectWrite)?;
                 let scope = ComposeScope::new(config.clone())
                     .bookmark(config.project.clone(), config.bookmark.clone())
                     .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
@@ -462,8 +747,12 @@ impl ServerHandler for EngineToolBox {
                 let response = ContinuityService::dream(
                     &scope,
                     self.state.mailbox(),
+                    self.state.canons(),
                     &request,
-                    &DreamOverrides::default(),
+                    &DreamOverrides {
+                        since_last: (name == "dream-since-last").then_some(true),
+                        ..Default::default()
+                    },
                 )
                 .await
                 .map_err(|e| ErrorData::internal_error(e.to_string(), None))?;
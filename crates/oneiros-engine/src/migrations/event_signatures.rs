@@ -0,0 +1,68 @@
+use std::path::PathBuf;
+
+use crate::Config;
+
+use super::{Migration, MigrationError};
+
+/// Add the `signature` column to every event log written before events
+/// were signed: the host DB's `events` table and each project's
+/// `events.db`. Existing rows stay unsigned.
+pub(crate) struct EventSignatures;
+
+impl Migration for EventSignatures {
+    fn name(&self) -> &'static str {
+        "event logs → signature column"
+    }
+
+    fn is_required(&self, config: &Config) -> Result<bool, MigrationError> {
+        for path in event_logs(config)? {
+            let conn = rusqlite::Connection::open(&path)?;
+            if needs_signature(&conn)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    fn apply(&self, config: &Config) -> Result<(), MigrationError> {
+        for path in event_logs(config)? {
+            let conn = rusqlite::Connection::open(&path)?;
+            if needs_signature(&conn)? {
+                conn.execute_batch("ALTER TABLE events ADD COLUMN signature TEXT")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The host DB plus every project directory's `events.db` that exists.
+fn event_logs(config: &Config) -> Result<Vec<PathBuf>, MigrationError> {
+    let platform = config.platform();
+    let mut paths = Vec::new();
+
+    let host_db = platform.host_db_path();
+    if host_db.exists() {
+        paths.push(host_db);
+    }
+
+    if platform.data_dir().is_dir() {
+        for entry in platform.read_dir(platform.data_dir())? {
+            let events_db = entry?.path().join("events.db");
+            if events_db.is_file() {
+                paths.push(events_db);
+            }
+        }
+    }
+
+    Ok(paths)
+}
+
+/// True when the connection has an `events` table without a `signature`
+/// column.
+fn needs_signature(conn: &rusqlite::Connection) -> Result<bool, rusqlite::Error> {
+    let mut stmt = conn.prepare("SELECT name FROM pragma_table_info('events')")?;
+    let columns = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(!columns.is_empty() && !columns.iter().any(|name| name == "signature"))
+}
@@ -22,11 +22,13 @@ mod outcome;
 mod service;
 
 mod brains_to_projects;
+mod event_signatures;
 mod system_db_to_host_db;
 
 pub(crate) use backup::*;
 pub(crate) use brains_to_projects::*;
 pub(crate) use error::*;
+pub(crate) use event_signatures::*;
 pub(crate) use outcome::*;
 pub(crate) use service::*;
 pub(crate) use system_db_to_host_db::*;
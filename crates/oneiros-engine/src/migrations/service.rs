@@ -1,7 +1,8 @@
 use crate::Config;
 
 use super::{
-    Backup, BrainsToProjects, Migration, MigrationError, MigrationOutcome, SystemDbToHostDb,
+    Backup, BrainsToProjects, EventSignatures, Migration, MigrationError, MigrationOutcome,
+    SystemDbToHostDb,
 };
 
 /// Orchestrates the layout-migration sweep. Owns the registry of known
@@ -58,6 +59,10 @@ impl MigrationService {
     /// Inventory in declaration order. Adding a new migration is one line
     /// here plus the module that owns it.
     fn registry() -> Vec<Box<dyn Migration>> {
-        vec![Box::new(SystemDbToHostDb), Box::new(BrainsToProjects)]
+        vec![
+            Box::new(SystemDbToHostDb),
+            Box::new(BrainsToProjects),
+            Box::new(EventSignatures),
+        ]
     }
 }
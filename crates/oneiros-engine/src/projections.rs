@@ -90,14 +90,16 @@ impl<T: Clone + Default> Projections<T> {
         Ok(())
     }
 
-    /// Replay all events through frames and reducers.
+    /// Replay all events except `skipped` through frames and reducers.
     #[tracing::instrument(skip_all, err(Display))]
-    pub(crate) fn replay(
+    pub(crate) fn replay_except(
         &self,
         db: &rusqlite::Connection,
         log: &EventLog,
+        skipped: &std::collections::HashSet<EventId>,
     ) -> Result<usize, EventError> {
-        let events = log.load_all()?;
+        let mut events = log.load_all()?;
+        events.retain(|event| !skipped.contains(&event.id));
 
         self.reset(db)?;
 
@@ -156,13 +158,15 @@ impl Projections<ProjectCanon> {
         Ok(())
     }
 
-    /// Replay for project projections — includes pressure sync at the end.
-    pub(crate) fn replay_project(
+    /// Replay the project, leaving out `skipped` events — the losing
+    /// sides of the merges the bookmark has settled — then sync pressures.
+    pub(crate) fn replay_project_except(
         &self,
         db: &rusqlite::Connection,
         log: &EventLog,
+        skipped: &std::collections::HashSet<EventId>,
     ) -> Result<usize, EventError> {
-        let count = self.replay(db, log)?;
+        let count = self.replay_except(db, log, skipped)?;
         self.sync_pressures(db)?;
         Ok(count)
     }
@@ -203,6 +207,7 @@ impl Projections<ProjectCanon> {
                     Frame::new(PressureProjections.all()),
                     Frame::new(SearchProjections.all()),
                     Frame::new(TrailProjections.all()),
+                    Frame::new(ContinuityProjections.all()),
                 ]),
             ],
             pipeline,
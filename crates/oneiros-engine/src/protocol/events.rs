@@ -71,6 +71,32 @@ impl Events {
             Events::Peer(e) => e.kind().to_string(),
         }
     }
+
+    /// The domain an event belongs to, e.g. `cognition` or `agent`.
+    pub(crate) fn domain(&self) -> &'static str {
+        match self {
+            Events::Level(_) => "level",
+            Events::Texture(_) => "texture",
+            Events::Sensation(_) => "sensation",
+            Events::Slice(_) => "slice",
+            Events::Nature(_) => "nature",
+            Events::Persona(_) => "persona",
+            Events::Urge(_) => "urge",
+            Events::Agent(_) => "agent",
+            Events::Cognition(_) => "cognition",
+            Events::Memory(_) => "memory",
+            Events::Experience(_) => "experience",
+            Events::Connection(_) => "connection",
+            Events::Storage(_) => "storage",
+            Events::Continuity(_) => "continuity",
+            Events::Tenant(_) => "tenant",
+            Events::Actor(_) => "actor",
+            Events::Project(_) => "project",
+            Events::Ticket(_) => "ticket",
+            Events::Bookmark(_) => "bookmark",
+            Events::Peer(_) => "peer",
+        }
+    }
 }
 
 collects_enum!(
@@ -18,6 +18,7 @@ pub(crate) enum Responses {
     Connection(ConnectionResponse),
     Continuity(ContinuityResponse),
     Doctor(DoctorResponse),
+    Event(EventResponse),
     Experience(ExperienceResponse),
     Follow(FollowResponse),
     Level(LevelResponse),
@@ -51,6 +52,7 @@ collects_enum!(
     Responses::Connection => ConnectionResponse,
     Responses::Continuity => ContinuityResponse,
     Responses::Doctor => DoctorResponse,
+    Responses::Event => EventResponse,
     Responses::Experience => ExperienceResponse,
     Responses::Follow => FollowResponse,
     Responses::Level => LevelResponse,
@@ -191,6 +191,25 @@ impl Scope<AtProject> {
     }
 }
 
+impl Scope<AtBookmark> {
+    /// Every bookmark in this scope's project, this one included — the
+    /// scopes that share its event log.
+    pub(crate) fn siblings(&self) -> Vec<Scope<AtBookmark>> {
+        self.inner
+            .project
+            .bookmarks
+            .values()
+            .map(|bookmark| {
+                Scope::wrap(AtBookmark {
+                    config: self.inner.config.clone(),
+                    project: self.inner.project.clone(),
+                    bookmark: bookmark.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────
 // Operations — substrate ops at each tier. Open connections per-call;
 // no held resources.
@@ -5,7 +5,8 @@
 //!
 //! `SkillPackage` represents the installable artifact — everything Claude Code
 //! needs to use oneiros as a skill: the SKILL.md, plugin metadata, hooks,
-//! agent definitions, resources, and command documentation.
+//! agent definitions, resources, and command documentation. Other harnesses
+//! take the slice of it they have a place for via `SkillPackage::assets_for`.
 use crate::*;
 
 /// The version of the package, stamped at compile time.
@@ -42,6 +43,7 @@ impl SkillInventory {
         skills.extend(ConnectionSkills::all());
         skills.extend(ContinuitySkills::all());
         skills.extend(DoctorSkills::all());
+        skills.extend(EventSkills::all());
         skills.extend(ExperienceSkills::all());
         skills.extend(FollowSkills::all());
         skills.extend(LevelSkills::all());
@@ -136,6 +138,61 @@ impl SkillPackage {
         assets
     }
 
+    /// The package as a harness takes it. Claude Code installs the whole
+    /// thing as a plugin; other harnesses get the skills rebased under
+    /// their own skills directory, plus anything else they have a place for.
+    pub(crate) fn assets_for(harness: Harness) -> Vec<SkillAsset> {
+        let Some(skills_dir) = harness.skills_dir() else {
+            return Self::assets();
+        };
+
+        Self::assets()
+            .into_iter()
+            .filter_map(|asset| {
+                if let Some(rest) = asset.path.strip_prefix("skills/") {
+                    Some(SkillAsset {
+                        path: leak_path(&format!("{skills_dir}/{rest}")),
+                        content: asset.content,
+                    })
+                } else if harness.hooks_path() == Some(asset.path) {
+                    Some(asset)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Write a harness's share of the package into the given project root.
+    /// Harnesses that take the package as a plugin get nothing written.
+    pub(crate) fn install(
+        platform: &Platform,
+        root: &std::path::Path,
+        harness: Harness,
+    ) -> std::io::Result<bool> {
+        if harness.skills_dir().is_none() {
+            return Ok(false);
+        }
+
+        for asset in Self::assets_for(harness) {
+            let path = root.join(asset.path);
+            if let Some(parent) = path.parent() {
+                platform.ensure_dir(parent)?;
+            }
+            platform.write(path, asset.content)?;
+        }
+
+        Ok(true)
+    }
+
+    /// Whether a harness's skills are installed under the given project
+    /// root, or `None` when the harness doesn't take them from a directory.
+    pub(crate) fn is_installed(root: &std::path::Path, harness: Harness) -> Option<bool> {
+        let skills_dir = harness.skills_dir()?;
+
+        Some(root.join(skills_dir).join("oneiros/SKILL.md").exists())
+    }
+
     /// Agent definition files.
     fn agents() -> Vec<(&'static str, &'static str)> {
         vec![
@@ -182,6 +239,37 @@ fn leak_path(s: &str) -> &'static str {
 mod tests {
     use super::*;
 
+    #[test]
+    fn claude_takes_the_whole_plugin() {
+        let paths: Vec<_> = SkillPackage::assets_for(Harness::Claude)
+            .iter()
+            .map(|a| a.path)
+            .collect();
+        let all: Vec<_> = SkillPackage::assets().iter().map(|a| a.path).collect();
+
+        assert_eq!(paths, all);
+    }
+
+    #[test]
+    fn other_harnesses_take_rebased_skills_only() {
+        let assets = SkillPackage::assets_for(Harness::Codex);
+
+        assert!(
+            assets
+                .iter()
+                .any(|a| a.path == ".codex/skills/oneiros/SKILL.md")
+        );
+        assert!(
+            assets
+                .iter()
+                .any(|a| a.path == ".codex/skills/oneiros/resources/getting-started.md")
+        );
+        assert!(
+            assets.iter().all(|a| a.path.starts_with(".codex/skills/")),
+            "codex has no place for plugin metadata, hooks or commands"
+        );
+    }
+
     #[test]
     fn inventory_is_not_empty() {
         let skills = SkillInventory::all();
@@ -255,14 +343,21 @@ mod tests {
     #[test]
     fn vocabulary_domains_are_complete() {
         let skills = SkillInventory::all();
-        for domain in &["texture", "sensation", "nature", "persona", "urge"] {
+        // Urges can also have their gauges tuned.
+        for (domain, expected) in [
+            ("texture", 4),
+            ("sensation", 4),
+            ("nature", 4),
+            ("persona", 4),
+            ("urge", 5),
+        ] {
             let domain_skills: Vec<_> = skills
                 .iter()
                 .filter(|s| s.name.starts_with(domain))
                 .collect();
             assert_eq!(
                 domain_skills.len(),
-                4,
+                expected,
                 "expected 4 skills for domain '{domain}', got {}",
                 domain_skills.len()
             );
@@ -157,6 +157,12 @@ impl Platform {
         std::fs::remove_file(path)
     }
 
+    /// Remove a directory and everything beneath it.
+    #[cfg(test)]
+    pub(crate) fn remove_dir_all(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        std::fs::remove_dir_all(path)
+    }
+
     /// Atomically rename a file or directory.
     pub(crate) fn rename(
         &self,
@@ -197,6 +197,209 @@ pub(crate) async fn import_bootstraps_fresh_project<B: Backend>() -> TestResult
     Ok(())
 }
 
+/// A gzipped export carries each blob once, however many storage entries
+/// point at it, and imports like a plain one.
+pub(crate) async fn gzip_export_dedupes_blobs<B: Backend>() -> TestResult {
+    let source = Harness::<B>::init_project().await?;
+
+    let temp_dir = tempfile::TempDir::new()?;
+    let platform = crate::Platform::new(temp_dir.path());
+    let file_path = temp_dir.path().join("shared.txt");
+    platform.write(&file_path, "The same bytes twice")?;
+
+    for key in ["first-copy", "second-copy"] {
+        source
+            .exec_json(&format!("storage set {key} {}", file_path.display()))
+            .await?;
+    }
+
+    let export_dir = tempfile::TempDir::new()?;
+    let export_response = source
+        .exec_json(&format!(
+            "project export --gzip --target {}",
+            export_dir.path().display()
+        ))
+        .await?;
+
+    let (export_path, blobs) = match export_response {
+        Responses::Project(ProjectResponse::WroteExport(WroteExportResponse::V1(details))) => {
+            (details.path, details.blobs)
+        }
+        other => panic!("expected WroteExport, got {other:#?}"),
+    };
+
+    assert_eq!(blobs, 1, "the shared blob should be exported once");
+    assert!(export_path.to_string_lossy().ends_with(".jsonl.gz"));
+
+    let mut reader = crate::archive_reader(platform.open_file(&export_path)?)?;
+    let (manifest, _) = crate::read_manifest(&mut reader)?;
+    let Some(crate::ExportManifest::V1(manifest)) = manifest else {
+        panic!("expected a manifest at the head of the export");
+    };
+    assert!(!manifest.filtered);
+    assert!(manifest.chronicle_root.is_some());
+
+    let destination = Harness::<B>::init_project().await?;
+    destination
+        .exec_json(&format!("project import {}", export_path.display()))
+        .await?;
+
+    for key in ["first-copy", "second-copy"] {
+        let response = destination
+            .exec_json(&format!("storage show {key}"))
+            .await?;
+        assert!(
+            matches!(
+                response,
+                Responses::Storage(StorageResponse::StorageDetails(_))
+            ),
+            "expected {key} after import, got {response:#?}"
+        );
+    }
+
+    Ok(())
+}
+
+/// Filters narrow an export to the events they select, and the manifest
+/// says so.
+pub(crate) async fn filtered_export_keeps_matching_events<B: Backend>() -> TestResult {
+    let harness = Harness::<B>::seed_project().await?;
+    harness
+        .exec_json("agent create thinker process --description 'A thinking agent'")
+        .await?;
+    harness
+        .exec_json("cognition add thinker.process observation 'Only this one'")
+        .await?;
+
+    let export_dir = tempfile::TempDir::new()?;
+    let export_response = harness
+        .exec_json(&format!(
+            "project export --event-type cognition-added --target {}",
+            export_dir.path().display()
+        ))
+        .await?;
+
+    let (export_path, events) = match export_response {
+        Responses::Project(ProjectResponse::WroteExport(WroteExportResponse::V1(details))) => {
+            (details.path, details.events)
+        }
+        other => panic!("expected WroteExport, got {other:#?}"),
+    };
+
+    assert_eq!(events, 1);
+
+    let content = crate::Platform::new(export_dir.path()).read_to_string(&export_path)?;
+    let mut lines = content.lines();
+    let header: crate::ExportHeader = serde_json::from_str(lines.next().unwrap_or_default())?;
+    let crate::ExportManifest::V1(manifest) = header.manifest;
+    assert!(manifest.filtered);
+    assert_eq!(manifest.events, 1);
+
+    let event: StoredEvent = serde_json::from_str(lines.next().unwrap_or_default())?;
+    assert_eq!(event.data.event_type(), "cognition-added");
+    assert!(lines.next().is_none());
+
+    Ok(())
+}
+
+/// An archive missing events its manifest counts is refused whole.
+pub(crate) async fn import_refuses_truncated_export<B: Backend>() -> TestResult {
+    let source = Harness::<B>::seed_project().await?;
+    source
+        .exec_json("agent create thinker process --description 'A thinking agent'")
+        .await?;
+
+    let export_dir = tempfile::TempDir::new()?;
+    let export_response = source
+        .exec_json(&format!(
+            "project export --target {}",
+            export_dir.path().display()
+        ))
+        .await?;
+
+    let export_path = match export_response {
+        Responses::Project(ProjectResponse::WroteExport(WroteExportResponse::V1(details))) => {
+            details.path
+        }
+        other => panic!("expected WroteExport, got {other:#?}"),
+    };
+
+    let platform = crate::Platform::new(export_dir.path());
+    let content = platform.read_to_string(&export_path)?;
+    let lines: Vec<&str> = content.lines().collect();
+    let truncated = lines[..lines.len() - 1].join("\n");
+    platform.write(&export_path, truncated)?;
+
+    let destination = Harness::<B>::init_project().await?;
+    let result = destination
+        .exec_json(&format!("project import {}", export_path.display()))
+        .await;
+
+    assert!(result.is_err(), "expected the truncated import to fail");
+
+    Ok(())
+}
+
+/// An archive missing a blob its manifest counts is refused whole, and
+/// the storage entry that pointed at it never lands.
+pub(crate) async fn import_refuses_export_missing_blobs<B: Backend>() -> TestResult {
+    let source = Harness::<B>::init_project().await?;
+
+    let temp_dir = tempfile::TempDir::new()?;
+    let file_path = temp_dir.path().join("test.txt");
+    crate::Platform::new(temp_dir.path()).write(&file_path, "Portable blob content")?;
+    source
+        .exec_json(&format!(
+            "storage set portable-doc {} --description 'A portable document'",
+            file_path.display()
+        ))
+        .await?;
+
+    let export_dir = tempfile::TempDir::new()?;
+    let export_response = source
+        .exec_json(&format!(
+            "project export --target {}",
+            export_dir.path().display()
+        ))
+        .await?;
+
+    let export_path = match export_response {
+        Responses::Project(ProjectResponse::WroteExport(WroteExportResponse::V1(details))) => {
+            assert_eq!(details.blobs, 1);
+            details.path
+        }
+        other => panic!("expected WroteExport, got {other:#?}"),
+    };
+
+    let platform = crate::Platform::new(export_dir.path());
+    let content = platform.read_to_string(&export_path)?;
+    let stripped: Vec<&str> = content
+        .lines()
+        .filter(|line| !line.contains("\"blob-stored\""))
+        .collect();
+    assert_eq!(stripped.len(), content.lines().count() - 1);
+    platform.write(&export_path, stripped.join("\n"))?;
+
+    let destination = Harness::<B>::init_project().await?;
+    let result = destination
+        .exec_json(&format!("project import {}", export_path.display()))
+        .await;
+
+    assert!(
+        result.is_err(),
+        "expected the import without its blob to fail"
+    );
+    assert!(
+        destination
+            .exec_json("storage show portable-doc")
+            .await
+            .is_err(),
+        "the refused import shouldn't leave its storage entry behind"
+    );
+
+    Ok(())
+}
+
 pub(crate) async fn replay_rebuilds_projections<B: Backend>() -> TestResult {
     let harness = Harness::<B>::init_project().await?;
     harness
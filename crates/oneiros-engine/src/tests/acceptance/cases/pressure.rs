@@ -58,6 +58,39 @@ pub(crate) async fn returns_readings_for_agent<B: Backend>() -> TestResult {
     Ok(())
 }
 
+pub(crate) async fn explain_names_the_tuning_behind_each_urgency<B: Backend>() -> TestResult {
+    let harness = Harness::<B>::seed_project().await?;
+    harness
+        .exec_json("agent create thinker process --description 'A thinking agent'")
+        .await?;
+    harness
+        .exec_json("urge tune catharsis --persona process --param tensions_weight=0.5")
+        .await?;
+
+    let response = harness
+        .exec_json("pressure explain thinker.process")
+        .await?;
+    let explained = match response {
+        Responses::Pressure(PressureResponse::Explained(ExplainedResponse::V1(result))) => result,
+        other => panic!("expected Pressure(Explained), got {other:#?}"),
+    };
+
+    let catharsis = explained
+        .explanations
+        .iter()
+        .find(|e| e.urge.as_str() == "catharsis")
+        .expect("catharsis should be explained");
+    assert_eq!(catharsis.tunings.len(), 1);
+    assert_eq!(
+        catharsis.tunings[0].layer,
+        TuningLayer::Persona(PersonaName::new("process"))
+    );
+    let params = GaugeParams::of(&catharsis.gauge);
+    assert!((params["tensions_weight"] - 0.5).abs() < f64::EPSILON);
+
+    Ok(())
+}
+
 pub(crate) async fn introspect_pressure_decreases_after_introspecting<B: Backend>() -> TestResult {
     let harness = Harness::<B>::seed_project().await?;
     harness
@@ -732,6 +732,22 @@ async fn export_import_preserves_storage() -> TestResult {
 async fn import_bootstraps_fresh_project() -> TestResult {
     cases::import_export::import_bootstraps_fresh_project::<EngineBackend>().await
 }
+#[tokio::test]
+async fn gzip_export_dedupes_blobs() -> TestResult {
+    cases::import_export::gzip_export_dedupes_blobs::<EngineBackend>().await
+}
+#[tokio::test]
+async fn filtered_export_keeps_matching_events() -> TestResult {
+    cases::import_export::filtered_export_keeps_matching_events::<EngineBackend>().await
+}
+#[tokio::test]
+async fn import_refuses_truncated_export() -> TestResult {
+    cases::import_export::import_refuses_truncated_export::<EngineBackend>().await
+}
+#[tokio::test]
+async fn import_refuses_export_missing_blobs() -> TestResult {
+    cases::import_export::import_refuses_export_missing_blobs::<EngineBackend>().await
+}
 
 // Pressure
 #[tokio::test]
@@ -739,6 +755,10 @@ async fn pressure_returns_readings() -> TestResult {
     cases::pressure::returns_readings_for_agent::<EngineBackend>().await
 }
 #[tokio::test]
+async fn pressure_explain_names_tuning() -> TestResult {
+    cases::pressure::explain_names_the_tuning_behind_each_urgency::<EngineBackend>().await
+}
+#[tokio::test]
 async fn pressure_introspect_decreases_after_introspecting() -> TestResult {
     cases::pressure::introspect_pressure_decreases_after_introspecting::<EngineBackend>().await
 }
@@ -280,6 +280,64 @@ async fn recollection_size_caps_non_core_memories() {
     assert_eq!(non_core_count, 2, "non-core capped at recollection_size");
 }
 
+// ── Recall scoring ───────────────────────────────────────────────
+
+#[tokio::test]
+async fn recall_prefers_connected_memories_over_newer_orphans() {
+    let app = seeded_app().await;
+    let client = app.client();
+    let agent = seed_agent(&client).await;
+
+    let anchor = add_memory(&client, &agent, "project", "the anchor memory").await;
+    for i in 0..3 {
+        let cognition = add_cognition(&client, &agent, &format!("builds on it {i}")).await;
+        connect(&client, &Ref::cognition(cognition), &Ref::memory(anchor)).await;
+    }
+    for i in 0..3 {
+        add_memory(&client, &agent, "project", &format!("newer orphan {i}")).await;
+    }
+
+    let overrides = DreamOverrides {
+        recollection_size: Some(1),
+        ..Default::default()
+    };
+    let context = dream_with(&client, &agent, &overrides).await;
+
+    let recalled: Vec<MemoryId> = context.memories.iter().map(|m| m.id).collect();
+    assert_eq!(
+        recalled,
+        vec![anchor],
+        "the connected memory outranks newer ones"
+    );
+    assert_eq!(context.recall.len(), 1);
+    assert_eq!(context.recall[0].memory, anchor);
+    assert!(context.recall[0].degree > 0.0, "breakdown shows the degree");
+    assert_eq!(context.recall[0].focus, None, "no focus, no focus factor");
+}
+
+#[tokio::test]
+async fn focus_pulls_on_topic_memories_into_recall() {
+    let app = seeded_app().await;
+    let client = app.client();
+    let agent = seed_agent(&client).await;
+
+    let harbor = add_memory(&client, &agent, "project", "harbor launch checklist").await;
+    for i in 0..3 {
+        add_memory(&client, &agent, "project", &format!("unrelated note {i}")).await;
+    }
+
+    let overrides = DreamOverrides {
+        recollection_size: Some(1),
+        focus: Some("harbor launch".into()),
+        ..Default::default()
+    };
+    let context = dream_with(&client, &agent, &overrides).await;
+
+    let recalled: Vec<MemoryId> = context.memories.iter().map(|m| m.id).collect();
+    assert_eq!(recalled, vec![harbor], "focus outweighs recency");
+    assert_eq!(context.recall[0].focus, Some(1.0));
+}
+
 // ── Cognition selection ──────────────────────────────────────────
 
 #[tokio::test]
@@ -529,6 +587,265 @@ async fn dream_overrides_change_output() {
     );
 }
 
+// ── Token budget ─────────────────────────────────────────────────
+
+#[tokio::test]
+async fn dream_budget_keeps_core_memories_and_reports_drops() {
+    let app = seeded_app().await;
+    let client = app.client();
+    let agent = seed_agent(&client).await;
+
+    let core = add_memory(&client, &agent, "core", "I am fundamentally a thinker").await;
+    for i in 0..10 {
+        add_cognition(
+            &client,
+            &agent,
+            &format!("a long and winding thought, number {i}, that costs a few tokens"),
+        )
+        .await;
+    }
+
+    let unbudgeted = dream(&client, &agent).await;
+    assert_eq!(unbudgeted.truncated, None, "no budget, no truncation");
+
+    let budget = DreamBudget::skeleton(&unbudgeted) + 60;
+    let overrides = DreamOverrides {
+        dream_budget: Some(budget),
+        ..Default::default()
+    };
+    let context = dream_with(&client, &agent, &overrides).await;
+
+    assert!(
+        context.memories.iter().any(|m| m.id == core),
+        "core memories are admitted first"
+    );
+    assert!(context.cognitions.len() < 10);
+
+    let truncated = context.truncated.clone().expect("budget should truncate");
+    assert_eq!(truncated.budget, budget);
+    assert!(truncated.estimated <= budget);
+    assert_eq!(truncated.cognitions, 10 - context.cognitions.len());
+
+    let rendered = DreamTemplate::new(&context).to_string();
+    assert!(rendered.contains(&format!("trimmed to fit about {budget} tokens")));
+}
+
+#[tokio::test]
+async fn dream_budget_prefers_recent_cognitions() {
+    let app = seeded_app().await;
+    let client = app.client();
+    let agent = seed_agent(&client).await;
+
+    for i in 0..6 {
+        add_cognition(&client, &agent, &format!("thought {i}")).await;
+    }
+    let newest = add_cognition(&client, &agent, "the newest thought").await;
+
+    // Room for the pressures and the newest cognition, and nothing else.
+    let full = dream(&client, &agent).await;
+    let pressures: usize = full
+        .pressures
+        .iter()
+        .map(|r| DreamBudget::estimate(&format!("{} {}", r.pressure.urge, r.cta)))
+        .sum();
+    let newest_line = full
+        .cognitions
+        .iter()
+        .find(|c| c.id == newest)
+        .map(|c| DreamBudget::estimate(&format!("- [{}] {}", c.ref_token(), c.content)))
+        .expect("newest cognition in the unbudgeted dream");
+
+    let overrides = DreamOverrides {
+        recent_window: Some(1),
+        dream_budget: Some(DreamBudget::skeleton(&full) + pressures + newest_line),
+        ..Default::default()
+    };
+    let context = dream_with(&client, &agent, &overrides).await;
+
+    let kept: Vec<_> = context.cognitions.iter().map(|c| c.id).collect();
+    assert_eq!(kept, vec![newest], "the recent window outranks the rest");
+    assert!(context.truncated.is_some());
+}
+
+// ── Dream recipes ────────────────────────────────────────────────
+
+async fn add_textured(
+    client: &TestClient,
+    agent: &AgentName,
+    texture: &str,
+    content: &str,
+) -> CognitionId {
+    match client
+        .cognition()
+        .add(
+            &AddCognition::builder_v1()
+                .agent(agent.clone())
+                .texture(texture)
+                .content(content)
+                .build()
+                .into(),
+        )
+        .await
+        .expect("add cognition")
+    {
+        CognitionResponse::CognitionAdded(CognitionAddedResponse::V1(added)) => added.cognition.id,
+        other => panic!("expected CognitionAdded, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn persona_recipe_selects_through_lenses() {
+    let app = seeded_app().await;
+    let client = app.client();
+
+    let section: DreamSection = "questions:2=texture(question) & agent(self)"
+        .parse()
+        .expect("section parses");
+    client
+        .persona()
+        .set(
+            &SetPersona::builder_v1()
+                .name("reviewer")
+                .description("Reviews work")
+                .prompt("You review")
+                .sections(vec![section])
+                .build()
+                .into(),
+        )
+        .await
+        .expect("set persona");
+    client
+        .agent()
+        .create(&CreateAgent::V1(
+            CreateAgentV1::builder()
+                .name("critic")
+                .persona("reviewer")
+                .description("A reviewing agent")
+                .prompt("You review")
+                .build(),
+        ))
+        .await
+        .expect("create agent");
+    let critic = AgentName::new("critic.reviewer");
+    let thinker = seed_agent(&client).await;
+
+    let core = add_memory(&client, &critic, "core", "I look for gaps").await;
+    add_memory(&client, &critic, "project", "not in any section").await;
+    add_textured(&client, &critic, "observation", "just noticing").await;
+    add_textured(&client, &critic, "question", "why this way?").await;
+    let second = add_textured(&client, &critic, "question", "what breaks first?").await;
+    let third = add_textured(&client, &critic, "question", "who reads this?").await;
+    add_textured(&client, &thinker, "question", "someone else's question").await;
+
+    let context = dream(&client, &critic).await;
+
+    let persona = context.persona.clone().expect("persona in dream");
+    assert_eq!(persona.recipe.map(|r| r.sections.len()), Some(1));
+
+    let memory_ids: Vec<_> = context.memories.iter().map(|m| m.id).collect();
+    assert_eq!(
+        memory_ids,
+        vec![core],
+        "core memories only, beside sections"
+    );
+
+    let cognition_ids: HashSet<_> = context.cognitions.iter().map(|c| c.id).collect();
+    assert_eq!(
+        cognition_ids,
+        HashSet::from([second, third]),
+        "the newest of the critic's own questions, capped at 2"
+    );
+    assert!(context.experiences.is_empty());
+}
+
+#[tokio::test]
+async fn persona_without_recipe_uses_default_recipe() {
+    let app = seeded_app().await;
+    let client = app.client();
+    let agent = seed_agent(&client).await;
+
+    add_textured(&client, &agent, "question", "a question").await;
+    add_textured(&client, &agent, "observation", "an observation").await;
+
+    let context = dream(&client, &agent).await;
+
+    assert_eq!(context.persona.and_then(|p| p.recipe), None);
+    assert_eq!(context.cognitions.len(), 2, "sparse graph includes all");
+}
+
+// ── Since last dream ─────────────────────────────────────────────
+
+async fn since_last(client: &TestClient, agent: &AgentName) -> DreamContext {
+    let overrides = DreamOverrides {
+        since_last: Some(true),
+        ..Default::default()
+    };
+    dream_with(client, agent, &overrides).await
+}
+
+#[tokio::test]
+async fn since_last_is_absent_before_the_first_dream() {
+    let app = seeded_app().await;
+    let client = app.client();
+    let agent = seed_agent(&client).await;
+
+    let context = since_last(&client, &agent).await;
+    assert!(
+        context.since_last.is_none(),
+        "nothing to compare against yet"
+    );
+}
+
+#[tokio::test]
+async fn since_last_reports_what_changed_since_the_last_dream() {
+    let app = seeded_app().await;
+    let client = app.client();
+    let agent = seed_agent(&client).await;
+    add_memory(&client, &agent, "core", "known before").await;
+
+    dream(&client, &agent).await;
+
+    client
+        .agent()
+        .create(&CreateAgent::V1(
+            CreateAgentV1::builder()
+                .name("watcher")
+                .persona("process")
+                .description("Another agent")
+                .prompt("You watch")
+                .build(),
+        ))
+        .await
+        .expect("create agent");
+    let watcher = AgentName::new("watcher.process");
+
+    let memory = add_memory(&client, &agent, "project", "learned while away").await;
+    let theirs = add_experience(&client, &watcher, "something they saw").await;
+    add_experience(&client, &agent, "my own experience").await;
+    connect(&client, &Ref::memory(memory), &Ref::experience(theirs)).await;
+
+    let context = since_last(&client, &agent).await;
+    let diff = context
+        .since_last
+        .as_ref()
+        .expect("agent has dreamed before");
+
+    let memories: Vec<MemoryId> = diff.memories.iter().map(|m| m.id).collect();
+    let experiences: Vec<ExperienceId> = diff.experiences.iter().map(|e| e.id).collect();
+    assert_eq!(memories, vec![memory], "only memories made since");
+    assert_eq!(experiences, vec![theirs], "only other agents' experiences");
+    assert_eq!(diff.connections.len(), 1);
+
+    let rendered = DreamTemplate::new(&context).to_string();
+    assert!(
+        rendered.starts_with("## Since you last dreamed"),
+        "the diff leads the dream, got: {}",
+        &rendered[..rendered.len().min(120)]
+    );
+    assert!(rendered.contains("learned while away"));
+    assert!(rendered.contains("something they saw"));
+}
+
 // ── Ordering ─────────────────────────────────────────────────────
 
 #[tokio::test]
@@ -47,6 +47,30 @@ impl TestApp {
     /// production migration hook runs).
     pub(crate) async fn with_data_dir(
         dir: tempfile::TempDir,
+    ) -> Result<Self, Box<dyn core::error::Error>> {
+        Self::boot(dir, SyncConfig::default(), RetentionConfig::default()).await
+    }
+
+    /// Boot a test app whose background follow sync runs on `sync`
+    /// instead of the production schedule.
+    pub(crate) async fn with_sync(sync: SyncConfig) -> Result<Self, Box<dyn core::error::Error>> {
+        let dir = tempfile::tempdir().expect("create tempdir");
+        Self::boot(dir, sync, RetentionConfig::default()).await
+    }
+
+    /// Boot a test app whose retention sweeps run on `retention`
+    /// instead of the production schedule.
+    pub(crate) async fn with_retention(
+        retention: RetentionConfig,
+    ) -> Result<Self, Box<dyn core::error::Error>> {
+        let dir = tempfile::tempdir().expect("create tempdir");
+        Self::boot(dir, SyncConfig::default(), retention).await
+    }
+
+    async fn boot(
+        dir: tempfile::TempDir,
+        sync: SyncConfig,
+        retention: RetentionConfig,
     ) -> Result<Self, Box<dyn core::error::Error>> {
         let mut config = Config::builder()
             .data_dir(dir.path().to_path_buf())
@@ -64,6 +88,8 @@ impl TestApp {
                 interval: std::time::Duration::from_millis(2),
                 timeout: std::time::Duration::from_millis(100),
             })
+            .sync(sync)
+            .retention(retention)
             .build();
 
         let handle = Server::new(config.clone()).spawn().await?;
@@ -83,6 +109,18 @@ impl TestApp {
         })
     }
 
+    /// Stop the server and boot a new one over the same data directory,
+    /// as restarting the host would. Only what was persisted carries
+    /// over; clients made before the restart point at the old server.
+    pub(crate) async fn restart(self) -> Result<Self, Box<dyn core::error::Error>> {
+        let sync = self.config().sync.clone();
+        let retention = self.config().retention.clone();
+        let Self { _dir, _handle, .. } = self;
+        drop(_handle);
+
+        Self::boot(_dir, sync, retention).await
+    }
+
     /// Initialize the host database and default tenant/actor.
     pub(crate) async fn init_host(self) -> Result<Self, Box<dyn core::error::Error>> {
         self.command("host init --name test").await?;
@@ -337,6 +375,14 @@ impl<'a> CognitionClient<'a> {
         let bytes = request.execute_request(self.client).await?;
         decode(bytes, "cognition")
     }
+
+    pub(crate) async fn retract(
+        &self,
+        request: &RetractCognition,
+    ) -> Result<CognitionResponse, ClientError> {
+        let bytes = request.execute_request(self.client).await?;
+        decode(bytes, "cognition")
+    }
 }
 
 pub(crate) struct ContinuityClient<'a> {
@@ -459,6 +505,15 @@ fn encode_dream_overrides(overrides: &DreamOverrides) -> String {
     if let Some(value) = overrides.experience_size {
         parts.push(format!("experience_size={value}"));
     }
+    if let Some(value) = overrides.dream_budget {
+        parts.push(format!("dream_budget={value}"));
+    }
+    if let Some(value) = &overrides.focus {
+        parts.push(format!("focus={value}"));
+    }
+    if let Some(value) = overrides.since_last {
+        parts.push(format!("since_last={value}"));
+    }
     parts.join("&")
 }
 
@@ -521,6 +576,14 @@ impl<'a> MemoryClient<'a> {
         let bytes = request.execute_request(self.client).await?;
         decode(bytes, "memory")
     }
+
+    pub(crate) async fn supersede(
+        &self,
+        request: &SupersedeMemory,
+    ) -> Result<MemoryResponse, ClientError> {
+        let bytes = request.execute_request(self.client).await?;
+        decode(bytes, "memory")
+    }
 }
 
 pub(crate) struct ExperienceClient<'a> {
@@ -555,6 +618,14 @@ impl<'a> ExperienceClient<'a> {
         let bytes = request.execute_request(self.client).await?;
         decode(bytes, "experience")
     }
+
+    pub(crate) async fn amend(
+        &self,
+        request: &AmendExperience,
+    ) -> Result<ExperienceResponse, ClientError> {
+        let bytes = request.execute_request(self.client).await?;
+        decode(bytes, "experience")
+    }
 }
 
 pub(crate) struct ConnectionClient<'a> {
@@ -612,6 +683,14 @@ impl<'a> PressureClient<'a> {
         let bytes = request.execute_request(self.client).await?;
         decode(bytes, "pressure")
     }
+
+    pub(crate) async fn explain(
+        &self,
+        request: &ExplainPressure,
+    ) -> Result<PressureResponse, ClientError> {
+        let bytes = request.execute_request(self.client).await?;
+        decode(bytes, "pressure")
+    }
 }
 
 pub(crate) struct PersonaClient<'a> {
@@ -724,6 +803,11 @@ impl<'a> UrgeClient<'a> {
         Self { client }
     }
 
+    pub(crate) async fn set(&self, request: &SetUrge) -> Result<UrgeResponse, ClientError> {
+        let bytes = request.execute_request(self.client).await?;
+        decode(bytes, "urge")
+    }
+
     pub(crate) async fn get(&self, request: &GetUrge) -> Result<UrgeResponse, ClientError> {
         let bytes = request.execute_request(self.client).await?;
         decode(bytes, "urge")
@@ -845,6 +929,14 @@ impl<'a> ProjectClient<'a> {
         let bytes = request.execute_request(self.client).await?;
         decode(bytes, "project")
     }
+
+    pub(crate) async fn redact(
+        &self,
+        request: &RedactProject,
+    ) -> Result<ProjectResponse, ClientError> {
+        let bytes = request.execute_request(self.client).await?;
+        decode(bytes, "project")
+    }
 }
 
 pub(crate) struct TicketClient<'a> {
@@ -881,6 +973,27 @@ impl<'a> TicketClient<'a> {
         let bytes = request.execute_request(self.client).await?;
         decode(bytes, "ticket")
     }
+
+    pub(crate) async fn revoke(
+        &self,
+        request: &RevokeTicket,
+    ) -> Result<TicketResponse, ClientError> {
+        let bytes = request.execute_request(self.client).await?;
+        decode(bytes, "ticket")
+    }
+
+    pub(crate) async fn rotate(
+        &self,
+        request: &RotateTicket,
+    ) -> Result<TicketResponse, ClientError> {
+        let bytes = request.execute_request(self.client).await?;
+        decode(bytes, "ticket")
+    }
+
+    pub(crate) async fn audit(&self, request: &AuditTicket) -> Result<TicketResponse, ClientError> {
+        let bytes = request.execute_request(self.client).await?;
+        decode(bytes, "ticket")
+    }
 }
 
 pub(crate) struct BookmarkClient<'a> {
@@ -15,6 +15,11 @@
 //! 7. Provenance survives across follow chains
 //! 8. Unfollowing severs the connection
 //! 9. Peers can be managed explicitly (add, list, remove)
+//! 10. Tickets can be rotated, revoked and audited
+//! 11. A running host keeps peer follows collected in the background
+//! 12. A pushing host streams new events to its followers as they happen
+//! 13. Collecting brings the blobs behind storage entries, and repairs
+//!     entries left without one
 
 use crate::tests::harness::TestApp;
 use crate::*;
@@ -435,6 +440,83 @@ async fn collect_is_incremental() -> Result<(), Box<dyn core::error::Error>> {
     Ok(())
 }
 
+/// A memory Alice made before Bob's last dream, but that Bob only
+/// collected after it, is still new to him: the diff goes by when the
+/// log took it in, not when it was made.
+#[tokio::test]
+async fn collected_material_is_new_since_the_last_dream() -> Result<(), Box<dyn core::error::Error>>
+{
+    let alice = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    alice.command("continuity emerge thinker process").await?;
+    alice
+        .command(r#"memory add thinker.process core "The first pattern""#)
+        .await?;
+
+    let link = alice.command("bookmark share main").await?;
+
+    let bob = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    bob.command(&format!("bookmark follow {} --name alice", link.prompt()))
+        .await?;
+    bob.command("bookmark collect alice").await?;
+    bob.command("bookmark switch alice").await?;
+
+    // Alice remembers something Bob hasn't collected yet, then Bob dreams.
+    alice
+        .command(r#"memory add thinker.process core "The second pattern""#)
+        .await?;
+
+    let agent = AgentName::new("thinker.process");
+    let since_last = DreamOverrides {
+        since_last: Some(true),
+        ..Default::default()
+    };
+    bob.client()
+        .continuity()
+        .dream_with(&agent, &since_last)
+        .await?;
+
+    bob.command("bookmark collect alice").await?;
+
+    match bob
+        .client()
+        .continuity()
+        .dream_with(&agent, &since_last)
+        .await?
+    {
+        ContinuityResponse::Dreaming(DreamingResponse::V1(details)) => {
+            let diff = details
+                .context
+                .since_last
+                .expect("Bob has dreamed before, so there should be a diff");
+            let new: Vec<String> = diff
+                .memories
+                .iter()
+                .map(|m| m.content.to_string())
+                .collect();
+            assert_eq!(new, vec!["The second pattern".to_string()]);
+        }
+        other => panic!("expected Dreaming, got {other:?}"),
+    }
+
+    Ok(())
+}
+
 // ── Merge: integrating a followed bookmark ──────────────────────
 
 /// After collecting, Bob merges the followed bookmark into his own
@@ -624,6 +706,44 @@ async fn provenance_survives_follow_chain() -> Result<(), Box<dyn core::error::E
 
 // ── Unfollow: releasing attention ───────────────────────────────
 
+/// A followed bookmark can't be deleted out from under its follow.
+#[tokio::test]
+async fn followed_bookmark_cannot_be_deleted() -> Result<(), Box<dyn core::error::Error>> {
+    let alice = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    let link = alice.command("bookmark share main").await?;
+
+    let bob = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    bob.command(&format!("bookmark follow {} --name alice", link.prompt()))
+        .await?;
+    bob.command("bookmark switch main").await?;
+
+    assert!(
+        bob.command("bookmark delete alice").await.is_err(),
+        "a bookmark a follow collects into can't be deleted"
+    );
+
+    bob.command("bookmark unfollow alice").await?;
+    bob.command("bookmark delete alice").await?;
+
+    Ok(())
+}
+
 /// Unfollowing a bookmark stops future collects from reaching out.
 /// The bookmark remains with whatever was last collected, but the
 /// remote connection is severed.
@@ -945,3 +1065,632 @@ async fn peer_list_reflects_follow() -> Result<(), Box<dyn core::error::Error>>
 
     Ok(())
 }
+
+// ── Tickets: rotation, revocation and audit ─────────────────────
+
+/// Every bridge request Bob makes with Alice's ticket is recorded
+/// against it. Rotating the ticket cuts Bob off until he follows the
+/// successor; revoking the successor cuts him off again, and the token
+/// no longer validates over HTTP either.
+#[tokio::test]
+async fn rotated_and_revoked_tickets_stop_working() -> Result<(), Box<dyn core::error::Error>> {
+    let alice = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    alice.command("continuity emerge thinker process").await?;
+    alice
+        .command(r#"cognition add thinker.process observation "Keys should turn over""#)
+        .await?;
+
+    let link = alice.command("bookmark share main").await?;
+    let first = match link.prompt().parse::<OneirosUri>()? {
+        OneirosUri::Peer(peer_link) => peer_link.link,
+        other => panic!("expected OneirosUri::Peer, got {other:?}"),
+    };
+    let first_id = match alice
+        .client()
+        .ticket()
+        .validate(
+            &ValidateTicket::builder_v1()
+                .token(first.token)
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        TicketResponse::Validated(TicketValidatedResponse::V1(validated)) => validated.ticket.id,
+        other => panic!("expected Validated, got {other:?}"),
+    };
+
+    let bob = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?;
+
+    bob.command(&format!("bookmark follow {} --name alice", link.prompt()))
+        .await?;
+    bob.command("bookmark collect alice").await?;
+
+    // The collect left an audit trail attributed to Bob's host.
+    match alice
+        .client()
+        .ticket()
+        .audit(&AuditTicket::builder_v1().key(first_id).build().into())
+        .await?
+    {
+        TicketResponse::Audited(TicketAuditResponse::V1(audit)) => {
+            assert!(!audit.uses.is_empty(), "collect should record ticket uses");
+            assert!(
+                audit.uses.iter().all(|used| used.peer.is_some()),
+                "every use should name the presenting peer"
+            );
+            assert!(
+                audit
+                    .uses
+                    .iter()
+                    .any(|used| used.operation == Some(BridgeOp::Diff)),
+                "collect should open with a diff request"
+            );
+            assert!(
+                audit
+                    .uses
+                    .iter()
+                    .all(|used| used.operation.is_some_and(|op| op.opens_session())),
+                "a collect's resolves and fetches shouldn't count as uses"
+            );
+        }
+        other => panic!("expected Audited, got {other:?}"),
+    }
+
+    // Rotation issues a successor for the same target and retires the original.
+    let rotated = match alice
+        .client()
+        .ticket()
+        .rotate(&RotateTicket::builder_v1().key(first_id).build().into())
+        .await?
+    {
+        TicketResponse::Rotated(TicketRotatedResponse::V1(rotated)) => rotated,
+        other => panic!("expected Rotated, got {other:?}"),
+    };
+    assert_eq!(rotated.revoked, first_id);
+    assert_eq!(rotated.ticket.link.target, first.target);
+    assert_ne!(rotated.ticket.id, first_id);
+
+    assert!(
+        bob.command("bookmark collect alice").await.is_err(),
+        "the rotated-out ticket should be refused over the bridge"
+    );
+
+    bob.command(&format!(
+        "bookmark follow {} --name alice-next",
+        rotated.uri
+    ))
+    .await?;
+    bob.command("bookmark collect alice-next").await?;
+
+    // Revoking the successor takes effect for the bridge and for HTTP.
+    alice
+        .client()
+        .ticket()
+        .revoke(
+            &RevokeTicket::builder_v1()
+                .key(rotated.ticket.id)
+                .build()
+                .into(),
+        )
+        .await?;
+
+    assert!(
+        bob.command("bookmark collect alice-next").await.is_err(),
+        "a revoked ticket should be refused over the bridge"
+    );
+    assert!(
+        alice
+            .client()
+            .ticket()
+            .validate(
+                &ValidateTicket::builder_v1()
+                    .token(rotated.ticket.link.token.clone())
+                    .build()
+                    .into()
+            )
+            .await
+            .is_err(),
+        "a revoked ticket should no longer validate"
+    );
+
+    // Revoking twice is a conflict, not a silent success.
+    assert!(
+        alice
+            .client()
+            .ticket()
+            .revoke(
+                &RevokeTicket::builder_v1()
+                    .key(rotated.ticket.id)
+                    .build()
+                    .into()
+            )
+            .await
+            .is_err()
+    );
+
+    Ok(())
+}
+
+// ── Background sync: follows collect themselves ─────────────────
+
+/// Bob never runs `bookmark collect`. His host's sync actor pulls
+/// Alice's material on its own schedule, and `follow list` reports how
+/// it went.
+#[tokio::test]
+async fn background_sync_collects_peer_follows() -> Result<(), Box<dyn core::error::Error>> {
+    let alice = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    alice.command("continuity emerge thinker process").await?;
+    for i in 0..3 {
+        alice
+            .command(&format!(
+                r#"cognition add thinker.process observation "Thought {i}""#
+            ))
+            .await?;
+    }
+
+    let link = alice.command("bookmark share main").await?;
+
+    let bob = TestApp::with_sync(
+        SyncConfig::builder()
+            .interval(std::time::Duration::from_millis(100))
+            .build(),
+    )
+    .await?
+    .init_host()
+    .await?
+    .init_project()
+    .await?
+    .seed_core()
+    .await?;
+
+    bob.command(&format!("bookmark follow {} --name alice", link.prompt()))
+        .await?;
+
+    let mut synced = None;
+    for _ in 0..100 {
+        let listed = bob.command("follow list").await?;
+        let Responses::Follow(FollowResponse::Listed(FollowsResponse::V1(listed))) =
+            listed.into_response()
+        else {
+            panic!("expected Follow(Listed)");
+        };
+        let status = listed
+            .follows
+            .items
+            .into_iter()
+            .find_map(|item| match item.data {
+                FollowFoundResponse::V2(found) => found.sync,
+                FollowFoundResponse::V1(_) => None,
+            });
+        if let Some(status) = status
+            && status.events_received > 0
+        {
+            synced = Some(status);
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+
+    let status = synced.expect("background sync should collect the follow");
+    assert!(status.last_success_at.is_some());
+    assert_eq!(status.last_error, None);
+    assert_eq!(status.failures, 0);
+
+    bob.command("bookmark switch alice").await?;
+    let cognitions = bob
+        .client()
+        .cognition()
+        .list(
+            &ListCognitions::builder_v1()
+                .agent(AgentName::new("thinker.process"))
+                .build()
+                .into(),
+        )
+        .await?;
+    match cognitions {
+        CognitionResponse::Cognitions(CognitionsResponse::V1(cogs)) => {
+            assert_eq!(cogs.items.len(), 3, "sync should bring all 3 cognitions");
+        }
+        other => panic!("expected Cognitions, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+/// With push on, Bob's host holds a subscription open to Alice's. Once
+/// Alice has accepted it, what she writes shows up in Bob's followed
+/// bookmark without anyone collecting — the scheduled sweep is an hour
+/// out, so only the push can have brought it.
+#[tokio::test]
+async fn push_sync_streams_new_events_to_followers() -> Result<(), Box<dyn core::error::Error>> {
+    let alice = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    alice.command("continuity emerge thinker process").await?;
+    alice
+        .command(r#"cognition add thinker.process observation "Before the push""#)
+        .await?;
+
+    let link = alice.command("bookmark share main").await?;
+    let token = match link.prompt().parse::<OneirosUri>()? {
+        OneirosUri::Peer(peer_link) => peer_link.link.token,
+        other => panic!("expected OneirosUri::Peer, got {other:?}"),
+    };
+    let ticket_id = match alice
+        .client()
+        .ticket()
+        .validate(&ValidateTicket::builder_v1().token(token).build().into())
+        .await?
+    {
+        TicketResponse::Validated(TicketValidatedResponse::V1(validated)) => validated.ticket.id,
+        other => panic!("expected Validated, got {other:?}"),
+    };
+
+    let bob = TestApp::with_sync(
+        SyncConfig::builder()
+            .interval(std::time::Duration::from_secs(60 * 60))
+            .push(true)
+            .build(),
+    )
+    .await?
+    .init_host()
+    .await?
+    .init_project()
+    .await?
+    .seed_core()
+    .await?;
+
+    bob.command(&format!("bookmark follow {} --name alice", link.prompt()))
+        .await?;
+    bob.command("bookmark collect alice").await?;
+
+    // Alice records the subscription as a use of her ticket.
+    let mut subscribed = false;
+    for _ in 0..100 {
+        let TicketResponse::Audited(TicketAuditResponse::V1(audit)) = alice
+            .client()
+            .ticket()
+            .audit(&AuditTicket::builder_v1().key(ticket_id).build().into())
+            .await?
+        else {
+            panic!("expected Audited");
+        };
+        if audit
+            .uses
+            .iter()
+            .any(|used| used.operation == Some(BridgeOp::Subscribe))
+        {
+            subscribed = true;
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+    assert!(subscribed, "bob's host should subscribe to the follow");
+
+    for i in 0..2 {
+        alice
+            .command(&format!(
+                r#"cognition add thinker.process observation "Pushed {i}""#
+            ))
+            .await?;
+    }
+
+    bob.command("bookmark switch alice").await?;
+    let mut received = 0;
+    for _ in 0..100 {
+        let cognitions = bob
+            .client()
+            .cognition()
+            .list(
+                &ListCognitions::builder_v1()
+                    .agent(AgentName::new("thinker.process"))
+                    .build()
+                    .into(),
+            )
+            .await?;
+        let CognitionResponse::Cognitions(CognitionsResponse::V1(cogs)) = cognitions else {
+            panic!("expected Cognitions");
+        };
+        received = cogs.items.len();
+        if received == 3 {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+    assert_eq!(received, 3, "both pushed cognitions should arrive");
+
+    Ok(())
+}
+
+/// With `on_change`, Bob's local writes prompt a collect from Alice.
+/// A burst of writes shares one: Alice's ticket sees a single diff for
+/// the whole burst.
+#[tokio::test]
+async fn a_burst_of_changes_collects_once() -> Result<(), Box<dyn core::error::Error>> {
+    let alice = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    alice.command("continuity emerge thinker process").await?;
+
+    let link = alice.command("bookmark share main").await?;
+    let token = match link.prompt().parse::<OneirosUri>()? {
+        OneirosUri::Peer(peer_link) => peer_link.link.token,
+        other => panic!("expected OneirosUri::Peer, got {other:?}"),
+    };
+    let ticket_id = match alice
+        .client()
+        .ticket()
+        .validate(&ValidateTicket::builder_v1().token(token).build().into())
+        .await?
+    {
+        TicketResponse::Validated(TicketValidatedResponse::V1(validated)) => validated.ticket.id,
+        other => panic!("expected Validated, got {other:?}"),
+    };
+
+    let diffs = async || -> Result<usize, Box<dyn core::error::Error>> {
+        let TicketResponse::Audited(TicketAuditResponse::V1(audit)) = alice
+            .client()
+            .ticket()
+            .audit(&AuditTicket::builder_v1().key(ticket_id).build().into())
+            .await?
+        else {
+            panic!("expected Audited");
+        };
+        Ok(audit
+            .uses
+            .iter()
+            .filter(|used| used.operation == Some(BridgeOp::Diff))
+            .count())
+    };
+
+    let bob = TestApp::with_sync(
+        SyncConfig::builder()
+            .interval(std::time::Duration::from_secs(60 * 60))
+            .on_change(true)
+            .build(),
+    )
+    .await?
+    .init_host()
+    .await?
+    .init_project()
+    .await?
+    .seed_core()
+    .await?;
+
+    bob.command(&format!("bookmark follow {} --name alice", link.prompt()))
+        .await?;
+    bob.command("bookmark collect alice").await?;
+    bob.command("bookmark switch main").await?;
+    let followed = diffs().await?;
+    bob.command("continuity emerge thinker process").await?;
+
+    // Collects land on alice's audit behind the response, so wait for
+    // each to show up rather than guessing how long it takes.
+    let collected = async |past: usize| -> Result<usize, Box<dyn core::error::Error>> {
+        for _ in 0..100 {
+            let seen = diffs().await?;
+            if seen > past {
+                tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+                return diffs().await;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        }
+        Err("no collect arrived".into())
+    };
+    let before = collected(followed).await?;
+
+    for i in 0..5 {
+        bob.command(&format!(
+            r#"cognition add thinker.process observation "Local {i}""#
+        ))
+        .await?;
+    }
+
+    assert_eq!(
+        collected(before).await? - before,
+        1,
+        "the burst should collect once"
+    );
+
+    Ok(())
+}
+
+// ── Blobs: storage content travels with its entries ─────────────
+
+/// Alice stores an artifact. When Bob collects, the `StorageSet`
+/// arrives with the blob behind it — Bob reads back exactly the bytes
+/// Alice stored.
+#[tokio::test]
+async fn collect_fetches_storage_blobs() -> Result<(), Box<dyn core::error::Error>> {
+    let alice = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    // Noise, so compression can't hide a truncated transfer.
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    let data: Vec<u8> = (0..256 * 1024)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        })
+        .collect();
+
+    alice
+        .client()
+        .storage()
+        .upload(
+            &UploadStorage::builder_v1()
+                .key("archive.bin")
+                .description("Collected artifact")
+                .data(data.clone())
+                .build()
+                .into(),
+        )
+        .await?;
+
+    let link = alice.command("bookmark share main").await?;
+
+    let bob = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    bob.command(&format!("bookmark follow {} --name alice", link.prompt()))
+        .await?;
+    bob.command("bookmark collect alice").await?;
+    bob.command("bookmark switch alice").await?;
+
+    let out = tempfile::tempdir()?;
+    let path = out.path().join("archive.bin");
+    bob.command(&format!("storage get archive.bin --out {}", path.display()))
+        .await?;
+
+    let fetched = crate::Platform::new(out.path()).read(&path)?;
+    assert_eq!(fetched.len(), data.len());
+    assert!(
+        fetched == data,
+        "the collected blob should match Alice's bytes"
+    );
+
+    Ok(())
+}
+
+/// Bob's copy of Alice's bookmark has lost a blob — as a collect from
+/// before blobs travelled would have left it. `storage fsck` reports
+/// the entry, and the next collect that brings anything fetches the
+/// blob back and restores the entry.
+#[tokio::test]
+async fn collect_repairs_dangling_storage() -> Result<(), Box<dyn core::error::Error>> {
+    let alice = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    alice.command("continuity emerge thinker process").await?;
+    alice
+        .client()
+        .storage()
+        .upload(
+            &UploadStorage::builder_v1()
+                .key("notes.md")
+                .description("Session notes")
+                .data(b"# Notes\nBlobs travel too.".to_vec())
+                .build()
+                .into(),
+        )
+        .await?;
+
+    let link = alice.command("bookmark share main").await?;
+
+    let bob = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    bob.command(&format!("bookmark follow {} --name alice", link.prompt()))
+        .await?;
+    bob.command("bookmark collect alice").await?;
+    bob.command("bookmark switch alice").await?;
+
+    let dangling = |checked: Rendered<Responses>| match checked.into_response() {
+        Responses::Storage(StorageResponse::Checked(StorageCheckedResponse::V1(checked))) => {
+            checked.dangling
+        }
+        other => panic!("expected Storage(Checked), got {other:?}"),
+    };
+    assert!(dangling(bob.command("storage fsck").await?).is_empty());
+
+    {
+        let config = bob.config();
+        let db = BookmarkDb::open_with(
+            &config.platform(),
+            &config.project,
+            &BookmarkName::new("alice"),
+            config.database.limit_attached,
+        )
+        .await?;
+        db.execute_batch("DELETE FROM storage; DELETE FROM blob;")?;
+    }
+
+    let missing = dangling(bob.command("storage fsck").await?);
+    assert_eq!(missing.len(), 1);
+    assert_eq!(missing[0].key, StorageKey::new("notes.md"));
+
+    alice
+        .command(r#"cognition add thinker.process observation "One more""#)
+        .await?;
+    bob.command("bookmark collect alice").await?;
+
+    assert!(dangling(bob.command("storage fsck").await?).is_empty());
+    match bob
+        .client()
+        .storage()
+        .show(
+            &GetStorage::builder_v1()
+                .key(StorageKey::new("notes.md"))
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        StorageResponse::StorageDetails(StorageDetailsResponse::V1(details)) => {
+            assert_eq!(details.entry.description.as_str(), "Session notes");
+        }
+        other => panic!("expected StorageDetails, got {other:?}"),
+    }
+
+    Ok(())
+}
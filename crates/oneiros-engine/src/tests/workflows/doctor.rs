@@ -53,3 +53,50 @@ async fn doctor_reports_token_rejected_from_mcp_json() -> Result<(), Box<dyn cor
 
     Ok(())
 }
+
+#[tokio::test]
+async fn doctor_reports_harness_config_without_oneiros() -> Result<(), Box<dyn core::error::Error>>
+{
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?;
+
+    let root = std::env::current_dir()?.join(".codex");
+    let platform = Platform::default();
+    platform.ensure_dir(&root)?;
+    platform.write(
+        root.join("config.toml"),
+        "[mcp_servers.other]\ncommand = \"other\"\n",
+    )?;
+
+    let rendered = app.command("doctor").await;
+    platform.remove_dir_all(&root)?;
+
+    let response = rendered?.response().clone();
+    let Responses::Doctor(DoctorResponse::CheckupStatus(CheckupStatusResponse::V1(details))) =
+        response
+    else {
+        panic!("expected Doctor(CheckupStatus(..)), got {response:#?}");
+    };
+
+    assert!(
+        details
+            .checks
+            .iter()
+            .any(|c| matches!(c, DoctorCheck::HarnessServerMissing(Harness::Codex))),
+        "expected HarnessServerMissing(codex) in checks: {:?}",
+        details.checks
+    );
+    assert!(
+        !details
+            .checks
+            .iter()
+            .any(|c| matches!(c, DoctorCheck::HarnessConfigured(Harness::Cursor))),
+        "harnesses without a config file stay quiet"
+    );
+
+    Ok(())
+}
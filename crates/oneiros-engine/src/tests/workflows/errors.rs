@@ -4,7 +4,7 @@
 //! should fail through the CLI, the typed client, and MCP alike. These
 //! tests prove the error contract across boundaries.
 
-use crate::tests::harness::{AgentClient, TenantClient, TestApp};
+use crate::tests::harness::{AgentClient, CognitionClient, TenantClient, TestApp, TicketClient};
 use crate::*;
 
 /// Project-scoped routes reject unauthenticated and badly-authenticated
@@ -63,6 +63,85 @@ async fn auth_boundaries() -> Result<(), Box<dyn core::error::Error>> {
     Ok(())
 }
 
+/// A ticket issued with explicit permissions reaches exactly the routes
+/// those permissions cover; everything else is refused with 403.
+#[tokio::test]
+async fn scoped_tickets_are_refused_outside_their_grant() -> Result<(), Box<dyn core::error::Error>>
+{
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    let client = app.client();
+    let own = app.token().expect("project token");
+    let actor_id = match client
+        .ticket()
+        .validate(&ValidateTicket::builder_v1().token(own).build().into())
+        .await?
+    {
+        TicketResponse::Validated(TicketValidatedResponse::V1(validated)) => {
+            validated.ticket.actor_id
+        }
+        other => panic!("expected Validated, got {other:?}"),
+    };
+
+    let issued = client
+        .ticket()
+        .issue(
+            &CreateTicket::builder_v1()
+                .actor_id(actor_id)
+                .project_name(app.config().project.clone())
+                .permissions(vec![PermissionOp::ProjectRead, PermissionOp::Search])
+                .build()
+                .into(),
+        )
+        .await?;
+    let token = match issued {
+        TicketResponse::Created(TicketCreatedResponse::V1(created)) => created.ticket.link.token,
+        other => panic!("expected Created, got {other:?}"),
+    };
+
+    let scoped = Client::with_token(app.base_url(), token)?;
+
+    assert!(
+        AgentClient::new(&scoped)
+            .list(&ListAgents::builder_v1().build().into())
+            .await
+            .is_ok(),
+        "project-read should cover listing agents"
+    );
+
+    let write = CognitionClient::new(&scoped)
+        .add(
+            &AddCognition::builder_v1()
+                .agent(AgentName::new("ghost.nobody"))
+                .texture("observation")
+                .content("hello")
+                .build()
+                .into(),
+        )
+        .await;
+    assert!(
+        matches!(write, Err(ClientError::Server { status: 403, .. })),
+        "writing a cognition needs cognition-write, got {write:?}"
+    );
+
+    let admin = TicketClient::new(&scoped)
+        .list(&ListTickets::builder_v1().build().into())
+        .await;
+    assert!(
+        matches!(admin, Err(ClientError::Server { status: 403, .. })),
+        "listing tickets needs host-admin, got {admin:?}"
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn missing_entities() -> Result<(), Box<dyn core::error::Error>> {
     let app = TestApp::new()
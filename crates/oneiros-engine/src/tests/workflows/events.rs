@@ -0,0 +1,274 @@
+//! Events workflow — the live event stream.
+//!
+//! `/events/stream` pushes each event appended to the project log as a
+//! Server-Sent Event, its sequence as the SSE id. A client that sends
+//! `Last-Event-ID` gets the log after that sequence replayed first.
+//! Events collected from a peer are pushed like local ones.
+
+use futures::StreamExt;
+
+use crate::tests::harness::TestApp;
+use crate::*;
+
+/// The next data frame on an SSE response, as JSON.
+async fn next_event<B: AsRef<[u8]>>(
+    body: &mut (impl futures::Stream<Item = reqwest::Result<B>> + Unpin),
+    buffer: &mut Vec<u8>,
+) -> Result<serde_json::Value, Box<dyn core::error::Error>> {
+    loop {
+        while let Some(end) = buffer.windows(2).position(|pair| pair == b"\n\n") {
+            let frame: Vec<u8> = buffer.drain(..end + 2).collect();
+            let frame = String::from_utf8(frame)?;
+            let data: String = frame
+                .lines()
+                .filter_map(|line| line.strip_prefix("data:"))
+                .map(str::trim_start)
+                .collect();
+            if !data.is_empty() {
+                return Ok(serde_json::from_str(&data)?);
+            }
+        }
+
+        let chunk = tokio::time::timeout(std::time::Duration::from_secs(5), body.next())
+            .await?
+            .ok_or("stream ended")??;
+        buffer.extend_from_slice(chunk.as_ref());
+    }
+}
+
+async fn add_cognition(app: &TestApp, agent: &str, content: &str) -> Result<(), ClientError> {
+    app.client()
+        .cognition()
+        .add(
+            &AddCognition::builder_v1()
+                .agent(AgentName::new(agent))
+                .texture(TextureName::new("observation"))
+                .content(content)
+                .build()
+                .into(),
+        )
+        .await?;
+    Ok(())
+}
+
+async fn create_agent(app: &TestApp, name: &str) -> Result<(), ClientError> {
+    app.client()
+        .agent()
+        .create(
+            &CreateAgent::builder_v1()
+                .name(AgentName::new(name))
+                .persona(PersonaName::new("process"))
+                .build()
+                .into(),
+        )
+        .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn stream_pushes_appended_events() -> Result<(), Box<dyn core::error::Error>> {
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+    create_agent(&app, "governor.process").await?;
+
+    let client = Client::from_config(app.config())?;
+    let response = client
+        .stream(
+            "/events/stream",
+            &[("type", "cognition-added".to_string())],
+            None,
+        )
+        .await?;
+    let mut body = response.bytes_stream();
+    let mut buffer = Vec::new();
+
+    add_cognition(&app, "governor.process", "heard live").await?;
+
+    let event = next_event(&mut body, &mut buffer).await?;
+    assert_eq!(event["data"]["type"], "cognition-added");
+    assert_eq!(event["data"]["data"]["content"], "heard live");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn stream_replays_after_last_event_id() -> Result<(), Box<dyn core::error::Error>> {
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+    create_agent(&app, "governor.process").await?;
+    add_cognition(&app, "governor.process", "first").await?;
+    add_cognition(&app, "governor.process", "second").await?;
+
+    let client = Client::from_config(app.config())?;
+    let filter = [("type", "cognition-added".to_string())];
+
+    let response = client.stream("/events/stream", &filter, Some(0)).await?;
+    let mut body = response.bytes_stream();
+    let mut buffer = Vec::new();
+    let first = next_event(&mut body, &mut buffer).await?;
+    let second = next_event(&mut body, &mut buffer).await?;
+    assert_eq!(first["data"]["data"]["content"], "first");
+    assert_eq!(second["data"]["data"]["content"], "second");
+
+    // Resuming from the first event's sequence skips it.
+    let sequence = first["sequence"].as_i64().expect("sequence");
+    let response = client
+        .stream("/events/stream", &filter, Some(sequence))
+        .await?;
+    let mut body = response.bytes_stream();
+    let mut buffer = Vec::new();
+    let resumed = next_event(&mut body, &mut buffer).await?;
+    assert_eq!(resumed["data"]["data"]["content"], "second");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn stream_filters_by_agent() -> Result<(), Box<dyn core::error::Error>> {
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+    create_agent(&app, "governor.process").await?;
+    create_agent(&app, "watcher.process").await?;
+    add_cognition(&app, "governor.process", "not mine").await?;
+    add_cognition(&app, "watcher.process", "mine").await?;
+
+    let client = Client::from_config(app.config())?;
+    let response = client
+        .stream(
+            "/events/stream",
+            &[
+                ("type", "cognition-added".to_string()),
+                ("agent", "watcher.process".to_string()),
+            ],
+            Some(0),
+        )
+        .await?;
+    let mut body = response.bytes_stream();
+    let mut buffer = Vec::new();
+
+    let event = next_event(&mut body, &mut buffer).await?;
+    assert_eq!(event["data"]["data"]["content"], "mine");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn stream_filters_by_lens() -> Result<(), Box<dyn core::error::Error>> {
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+    create_agent(&app, "governor.process").await?;
+    create_agent(&app, "watcher.process").await?;
+
+    let client = Client::from_config(app.config())?;
+    let response = client
+        .stream(
+            "/events/stream",
+            &[
+                ("type", "cognition-added".to_string()),
+                ("lens", "agent(watcher.process)".to_string()),
+            ],
+            None,
+        )
+        .await?;
+    let mut body = response.bytes_stream();
+    let mut buffer = Vec::new();
+
+    add_cognition(&app, "governor.process", "not mine").await?;
+    add_cognition(&app, "watcher.process", "mine").await?;
+
+    let event = next_event(&mut body, &mut buffer).await?;
+    assert_eq!(event["data"]["data"]["content"], "mine");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn stream_pushes_collected_events() -> Result<(), Box<dyn core::error::Error>> {
+    let alice = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+    create_agent(&alice, "governor.process").await?;
+    let link = alice.command("bookmark share main").await?;
+
+    let bob = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+    bob.command(&format!("bookmark follow {} --name alice", link.prompt()))
+        .await?;
+
+    let client = Client::from_config(bob.config())?;
+    let response = client
+        .stream(
+            "/events/stream",
+            &[("type", "cognition-added".to_string())],
+            None,
+        )
+        .await?;
+    let mut body = response.bytes_stream();
+    let mut buffer = Vec::new();
+
+    add_cognition(&alice, "governor.process", "heard from afar").await?;
+    bob.command("bookmark collect alice").await?;
+
+    let event = next_event(&mut body, &mut buffer).await?;
+    assert_eq!(event["data"]["data"]["content"], "heard from afar");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn stream_rejects_unknown_agents() -> Result<(), Box<dyn core::error::Error>> {
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?;
+
+    let client = Client::from_config(app.config())?;
+    let result = client
+        .stream(
+            "/events/stream",
+            &[("agent", "nobody.process".to_string())],
+            None,
+        )
+        .await;
+
+    assert!(matches!(result, Err(ClientError::NotFound(_))));
+
+    Ok(())
+}
@@ -52,8 +52,21 @@ async fn mcp_initialize_with_token(
     url: &str,
     token: &Token,
 ) -> Option<String> {
-    let init_resp = mcp_post(http, url)
-        .header("Authorization", format!("Bearer {token}"))
+    mcp_initialize_on(http, url, token, None).await
+}
+
+/// Initialize a session, on `bookmark` when one is named.
+async fn mcp_initialize_on(
+    http: &reqwest::Client,
+    url: &str,
+    token: &Token,
+    bookmark: Option<&str>,
+) -> Option<String> {
+    let mut init = mcp_post(http, url).header("Authorization", format!("Bearer {token}"));
+    if let Some(bookmark) = bookmark {
+        init = init.header("X-Bookmark", bookmark);
+    }
+    let init_resp = init
         .json(&serde_json::json!({
             "jsonrpc": "2.0",
             "id": 1,
@@ -203,8 +216,8 @@ async fn tools_discovery() -> Result<(), Box<dyn core::error::Error>> {
 
     assert_eq!(
         tools.len(),
-        8,
-        "expected exactly 8 tools, got {}",
+        26,
+        "expected exactly 26 tools, got {}",
         tools.len()
     );
 
@@ -213,10 +226,28 @@ async fn tools_discovery() -> Result<(), Box<dyn core::error::Error>> {
     assert!(tool_names.contains(&"update-agent"));
     assert!(tool_names.contains(&"remove-agent"));
     assert!(tool_names.contains(&"add-cognition"));
+    assert!(tool_names.contains(&"explain-pressure"));
+    assert!(tool_names.contains(&"supersede-memory"));
+    assert!(tool_names.contains(&"retract-cognition"));
+    assert!(tool_names.contains(&"amend-experience"));
     assert!(tool_names.contains(&"add-memory"));
     assert!(tool_names.contains(&"create-experience"));
     assert!(tool_names.contains(&"create-connection"));
     assert!(tool_names.contains(&"search-query"));
+    assert!(tool_names.contains(&"preview-merge"));
+    assert!(tool_names.contains(&"preview-collect"));
+    assert!(tool_names.contains(&"explain-lens"));
+    assert!(tool_names.contains(&"query-lens"));
+    assert!(tool_names.contains(&"upload-storage"));
+    assert!(tool_names.contains(&"get-storage"));
+    assert!(tool_names.contains(&"trail-of"));
+    assert!(tool_names.contains(&"trail-from"));
+    assert!(tool_names.contains(&"wake-agent"));
+    assert!(tool_names.contains(&"introspect-agent"));
+    assert!(tool_names.contains(&"reflect-agent"));
+    assert!(tool_names.contains(&"sense-content"));
+    assert!(tool_names.contains(&"sleep-agent"));
+    assert!(tool_names.contains(&"get-pressure"));
 
     for tool in tools {
         assert!(tool["name"].is_string());
@@ -266,6 +297,182 @@ async fn tool_responses_are_markdown_with_hints() -> Result<(), Box<dyn core::er
     Ok(())
 }
 
+#[tokio::test]
+async fn lens_tools_query_and_explain() -> Result<(), Box<dyn core::error::Error>> {
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+    app.command(r#"agent create thinker process --description "A thinking agent""#)
+        .await?;
+    app.command(r#"cognition add thinker.process observation "Lenses from the toolbox""#)
+        .await?;
+
+    let token = app.token().expect("project should have a token");
+    let http = reqwest::Client::new();
+    let url = app.mcp_url();
+    let session_id = mcp_initialize_with_token(&http, &url, &token).await;
+
+    let result = mcp_call_tool(
+        &http,
+        &url,
+        &session_id,
+        3,
+        "query-lens",
+        serde_json::json!({ "source": "agent(thinker.process)" }),
+    )
+    .await;
+    let text = tool_text(&result);
+    assert!(
+        text.contains("# Lens — agent(thinker.process)") && text.contains("entity"),
+        "lens query should list hits, got: {text}"
+    );
+
+    let result = mcp_call_tool(
+        &http,
+        &url,
+        &session_id,
+        4,
+        "explain-lens",
+        serde_json::json!({ "source": "agent(thinker.process)" }),
+    )
+    .await;
+    let text = tool_text(&result);
+    assert!(
+        text.contains("## Plan"),
+        "explain should show the plan, got: {text}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn lifecycle_tools_render_prompts() -> Result<(), Box<dyn core::error::Error>> {
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+    app.command(r#"agent create thinker process --description "A thinking agent""#)
+        .await?;
+
+    let token = app.token().expect("project should have a token");
+    let http = reqwest::Client::new();
+    let url = app.mcp_url();
+    let session_id = mcp_initialize_with_token(&http, &url, &token).await;
+
+    let result = mcp_call_tool(
+        &http,
+        &url,
+        &session_id,
+        3,
+        "wake-agent",
+        serde_json::json!({ "agent": "thinker.process" }),
+    )
+    .await;
+    assert!(!result["result"]["isError"].as_bool().unwrap_or(false));
+    let text = tool_text(&result);
+    assert!(
+        text.contains("thinker.process"),
+        "wake should render the dream context, got: {text}"
+    );
+
+    let result = mcp_call_tool(
+        &http,
+        &url,
+        &session_id,
+        4,
+        "get-pressure",
+        serde_json::json!({ "agent": "thinker.process" }),
+    )
+    .await;
+    let text = tool_text(&result);
+    assert!(
+        text.contains("# Pressure — thinker.process"),
+        "pressure should be markdown, got: {text}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn storage_tools_archive_and_trail() -> Result<(), Box<dyn core::error::Error>> {
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    let token = app.token().expect("project should have a token");
+    let http = reqwest::Client::new();
+    let url = app.mcp_url();
+    let session_id = mcp_initialize_with_token(&http, &url, &token).await;
+
+    let result = mcp_call_tool(
+        &http,
+        &url,
+        &session_id,
+        3,
+        "upload-storage",
+        serde_json::json!({
+            "key": "notes.txt",
+            "description": "Session notes",
+            "data": data_encoding::BASE64.encode(b"archived without a shell"),
+        }),
+    )
+    .await;
+    let text = tool_text(&result);
+    assert!(text.contains("Stored notes.txt."), "got: {text}");
+
+    let result = mcp_call_tool(
+        &http,
+        &url,
+        &session_id,
+        6,
+        "upload-storage",
+        serde_json::json!({ "key": "notes.txt", "data": "not base64!" }),
+    )
+    .await;
+    assert_eq!(result["result"]["isError"].as_bool(), Some(true));
+
+    let result = mcp_call_tool(
+        &http,
+        &url,
+        &session_id,
+        4,
+        "get-storage",
+        serde_json::json!({ "key": "notes.txt" }),
+    )
+    .await;
+    let text = tool_text(&result);
+    assert!(text.contains("Session notes"), "got: {text}");
+
+    let entity = RefToken::new(Ref::storage(StorageKey::new("notes.txt")));
+    let result = mcp_call_tool(
+        &http,
+        &url,
+        &session_id,
+        5,
+        "trail-of",
+        serde_json::json!({ "ref": entity.to_string() }),
+    )
+    .await;
+    let text = tool_text(&result);
+    assert!(text.contains("storage-set"), "got: {text}");
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn tool_auth_session() -> Result<(), Box<dyn core::error::Error>> {
     let app = TestApp::new()
@@ -301,6 +508,89 @@ async fn tool_auth_session() -> Result<(), Box<dyn core::error::Error>> {
     Ok(())
 }
 
+#[tokio::test]
+async fn scoped_ticket_sessions_only_reach_granted_tools() -> Result<(), Box<dyn core::error::Error>>
+{
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    let client = app.client();
+    let own = app.token().expect("project token");
+    let actor_id = match client
+        .ticket()
+        .validate(&ValidateTicket::builder_v1().token(own).build().into())
+        .await?
+    {
+        TicketResponse::Validated(TicketValidatedResponse::V1(validated)) => {
+            validated.ticket.actor_id
+        }
+        other => panic!("expected Validated, got {other:?}"),
+    };
+    let token = match client
+        .ticket()
+        .issue(
+            &CreateTicket::builder_v1()
+                .actor_id(actor_id)
+                .project_name(app.config().project.clone())
+                .permissions(vec![PermissionOp::ProjectRead, PermissionOp::Search])
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        TicketResponse::Created(TicketCreatedResponse::V1(created)) => created.ticket.link.token,
+        other => panic!("expected Created, got {other:?}"),
+    };
+
+    let http = reqwest::Client::new();
+    let url = app.mcp_url();
+    let session_id = mcp_initialize_with_token(&http, &url, &token).await;
+
+    let result = mcp_call_tool(
+        &http,
+        &url,
+        &session_id,
+        2,
+        "search-query",
+        serde_json::json!({ "query": "test" }),
+    )
+    .await;
+    assert_eq!(result["result"]["isError"].as_bool(), Some(false));
+
+    for (id, tool, arguments) in [
+        (
+            3,
+            "add-cognition",
+            serde_json::json!({
+                "agent": "thinker.process",
+                "texture": "observation",
+                "content": "Not mine to write",
+            }),
+        ),
+        (
+            4,
+            "upload-storage",
+            serde_json::json!({ "key": "notes.txt", "data": "" }),
+        ),
+    ] {
+        let result = mcp_call_tool(&http, &url, &session_id, id, tool, arguments).await;
+        assert_eq!(
+            result["result"]["isError"].as_bool(),
+            Some(true),
+            "{tool} should be refused: {result}"
+        );
+        assert!(tool_text(&result).contains("does not grant"), "{result}");
+    }
+
+    Ok(())
+}
+
 // ── Resource surface ────────────────────────────────────────────
 
 #[tokio::test]
@@ -453,6 +743,150 @@ async fn resource_reads_return_markdown_with_hints() -> Result<(), Box<dyn core:
     Ok(())
 }
 
+/// Open a session's standalone stream, where server-initiated
+/// notifications arrive. Open it before anything changes.
+async fn open_notifications(
+    http: &reqwest::Client,
+    url: &str,
+    session_id: &Option<String>,
+) -> Result<
+    impl futures::Stream<Item = reqwest::Result<impl AsRef<[u8]>>> + Unpin,
+    Box<dyn core::error::Error>,
+> {
+    Ok(http
+        .get(url)
+        .header("Accept", "text/event-stream")
+        .header("mcp-session-id", session_id.as_deref().unwrap_or_default())
+        .send()
+        .await?
+        .bytes_stream())
+}
+
+/// Wait for the next `notifications/resources/updated` on a standalone
+/// stream.
+async fn resource_updated<B: AsRef<[u8]>>(
+    standalone: &mut (impl futures::Stream<Item = reqwest::Result<B>> + Unpin),
+) -> Result<serde_json::Value, Box<dyn core::error::Error>> {
+    use futures::StreamExt;
+
+    let mut buffer = String::new();
+    Ok(
+        tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            loop {
+                let chunk = standalone.next().await.expect("stream ended").unwrap();
+                buffer.push_str(&String::from_utf8_lossy(chunk.as_ref()));
+                let updated = buffer
+                    .lines()
+                    .filter_map(|line| line.strip_prefix("data:"))
+                    .filter_map(|data| serde_json::from_str::<serde_json::Value>(data.trim()).ok())
+                    .find(|message| message["method"] == "notifications/resources/updated");
+                if let Some(updated) = updated {
+                    return updated;
+                }
+            }
+        })
+        .await?,
+    )
+}
+
+#[tokio::test]
+async fn resource_subscriptions_notify_on_change() -> Result<(), Box<dyn core::error::Error>> {
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+    app.command(r#"agent create thinker process --description "A thinking agent""#)
+        .await?;
+
+    let token = app.token().expect("project should have a token");
+    let http = reqwest::Client::new();
+    let url = app.mcp_url();
+    let session_id = mcp_initialize_with_token(&http, &url, &token).await;
+
+    let mut standalone = open_notifications(&http, &url, &session_id).await?;
+
+    let uri = "oneiros-mcp://agent/thinker.process/memories";
+    let json = mcp_rpc(
+        &http,
+        &url,
+        &session_id,
+        20,
+        "resources/subscribe",
+        serde_json::json!({ "uri": uri }),
+    )
+    .await;
+    assert!(json.get("error").is_none(), "subscribe failed: {json}");
+
+    app.command(r#"memory add thinker.process session "Subscribed and remembered""#)
+        .await?;
+
+    let notification = resource_updated(&mut standalone).await?;
+    assert_eq!(notification["params"]["uri"], uri);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn resource_subscriptions_notify_on_collected_changes()
+-> Result<(), Box<dyn core::error::Error>> {
+    let alice = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+    alice
+        .command(r#"agent create thinker process --description "A thinking agent""#)
+        .await?;
+    let link = alice.command("bookmark share main").await?;
+
+    let bob = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+    bob.command(&format!("bookmark follow {} --name alice", link.prompt()))
+        .await?;
+    bob.command("bookmark collect alice").await?;
+
+    let token = bob.token().expect("project should have a token");
+    let http = reqwest::Client::new();
+    let url = bob.mcp_url();
+    let session_id = mcp_initialize_on(&http, &url, &token, Some("alice")).await;
+    let mut standalone = open_notifications(&http, &url, &session_id).await?;
+
+    let uri = "oneiros-mcp://agent/thinker.process/memories";
+    let json = mcp_rpc(
+        &http,
+        &url,
+        &session_id,
+        20,
+        "resources/subscribe",
+        serde_json::json!({ "uri": uri }),
+    )
+    .await;
+    assert!(json.get("error").is_none(), "subscribe failed: {json}");
+
+    alice
+        .command(r#"memory add thinker.process session "Remembered elsewhere""#)
+        .await?;
+    bob.command("bookmark collect alice").await?;
+
+    let notification = resource_updated(&mut standalone).await?;
+    assert_eq!(notification["params"]["uri"], uri);
+
+    Ok(())
+}
+
 // ── Prompt surface ──────────────────────────────────────────────
 
 #[tokio::test]
@@ -485,8 +919,14 @@ async fn prompts_discovery() -> Result<(), Box<dyn core::error::Error>> {
         .as_array()
         .expect("prompts should be an array");
 
-    assert_eq!(prompts.len(), 1, "expected 1 prompt, got {}", prompts.len());
+    assert_eq!(
+        prompts.len(),
+        2,
+        "expected 2 prompts, got {}",
+        prompts.len()
+    );
     assert_eq!(prompts[0]["name"].as_str(), Some("dream"));
+    assert_eq!(prompts[1]["name"].as_str(), Some("dream-since-last"));
     assert!(prompts[0]["arguments"].is_array());
 
     Ok(())
@@ -715,3 +1155,76 @@ async fn initialize_with_non_bearer_scheme_fails() -> Result<(), Box<dyn core::e
 
     Ok(())
 }
+
+// ── Stdio transport ─────────────────────────────────────────────
+
+#[tokio::test]
+async fn stdio_session_needs_no_token() -> Result<(), Box<dyn core::error::Error>> {
+    use rmcp::ServiceExt;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    let state = ServerState::open(app.config().clone())?;
+    state.hydrate();
+
+    // A duplex pipe stands in for the child process's stdin and stdout.
+    let (client, server) = tokio::io::duplex(64 * 1024);
+    tokio::spawn(async move {
+        if let Ok(service) = EngineToolBox::local(state)
+            .serve(tokio::io::split(server))
+            .await
+        {
+            let _ = service.waiting().await;
+        }
+    });
+
+    let (reader, mut writer) = tokio::io::split(client);
+    let mut lines = BufReader::new(reader).lines();
+    let mut send = async |message: serde_json::Value| -> std::io::Result<()> {
+        writer.write_all(format!("{message}\n").as_bytes()).await
+    };
+
+    send(serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": "2025-03-26",
+            "capabilities": {},
+            "clientInfo": { "name": "test", "version": "0.1.0" }
+        }
+    }))
+    .await?;
+    let initialized: serde_json::Value =
+        serde_json::from_str(&lines.next_line().await?.ok_or("stdout closed")?)?;
+    assert!(
+        initialized.get("error").is_none(),
+        "stdio initialize should not need a token: {initialized}"
+    );
+
+    send(serde_json::json!({ "jsonrpc": "2.0", "method": "notifications/initialized" })).await?;
+    send(serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "method": "resources/read",
+        "params": { "uri": "oneiros-mcp://agents" }
+    }))
+    .await?;
+    let read: serde_json::Value =
+        serde_json::from_str(&lines.next_line().await?.ok_or("stdout closed")?)?;
+    let text = resource_text(&read);
+    assert!(
+        text.contains("# Agents"),
+        "stdio session should read the project, got: {read}"
+    );
+
+    Ok(())
+}
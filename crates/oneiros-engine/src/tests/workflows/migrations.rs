@@ -91,6 +91,18 @@ async fn pre_rename_data_dir_boots_into_current_layout() -> Result<(), Box<dyn c
         "legacy event_type column values are preserved on disk"
     );
 
+    // The event log gained a signature column; legacy rows stay unsigned.
+    assert!(
+        fixture::column_exists(&events_db, "events", "signature"),
+        "events.signature column should exist"
+    );
+    let signed: i64 = events_db.query_row(
+        "SELECT count(*) FROM events WHERE signature IS NOT NULL",
+        [],
+        |row| row.get(0),
+    )?;
+    assert_eq!(signed, 0, "legacy events should stay unsigned");
+
     // Each preserved row decodes through the current Events enum without
     // landing in `Event::Unknown` — proving the versioning layer handles
     // legacy data end-to-end.
@@ -4,6 +4,7 @@ mod dashboard;
 mod distribution;
 mod doctor;
 mod errors;
+mod events;
 mod host;
 mod lens;
 mod mcp;
@@ -12,7 +13,10 @@ mod namespace;
 mod portability;
 mod pressure;
 mod protocol;
+mod redaction;
 mod remotes;
+mod retention;
+mod revisions;
 mod search;
 mod skills;
 mod slice;
@@ -278,3 +278,72 @@ async fn continuity_survives_export_import() -> Result<(), Box<dyn core::error::
 
     Ok(())
 }
+
+#[tokio::test]
+async fn tampered_events_are_reported_and_refused() -> Result<(), Box<dyn core::error::Error>> {
+    let app_a = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    // Everything the host appended is signed.
+    let verified = app_a.command("project verify").await?;
+    match verified.response() {
+        Responses::Project(ProjectResponse::Verified(VerifiedResponse::V1(details))) => {
+            assert!(details.checked > 0, "the log should not be empty");
+            assert_eq!(details.signed, details.checked, "every event is signed");
+            assert!(details.issues.is_empty());
+        }
+        other => panic!("expected Verified, got {other:?}"),
+    }
+
+    // Rewrite one event's timestamp behind the log's back.
+    let events_db = rusqlite::Connection::open(app_a.config().events_db_path())?;
+    events_db.execute(
+        "UPDATE events SET created_at = '2020-01-01T00:00:00+00:00'
+         WHERE rowid = (SELECT min(rowid) FROM events)",
+        [],
+    )?;
+    drop(events_db);
+
+    let verified = app_a.command("project verify").await?;
+    match verified.response() {
+        Responses::Project(ProjectResponse::Verified(VerifiedResponse::V1(details))) => {
+            assert_eq!(details.signed, details.checked - 1);
+            assert_eq!(details.issues.len(), 1);
+            assert_eq!(details.issues[0].problem, ProvenanceProblem::Tampered);
+        }
+        other => panic!("expected Verified, got {other:?}"),
+    }
+
+    // The tampered event travels with an export and sinks the import.
+    let export_dir = tempfile::tempdir()?;
+    app_a
+        .command(&format!(
+            "project export --target {}",
+            export_dir.path().display()
+        ))
+        .await?;
+    let export_file = crate::Platform::new(export_dir.path())
+        .read_dir(export_dir.path())?
+        .filter_map(|e| e.ok())
+        .find(|e| e.path().extension().is_some_and(|ext| ext == "jsonl"))
+        .expect("export should produce a .jsonl file");
+
+    let app_b = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?;
+    let imported = app_b
+        .command(&format!("project import {}", export_file.path().display()))
+        .await;
+    assert!(imported.is_err(), "a tampered export should be refused");
+
+    Ok(())
+}
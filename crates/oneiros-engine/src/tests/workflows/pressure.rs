@@ -385,3 +385,165 @@ async fn consolidating_reduces_recollect_pressure() -> Result<(), Box<dyn core::
 
     Ok(())
 }
+
+#[tokio::test]
+async fn custom_urges_build_pressure_from_their_gauge() -> Result<(), Box<dyn core::error::Error>> {
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    let client = app.client();
+    let agent = AgentName::new("thinker.process");
+
+    app.command("continuity emerge thinker process").await?;
+    app.command(
+        r#"urge set curiosity --description "Open questions" --factor "texture:2:0.6=question" --factor "lens:1:0.4=texture(question) & agent(self)""#,
+    )
+    .await?;
+
+    for i in 0..2 {
+        app.command(&format!(
+            r#"cognition add thinker.process question "Why {i}?""#
+        ))
+        .await?;
+    }
+
+    let readings = match client
+        .pressure()
+        .get(
+            &GetPressure::builder_v1()
+                .agent(agent.clone())
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        PressureResponse::Readings(ReadingsResponse::V1(r)) => r.pressures,
+        other => panic!("expected Readings, got {other:?}"),
+    };
+
+    let curiosity = readings
+        .iter()
+        .find(|p| p.urge.as_str() == "curiosity")
+        .expect("should have curiosity pressure");
+    let Gauge::Declared(gauge) = &curiosity.data else {
+        panic!("expected a declared gauge, got {:?}", curiosity.data);
+    };
+
+    // texture: saturate(2, 2) = 0.5; lens: saturate(2, 1) ≈ 0.667
+    assert_eq!(gauge.readings.len(), 2);
+    assert!((gauge.readings[0].input - 2.0).abs() < f64::EPSILON);
+    assert!((gauge.readings[1].input - 2.0).abs() < f64::EPSILON);
+    assert!((curiosity.data.urgency() - (0.5 * 0.6 + 2.0 / 3.0 * 0.4)).abs() < 0.01);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn urges_refuse_lenses_the_registry_cannot_compile() -> Result<(), Box<dyn core::error::Error>>
+{
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    // Parses fine, but names no known predicate.
+    let refused = app
+        .client()
+        .urge()
+        .set(
+            &SetUrge::builder_v1()
+                .name("curiosity")
+                .factors(vec![GaugeFactor {
+                    measure: Measure::Lens("vibes(self)".into()),
+                    midpoint: 1.0,
+                    weight: 1.0,
+                }])
+                .build()
+                .into(),
+        )
+        .await;
+    assert!(refused.is_err(), "an uncompilable lens is refused at set");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn tunings_reconfigure_gauges_and_explain_says_so() -> Result<(), Box<dyn core::error::Error>>
+{
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    let client = app.client();
+    let agent = AgentName::new("thinker.process");
+
+    app.command("continuity emerge thinker process").await?;
+    app.command("urge tune introspect --param time_midpoint=100 --param session_midpoint=30")
+        .await?;
+    app.command("urge tune introspect --persona process --param time_midpoint=2")
+        .await?;
+
+    let explained = match client
+        .pressure()
+        .explain(
+            &ExplainPressure::builder_v1()
+                .agent(agent.clone())
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        PressureResponse::Explained(ExplainedResponse::V1(e)) => e,
+        other => panic!("expected Explained, got {other:?}"),
+    };
+
+    assert_eq!(explained.persona.as_str(), "process");
+    let introspect = explained
+        .explanations
+        .iter()
+        .find(|e| e.urge.as_str() == "introspect")
+        .expect("should explain introspect");
+    let layers: Vec<_> = introspect.tunings.iter().map(|t| &t.layer).collect();
+    assert_eq!(
+        layers,
+        [
+            &TuningLayer::Project,
+            &TuningLayer::Persona(PersonaName::new("process"))
+        ]
+    );
+
+    let Gauge::Introspect(gauge) = &introspect.gauge else {
+        panic!("expected an introspect gauge, got {:?}", introspect.gauge);
+    };
+    assert!((gauge.config.time_midpoint - 2.0).abs() < f64::EPSILON);
+    assert!((gauge.config.session_midpoint - 30.0).abs() < f64::EPSILON);
+
+    let catharsis = explained
+        .explanations
+        .iter()
+        .find(|e| e.urge.as_str() == "catharsis")
+        .expect("should explain catharsis");
+    assert!(catharsis.tunings.is_empty());
+
+    let unknown = app
+        .command("urge tune introspect --param tensions_weight=0.5")
+        .await;
+    assert!(unknown.is_err(), "unknown parameters should be rejected");
+
+    Ok(())
+}
@@ -107,12 +107,30 @@ const TAGGED_DOMAINS: &[&str] = &[
 const MCP_TOOLS: &[&str] = &[
     "add-cognition",
     "add-memory",
+    "amend-experience",
     "create-agent",
     "create-connection",
     "create-experience",
+    "explain-lens",
+    "explain-pressure",
+    "get-pressure",
+    "get-storage",
+    "introspect-agent",
+    "preview-collect",
+    "preview-merge",
+    "query-lens",
+    "reflect-agent",
     "remove-agent",
+    "retract-cognition",
     "search-query",
+    "sense-content",
+    "sleep-agent",
+    "supersede-memory",
+    "trail-from",
+    "trail-of",
     "update-agent",
+    "upload-storage",
+    "wake-agent",
 ];
 
 // ── Canonical list consistency ──────────────────────────────────
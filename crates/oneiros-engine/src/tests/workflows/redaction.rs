@@ -0,0 +1,429 @@
+//! Redaction workflow — purging what should never have been written.
+//!
+//! `project redact` replaces the payloads of every event about a
+//! cognition, memory or experience with tombstones. Ids and times
+//! survive; the content doesn't — not in the event log, not in the
+//! projections or search index, and not on followers once they collect
+//! the redaction notice.
+
+use crate::tests::harness::TestApp;
+use crate::*;
+
+const SECRET: &str = "hunter2";
+
+/// How many rows of a project's event log still mention the secret.
+fn rows_mentioning_secret(app: &TestApp) -> Result<i64, Box<dyn core::error::Error>> {
+    let events_db = rusqlite::Connection::open(app.config().events_db_path())?;
+    Ok(events_db.query_row(
+        "SELECT COUNT(*) FROM events WHERE data LIKE ?1",
+        [format!("%{SECRET}%")],
+        |row| row.get(0),
+    )?)
+}
+
+async fn add_secret(app: &TestApp) -> Result<Cognition, Box<dyn core::error::Error>> {
+    match app
+        .client()
+        .cognition()
+        .add(
+            &AddCognition::builder_v1()
+                .agent(AgentName::new("thinker.process"))
+                .texture("observation")
+                .content(format!("The staging password is {SECRET}"))
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        CognitionResponse::CognitionAdded(CognitionAddedResponse::V1(added)) => Ok(added.cognition),
+        other => panic!("expected CognitionAdded, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn redaction_leaves_tombstones_where_content_was() -> Result<(), Box<dyn core::error::Error>>
+{
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    let client = app.client();
+    app.command("agent create thinker process").await?;
+    let cognition = add_secret(&app).await?;
+    client
+        .cognition()
+        .retract(
+            &RetractCognition::builder_v1()
+                .id(cognition.id)
+                .reason(format!("It mentions {SECRET}"))
+                .build()
+                .into(),
+        )
+        .await?;
+    app.command(r#"cognition add thinker.process observation "Nothing to hide""#)
+        .await?;
+    assert_eq!(rows_mentioning_secret(&app)?, 2);
+
+    let target = Ref::cognition(cognition.id);
+    let redacted = match client
+        .project()
+        .redact(
+            &RedactProject::builder_v1()
+                .target(RefToken::new(target.clone()))
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        ProjectResponse::Redacted(RedactedResponse::V1(redacted)) => redacted,
+        other => panic!("expected Redacted, got {other:?}"),
+    };
+    assert_eq!(redacted.target, target);
+    assert_eq!(redacted.events.len(), 2, "the addition and the retraction");
+
+    // The content is gone from the log, but the events keep their place.
+    assert_eq!(rows_mentioning_secret(&app)?, 0);
+    let events_db = rusqlite::Connection::open(app.config().events_db_path())?;
+    let tombstones: i64 = events_db.query_row(
+        "SELECT COUNT(*) FROM events WHERE event_type = 'tombstone'",
+        [],
+        |row| row.get(0),
+    )?;
+    assert_eq!(tombstones, 2);
+    drop(events_db);
+
+    // Projections and search were rebuilt without it.
+    match client
+        .search()
+        .search(
+            &SearchQuery::builder_v1()
+                .query(SECRET)
+                .include_retracted(true)
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        SearchResponse::Results(ResultsResponse::V1(results)) => assert!(results.hits.is_empty()),
+    }
+    match client
+        .cognition()
+        .list(
+            &ListCognitions::builder_v1()
+                .agent(AgentName::new("thinker.process"))
+                .include_retracted(true)
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        CognitionResponse::Cognitions(CognitionsResponse::V1(listed)) => {
+            let ids: Vec<CognitionId> = listed.items.iter().map(|c| c.id).collect();
+            assert_eq!(ids.len(), 1, "the other cognition is untouched");
+            assert!(!ids.contains(&cognition.id));
+        }
+        other => panic!("expected Cognitions, got {other:?}"),
+    }
+
+    // The host log keeps an audit of the redaction.
+    let mut audited = 0i64;
+    for _ in 0..40 {
+        let host_db = app.config().host_db()?;
+        audited = host_db.query_row(
+            "SELECT COUNT(*) FROM events WHERE event_type = 'project-redacted'",
+            [],
+            |row| row.get(0),
+        )?;
+        if audited > 0 {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+    assert_eq!(audited, 1);
+
+    // Tombstones have nothing left to sign, and aren't flagged for it.
+    let verified = app.command("project verify").await?;
+    match verified.response() {
+        Responses::Project(ProjectResponse::Verified(VerifiedResponse::V1(details))) => {
+            assert_eq!(details.redacted, 2);
+            assert!(details.issues.is_empty());
+        }
+        other => panic!("expected Verified, got {other:?}"),
+    }
+
+    // Nothing is left to redact, and only content can be redacted.
+    let again = app
+        .command(&format!("project redact {}", RefToken::new(target)))
+        .await;
+    assert!(again.is_err());
+    let not_content = app
+        .command(&format!(
+            "project redact {}",
+            RefToken::new(Ref::agent(AgentId::new()))
+        ))
+        .await;
+    assert!(not_content.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn followers_purge_what_was_redacted() -> Result<(), Box<dyn core::error::Error>> {
+    let alice = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    alice.command("agent create thinker process").await?;
+    let cognition = add_secret(&alice).await?;
+
+    let link = alice.command("bookmark share main").await?;
+
+    let bob = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    bob.command(&format!("bookmark follow {} --name alice", link.prompt()))
+        .await?;
+    bob.command("bookmark collect alice").await?;
+    bob.command("bookmark switch alice").await?;
+    assert_eq!(rows_mentioning_secret(&bob)?, 1);
+
+    alice
+        .command(&format!(
+            "project redact {}",
+            RefToken::new(Ref::cognition(cognition.id))
+        ))
+        .await?;
+
+    // The notice arrives with the next collect, and Bob purges his copy.
+    bob.command("bookmark collect alice").await?;
+
+    let mut remaining = rows_mentioning_secret(&bob)?;
+    for _ in 0..40 {
+        if remaining == 0 {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        remaining = rows_mentioning_secret(&bob)?;
+    }
+    assert_eq!(
+        remaining, 0,
+        "the follower should purge the redacted content"
+    );
+
+    let mut gone = false;
+    for _ in 0..40 {
+        gone = matches!(
+            bob.client()
+                .cognition()
+                .list(
+                    &ListCognitions::builder_v1()
+                        .agent(AgentName::new("thinker.process"))
+                        .include_retracted(true)
+                        .build()
+                        .into(),
+                )
+                .await?,
+            CognitionResponse::NoCognitions
+        );
+        if gone {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+    assert!(gone, "the follower's projections should drop the cognition");
+
+    // Both chronicles settle on the tombstones, so there's nothing left
+    // to fetch.
+    let collected = bob.command("bookmark collect alice").await?;
+    match collected.response() {
+        Responses::Bookmark(BookmarkResponse::Collected(result)) => {
+            assert_eq!(result.events_received, 0);
+        }
+        other => panic!("expected Collected, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn redacting_a_replacement_takes_its_supersede_chain()
+-> Result<(), Box<dyn core::error::Error>> {
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    let client = app.client();
+    let agent = AgentName::new("thinker.process");
+    app.command("agent create thinker process").await?;
+
+    let original = match client
+        .memory()
+        .add(
+            &AddMemory::builder_v1()
+                .agent(agent.clone())
+                .level("core")
+                .content(format!("The staging password is {SECRET}"))
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        MemoryResponse::MemoryAdded(MemoryAddedResponse::V1(added)) => added.memory,
+        other => panic!("expected MemoryAdded, got {other:?}"),
+    };
+
+    let replacement = match client
+        .memory()
+        .supersede(
+            &SupersedeMemory::builder_v1()
+                .id(original.id)
+                .content("The staging password lives in the vault")
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        MemoryResponse::MemorySuperseded(MemorySupersededResponse::V1(superseded)) => {
+            superseded.memory
+        }
+        other => panic!("expected MemorySuperseded, got {other:?}"),
+    };
+
+    let redacted = app
+        .command(&format!(
+            "project redact {}",
+            RefToken::new(Ref::memory(replacement.id))
+        ))
+        .await?;
+    match redacted.response() {
+        Responses::Project(ProjectResponse::Redacted(RedactedResponse::V1(details))) => {
+            assert_eq!(
+                details.events.len(),
+                2,
+                "the original's addition and the supersede"
+            );
+        }
+        other => panic!("expected Redacted, got {other:?}"),
+    }
+    assert_eq!(rows_mentioning_secret(&app)?, 0);
+
+    // With the supersede tombstoned, the original must not come back in
+    // the rebuilt projections.
+    match client
+        .memory()
+        .list(
+            &ListMemories::builder_v1()
+                .agent(agent)
+                .include_retracted(true)
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        MemoryResponse::NoMemories => {}
+        MemoryResponse::Memories(MemoriesResponse::V1(listed)) => {
+            panic!("expected no memories, got {:?}", listed.items)
+        }
+        other => panic!("expected NoMemories, got {other:?}"),
+    }
+    assert!(
+        client
+            .memory()
+            .get(&GetMemory::builder_v1().key(original.id).build().into())
+            .await
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn followers_refuse_notices_for_events_they_authored()
+-> Result<(), Box<dyn core::error::Error>> {
+    let bob = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    bob.command("agent create thinker process").await?;
+    let cognition = add_secret(&bob).await?;
+    let bobs_link = bob.command("bookmark share main").await?;
+
+    // Alice holds a copy of Bob's cognition and redacts it on her host.
+    let alice = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    alice
+        .command(&format!(
+            "bookmark follow {} --name bob",
+            bobs_link.prompt()
+        ))
+        .await?;
+    alice.command("bookmark collect bob").await?;
+    alice.command("bookmark switch bob").await?;
+    alice
+        .command(&format!(
+            "project redact {}",
+            RefToken::new(Ref::cognition(cognition.id))
+        ))
+        .await?;
+    let alices_link = alice.command("bookmark share bob").await?;
+
+    // Her notice names events Bob signed, so Bob keeps his content and
+    // records the refusal.
+    bob.command(&format!(
+        "bookmark follow {} --name alice",
+        alices_link.prompt()
+    ))
+    .await?;
+    bob.command("bookmark collect alice").await?;
+
+    let mut refused = 0i64;
+    for _ in 0..40 {
+        let host_db = bob.config().host_db()?;
+        refused = host_db.query_row(
+            "SELECT COUNT(*) FROM events WHERE event_type = 'redaction-refused'",
+            [],
+            |row| row.get(0),
+        )?;
+        if refused > 0 {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+    assert_eq!(refused, 1, "the refusal lands on the host log");
+    assert_eq!(rows_mentioning_secret(&bob)?, 1);
+
+    Ok(())
+}
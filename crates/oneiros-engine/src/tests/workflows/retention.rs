@@ -0,0 +1,299 @@
+//! Retention workflow — levels rank memories and let them go.
+//!
+//! A level's rank decides whether dreams recall its memories. A level's
+//! retention policy decides how long they stay there: the host sweeps on
+//! a schedule, demoting what's expired to a lower level, or archiving it
+//! when there's nowhere lower to go.
+
+use std::time::Duration;
+
+use crate::tests::harness::{TestApp, TestClient};
+use crate::*;
+
+fn fast_retention() -> RetentionConfig {
+    RetentionConfig::builder()
+        .enabled(true)
+        .interval(Duration::from_millis(50))
+        .build()
+}
+
+async fn remember(
+    client: &TestClient,
+    agent: &AgentName,
+    level: &str,
+    content: &str,
+) -> Result<MemoryId, Box<dyn core::error::Error>> {
+    match client
+        .memory()
+        .add(
+            &AddMemory::builder_v1()
+                .agent(agent.clone())
+                .level(level)
+                .content(content)
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        MemoryResponse::MemoryAdded(MemoryAddedResponse::V1(added)) => Ok(added.memory.id),
+        other => panic!("expected MemoryAdded, got {other:?}"),
+    }
+}
+
+/// Poll a memory until `done` holds, giving the sweep a few ticks.
+async fn settle(
+    client: &TestClient,
+    id: MemoryId,
+    done: impl Fn(&Memory) -> bool,
+) -> Result<Memory, Box<dyn core::error::Error>> {
+    for _ in 0..250 {
+        match client
+            .memory()
+            .get(&GetMemory::builder_v1().key(id).build().into())
+            .await?
+        {
+            MemoryResponse::MemoryDetails(MemoryDetailsResponse::V1(details))
+                if done(&details.memory) =>
+            {
+                return Ok(details.memory);
+            }
+            MemoryResponse::MemoryDetails(_) => {}
+            other => panic!("expected MemoryDetails, got {other:?}"),
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    Err(format!("memory {id} never settled").into())
+}
+
+#[tokio::test]
+async fn dreams_recall_by_configured_rank() -> Result<(), Box<dyn core::error::Error>> {
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    let client = app.client();
+    let agent = AgentName::new("thinker.process");
+    app.command("agent create thinker process").await?;
+
+    // Neither level is in the seeded vocabulary; only rank places them.
+    app.command(r#"level set insight --description "Hard-won" --prompt "" --rank 3"#)
+        .await?;
+    app.command(r#"level set trivia --description "Passing" --prompt "" --rank 1"#)
+        .await?;
+
+    let kept = remember(&client, &agent, "insight", "Projections are disposable").await?;
+    let dropped = remember(&client, &agent, "trivia", "The build took a while").await?;
+
+    match client.continuity().dream(&agent).await? {
+        ContinuityResponse::Dreaming(DreamingResponse::V1(details)) => {
+            let recalled: Vec<MemoryId> = details.context.memories.iter().map(|m| m.id).collect();
+            assert!(recalled.contains(&kept), "insight outranks project");
+            assert!(!recalled.contains(&dropped), "trivia ranks below project");
+        }
+        other => panic!("expected Dreaming, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn overflow_is_demoted_to_the_configured_level() -> Result<(), Box<dyn core::error::Error>> {
+    let app = TestApp::with_retention(fast_retention())
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    let client = app.client();
+    let agent = AgentName::new("thinker.process");
+    app.command("agent create thinker process").await?;
+    app.command(
+        r#"level set scratch --description "Scratch" --prompt "" --rank 4 --max-per-agent 1 --demote-to archival"#,
+    )
+    .await?;
+
+    let older = remember(&client, &agent, "scratch", "First draft").await?;
+    let newer = remember(&client, &agent, "scratch", "Second draft").await?;
+
+    let demoted = settle(&client, older, |m| m.level.as_str() == "archival").await?;
+    assert!(!demoted.is_archived());
+
+    match client
+        .memory()
+        .get(&GetMemory::builder_v1().key(newer).build().into())
+        .await?
+    {
+        MemoryResponse::MemoryDetails(MemoryDetailsResponse::V1(details)) => {
+            assert_eq!(details.memory.level.as_str(), "scratch");
+        }
+        other => panic!("expected MemoryDetails, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn expired_memories_without_a_target_are_archived() -> Result<(), Box<dyn core::error::Error>>
+{
+    let app = TestApp::with_retention(fast_retention())
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    let client = app.client();
+    let agent = AgentName::new("thinker.process");
+    app.command("agent create thinker process").await?;
+    app.command(r#"level set fleeting --description "Fleeting" --prompt "" --rank 4 --ttl 1ms"#)
+        .await?;
+
+    let id = remember(&client, &agent, "fleeting", "Gone by morning").await?;
+
+    let archived = settle(&client, id, Memory::is_archived).await?;
+    assert_eq!(archived.level.as_str(), "fleeting");
+
+    match client
+        .search()
+        .search(&SearchQuery::builder_v1().query("morning").build().into())
+        .await?
+    {
+        SearchResponse::Results(ResultsResponse::V1(results)) => {
+            assert!(
+                results
+                    .hits
+                    .iter()
+                    .all(|hit| hit.resource_ref() != Ref::memory(id)),
+                "archived memories drop out of search"
+            );
+        }
+    }
+
+    match client.continuity().dream(&agent).await? {
+        ContinuityResponse::Dreaming(DreamingResponse::V1(details)) => {
+            assert!(
+                details.context.memories.iter().all(|m| m.id != id),
+                "archived memories don't surface in dreams"
+            );
+        }
+        other => panic!("expected Dreaming, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn demotion_chains_cannot_loop() -> Result<(), Box<dyn core::error::Error>> {
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    app.command(
+        r#"level set scratch --description "Scratch" --prompt "" --ttl 1d --demote-to archival"#,
+    )
+    .await?;
+    let looped = app
+        .command(
+            r#"level set archival --description "Deep" --prompt "" --ttl 1d --demote-to scratch"#,
+        )
+        .await;
+
+    assert!(looped.is_err(), "archival -> scratch -> archival is a loop");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn demoted_memories_age_from_their_new_level() -> Result<(), Box<dyn core::error::Error>> {
+    let app = TestApp::with_retention(fast_retention())
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    let client = app.client();
+    let agent = AgentName::new("thinker.process");
+    app.command("agent create thinker process").await?;
+    app.command(
+        r#"level set holding --description "Holding" --prompt "" --rank 3 --ttl 3s --demote-to archival"#,
+    )
+    .await?;
+    app.command(
+        r#"level set scratch --description "Scratch" --prompt "" --rank 4 --max-per-agent 1 --demote-to holding"#,
+    )
+    .await?;
+
+    // Older than holding's ttl by the time the overflow demotes it.
+    let older = remember(&client, &agent, "scratch", "First draft").await?;
+    tokio::time::sleep(Duration::from_millis(3500)).await;
+    remember(&client, &agent, "scratch", "Second draft").await?;
+
+    settle(&client, older, |m| m.level.as_str() == "holding").await?;
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    let held = settle(&client, older, |_| true).await?;
+    assert_eq!(
+        held.level.as_str(),
+        "holding",
+        "holding's ttl counts from the demotion, not the memory's creation"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn forks_share_one_demotion() -> Result<(), Box<dyn core::error::Error>> {
+    let app = TestApp::with_retention(fast_retention())
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    let client = app.client();
+    let agent = AgentName::new("thinker.process");
+    app.command("agent create thinker process").await?;
+    app.command(
+        r#"level set scratch --description "Scratch" --prompt "" --rank 4 --ttl 1s --demote-to archival"#,
+    )
+    .await?;
+
+    // Both bookmarks hold the memory when it expires, and both see it
+    // demoted.
+    let id = remember(&client, &agent, "scratch", "Shared draft").await?;
+    app.command("bookmark create experiment").await?;
+
+    settle(&client, id, |m| m.level.as_str() == "archival").await?;
+    app.command("bookmark switch main").await?;
+    settle(&client, id, |m| m.level.as_str() == "archival").await?;
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    let events = rusqlite::Connection::open(app.config().events_db_path())?;
+    let demotions: i64 = events.query_row(
+        "SELECT COUNT(*) FROM events WHERE event_type = 'memory-demoted'",
+        [],
+        |row| row.get(0),
+    )?;
+    assert_eq!(demotions, 1, "the shared log records the demotion once");
+
+    Ok(())
+}
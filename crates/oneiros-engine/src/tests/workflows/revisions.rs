@@ -0,0 +1,359 @@
+//! Revision workflow — taking back what no longer holds.
+//!
+//! Memories are superseded, cognitions retracted, experiences amended.
+//! Each revision is its own event: the original stays in the log, but
+//! listings, search and dreams stop surfacing it unless asked to with
+//! `include_retracted`.
+
+use crate::tests::harness::{TestApp, TestClient};
+use crate::*;
+
+async fn search_hits(
+    client: &TestClient,
+    query: &str,
+    include_retracted: bool,
+) -> Result<Vec<Ref>, Box<dyn core::error::Error>> {
+    match client
+        .search()
+        .search(
+            &SearchQuery::builder_v1()
+                .query(query)
+                .include_retracted(include_retracted)
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        SearchResponse::Results(ResultsResponse::V1(results)) => Ok(results
+            .hits
+            .into_iter()
+            .map(|hit| hit.resource_ref())
+            .collect()),
+    }
+}
+
+async fn dream(
+    client: &TestClient,
+    agent: &AgentName,
+) -> Result<DreamContext, Box<dyn core::error::Error>> {
+    match client.continuity().dream(agent).await? {
+        ContinuityResponse::Dreaming(DreamingResponse::V1(details)) => Ok(details.context),
+        other => panic!("expected Dreaming, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn superseded_memories_give_way_to_their_replacement()
+-> Result<(), Box<dyn core::error::Error>> {
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    let client = app.client();
+    let agent = AgentName::new("thinker.process");
+    app.command("agent create thinker process").await?;
+
+    let original = match client
+        .memory()
+        .add(
+            &AddMemory::builder_v1()
+                .agent(agent.clone())
+                .level("core")
+                .content("Projections rebuild in a single pass")
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        MemoryResponse::MemoryAdded(MemoryAddedResponse::V1(added)) => added.memory,
+        other => panic!("expected MemoryAdded, got {other:?}"),
+    };
+
+    let replacement = match client
+        .memory()
+        .supersede(
+            &SupersedeMemory::builder_v1()
+                .id(original.id)
+                .content("Projections rebuild in two passes")
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        MemoryResponse::MemorySuperseded(MemorySupersededResponse::V1(superseded)) => {
+            assert_eq!(superseded.superseded, original.id);
+            superseded.memory
+        }
+        other => panic!("expected MemorySuperseded, got {other:?}"),
+    };
+    assert_eq!(replacement.level.as_str(), "core");
+    assert_eq!(replacement.agent_id, original.agent_id);
+
+    match client
+        .memory()
+        .get(&GetMemory::builder_v1().key(original.id).build().into())
+        .await?
+    {
+        MemoryResponse::MemoryDetails(MemoryDetailsResponse::V1(details)) => {
+            assert_eq!(details.memory.superseded_by, Some(replacement.id));
+        }
+        other => panic!("expected MemoryDetails, got {other:?}"),
+    }
+
+    match client
+        .memory()
+        .list(
+            &ListMemories::builder_v1()
+                .agent(agent.clone())
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        MemoryResponse::Memories(MemoriesResponse::V1(listed)) => {
+            let ids: Vec<MemoryId> = listed.items.iter().map(|m| m.id).collect();
+            assert_eq!(ids, vec![replacement.id]);
+        }
+        other => panic!("expected Memories, got {other:?}"),
+    }
+
+    match client
+        .memory()
+        .list(
+            &ListMemories::builder_v1()
+                .agent(agent.clone())
+                .include_retracted(true)
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        MemoryResponse::Memories(MemoriesResponse::V1(listed)) => assert_eq!(listed.total, 2),
+        other => panic!("expected Memories, got {other:?}"),
+    }
+
+    assert_eq!(
+        search_hits(&client, "single", false).await?,
+        Vec::<Ref>::new()
+    );
+    assert_eq!(
+        search_hits(&client, "single", true).await?,
+        vec![Ref::memory(original.id)]
+    );
+
+    let context = dream(&client, &agent).await?;
+    let dreamt: Vec<MemoryId> = context.memories.iter().map(|m| m.id).collect();
+    assert!(dreamt.contains(&replacement.id));
+    assert!(!dreamt.contains(&original.id));
+
+    let again = client
+        .memory()
+        .supersede(
+            &SupersedeMemory::builder_v1()
+                .id(original.id)
+                .content("A third take")
+                .build()
+                .into(),
+        )
+        .await;
+    assert!(
+        again.is_err(),
+        "a superseded memory can't be superseded again"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn retracted_cognitions_keep_their_reason() -> Result<(), Box<dyn core::error::Error>> {
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    let client = app.client();
+    let agent = AgentName::new("thinker.process");
+    app.command("agent create thinker process").await?;
+
+    let cognition = match client
+        .cognition()
+        .add(
+            &AddCognition::builder_v1()
+                .agent(agent.clone())
+                .texture("observation")
+                .content("The cache is never invalidated")
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        CognitionResponse::CognitionAdded(CognitionAddedResponse::V1(added)) => added.cognition,
+        other => panic!("expected CognitionAdded, got {other:?}"),
+    };
+
+    match client
+        .cognition()
+        .retract(
+            &RetractCognition::builder_v1()
+                .id(cognition.id)
+                .reason("Found the invalidation hook")
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        CognitionResponse::CognitionRetracted(CognitionRetractedResponse::V1(retracted)) => {
+            let retraction = retracted.cognition.retraction.expect("retraction recorded");
+            assert_eq!(retraction.reason.as_str(), "Found the invalidation hook");
+        }
+        other => panic!("expected CognitionRetracted, got {other:?}"),
+    }
+
+    match client
+        .cognition()
+        .list(
+            &ListCognitions::builder_v1()
+                .agent(agent.clone())
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        CognitionResponse::NoCognitions => {}
+        other => panic!("expected NoCognitions, got {other:?}"),
+    }
+
+    match client
+        .cognition()
+        .list(
+            &ListCognitions::builder_v1()
+                .agent(agent.clone())
+                .include_retracted(true)
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        CognitionResponse::Cognitions(CognitionsResponse::V1(listed)) => {
+            assert_eq!(listed.items.len(), 1);
+            assert!(listed.items[0].is_retracted());
+        }
+        other => panic!("expected Cognitions, got {other:?}"),
+    }
+
+    assert!(search_hits(&client, "cache", false).await?.is_empty());
+    assert_eq!(search_hits(&client, "cache", true).await?.len(), 1);
+
+    let context = dream(&client, &agent).await?;
+    assert!(context.cognitions.iter().all(|c| c.id != cognition.id));
+
+    let again = client
+        .cognition()
+        .retract(
+            &RetractCognition::builder_v1()
+                .id(cognition.id)
+                .reason("Still wrong")
+                .build()
+                .into(),
+        )
+        .await;
+    assert!(
+        again.is_err(),
+        "a retracted cognition can't be retracted again"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn amended_experiences_carry_over_what_is_unchanged()
+-> Result<(), Box<dyn core::error::Error>> {
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    let client = app.client();
+    let agent = AgentName::new("thinker.process");
+    app.command("agent create thinker process").await?;
+
+    let original = match client
+        .experience()
+        .create(
+            &CreateExperience::builder_v1()
+                .agent(agent.clone())
+                .sensation("echoes")
+                .description("The outage traced back to DNS")
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        ExperienceResponse::ExperienceCreated(ExperienceCreatedResponse::V1(created)) => {
+            created.experience
+        }
+        other => panic!("expected ExperienceCreated, got {other:?}"),
+    };
+
+    let replacement = match client
+        .experience()
+        .amend(
+            &AmendExperience::builder_v1()
+                .id(original.id)
+                .description(Description::new("The outage traced back to a stale lease"))
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        ExperienceResponse::ExperienceAmended(ExperienceAmendedResponse::V1(amended)) => {
+            assert_eq!(amended.amended, original.id);
+            amended.experience
+        }
+        other => panic!("expected ExperienceAmended, got {other:?}"),
+    };
+    assert_eq!(replacement.sensation, original.sensation);
+
+    match client
+        .experience()
+        .list(
+            &ListExperiences::builder_v1()
+                .agent(agent.clone())
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        ExperienceResponse::Experiences(ExperiencesResponse::V1(listed)) => {
+            let ids: Vec<ExperienceId> = listed.items.iter().map(|e| e.id).collect();
+            assert_eq!(ids, vec![replacement.id]);
+        }
+        other => panic!("expected Experiences, got {other:?}"),
+    }
+
+    assert!(search_hits(&client, "DNS", false).await?.is_empty());
+    assert_eq!(
+        search_hits(&client, "DNS", true).await?,
+        vec![Ref::experience(original.id)]
+    );
+
+    let context = dream(&client, &agent).await?;
+    let dreamt: Vec<ExperienceId> = context.experiences.iter().map(|e| e.id).collect();
+    assert!(dreamt.contains(&replacement.id));
+    assert!(!dreamt.contains(&original.id));
+
+    Ok(())
+}
@@ -83,3 +83,43 @@ async fn search_across_domains() -> Result<(), Box<dyn core::error::Error>> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn search_by_minimum_level() -> Result<(), Box<dyn core::error::Error>> {
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    let client = app.client();
+
+    app.command(r#"agent create gov process --description "Governor agent""#)
+        .await?;
+    app.command(r#"memory add gov.process core "Ranked memory at the core""#)
+        .await?;
+    app.command(r#"memory add gov.process session "Ranked memory in the session""#)
+        .await?;
+    app.command(r#"memory add gov.process archival "Ranked memory in the archive""#)
+        .await?;
+
+    // Seeded ranks: core 5, session 3, archival 1
+    match client
+        .search()
+        .search(
+            &SearchQuery::builder_v1()
+                .query("ranked")
+                .min_level(LevelName::new("session"))
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        SearchResponse::Results(ResultsResponse::V1(r)) => assert_eq!(r.hits.len(), 2),
+    }
+
+    Ok(())
+}
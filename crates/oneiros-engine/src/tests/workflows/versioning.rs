@@ -142,6 +142,405 @@ async fn branch_switch_and_merge() -> Result<(), Box<dyn core::error::Error>> {
     Ok(())
 }
 
+/// Two bookmarks that each changed the same texture since forking
+/// conflict on merge. `--strategy fail` refuses and leaves the target
+/// alone; `--strategy theirs` takes the source's version and reports it.
+#[tokio::test]
+async fn merge_settles_conflicts_by_strategy() -> Result<(), Box<dyn core::error::Error>> {
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    let client = app.client();
+
+    let musing = async || -> Result<Description, Box<dyn core::error::Error>> {
+        match client
+            .texture()
+            .get(
+                &GetTexture::builder_v1()
+                    .key(TextureName::new("musing"))
+                    .build()
+                    .into(),
+            )
+            .await?
+        {
+            TextureResponse::TextureDetails(TextureDetailsResponse::V1(details)) => {
+                Ok(details.texture.description)
+            }
+            other => panic!("expected TextureDetails, got {other:?}"),
+        }
+    };
+
+    app.command("texture set musing --description 'before fork'")
+        .await?;
+    app.command("bookmark create experiment").await?;
+    app.command("texture set musing --description 'experiment view'")
+        .await?;
+    app.command("bookmark switch main").await?;
+    app.command("texture set musing --description 'main view'")
+        .await?;
+
+    assert!(
+        app.command("bookmark merge experiment --strategy fail")
+            .await
+            .is_err(),
+        "conflicting merge with --strategy fail should be refused"
+    );
+    assert_eq!(musing().await?, Description::new("main view"));
+
+    let merged = app
+        .command("bookmark merge experiment --strategy theirs")
+        .await?;
+    match merged.response() {
+        Responses::Bookmark(BookmarkResponse::Merged(BookmarkMergedResponse::V2(report))) => {
+            assert_eq!(report.strategy, MergeStrategy::Theirs);
+            assert_eq!(report.conflicts.len(), 1);
+            assert_eq!(report.conflicts[0].subject, "texture:musing");
+            assert_eq!(report.conflicts[0].resolution, MergeStrategy::Theirs);
+        }
+        other => panic!("expected a merge report, got {other:?}"),
+    }
+    assert_eq!(musing().await?, Description::new("experiment view"));
+
+    Ok(())
+}
+
+/// Sibling bookmarks merge against where their fork chains meet. `right`
+/// was forked from `main` after `main` changed the texture, so only
+/// `left`'s own fork point shows both sides touching it.
+#[tokio::test]
+async fn sibling_bookmarks_merge_from_where_they_meet() -> Result<(), Box<dyn core::error::Error>> {
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    app.command("texture set musing --description 'before fork'")
+        .await?;
+    app.command("bookmark create left").await?;
+    app.command("texture set musing --description 'left view'")
+        .await?;
+    app.command("bookmark switch main").await?;
+    app.command("texture set musing --description 'main view'")
+        .await?;
+    app.command("bookmark create right").await?;
+    app.command("bookmark switch left").await?;
+
+    assert!(
+        app.command("bookmark merge right --strategy fail")
+            .await
+            .is_err(),
+        "siblings that both changed the texture should conflict"
+    );
+
+    let merged = app
+        .command("bookmark merge right --strategy theirs")
+        .await?;
+    match merged.response() {
+        Responses::Bookmark(BookmarkResponse::Merged(BookmarkMergedResponse::V2(report))) => {
+            assert_eq!(report.conflicts.len(), 1);
+            assert_eq!(report.conflicts[0].subject, "texture:musing");
+        }
+        other => panic!("expected a merge report, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+/// A merge's settlement outlives the merge itself: rebuilding the
+/// target's projections from the log still leaves out the losing side.
+#[tokio::test]
+async fn merge_settlements_survive_replay() -> Result<(), Box<dyn core::error::Error>> {
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    let client = app.client();
+
+    let musing = async || -> Result<Description, Box<dyn core::error::Error>> {
+        match client
+            .texture()
+            .get(
+                &GetTexture::builder_v1()
+                    .key(TextureName::new("musing"))
+                    .build()
+                    .into(),
+            )
+            .await?
+        {
+            TextureResponse::TextureDetails(TextureDetailsResponse::V1(details)) => {
+                Ok(details.texture.description)
+            }
+            other => panic!("expected TextureDetails, got {other:?}"),
+        }
+    };
+
+    app.command("bookmark create experiment").await?;
+    app.command("texture set musing --description 'experiment view'")
+        .await?;
+    app.command("bookmark switch main").await?;
+    app.command("texture set musing --description 'main view'")
+        .await?;
+    app.command("bookmark merge experiment --strategy theirs")
+        .await?;
+    assert_eq!(musing().await?, Description::new("experiment view"));
+
+    // The merge lands on the host log behind the response.
+    for _ in 0..40 {
+        let host_db = app.config().host_db()?;
+        let merged: i64 = host_db.query_row(
+            "SELECT COUNT(*) FROM events WHERE event_type = 'bookmark-merged'",
+            [],
+            |row| row.get(0),
+        )?;
+        if merged > 0 {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+
+    app.command("project replay").await?;
+    assert_eq!(musing().await?, Description::new("experiment view"));
+
+    Ok(())
+}
+
+/// Bookmarks come back from a restart where they stood: the fork base
+/// and each side's changes survive, so the conflict is still found.
+#[tokio::test]
+async fn merge_after_restart_finds_the_same_conflicts() -> Result<(), Box<dyn core::error::Error>> {
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    app.command("texture set musing --description 'before fork'")
+        .await?;
+    app.command("bookmark create experiment").await?;
+    app.command("texture set musing --description 'experiment view'")
+        .await?;
+    app.command("bookmark switch main").await?;
+    app.command("texture set musing --description 'main view'")
+        .await?;
+
+    // Chronicles record behind the responses; wait until both sides
+    // have, so their heads are saved before the host goes down.
+    let mut conflicts = 0;
+    for _ in 0..40 {
+        let preview = app.command("bookmark merge experiment --dry-run").await?;
+        if let Responses::Bookmark(BookmarkResponse::Previewed(BookmarkPreviewResponse::V1(
+            preview,
+        ))) = preview.response()
+        {
+            conflicts = preview.conflicts.len();
+        }
+        if conflicts > 0 {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+    assert_eq!(conflicts, 1);
+
+    let app = app.restart().await?;
+
+    let merged = app
+        .command("bookmark merge experiment --strategy theirs")
+        .await?;
+    match merged.response() {
+        Responses::Bookmark(BookmarkResponse::Merged(BookmarkMergedResponse::V2(report))) => {
+            assert_eq!(report.conflicts.len(), 1);
+            assert_eq!(report.conflicts[0].subject, "texture:musing");
+        }
+        other => panic!("expected a merge report, got {other:?}"),
+    }
+
+    match app
+        .client()
+        .texture()
+        .get(
+            &GetTexture::builder_v1()
+                .key(TextureName::new("musing"))
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        TextureResponse::TextureDetails(TextureDetailsResponse::V1(details)) => {
+            assert_eq!(
+                details.texture.description,
+                Description::new("experiment view")
+            );
+        }
+        other => panic!("expected TextureDetails, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+/// `bookmark merge --dry-run` reports the incoming events without
+/// bringing any of them into the active bookmark.
+#[tokio::test]
+async fn merge_dry_run_previews_without_applying() -> Result<(), Box<dyn core::error::Error>> {
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    let client = app.client();
+
+    app.command("continuity emerge thinker process").await?;
+    app.command("bookmark create experiment").await?;
+    app.command("cognition add thinker.process observation 'thought on experiment'")
+        .await?;
+    app.command("bookmark switch main").await?;
+
+    let preview = app.command("bookmark merge experiment --dry-run").await?;
+    match preview.response() {
+        Responses::Bookmark(BookmarkResponse::Previewed(BookmarkPreviewResponse::V1(preview))) => {
+            assert_eq!(preview.target, BookmarkName::main());
+            assert_eq!(preview.events.len(), 1);
+            assert_eq!(preview.events[0].event_type, "cognition-added");
+            assert_eq!(preview.groups.len(), 1);
+            assert_eq!(preview.groups[0].domain, "cognition");
+            assert!(preview.groups[0].agent_id.is_some());
+        }
+        other => panic!("expected a merge preview, got {other:?}"),
+    }
+
+    match client
+        .cognition()
+        .list(
+            &ListCognitions::builder_v1()
+                .agent(AgentName::new("thinker.process"))
+                .build()
+                .into(),
+        )
+        .await?
+    {
+        CognitionResponse::NoCognitions => {}
+        CognitionResponse::Cognitions(CognitionsResponse::V1(cogs)) => assert!(
+            cogs.items.is_empty(),
+            "a dry run should not bring cognitions into main"
+        ),
+        other => panic!("expected cognitions on main, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+/// Bookmarks can be renamed and deleted, but never the trunk or the
+/// one you're standing on. Deleting leaves chronicle nodes behind for
+/// `bookmark gc` to sweep.
+#[tokio::test]
+async fn rename_delete_and_sweep_bookmarks() -> Result<(), Box<dyn core::error::Error>> {
+    let app = TestApp::new()
+        .await?
+        .init_host()
+        .await?
+        .init_project()
+        .await?
+        .seed_core()
+        .await?;
+
+    let client = app.client();
+
+    let names = async || -> Result<Vec<BookmarkName>, Box<dyn core::error::Error>> {
+        match client
+            .bookmark()
+            .list(&ListBookmarks::builder_v1().build().into())
+            .await?
+        {
+            BookmarkResponse::Bookmarks(listed) => {
+                let mut names: Vec<_> = listed.items.into_iter().map(|b| b.name).collect();
+                names.sort_by_key(|name| name.to_string());
+                Ok(names)
+            }
+            other => panic!("expected Bookmarks response, got {other:?}"),
+        }
+    };
+
+    app.command("continuity emerge thinker process").await?;
+    app.command("bookmark create experiment").await?;
+    app.command("cognition add thinker.process observation 'a passing thought'")
+        .await?;
+
+    assert!(
+        app.command("bookmark delete experiment").await.is_err(),
+        "the active bookmark can't be deleted"
+    );
+
+    let renamed = app.command("bookmark rename experiment trial").await?;
+    match renamed.response() {
+        Responses::Bookmark(BookmarkResponse::Renamed(BookmarkRenamedResponse::V1(renamed))) => {
+            assert_eq!(renamed.from, BookmarkName::new("experiment"));
+            assert_eq!(renamed.to, BookmarkName::new("trial"));
+        }
+        other => panic!("expected a rename, got {other:?}"),
+    }
+    assert_eq!(
+        names().await?,
+        vec![BookmarkName::main(), BookmarkName::new("trial")]
+    );
+
+    app.command("bookmark switch main").await?;
+    assert!(
+        app.command("bookmark delete main").await.is_err(),
+        "main is the trunk and can't be deleted"
+    );
+    assert!(
+        app.command("bookmark rename trial main").await.is_err(),
+        "renaming onto an existing bookmark is refused"
+    );
+
+    app.command("bookmark delete trial").await?;
+    assert_eq!(names().await?, vec![BookmarkName::main()]);
+
+    let swept = app.command("bookmark gc").await?;
+    match swept.response() {
+        Responses::Bookmark(BookmarkResponse::Swept(BookmarkSweptResponse::V1(swept))) => {
+            assert!(swept.removed > 0, "superseded chronicle nodes are swept");
+            assert!(swept.bytes_reclaimed > 0);
+            assert!(swept.kept > 0, "main's chronicle survives");
+        }
+        other => panic!("expected a sweep report, got {other:?}"),
+    }
+
+    let again = app.command("bookmark gc").await?;
+    match again.response() {
+        Responses::Bookmark(BookmarkResponse::Swept(BookmarkSweptResponse::V1(swept))) => {
+            assert_eq!(swept.removed, 0, "a second sweep finds nothing new");
+        }
+        other => panic!("expected a sweep report, got {other:?}"),
+    }
+
+    app.command("cognition add thinker.process observation 'still recording'")
+        .await?;
+
+    Ok(())
+}
+
 /// A fresh project — one with no forks, no merges, nothing but project create —
 /// should still show its `main` bookmark in `bookmark list`. This locks in
 /// the "nothing implicit" rule: the default main bookmark exists via an
@@ -1,13 +1,20 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, RwLock};
 
 use crate::*;
 
 /// A bookmark entry — a reducer pipeline paired with its chronicle.
+///
+/// `base` is the chronicle root this bookmark was forked from, `from`
+/// the bookmark it was forked off and `forked_at` when — enough to find
+/// the common ancestor merges are computed against.
 #[derive(Clone)]
 pub(crate) struct BookmarkEntry {
     pub(crate) pipeline: ReducerPipeline<ProjectCanon>,
     pub(crate) chronicle: Chronicle,
+    pub(crate) base: Option<ContentHash>,
+    pub(crate) from: Option<BookmarkName>,
+    pub(crate) forked_at: Option<Timestamp>,
 }
 
 impl Default for BookmarkEntry {
@@ -15,6 +22,9 @@ impl Default for BookmarkEntry {
         Self {
             pipeline: ReducerPipeline::project(),
             chronicle: Chronicle::new(),
+            base: None,
+            from: None,
+            forked_at: None,
         }
     }
 }
@@ -46,6 +56,45 @@ impl Shelf {
     pub(crate) fn active_entry(&self) -> BookmarkEntry {
         self.branches.get(&self.active).cloned().unwrap_or_default()
     }
+
+    /// `bookmark` and the bookmarks it descends from by forking, nearest
+    /// first. The chain stops at a bookmark that no longer exists.
+    fn ancestry<'a>(&'a self, bookmark: &'a BookmarkName) -> Vec<&'a BookmarkName> {
+        let mut chain = Vec::new();
+        let mut next = Some(bookmark);
+        while let Some(name) = next
+            && !chain.contains(&name)
+            && let Some(entry) = self.branches.get(name)
+        {
+            chain.push(name);
+            next = entry.from.as_ref();
+        }
+        chain
+    }
+
+    /// Where the fork chains of two bookmarks meet: the root the nearest
+    /// shared bookmark had when the first of them was forked off it.
+    fn merge_base(&self, source: &BookmarkName, target: &BookmarkName) -> Option<ContentHash> {
+        let theirs = self.ancestry(source);
+        let ours = self.ancestry(target);
+        let (at_theirs, at_ours) = theirs
+            .iter()
+            .enumerate()
+            .find_map(|(i, name)| Some((i, ours.iter().position(|ours| ours == name)?)))?;
+
+        // The bookmark each side forked off the shared one, unless that
+        // side is the shared bookmark itself.
+        let forked_off = |chain: &[&BookmarkName], at: usize| {
+            at.checked_sub(1)
+                .and_then(|below| self.branches.get(chain[below]))
+        };
+
+        match (forked_off(&theirs, at_theirs), forked_off(&ours, at_ours)) {
+            (Some(theirs), Some(ours)) if ours.forked_at < theirs.forked_at => ours.base.clone(),
+            (Some(first), _) | (None, Some(first)) => first.base.clone(),
+            (None, None) => None,
+        }
+    }
 }
 
 /// A shared index of bookmark state, keyed by project name.
@@ -108,6 +157,52 @@ impl CanonIndex {
             .unwrap_or_default())
     }
 
+    /// The chronicle root a bookmark was forked from, if it was forked.
+    pub(crate) fn bookmark_base(
+        &self,
+        project: &ProjectName,
+        bookmark: &BookmarkName,
+    ) -> Result<Option<ContentHash>, EventError> {
+        let read = self
+            .projects
+            .read()
+            .map_err(|e| EventError::Lock(e.to_string()))?;
+
+        Ok(read
+            .get(project)
+            .and_then(|shelf| shelf.branches.get(bookmark))
+            .and_then(|entry| entry.base.clone()))
+    }
+
+    /// Save where a bookmark's chronicle stands, and the root it was
+    /// forked from, so hydration can resume it after a restart.
+    pub(crate) fn save_head(
+        &self,
+        store: &ChronicleStore,
+        project: &ProjectName,
+        bookmark: &BookmarkName,
+    ) -> Result<(), EventError> {
+        let entry = {
+            let read = self
+                .projects
+                .read()
+                .map_err(|e| EventError::Lock(e.to_string()))?;
+            read.get(project)
+                .and_then(|shelf| shelf.branches.get(bookmark))
+                .cloned()
+        };
+
+        if let Some(entry) = entry {
+            store.save_head(
+                project,
+                bookmark,
+                entry.chronicle.root()?.as_ref(),
+                entry.base.as_ref(),
+            )?;
+        }
+        Ok(())
+    }
+
     /// Get the active bookmark name for a project.
     pub(crate) fn active_bookmark(
         &self,
@@ -172,7 +267,10 @@ impl CanonIndex {
 
         let forked = BookmarkEntry {
             pipeline: ReducerPipeline::project_with_state(active.pipeline.state()?)?,
+            base: active.chronicle.root()?,
             chronicle: active.chronicle.fork()?,
+            from: Some(shelf.active.clone()),
+            forked_at: Some(Timestamp::now()),
         };
         shelf.branches.insert(bookmark.clone(), forked);
         shelf.active = bookmark.clone();
@@ -198,12 +296,126 @@ impl CanonIndex {
         Ok(())
     }
 
-    /// Merge source bookmark's chronicle into target bookmark.
+    /// Drop a bookmark's entry. Its chronicle nodes stay in the store
+    /// until the next sweep finds them unreachable.
+    pub(crate) fn remove_bookmark(
+        &self,
+        project: &ProjectName,
+        bookmark: &BookmarkName,
+    ) -> Result<(), EventError> {
+        let mut write = self
+            .projects
+            .write()
+            .map_err(|e| EventError::Lock(e.to_string()))?;
+
+        if let Some(shelf) = write.get_mut(project) {
+            shelf.branches.remove(bookmark);
+        }
+
+        Ok(())
+    }
+
+    /// Move a bookmark's entry to a new name, carrying the active
+    /// marker along if it was the active bookmark.
+    pub(crate) fn rename_bookmark(
+        &self,
+        project: &ProjectName,
+        from: &BookmarkName,
+        to: &BookmarkName,
+    ) -> Result<(), EventError> {
+        let mut write = self
+            .projects
+            .write()
+            .map_err(|e| EventError::Lock(e.to_string()))?;
+
+        if let Some(shelf) = write.get_mut(project)
+            && let Some(entry) = shelf.branches.remove(from)
+        {
+            shelf.branches.insert(to.clone(), entry);
+            if shelf.active == *from {
+                shelf.active = to.clone();
+            }
+            for entry in shelf.branches.values_mut() {
+                if entry.from.as_ref() == Some(from) {
+                    entry.from = Some(to.clone());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Mark-and-sweep the chronicle node store.
+    ///
+    /// Every bookmark's current root and fork base is live; anything
+    /// they can't reach is garbage. Chronicles are held still for the
+    /// duration so no record can land between marking and sweeping.
+    /// Projects not in the index lose nothing they can't get back —
+    /// hydration rebuilds their chronicles from the event log.
+    pub(crate) fn collect_garbage(&self, config: &Config) -> Result<ChronicleSweep, EventError> {
+        let read = self
+            .projects
+            .read()
+            .map_err(|e| EventError::Lock(e.to_string()))?;
+
+        let db = config.host_db()?;
+        let store = ChronicleStore::new(&db);
+        store.migrate()?;
+
+        let entries: Vec<&BookmarkEntry> = read
+            .values()
+            .flat_map(|shelf| shelf.branches.values())
+            .collect();
+        let held = entries
+            .iter()
+            .map(|entry| entry.chronicle.hold())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Saved heads keep bookmarks of projects not hydrated here alive.
+        let saved = store.heads()?;
+
+        let resolve = store.resolver();
+        let mut live = HashSet::new();
+        let roots = held
+            .iter()
+            .filter_map(|root| root.as_ref())
+            .chain(entries.iter().filter_map(|entry| entry.base.as_ref()))
+            .chain(saved.iter());
+        for root in roots {
+            Ledger::mark_reachable(root, &resolve, &mut live);
+        }
+
+        store.sweep(&live)
+    }
+
+    /// The common ancestor of two bookmarks, found by walking both fork
+    /// chains to the nearest bookmark they share. `None` when the chains
+    /// never meet.
+    pub(crate) fn merge_base(
+        &self,
+        project: &ProjectName,
+        source: &BookmarkName,
+        target: &BookmarkName,
+    ) -> Result<Option<ContentHash>, EventError> {
+        let read = self
+            .projects
+            .read()
+            .map_err(|e| EventError::Lock(e.to_string()))?;
+
+        Ok(read
+            .get(project)
+            .and_then(|shelf| shelf.merge_base(source, target)))
+    }
+
+    /// Merge source bookmark's chronicle into target bookmark, leaving
+    /// out the `excluded` events.
     pub(crate) fn merge_project(
         &self,
+        config: &Config,
         project: &ProjectName,
         source: &BookmarkName,
         target: &BookmarkName,
+        excluded: &HashSet<EventId>,
     ) -> Result<(), EventError> {
         let read = self
             .projects
@@ -215,29 +427,35 @@ impl CanonIndex {
                 (shelf.branches.get(source), shelf.branches.get(target))
         {
             // Chronicle objects live in the host DB.
-            let config = Config {
-                project: project.clone(),
-                ..Default::default()
-            };
-
-            if let Ok(db) = config.host_db() {
-                let store = ChronicleStore::new(&db);
-                let _ = store.migrate();
-                let _ = target_entry.chronicle.merge(
-                    &source_entry.chronicle,
-                    &store.resolver(),
-                    &store.writer(),
-                );
-            }
+            let db = config.host_db()?;
+            let store = ChronicleStore::new(&db);
+            store.migrate()?;
+            target_entry.chronicle.merge(
+                &source_entry.chronicle,
+                excluded,
+                &store.resolver(),
+                &store.writer(),
+            )?;
+            store.save_head(
+                project,
+                target,
+                target_entry.chronicle.root()?.as_ref(),
+                target_entry.base.as_ref(),
+            )?;
         }
 
         Ok(())
     }
 
-    /// Hydrate a project's reducer pipeline and chronicle from its event log.
+    /// Hydrate a project's bookmarks from its event log and the host DB.
     ///
-    /// Opens events.db standalone for the event log, then the bookmark
-    /// connection for projection migrations.
+    /// Every bookmark resumes its chronicle at the head saved in the
+    /// chronicle store; one forked but never saved resumes at its fork
+    /// base. The bookmarks beside `main`, and their bases, come from the
+    /// host log, and their pipelines replay only what they witnessed.
+    /// `main` replays the whole log into its pipeline, and its chronicle
+    /// takes up any event no bookmark witnessed — events that reached
+    /// the log while the host was away.
     pub(crate) fn hydrate_project(
         &self,
         config: &Config,
@@ -261,23 +479,70 @@ impl CanonIndex {
 
         let events = log.load_all()?;
 
-        let entry = self.project_entry(name)?;
+        let host_db = config.host_db()?;
+        let store = ChronicleStore::new(&host_db);
+        store.migrate()?;
 
+        let main = BookmarkName::main();
+        let mut shelf = Shelf::default();
+        let mut witnessed = HashSet::new();
+
+        let main_head = store.head(name, &main)?;
+        witnessed.extend(Ledger::collect_all_ids(
+            main_head.as_ref(),
+            &store.resolver(),
+        ));
+        let main_entry = BookmarkEntry {
+            chronicle: Chronicle::at(main_head),
+            ..BookmarkEntry::default()
+        };
         for event in &events {
             if let Event::Known(data) = &event.data {
-                entry.pipeline.apply(data)?;
+                main_entry.pipeline.apply(data)?;
             }
         }
 
-        // Rebuild the chronicle in the host DB.
-        let host_db = config.host_db()?;
-        let store = ChronicleStore::new(&host_db);
-        store.migrate()?;
+        let history = BookmarkHistory::load(&host_db, name)?;
+        for (bookmark, base) in history.branches() {
+            let root = store.head(name, bookmark)?.or_else(|| base.cloned());
+            let seen = Ledger::collect_all_ids(root.as_ref(), &store.resolver());
+
+            let pipeline = ReducerPipeline::project();
+            for event in &events {
+                if let Event::Known(data) = &event.data
+                    && seen.contains(&event.id.to_string())
+                {
+                    pipeline.apply(data)?;
+                }
+            }
+
+            witnessed.extend(seen);
+            shelf.branches.insert(
+                bookmark.clone(),
+                BookmarkEntry {
+                    pipeline,
+                    chronicle: Chronicle::at(root),
+                    base: base.cloned(),
+                    from: history.forked_from(bookmark).cloned(),
+                    forked_at: history.forked_at(bookmark),
+                },
+            );
+        }
+
         for event in &events {
-            entry
-                .chronicle
-                .record(event, &store.resolver(), &store.writer())?;
+            if !witnessed.contains(&event.id.to_string()) {
+                main_entry
+                    .chronicle
+                    .record(event, &store.resolver(), &store.writer())?;
+            }
         }
+        store.save_head(name, &main, main_entry.chronicle.root()?.as_ref(), None)?;
+        shelf.branches.insert(main, main_entry);
+
+        self.projects
+            .write()
+            .map_err(|e| EventError::Lock(e.to_string()))?
+            .insert(name.clone(), shelf);
 
         Ok(())
     }
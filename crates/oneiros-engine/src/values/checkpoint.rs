@@ -20,6 +20,18 @@ pub(crate) struct Checkpoint {
     pub(crate) head: Option<EventId>,
     /// When this checkpoint was recorded.
     pub(crate) taken_at: Timestamp,
+    /// What an interrupted collect still had to fetch. The next collect
+    /// fetches these directly instead of diffing again.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) pending: Option<PendingFetch>,
+}
+
+/// The unfinished part of a collect: the peer's chronicle root when it
+/// was diffed, and the events from that diff not yet fetched.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, schemars::JsonSchema)]
+pub(crate) struct PendingFetch {
+    pub(crate) server_root: ContentHash,
+    pub(crate) remaining: Vec<EventId>,
 }
 
 impl Checkpoint {
@@ -31,6 +43,7 @@ impl Checkpoint {
             cumulative_hash: ContentHash::default(),
             head: None,
             taken_at: Timestamp::now(),
+            pending: None,
         }
     }
 
@@ -61,6 +74,10 @@ mod tests {
             cumulative_hash: ContentHash::new("abc123"),
             head: Some(EventId::new()),
             taken_at: Timestamp::now(),
+            pending: Some(PendingFetch {
+                server_root: ContentHash::new("def456"),
+                remaining: vec![EventId::new()],
+            }),
         };
         let json = serde_json::to_string(&cp).unwrap();
         let decoded: Checkpoint = serde_json::from_str(&json).unwrap();
@@ -74,7 +91,16 @@ mod tests {
             cumulative_hash: ContentHash::new("xyz"),
             head: Some(EventId::new()),
             taken_at: Timestamp::now(),
+            pending: None,
         };
         assert!(!cp.is_empty());
     }
+
+    #[test]
+    fn checkpoints_from_before_pending_decode() {
+        let json = r#"{"sequence":3,"cumulative_hash":"abc","head":null,"taken_at":"2026-01-01T00:00:00Z"}"#;
+        let decoded: Checkpoint = serde_json::from_str(json).unwrap();
+        assert_eq!(decoded.sequence, 3);
+        assert_eq!(decoded.pending, None);
+    }
 }
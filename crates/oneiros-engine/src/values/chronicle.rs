@@ -1,4 +1,5 @@
-use std::sync::{Arc, Mutex};
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex, MutexGuard};
 
 use crate::*;
 
@@ -30,6 +31,13 @@ impl Chronicle {
         }
     }
 
+    /// A chronicle resumed at a root recorded earlier.
+    pub(crate) fn at(root: Option<ContentHash>) -> Self {
+        Self {
+            root: Arc::new(Mutex::new(root)),
+        }
+    }
+
     /// The current root hash, if any events have been recorded.
     pub(crate) fn root(&self) -> Result<Option<ContentHash>, EventError> {
         let guard = self
@@ -39,6 +47,15 @@ impl Chronicle {
         Ok(guard.clone())
     }
 
+    /// Hold the root in place. Nothing can be recorded into this
+    /// chronicle until the guard drops — used to keep roots still
+    /// while the node store is swept.
+    pub(crate) fn hold(&self) -> Result<MutexGuard<'_, Option<ContentHash>>, EventError> {
+        self.root
+            .lock()
+            .map_err(|e| EventError::Lock(e.to_string()))
+    }
+
     /// Record an event in this chronicle.
     pub(crate) fn record(
         &self,
@@ -46,7 +63,7 @@ impl Chronicle {
         resolve: &impl Fn(&ContentHash) -> Option<LedgerNode>,
         store: &impl Fn(&LedgerNode) -> ContentHash,
     ) -> Result<(), EventError> {
-        let event_hash = ContentHash::compute(&serde_json::to_vec(&event.data).unwrap_or_default());
+        let event_hash = ContentHash::compute(event.payload()?.get().as_bytes());
 
         let mut guard = self
             .root
@@ -104,10 +121,12 @@ impl Chronicle {
     }
 
     /// Merge another chronicle's events into this one.
-    /// Records all event IDs from the other chronicle that aren't already present.
+    /// Records all event IDs from the other chronicle that aren't already
+    /// present, except those in `excluded` (the losing side of a conflict).
     pub(crate) fn merge(
         &self,
         other: &Chronicle,
+        excluded: &HashSet<EventId>,
         resolve: &impl Fn(&ContentHash) -> Option<LedgerNode>,
         store: &impl Fn(&LedgerNode) -> ContentHash,
     ) -> Result<(), EventError> {
@@ -125,7 +144,9 @@ impl Chronicle {
                 .map_err(|e| EventError::Lock(e.to_string()))?;
 
             for (key, value) in other_entries {
-                if let Ok(event_id) = key.parse() {
+                if let Ok(event_id) = key.parse()
+                    && !excluded.contains(&event_id)
+                {
                     *guard = Some(Ledger::record(
                         guard.as_ref(),
                         &event_id,
@@ -227,4 +248,26 @@ mod tests {
         let changes = chronicle.diff(&forked, &resolve).unwrap();
         assert!(changes.is_empty());
     }
+
+    #[test]
+    fn merge_skips_excluded_events() {
+        let (store, resolve) = memory_store();
+
+        let main = Chronicle::new();
+        main.record(&test_event(1), &resolve, &store).unwrap();
+
+        let experiment = main.fork().unwrap();
+        let kept = test_event(2);
+        let dropped = test_event(3);
+        experiment.record(&kept, &resolve, &store).unwrap();
+        experiment.record(&dropped, &resolve, &store).unwrap();
+
+        let excluded = HashSet::from([dropped.id]);
+        main.merge(&experiment, &excluded, &resolve, &store)
+            .unwrap();
+
+        let changes = main.diff(&experiment, &resolve).unwrap();
+        assert_eq!(changes.len(), 1);
+        assert!(matches!(&changes[0], LedgerChange::Added(id) if *id == dropped.id));
+    }
 }
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::*;
@@ -32,4 +34,10 @@ pub(crate) struct DashboardBootstrap {
     /// Every peer this host knows — other hosts it has been told about
     /// for distribution. Does not imply live reachability.
     pub(crate) peers: Vec<Peer>,
+    /// Every follow on this host, local and peer.
+    pub(crate) follows: Vec<Follow>,
+    /// Background sync status for peer follows, keyed by follow id.
+    /// Follows without an entry haven't been collected since the host
+    /// started.
+    pub(crate) follow_status: HashMap<FollowId, FollowSyncStatus>,
 }
@@ -0,0 +1,545 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+/// Rough characters-per-token ratio for English prose. Close enough to
+/// keep a dream near its budget without a tokenizer on the hot path.
+const CHARS_PER_TOKEN: usize = 4;
+
+/// What a token budget left out of a dream.
+///
+/// Present on a [`DreamContext`] only when something was dropped, so an
+/// agent can tell its dream is partial and reach for the rest.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub(crate) struct DreamTruncation {
+    /// The token budget the dream was fitted to.
+    pub(crate) budget: usize,
+    /// Estimated tokens spent on what was kept.
+    pub(crate) estimated: usize,
+    pub(crate) memories: usize,
+    pub(crate) cognitions: usize,
+    pub(crate) experiences: usize,
+    pub(crate) connections: usize,
+    pub(crate) pressures: usize,
+}
+
+impl DreamTruncation {
+    /// Total number of items dropped.
+    pub(crate) fn dropped(&self) -> usize {
+        self.memories + self.cognitions + self.experiences + self.connections + self.pressures
+    }
+
+    /// Human-readable tally of what was dropped, e.g.
+    /// `2 memories, 14 cognitions`.
+    pub(crate) fn summary(&self) -> String {
+        [
+            (self.memories, "memory", "memories"),
+            (self.cognitions, "cognition", "cognitions"),
+            (self.experiences, "experience", "experiences"),
+            (self.connections, "connection", "connections"),
+            (self.pressures, "pressure", "pressures"),
+        ]
+        .into_iter()
+        .filter(|(count, _, _)| *count > 0)
+        .map(|(count, one, many)| format!("{count} {}", if count == 1 { one } else { many }))
+        .collect::<Vec<_>>()
+        .join(", ")
+    }
+}
+
+/// An item competing for room in the dream.
+#[derive(Clone, Copy)]
+enum Slot {
+    Memory(usize),
+    Cognition(usize),
+    Experience(usize),
+    Pressure(usize),
+}
+
+/// Which of two items in the same tier is admitted first: the better
+/// recall score, then the newer. Only memories carry a score.
+#[derive(Clone, Copy)]
+struct Precedence {
+    score: Option<f64>,
+    created_at: Option<Timestamp>,
+}
+
+impl Ord for Precedence {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let score = |p: &Self| p.score.unwrap_or(f64::NEG_INFINITY);
+        score(other)
+            .total_cmp(&score(self))
+            .then(other.created_at.cmp(&self.created_at))
+    }
+}
+
+impl PartialOrd for Precedence {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Precedence {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for Precedence {}
+
+/// Fits an assembled dream into an approximate token budget.
+///
+/// The dream's skeleton — its identity, vocabulary, instructions and
+/// what changed since the last dream, rendered with nothing else in it —
+/// is spent first. Each memory, cognition, experience, connection and
+/// pressure is costed by the size of its rendered line, then admitted by
+/// priority until the budget is spent: core memories first, then
+/// pressures, then the recent window, then everything else by rank —
+/// nearest first by BFS depth, or by recipe section. Within a rank,
+/// memories go by their recall score, and newest first breaks ties and
+/// orders everything unscored. Items that don't fit are
+/// skipped rather than ending the fill, so a long cognition doesn't crowd
+/// out several short ones behind it. Connections go last, and only when
+/// both of their endpoints made it in.
+pub(crate) struct DreamBudget {
+    limit: usize,
+}
+
+impl DreamBudget {
+    pub(crate) fn new(limit: usize) -> Self {
+        Self { limit }
+    }
+
+    /// Estimated token cost of a piece of rendered text.
+    pub(crate) fn estimate(text: &str) -> usize {
+        text.chars().count().div_ceil(CHARS_PER_TOKEN)
+    }
+
+    /// Estimated token cost of the dream rendered without any of the
+    /// items the budget chooses between — what every dream spends before
+    /// its first memory. The since-last section renders only its newest
+    /// few of each, so it is spent here whole.
+    pub(crate) fn skeleton(context: &DreamContext) -> usize {
+        let skeleton = DreamContext::builder()
+            .agent(context.agent.clone())
+            .maybe_since_last(context.since_last.clone())
+            .maybe_persona(context.persona.clone())
+            .textures(context.textures.clone())
+            .levels(context.levels.clone())
+            .sensations(context.sensations.clone())
+            .natures(context.natures.clone())
+            .urges(context.urges.clone())
+            .build();
+        Self::estimate(&DreamTemplate::new(&skeleton).to_string())
+    }
+
+    /// Trim `context` to the budget. `recent_window` is how many of the
+    /// newest cognitions and experiences count as the recent window;
+    /// `depths` ranks everything else — BFS depth for the default
+    /// recipe, section index for a persona's own — and unranked items go
+    /// last. Memories within a rank are ordered by the context's recall
+    /// scores. Kept items retain their original order.
+    pub(crate) fn fit(
+        &self,
+        mut context: DreamContext,
+        recent_window: usize,
+        depths: &HashMap<Ref, usize>,
+    ) -> DreamContext {
+        const CORE: usize = 0;
+        const PRESSURE: usize = 1;
+        const RECENT: usize = 2;
+        const DISCOVERED: usize = 3;
+
+        let recent_cognitions = Self::newest(
+            context.cognitions.iter().map(|c| (c.id, c.created_at)),
+            recent_window,
+        );
+        let recent_experiences = Self::newest(
+            context.experiences.iter().map(|e| (e.id, e.created_at)),
+            recent_window,
+        );
+        let discovered = |r: Ref| {
+            depths
+                .get(&r)
+                .map_or(usize::MAX, |depth| DISCOVERED.saturating_add(*depth))
+        };
+
+        let ranks = LevelRanks::new(&context.levels);
+        let scores: HashMap<MemoryId, f64> = context
+            .recall
+            .iter()
+            .map(|score| (score.memory, score.total))
+            .collect();
+        let newest = |created_at: Timestamp| Precedence {
+            score: None,
+            created_at: Some(created_at),
+        };
+        let mut candidates: Vec<(usize, Precedence, Slot, usize)> = Vec::new();
+
+        for (index, memory) in context.memories.iter().enumerate() {
+            let tier = if ranks.is_core(&memory.level) {
+                CORE
+            } else {
+                discovered(Ref::memory(memory.id))
+            };
+            let cost = Self::estimate(&format!("- [{}] {}", memory.ref_token(), memory.content));
+            let precedence = Precedence {
+                score: scores.get(&memory.id).copied(),
+                created_at: Some(memory.created_at),
+            };
+            candidates.push((tier, precedence, Slot::Memory(index), cost));
+        }
+
+        for (index, reading) in context.pressures.iter().enumerate() {
+            let cost = Self::estimate(&format!("{} {}", reading.pressure.urge, reading.cta));
+            let precedence = Precedence {
+                score: None,
+                created_at: None,
+            };
+            candidates.push((PRESSURE, precedence, Slot::Pressure(index), cost));
+        }
+
+        for (index, cognition) in context.cognitions.iter().enumerate() {
+            let tier = if recent_cognitions.contains(&cognition.id) {
+                RECENT
+            } else {
+                discovered(Ref::cognition(cognition.id))
+            };
+            let cost = Self::estimate(&format!(
+                "- [{}] {}",
+                cognition.ref_token(),
+                cognition.content
+            ));
+            candidates.push((
+                tier,
+                newest(cognition.created_at),
+                Slot::Cognition(index),
+                cost,
+            ));
+        }
+
+        for (index, experience) in context.experiences.iter().enumerate() {
+            let tier = if recent_experiences.contains(&experience.id) {
+                RECENT
+            } else {
+                discovered(Ref::experience(experience.id))
+            };
+            let cost = Self::estimate(&format!(
+                "- [{}] {}",
+                experience.ref_token(),
+                experience.description
+            ));
+            candidates.push((
+                tier,
+                newest(experience.created_at),
+                Slot::Experience(index),
+                cost,
+            ));
+        }
+
+        // Stable, so pressures keep their reading order within the tier.
+        candidates.sort_by_key(|(tier, precedence, _, _)| (*tier, *precedence));
+
+        let mut spent = Self::skeleton(&context);
+        let mut keep_memories = HashSet::new();
+        let mut keep_cognitions = HashSet::new();
+        let mut keep_experiences = HashSet::new();
+        let mut keep_pressures = HashSet::new();
+
+        for (_, _, slot, cost) in candidates {
+            if spent + cost > self.limit {
+                continue;
+            }
+            spent += cost;
+            match slot {
+                Slot::Memory(index) => keep_memories.insert(index),
+                Slot::Cognition(index) => keep_cognitions.insert(index),
+                Slot::Experience(index) => keep_experiences.insert(index),
+                Slot::Pressure(index) => keep_pressures.insert(index),
+            };
+        }
+
+        let mut truncation = DreamTruncation {
+            budget: self.limit,
+            ..Default::default()
+        };
+
+        truncation.memories = Self::retain(&mut context.memories, &keep_memories);
+        truncation.cognitions = Self::retain(&mut context.cognitions, &keep_cognitions);
+        truncation.experiences = Self::retain(&mut context.experiences, &keep_experiences);
+        truncation.pressures = Self::retain(&mut context.pressures, &keep_pressures);
+
+        let kept_refs: HashSet<Ref> = context
+            .memories
+            .iter()
+            .map(|m| Ref::memory(m.id))
+            .chain(context.cognitions.iter().map(|c| Ref::cognition(c.id)))
+            .chain(context.experiences.iter().map(|e| Ref::experience(e.id)))
+            .collect();
+
+        let mut by_recency: Vec<usize> = (0..context.connections.len()).collect();
+        by_recency.sort_by_key(|index| Reverse(context.connections[*index].created_at));
+
+        let mut keep_connections = HashSet::new();
+        for index in by_recency {
+            let connection = &context.connections[index];
+            if !kept_refs.contains(&connection.from_ref) || !kept_refs.contains(&connection.to_ref)
+            {
+                continue;
+            }
+            let cost = Self::estimate(&format!(
+                "- {} [{}] {}",
+                connection.get_from_token(),
+                connection.nature,
+                connection.get_to_token()
+            ));
+            if spent + cost > self.limit {
+                continue;
+            }
+            spent += cost;
+            keep_connections.insert(index);
+        }
+
+        truncation.connections = Self::retain(&mut context.connections, &keep_connections);
+        truncation.estimated = spent;
+
+        context.truncated = (truncation.dropped() > 0).then_some(truncation);
+        context
+    }
+
+    /// The ids of the `count` newest items.
+    fn newest<Id: Eq + std::hash::Hash>(
+        items: impl Iterator<Item = (Id, Timestamp)>,
+        count: usize,
+    ) -> HashSet<Id> {
+        let mut items: Vec<_> = items.collect();
+        items.sort_by_key(|(_, created_at)| Reverse(*created_at));
+        items.into_iter().take(count).map(|(id, _)| id).collect()
+    }
+
+    /// Keep the items at the given indices; returns how many were dropped.
+    fn retain<T>(items: &mut Vec<T>, keep: &HashSet<usize>) -> usize {
+        let before = items.len();
+        let mut index = 0;
+        items.retain(|_| {
+            let kept = keep.contains(&index);
+            index += 1;
+            kept
+        });
+        before - items.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn agent() -> Agent {
+        Agent::builder()
+            .name("gale")
+            .persona("process")
+            .description("")
+            .prompt("")
+            .build()
+    }
+
+    fn memory(level: &str, content: &str) -> Memory {
+        Memory::builder()
+            .agent_id(AgentId::new())
+            .level(level)
+            .content(content)
+            .build()
+    }
+
+    fn core_level() -> Level {
+        Level::builder()
+            .name("core")
+            .description("")
+            .prompt("")
+            .rank(5)
+            .build()
+    }
+
+    fn cognition(content: &str, created_at: Timestamp) -> Cognition {
+        Cognition::builder()
+            .agent_id(AgentId::new())
+            .texture("observation")
+            .content(content)
+            .created_at(created_at)
+            .build()
+    }
+
+    fn minutes_ago(minutes: i64) -> Timestamp {
+        (chrono::Utc::now() - chrono::Duration::minutes(minutes)).into()
+    }
+
+    #[test]
+    fn estimate_rounds_up() {
+        assert_eq!(DreamBudget::estimate(""), 0);
+        assert_eq!(DreamBudget::estimate("abc"), 1);
+        assert_eq!(DreamBudget::estimate("abcdefghi"), 3);
+    }
+
+    #[test]
+    fn generous_budget_keeps_everything() {
+        let context = DreamContext::builder()
+            .agent(agent())
+            .levels(vec![core_level()])
+            .memories(vec![memory("core", "who I am")])
+            .cognitions(vec![cognition("a thought", minutes_ago(1))])
+            .build();
+
+        let fitted = DreamBudget::new(10_000).fit(context, 5, &HashMap::new());
+
+        assert_eq!(fitted.memories.len(), 1);
+        assert_eq!(fitted.cognitions.len(), 1);
+        assert_eq!(fitted.truncated, None);
+    }
+
+    #[test]
+    fn core_memories_win_over_cognitions() {
+        let core = memory("core", "who I am, at length, in some detail");
+        let thought = cognition("a passing thought of similar length", minutes_ago(1));
+        let context = DreamContext::builder()
+            .agent(agent())
+            .levels(vec![core_level()])
+            .memories(vec![core.clone()])
+            .cognitions(vec![thought])
+            .build();
+        let budget = DreamBudget::skeleton(&context)
+            + DreamBudget::estimate(&format!("- [{}] {}", core.ref_token(), core.content));
+
+        let fitted = DreamBudget::new(budget).fit(context, 5, &HashMap::new());
+
+        assert_eq!(fitted.memories.len(), 1);
+        assert!(fitted.cognitions.is_empty());
+        let truncated = fitted.truncated.expect("dream should report truncation");
+        assert_eq!(truncated.cognitions, 1);
+        assert_eq!(truncated.summary(), "1 cognition");
+    }
+
+    #[test]
+    fn recent_window_wins_over_deeper_discoveries() {
+        let old = cognition("found two hops out in the graph", minutes_ago(60));
+        let near = cognition("found one hop out in the graph", minutes_ago(30));
+        let recent = cognition("the newest thing on my mind now", minutes_ago(1));
+        let depths = HashMap::from([(Ref::cognition(old.id), 2), (Ref::cognition(near.id), 1)]);
+        let context = DreamContext::builder()
+            .agent(agent())
+            .cognitions(vec![old.clone(), near.clone(), recent.clone()])
+            .build();
+        let line =
+            |c: &Cognition| DreamBudget::estimate(&format!("- [{}] {}", c.ref_token(), c.content));
+        let budget = DreamBudget::skeleton(&context) + line(&recent) + line(&near);
+
+        let fitted = DreamBudget::new(budget).fit(context, 1, &depths);
+
+        let kept: Vec<_> = fitted.cognitions.iter().map(|c| c.id).collect();
+        assert_eq!(kept, vec![near.id, recent.id], "original order is kept");
+        assert_eq!(fitted.truncated.map(|t| t.cognitions), Some(1));
+    }
+
+    #[test]
+    fn connections_need_both_endpoints() {
+        let kept = memory("core", "kept");
+        let dropped = cognition(&"long ".repeat(100), minutes_ago(1));
+        let connection = Connection::builder()
+            .from_ref(Ref::memory(kept.id))
+            .to_ref(Ref::cognition(dropped.id))
+            .nature("context")
+            .build();
+        let context = DreamContext::builder()
+            .agent(agent())
+            .levels(vec![core_level()])
+            .memories(vec![kept])
+            .cognitions(vec![dropped])
+            .connections(vec![connection])
+            .build();
+
+        let budget = DreamBudget::skeleton(&context) + 50;
+
+        let fitted = DreamBudget::new(budget).fit(context, 5, &HashMap::new());
+
+        assert!(fitted.connections.is_empty());
+        let truncated = fitted.truncated.expect("dream should report truncation");
+        assert_eq!(truncated.cognitions, 1);
+        assert_eq!(truncated.connections, 1);
+    }
+
+    #[test]
+    fn the_skeleton_is_spent_first() {
+        let context = DreamContext::builder()
+            .agent(agent())
+            .levels(vec![core_level()])
+            .memories(vec![memory("core", "who I am")])
+            .build();
+        let skeleton = DreamBudget::skeleton(&context);
+        assert!(skeleton > 0);
+
+        let fitted = DreamBudget::new(skeleton).fit(context, 5, &HashMap::new());
+
+        assert!(fitted.memories.is_empty());
+        let truncated = fitted.truncated.expect("dream should report truncation");
+        assert_eq!(truncated.memories, 1);
+        assert_eq!(truncated.estimated, skeleton);
+    }
+
+    #[test]
+    fn the_diff_is_part_of_the_skeleton() {
+        let quiet = DreamContext::builder().agent(agent()).build();
+        let mut diff = DreamDiff::since(&DreamReceipt {
+            dreamed_at: Timestamp::now(),
+            sequence: 1,
+            pressures: Vec::new(),
+        });
+        diff.memories = vec![memory("core", "something collected since")];
+        let changed = DreamContext::builder()
+            .agent(agent())
+            .since_last(Box::new(diff))
+            .build();
+
+        assert!(DreamBudget::skeleton(&changed) > DreamBudget::skeleton(&quiet));
+    }
+
+    #[test]
+    fn memories_are_admitted_by_recall_score_before_recency() {
+        let relevant = Memory {
+            created_at: minutes_ago(60),
+            ..memory("project", "the focused memory")
+        };
+        let recent = Memory {
+            created_at: minutes_ago(1),
+            ..memory("project", "the newest memory")
+        };
+        let score = |memory: &Memory, total: f64| RecallScore {
+            memory: memory.id,
+            level: 0.0,
+            recency: 0.0,
+            degree: 0.0,
+            surfaced: 0.0,
+            focus: None,
+            total,
+        };
+        let context = DreamContext::builder()
+            .agent(agent())
+            .levels(vec![core_level()])
+            .recall(vec![score(&relevant, 0.9), score(&recent, 0.1)])
+            .memories(vec![relevant.clone(), recent])
+            .build();
+        let skeleton = DreamBudget::skeleton(&context);
+        let one = DreamBudget::estimate(&format!(
+            "- [{}] {}",
+            relevant.ref_token(),
+            relevant.content
+        ));
+
+        let fitted = DreamBudget::new(skeleton + one).fit(context, 5, &HashMap::new());
+
+        let kept: Vec<MemoryId> = fitted.memories.iter().map(|m| m.id).collect();
+        assert_eq!(kept, vec![relevant.id]);
+    }
+}
@@ -58,6 +58,12 @@ pub(crate) struct DreamConfig {
     #[arg(long, global = true, default_value_t = default_experience_size())]
     #[serde(default = "default_experience_size")]
     pub(crate) experience_size: usize,
+    /// Approximate token budget for the rendered dream. Items are
+    /// admitted by priority until the budget is spent; unlimited when
+    /// unset.
+    #[arg(long, global = true)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) dream_budget: Option<usize>,
 }
 
 impl Default for DreamConfig {
@@ -69,6 +75,7 @@ impl Default for DreamConfig {
             recollection_level: default_recollection_level(),
             recollection_size: default_recollection_size(),
             experience_size: default_experience_size(),
+            dream_budget: None,
         }
     }
 }
@@ -91,6 +98,7 @@ impl DreamConfig {
                 .recollection_size
                 .unwrap_or(self.recollection_size),
             experience_size: overrides.experience_size.unwrap_or(self.experience_size),
+            dream_budget: overrides.dream_budget.or(self.dream_budget),
         }
     }
 }
@@ -108,6 +116,13 @@ pub(crate) struct DreamOverrides {
     pub(crate) recollection_level: Option<LevelName>,
     pub(crate) recollection_size: Option<usize>,
     pub(crate) experience_size: Option<usize>,
+    pub(crate) dream_budget: Option<usize>,
+    /// A topic to weigh memory recall toward. Per-request only — there's
+    /// no server default to merge it onto.
+    pub(crate) focus: Option<String>,
+    /// Lead with what changed since the agent last dreamed. Per-request
+    /// only, like `focus`.
+    pub(crate) since_last: Option<bool>,
 }
 
 #[cfg(test)]
@@ -123,6 +138,7 @@ mod tests {
         assert_eq!(config.recollection_level, LevelName::new("project"));
         assert_eq!(config.recollection_size, 30);
         assert_eq!(config.experience_size, 10);
+        assert_eq!(config.dream_budget, None);
     }
 
     #[test]
@@ -137,6 +153,7 @@ mod tests {
         assert_eq!(merged.recollection_level, config.recollection_level);
         assert_eq!(merged.recollection_size, config.recollection_size);
         assert_eq!(merged.experience_size, config.experience_size);
+        assert_eq!(merged.dream_budget, config.dream_budget);
     }
 
     #[test]
@@ -168,6 +185,9 @@ mod tests {
             recollection_level: Some(LevelName::new("session")),
             recollection_size: Some(50),
             experience_size: Some(25),
+            dream_budget: Some(8000),
+            focus: None,
+            since_last: None,
         };
         let merged = config.merge(&overrides);
 
@@ -177,5 +197,6 @@ mod tests {
         assert_eq!(merged.recollection_level, LevelName::new("session"));
         assert_eq!(merged.recollection_size, 50);
         assert_eq!(merged.experience_size, 25);
+        assert_eq!(merged.dream_budget, Some(8000));
     }
 }
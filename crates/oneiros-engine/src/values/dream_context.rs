@@ -38,4 +38,16 @@ pub(crate) struct DreamContext {
     #[builder(default)]
     #[serde(default)]
     pub(crate) pressures: Vec<PressureReading>,
+    /// Score breakdowns for the non-core memories recalled into the
+    /// dream, highest first.
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) recall: Vec<RecallScore>,
+    /// What changed since the agent last dreamed, when asked for. Boxed
+    /// to keep continuity responses from ballooning when it's absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) since_last: Option<Box<DreamDiff>>,
+    /// What the dream budget left out, if anything.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) truncated: Option<DreamTruncation>,
 }
@@ -0,0 +1,123 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+/// What an agent was handed the last time it dreamed: when, where in
+/// the event log, and the pressures it was under.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub(crate) struct DreamReceipt {
+    pub(crate) dreamed_at: Timestamp,
+    /// The local sequence of the dream's event. Whatever the log took in
+    /// after it is new to the agent, however long ago it was made
+    /// elsewhere. Zero for receipts recorded before sequences were kept.
+    pub(crate) sequence: i64,
+    pub(crate) pressures: Vec<PressureSummary>,
+}
+
+/// An urge whose pressure moved since the last dream. `before` is absent
+/// when the urge wasn't pressing then.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub(crate) struct PressureChange {
+    pub(crate) urge: UrgeName,
+    pub(crate) before: Option<u8>,
+    pub(crate) after: u8,
+}
+
+/// What's new since the agent last dreamed — its own new memories,
+/// experiences other agents recorded, new connections anywhere in the
+/// project, and pressures that moved.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub(crate) struct DreamDiff {
+    pub(crate) since: Timestamp,
+    #[serde(default)]
+    pub(crate) memories: Vec<Memory>,
+    #[serde(default)]
+    pub(crate) experiences: Vec<Experience>,
+    #[serde(default)]
+    pub(crate) connections: Vec<Connection>,
+    #[serde(default)]
+    pub(crate) pressures: Vec<PressureChange>,
+}
+
+impl DreamDiff {
+    /// An empty diff starting from the receipt's dream.
+    pub(crate) fn since(receipt: &DreamReceipt) -> Self {
+        Self {
+            since: receipt.dreamed_at,
+            memories: Vec::new(),
+            experiences: Vec::new(),
+            connections: Vec::new(),
+            pressures: Vec::new(),
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.memories.is_empty()
+            && self.experiences.is_empty()
+            && self.connections.is_empty()
+            && self.pressures.is_empty()
+    }
+
+    /// Compare the pressures the receipt recorded against the current
+    /// ones, keeping urges whose percentage changed.
+    pub(crate) fn pressure_changes(
+        before: &[PressureSummary],
+        after: &[PressureSummary],
+    ) -> Vec<PressureChange> {
+        after
+            .iter()
+            .filter_map(|now| {
+                let then = before
+                    .iter()
+                    .find(|p| p.urge == now.urge)
+                    .map(|p| p.percent);
+                (then != Some(now.percent)).then(|| PressureChange {
+                    urge: now.urge.clone(),
+                    before: then,
+                    after: now.percent,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(urge: &str, percent: u8) -> PressureSummary {
+        PressureSummary {
+            urge: UrgeName::new(urge),
+            percent,
+        }
+    }
+
+    #[test]
+    fn pressure_changes_keep_only_moved_urges() {
+        let before = vec![summary("introspect", 40), summary("catharsis", 10)];
+        let after = vec![
+            summary("introspect", 65),
+            summary("catharsis", 10),
+            summary("retrospect", 20),
+        ];
+
+        let changes = DreamDiff::pressure_changes(&before, &after);
+
+        assert_eq!(
+            changes,
+            vec![
+                PressureChange {
+                    urge: UrgeName::new("introspect"),
+                    before: Some(40),
+                    after: 65,
+                },
+                PressureChange {
+                    urge: UrgeName::new("retrospect"),
+                    before: None,
+                    after: 20,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+/// How many items a section surfaces when its spec doesn't say.
+const DEFAULT_SECTION_CAP: usize = 10;
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum DreamSectionError {
+    #[error("dream section {0:?} must look like NAME[:CAP]=LENS")]
+    Malformed(String),
+    #[error("dream section {name:?} has an invalid cap {cap:?}")]
+    InvalidCap { name: String, cap: String },
+    #[error("dream section {name:?} has an invalid lens: {source}")]
+    Lens {
+        name: String,
+        #[source]
+        source: LensParseError,
+    },
+}
+
+/// One section of a dream recipe: a lens selecting what to surface and
+/// a cap on how many of its newest hits make it in.
+///
+/// Written on the command line as `NAME[:CAP]=LENS`, e.g.
+/// `questions:5=texture(question) & agent(self)`. The lens may use
+/// `self` for the dreaming agent.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub(crate) struct DreamSection {
+    pub(crate) name: String,
+    pub(crate) lens: String,
+    pub(crate) cap: usize,
+}
+
+impl core::str::FromStr for DreamSection {
+    type Err = DreamSectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (head, lens) = s
+            .split_once('=')
+            .ok_or_else(|| DreamSectionError::Malformed(s.to_string()))?;
+        let (name, cap) = match head.split_once(':') {
+            Some((name, cap)) => {
+                let parsed = cap
+                    .trim()
+                    .parse()
+                    .map_err(|_| DreamSectionError::InvalidCap {
+                        name: name.trim().to_string(),
+                        cap: cap.to_string(),
+                    })?;
+                (name.trim(), parsed)
+            }
+            None => (head.trim(), DEFAULT_SECTION_CAP),
+        };
+        let lens = lens.trim();
+        if name.is_empty() || lens.is_empty() {
+            return Err(DreamSectionError::Malformed(s.to_string()));
+        }
+        Lens::parse(lens).map_err(|source| DreamSectionError::Lens {
+            name: name.to_string(),
+            source,
+        })?;
+
+        Ok(Self {
+            name: name.to_string(),
+            lens: lens.to_string(),
+            cap,
+        })
+    }
+}
+
+impl core::fmt::Display for DreamSection {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}:{}={}", self.name, self.cap, self.lens)
+    }
+}
+
+/// An ordered list of dream sections, stored on a persona.
+///
+/// A persona with a recipe wakes with what its sections select — core
+/// memories always included — instead of the default recipe: level-
+/// filtered memories, the recent window, and a walk of the connection
+/// graph. Earlier sections take priority when a dream budget is tight.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub(crate) struct DreamRecipe {
+    pub(crate) sections: Vec<DreamSection>,
+}
+
+impl DreamRecipe {
+    pub(crate) fn new(sections: impl IntoIterator<Item = DreamSection>) -> Self {
+        Self {
+            sections: sections.into_iter().collect(),
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.sections.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn section_parses_name_cap_and_lens() {
+        let section: DreamSection = "questions:5=texture(question) & agent(self)"
+            .parse()
+            .unwrap();
+        assert_eq!(section.name, "questions");
+        assert_eq!(section.cap, 5);
+        assert_eq!(section.lens, "texture(question) & agent(self)");
+    }
+
+    #[test]
+    fn section_cap_defaults_when_omitted() {
+        let section: DreamSection = "notes=kind(memory)".parse().unwrap();
+        assert_eq!(section.cap, DEFAULT_SECTION_CAP);
+    }
+
+    #[test]
+    fn section_round_trips_through_display() {
+        let section: DreamSection = "notes:3=kind(memory)".parse().unwrap();
+        let reparsed: DreamSection = section.to_string().parse().unwrap();
+        assert_eq!(section, reparsed);
+    }
+
+    #[test]
+    fn section_rejects_bad_input() {
+        assert!(matches!(
+            "no-lens-here".parse::<DreamSection>(),
+            Err(DreamSectionError::Malformed(_))
+        ));
+        assert!(matches!(
+            "notes:many=kind(memory)".parse::<DreamSection>(),
+            Err(DreamSectionError::InvalidCap { .. })
+        ));
+        assert!(matches!(
+            "notes=kind(".parse::<DreamSection>(),
+            Err(DreamSectionError::Lens { .. })
+        ));
+    }
+}
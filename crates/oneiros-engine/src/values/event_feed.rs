@@ -0,0 +1,69 @@
+use tokio::sync::broadcast;
+
+use crate::*;
+
+/// How many appended events a slow subscriber may fall behind before it
+/// starts missing them.
+const FEED_CAPACITY: usize = 1024;
+
+/// An event just written to a project's log on this host, with the
+/// bookmark it was written under. `stored` carries the sequence it was
+/// stored at here, imported or not.
+#[derive(Clone, Debug)]
+pub(crate) struct AppendedEvent {
+    pub(crate) project: ProjectName,
+    pub(crate) bookmark: BookmarkName,
+    pub(crate) stored: Box<StoredEvent>,
+    /// Whether the event came from a peer rather than a local append.
+    pub(crate) imported: bool,
+}
+
+/// A broadcast of the events written to this host's logs.
+///
+/// Published by the project and host log actors after each append, and
+/// by the import actor for each event a peer sent that was new here;
+/// read by anything that wants new events as they happen rather than by
+/// polling — push subscriptions on the sync bridge and the `/events`
+/// streams among them. Push subscriptions pass over imports, so two
+/// hosts pushing to each other can't echo an event back and forth. A
+/// subscriber that falls more than the feed's capacity behind sees
+/// `RecvError::Lagged` and should fall back to a pull.
+#[derive(Clone)]
+pub(crate) struct EventFeed {
+    tx: broadcast::Sender<AppendedEvent>,
+    host_tx: broadcast::Sender<Box<StoredEvent>>,
+}
+
+impl EventFeed {
+    pub(crate) fn new() -> Self {
+        let (tx, _) = broadcast::channel(FEED_CAPACITY);
+        let (host_tx, _) = broadcast::channel(FEED_CAPACITY);
+        Self { tx, host_tx }
+    }
+
+    /// Publish an appended event. Nobody listening is not an error.
+    pub(crate) fn publish(&self, appended: AppendedEvent) {
+        let _ = self.tx.send(appended);
+    }
+
+    /// Receive every event published from now on.
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<AppendedEvent> {
+        self.tx.subscribe()
+    }
+
+    /// Publish an event appended to the host log.
+    pub(crate) fn publish_host(&self, stored: Box<StoredEvent>) {
+        let _ = self.host_tx.send(stored);
+    }
+
+    /// Receive every host log event published from now on.
+    pub(crate) fn subscribe_host(&self) -> broadcast::Receiver<Box<StoredEvent>> {
+        self.host_tx.subscribe()
+    }
+}
+
+impl Default for EventFeed {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,110 @@
+use data_encoding::HEXLOWER;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::*;
+
+/// An ed25519 signature over a stored event, made by the host that
+/// minted it.
+///
+/// Carries the signer's public key alongside the signature so a log
+/// can be verified without knowing in advance which host wrote each
+/// event. The signature is serialized as hex, like the key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct EventSignature {
+    pub(crate) signer: PeerKey,
+    #[serde(with = "hex_signature")]
+    signature: [u8; Self::LENGTH],
+}
+
+impl EventSignature {
+    pub(crate) const LENGTH: usize = 64;
+
+    /// Sign `payload` with the host's secret key.
+    pub(crate) fn sign(secret: &iroh::SecretKey, payload: &[u8]) -> Self {
+        Self {
+            signer: PeerKey::from_bytes(*secret.public().as_bytes()),
+            signature: secret.sign(payload).to_bytes(),
+        }
+    }
+
+    /// True when this signature was made over `payload` by `signer`.
+    pub(crate) fn verify(&self, payload: &[u8]) -> bool {
+        let Ok(public) = iroh::PublicKey::from_bytes(self.signer.as_bytes()) else {
+            return false;
+        };
+        public
+            .verify(payload, &iroh::Signature::from_bytes(&self.signature))
+            .is_ok()
+    }
+}
+
+/// What a stored event's signature says about where it came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Provenance {
+    /// No signature — written before signing, or stripped in transit.
+    Unsigned,
+    /// Signed, and the signature matches the event's content.
+    Signed(PeerKey),
+    /// Signed, but the content no longer matches the signature.
+    Tampered(PeerKey),
+    /// A tombstone left by redaction. Its content is gone, so there is
+    /// nothing left to sign.
+    Redacted,
+}
+
+mod hex_signature {
+    use super::*;
+
+    pub(super) fn serialize<S: Serializer>(
+        bytes: &[u8; EventSignature::LENGTH],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&HEXLOWER.encode(bytes))
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<[u8; EventSignature::LENGTH], D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        let bytes = HEXLOWER
+            .decode(encoded.as_bytes())
+            .map_err(serde::de::Error::custom)?;
+        let len = bytes.len();
+        bytes.try_into().map_err(|_| {
+            serde::de::Error::custom(format!(
+                "invalid signature length: expected {} bytes, got {len}",
+                EventSignature::LENGTH
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signature_verifies_its_payload() {
+        let secret = iroh::SecretKey::generate();
+        let signature = EventSignature::sign(&secret, b"payload");
+
+        assert_eq!(
+            signature.signer,
+            PeerKey::from_bytes(*secret.public().as_bytes())
+        );
+        assert!(signature.verify(b"payload"));
+        assert!(!signature.verify(b"tampered"));
+    }
+
+    #[test]
+    fn signature_roundtrips_through_serde() {
+        let secret = iroh::SecretKey::generate();
+        let signature = EventSignature::sign(&secret, b"payload");
+
+        let json = serde_json::to_string(&signature).unwrap();
+        let decoded: EventSignature = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(signature, decoded);
+        assert!(decoded.verify(b"payload"));
+    }
+}
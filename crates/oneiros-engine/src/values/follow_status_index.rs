@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+use std::sync::{Arc, PoisonError, RwLock};
+
+use crate::*;
+
+/// A shared index of background sync status, keyed by follow.
+///
+/// Written by the follow sync actor and by manual collects, read by
+/// `follow list` and the dashboard. Held in memory only — a restarted
+/// host starts with every follow unattempted.
+#[derive(Clone, Default)]
+pub(crate) struct FollowStatusIndex {
+    follows: Arc<RwLock<HashMap<FollowId, FollowSyncStatus>>>,
+}
+
+impl FollowStatusIndex {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// The status for a follow, if a collect has been attempted.
+    pub(crate) fn get(&self, id: FollowId) -> Option<FollowSyncStatus> {
+        self.follows
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&id)
+            .cloned()
+    }
+
+    /// Every tracked status.
+    pub(crate) fn all(&self) -> HashMap<FollowId, FollowSyncStatus> {
+        self.follows
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    pub(crate) fn succeeded(&self, id: FollowId, events_received: u64) {
+        self.update(id, |status| status.succeeded(events_received));
+    }
+
+    pub(crate) fn failed(&self, id: FollowId, error: impl ToString) {
+        self.update(id, |status| status.failed(error));
+    }
+
+    /// Drop statuses for follows that no longer exist.
+    pub(crate) fn retain(&self, live: &[FollowId]) {
+        self.follows
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain(|id, _| live.contains(id));
+    }
+
+    fn update(&self, id: FollowId, apply: impl FnOnce(&mut FollowSyncStatus)) {
+        let mut follows = self.follows.write().unwrap_or_else(PoisonError::into_inner);
+        apply(follows.entry(id).or_default());
+    }
+}
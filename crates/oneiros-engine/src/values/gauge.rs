@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::*;
+
 /// Smooth saturation function: maps [0, ∞) → [0, 1).
 /// At value == midpoint, output == 0.5.
 fn saturate(value: f64, midpoint: f64) -> f64 {
@@ -19,6 +21,7 @@ pub(crate) enum Gauge {
     Catharsis(CatharsisGauge),
     Recollect(RecollectGauge),
     Retrospect(RetrospectGauge),
+    Declared(DeclaredGauge),
 }
 
 impl Gauge {
@@ -29,6 +32,7 @@ impl Gauge {
             Gauge::Catharsis(g) => g.urgency(),
             Gauge::Recollect(g) => g.urgency(),
             Gauge::Retrospect(g) => g.urgency(),
+            Gauge::Declared(g) => g.urgency(),
         }
     }
 }
@@ -164,6 +168,7 @@ pub(crate) struct CatharsisGauge {
 }
 
 impl CatharsisGauge {
+    #[cfg(test)]
     pub(crate) fn from_inputs(inputs: CatharsisInputs) -> Self {
         Self::from_inputs_with_config(inputs, CatharsisConfig::default())
     }
@@ -274,6 +279,7 @@ pub(crate) struct RecollectGauge {
 }
 
 impl RecollectGauge {
+    #[cfg(test)]
     pub(crate) fn from_inputs(inputs: RecollectInputs) -> Self {
         Self::from_inputs_with_config(inputs, RecollectConfig::default())
     }
@@ -384,6 +390,7 @@ pub(crate) struct RetrospectGauge {
 }
 
 impl RetrospectGauge {
+    #[cfg(test)]
     pub(crate) fn from_inputs(inputs: RetrospectInputs) -> Self {
         Self::from_inputs_with_config(inputs, RetrospectConfig::default())
     }
@@ -477,6 +484,48 @@ pub(crate) struct RetrospectCalculation {
     pub(crate) experience_accumulation_factor: f64,
 }
 
+// ── Declared Gauge ──────────────────────────────────────────────
+
+/// Declared gauge: pressure for an urge that carries its own
+/// [`GaugeDefinition`].
+///
+/// Each reading keeps the factor as declared alongside the raw input the
+/// reducer measured and the saturated value, so the gauge explains itself
+/// the same way the built-in ones do.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, schemars::JsonSchema)]
+pub(crate) struct DeclaredGauge {
+    pub(crate) readings: Vec<FactorReading>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, schemars::JsonSchema)]
+pub(crate) struct FactorReading {
+    pub(crate) factor: GaugeFactor,
+    pub(crate) input: f64,
+    pub(crate) value: f64,
+}
+
+impl DeclaredGauge {
+    pub(crate) fn from_inputs(inputs: impl IntoIterator<Item = (GaugeFactor, f64)>) -> Self {
+        let readings = inputs
+            .into_iter()
+            .map(|(factor, input)| FactorReading {
+                value: saturate(input, factor.midpoint),
+                factor,
+                input,
+            })
+            .collect();
+
+        Self { readings }
+    }
+
+    pub(crate) fn urgency(&self) -> f64 {
+        self.readings
+            .iter()
+            .map(|reading| reading.value * reading.factor.weight)
+            .sum()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -803,4 +852,38 @@ mod tests {
         let gauge = Gauge::Retrospect(retrospect.clone());
         assert!((gauge.urgency() - retrospect.urgency()).abs() < f64::EPSILON);
     }
+
+    #[test]
+    fn declared_gauge_weights_saturated_inputs() {
+        let gauge = DeclaredGauge::from_inputs([
+            ("texture:5:0.6=question".parse().unwrap(), 5.0),
+            ("hours-since:8:0.4=reflect".parse().unwrap(), 24.0),
+        ]);
+
+        // texture: saturate(5, 5) = 0.5
+        assert!((gauge.readings[0].value - 0.5).abs() < f64::EPSILON);
+        // hours: saturate(24, 8) = 0.75
+        assert!((gauge.readings[1].value - 0.75).abs() < f64::EPSILON);
+
+        // urgency: 0.5*0.6 + 0.75*0.4 = 0.6
+        assert!((gauge.urgency() - 0.6).abs() < 0.01);
+    }
+
+    #[test]
+    fn declared_gauge_serde_roundtrip() {
+        let gauge = Gauge::Declared(DeclaredGauge::from_inputs([(
+            "orphan-ratio:0.5:1".parse().unwrap(),
+            0.25,
+        )]));
+        let json = serde_json::to_string(&gauge).unwrap();
+        let roundtripped: Gauge = serde_json::from_str(&json).unwrap();
+        assert_eq!(gauge, roundtripped);
+
+        let json_value: serde_json::Value = serde_json::to_value(&gauge).unwrap();
+        assert_eq!(json_value["type"], "declared");
+        assert_eq!(
+            json_value["data"]["readings"][0]["factor"]["measure"]["kind"],
+            "orphan-ratio"
+        );
+    }
 }
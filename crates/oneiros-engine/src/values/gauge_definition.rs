@@ -0,0 +1,262 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum GaugeFactorError {
+    #[error("gauge factor {0:?} must look like MEASURE:MIDPOINT:WEIGHT[=ARG]")]
+    Malformed(String),
+    #[error("gauge factor {spec:?} has an invalid number {value:?}")]
+    InvalidNumber { spec: String, value: String },
+    #[error("unknown gauge measure {0:?}; expected one of {MEASURES}")]
+    UnknownMeasure(String),
+    #[error("gauge measure {0:?} needs an argument, e.g. {0}:4:0.5=ARG")]
+    MissingArgument(String),
+    #[error("gauge measure {0:?} takes no argument")]
+    UnexpectedArgument(String),
+    #[error("unknown continuity moment {0:?}; expected introspect, reflect, dream or sleep")]
+    UnknownMoment(String),
+    #[error("gauge factor has an invalid lens: {0}")]
+    Lens(#[from] LensError),
+}
+
+const MEASURES: &str = "hours-since, texture, orphan-ratio, level, lens";
+
+/// A continuity event an agent's gauge can measure time from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum ContinuityMoment {
+    Introspect,
+    Reflect,
+    Dream,
+    Sleep,
+}
+
+impl ContinuityMoment {
+    pub(crate) fn of(&self, timestamps: &AgentTimestamps) -> Option<Timestamp> {
+        match self {
+            Self::Introspect => timestamps.last_introspect,
+            Self::Reflect => timestamps.last_reflect,
+            Self::Dream => timestamps.last_dream,
+            Self::Sleep => timestamps.last_sleep,
+        }
+    }
+}
+
+impl core::str::FromStr for ContinuityMoment {
+    type Err = GaugeFactorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "introspect" => Ok(Self::Introspect),
+            "reflect" => Ok(Self::Reflect),
+            "dream" => Ok(Self::Dream),
+            "sleep" => Ok(Self::Sleep),
+            other => Err(GaugeFactorError::UnknownMoment(other.to_string())),
+        }
+    }
+}
+
+impl core::fmt::Display for ContinuityMoment {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Introspect => f.write_str("introspect"),
+            Self::Reflect => f.write_str("reflect"),
+            Self::Dream => f.write_str("dream"),
+            Self::Sleep => f.write_str("sleep"),
+        }
+    }
+}
+
+/// What a gauge factor measures for an agent — the fixed catalogue a
+/// declared gauge draws from.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "kind", content = "arg", rename_all = "kebab-case")]
+pub(crate) enum Measure {
+    /// Hours since the agent's last continuity event of this kind.
+    HoursSince(ContinuityMoment),
+    /// How many of the agent's cognitions carry this texture.
+    Texture(TextureName),
+    /// The fraction of the agent's cognitions no connection references.
+    OrphanRatio,
+    /// How many of the agent's memories sit at this level.
+    Level(LevelName),
+    /// How many hits this lens selects, with `self` bound to the agent.
+    Lens(String),
+}
+
+/// One factor of a declared gauge: a measure, saturated around its
+/// midpoint and scaled by its weight.
+///
+/// Written on the command line as `MEASURE:MIDPOINT:WEIGHT[=ARG]`, e.g.
+/// `texture:5:0.4=question` or `lens:3:0.3=texture(question) & agent(self)`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub(crate) struct GaugeFactor {
+    pub(crate) measure: Measure,
+    pub(crate) midpoint: f64,
+    pub(crate) weight: f64,
+}
+
+impl core::str::FromStr for GaugeFactor {
+    type Err = GaugeFactorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (head, arg) = match s.split_once('=') {
+            Some((head, arg)) => (head, Some(arg.trim()).filter(|arg| !arg.is_empty())),
+            None => (s, None),
+        };
+        let mut parts = head.split(':').map(str::trim);
+        let (Some(measure), Some(midpoint), Some(weight), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(GaugeFactorError::Malformed(s.to_string()));
+        };
+        let number = |value: &str| {
+            value
+                .parse::<f64>()
+                .map_err(|_| GaugeFactorError::InvalidNumber {
+                    spec: s.to_string(),
+                    value: value.to_string(),
+                })
+        };
+        let midpoint = number(midpoint)?;
+        let weight = number(weight)?;
+
+        let needs_arg = || arg.ok_or_else(|| GaugeFactorError::MissingArgument(measure.into()));
+        let measure = match measure {
+            "hours-since" => Measure::HoursSince(needs_arg()?.parse()?),
+            "texture" => Measure::Texture(TextureName::new(needs_arg()?)),
+            "level" => Measure::Level(LevelName::new(needs_arg()?)),
+            "lens" => {
+                let lens = needs_arg()?;
+                GaugeLenses::compile(lens)?;
+                Measure::Lens(lens.to_string())
+            }
+            "orphan-ratio" => match arg {
+                None => Measure::OrphanRatio,
+                Some(_) => return Err(GaugeFactorError::UnexpectedArgument(measure.into())),
+            },
+            other => return Err(GaugeFactorError::UnknownMeasure(other.to_string())),
+        };
+
+        Ok(Self {
+            measure,
+            midpoint,
+            weight,
+        })
+    }
+}
+
+impl core::fmt::Display for GaugeFactor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (name, arg) = match &self.measure {
+            Measure::HoursSince(moment) => ("hours-since", Some(moment.to_string())),
+            Measure::Texture(texture) => ("texture", Some(texture.to_string())),
+            Measure::OrphanRatio => ("orphan-ratio", None),
+            Measure::Level(level) => ("level", Some(level.to_string())),
+            Measure::Lens(lens) => ("lens", Some(lens.clone())),
+        };
+        write!(f, "{name}:{}:{}", self.midpoint, self.weight)?;
+        if let Some(arg) = arg {
+            write!(f, "={arg}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The factors that build an urge's pressure, stored on the urge.
+///
+/// An urge with a definition is gauged by it, whatever its name; the
+/// built-in urges fall back to their own gauges without one. Weights
+/// are expected to sum to 1 so urgency stays within 0–1.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub(crate) struct GaugeDefinition {
+    pub(crate) factors: Vec<GaugeFactor>,
+}
+
+impl GaugeDefinition {
+    pub(crate) fn new(factors: impl IntoIterator<Item = GaugeFactor>) -> Self {
+        Self {
+            factors: factors.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn factor_parses_measure_midpoint_weight_and_arg() {
+        let factor: GaugeFactor = "texture:5:0.4=question".parse().unwrap();
+        assert_eq!(
+            factor.measure,
+            Measure::Texture(TextureName::new("question"))
+        );
+        assert!((factor.midpoint - 5.0).abs() < f64::EPSILON);
+        assert!((factor.weight - 0.4).abs() < f64::EPSILON);
+
+        let factor: GaugeFactor = "hours-since:8:0.3=reflect".parse().unwrap();
+        assert_eq!(
+            factor.measure,
+            Measure::HoursSince(ContinuityMoment::Reflect)
+        );
+    }
+
+    #[test]
+    fn lens_factor_keeps_the_whole_expression() {
+        let factor: GaugeFactor = "lens:3:0.5=texture(question) & agent(self)"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            factor.measure,
+            Measure::Lens("texture(question) & agent(self)".into())
+        );
+    }
+
+    #[test]
+    fn factor_round_trips_through_display() {
+        for spec in ["orphan-ratio:0.5:0.2", "level:10:0.3=session"] {
+            let factor: GaugeFactor = spec.parse().unwrap();
+            let reparsed: GaugeFactor = factor.to_string().parse().unwrap();
+            assert_eq!(factor, reparsed);
+        }
+    }
+
+    #[test]
+    fn factor_rejects_bad_input() {
+        assert!(matches!(
+            "texture:5=question".parse::<GaugeFactor>(),
+            Err(GaugeFactorError::Malformed(_))
+        ));
+        assert!(matches!(
+            "texture:lots:0.4=question".parse::<GaugeFactor>(),
+            Err(GaugeFactorError::InvalidNumber { .. })
+        ));
+        assert!(matches!(
+            "vibes:5:0.4".parse::<GaugeFactor>(),
+            Err(GaugeFactorError::UnknownMeasure(_))
+        ));
+        assert!(matches!(
+            "level:5:0.4".parse::<GaugeFactor>(),
+            Err(GaugeFactorError::MissingArgument(_))
+        ));
+        assert!(matches!(
+            "orphan-ratio:5:0.4=cognition".parse::<GaugeFactor>(),
+            Err(GaugeFactorError::UnexpectedArgument(_))
+        ));
+        assert!(matches!(
+            "hours-since:5:0.4=lunch".parse::<GaugeFactor>(),
+            Err(GaugeFactorError::UnknownMoment(_))
+        ));
+        assert!(matches!(
+            "lens:5:0.4=texture(".parse::<GaugeFactor>(),
+            Err(GaugeFactorError::Lens(_))
+        ));
+        assert!(matches!(
+            "lens:5:0.4=vibes(self)".parse::<GaugeFactor>(),
+            Err(GaugeFactorError::Lens(_))
+        ));
+    }
+}
@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::*;
+
+/// The name a gauge lens uses for the agent it is read for.
+const SELF: &str = "self";
+
+/// Every gauge lens in a project, compiled once as the urge declaring
+/// it is set, keyed by its source.
+///
+/// A lens is compiled with `self` left as a name; [`GaugeLenses::bind`]
+/// puts the agent in its place, so one compile serves every agent.
+#[derive(Default, Clone, Debug)]
+pub(crate) struct GaugeLenses {
+    compiled: HashMap<String, Arc<Ir>>,
+}
+
+impl GaugeLenses {
+    /// Parse, validate and compile a gauge lens against the default
+    /// registry.
+    pub(crate) fn compile(source: &str) -> Result<Ir, LensError> {
+        let lens = Lens::parse(source)?;
+        let registry = Registry::seed_default();
+        lens.validate(&registry)?;
+        Ok(Compiler::new(registry).compile(&lens)?)
+    }
+
+    /// Compile the lenses of a gauge definition not already held. One
+    /// that no longer compiles is logged and left out, and reads as
+    /// selecting nothing.
+    pub(crate) fn learn(&mut self, definition: &GaugeDefinition) {
+        for factor in &definition.factors {
+            let Measure::Lens(source) = &factor.measure else {
+                continue;
+            };
+            if self.compiled.contains_key(source) {
+                continue;
+            }
+            match Self::compile(source) {
+                Ok(ir) => {
+                    self.compiled.insert(source.clone(), Arc::new(ir));
+                }
+                Err(error) => tracing::warn!(%error, lens = %source, "gauge lens does not compile"),
+            }
+        }
+    }
+
+    /// The compiled lens for `source`, with `self` bound to `agent`.
+    pub(crate) fn bind(&self, source: &str, agent: &AgentName) -> Option<Ir> {
+        let ir = self.compiled.get(source)?;
+        let ops = ir
+            .ops
+            .iter()
+            .map(|op| match op {
+                Op::Const(ConstValue::Name { name, kind }) if name == SELF => {
+                    Op::Const(ConstValue::Name {
+                        name: agent.to_string(),
+                        kind: *kind,
+                    })
+                }
+                op => op.clone(),
+            })
+            .collect();
+        Some(Ir::new(ops))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn self_is_bound_per_agent() {
+        let mut lenses = GaugeLenses::default();
+        let source = "texture(question) & agent(self)";
+        lenses.learn(&GaugeDefinition::new([GaugeFactor {
+            measure: Measure::Lens(source.into()),
+            midpoint: 3.0,
+            weight: 1.0,
+        }]));
+
+        let bound = lenses.bind(source, &AgentName::new("gov.process")).unwrap();
+        let agents: Vec<&str> = bound
+            .ops
+            .iter()
+            .filter_map(|op| match op {
+                Op::Const(ConstValue::Name {
+                    name,
+                    kind: NameKind::Agent,
+                }) => Some(name.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(agents, ["gov.process"]);
+    }
+
+    #[test]
+    fn lenses_that_do_not_compile_are_left_out() {
+        let mut lenses = GaugeLenses::default();
+        lenses.learn(&GaugeDefinition::new([GaugeFactor {
+            measure: Measure::Lens("vibes(self)".into()),
+            midpoint: 3.0,
+            weight: 1.0,
+        }]));
+
+        assert!(
+            lenses
+                .bind("vibes(self)", &AgentName::new("gov.process"))
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,329 @@
+use std::collections::{BTreeMap, HashMap};
+
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum GaugeTuningError {
+    #[error("gauge parameter {0:?} must look like NAME=VALUE")]
+    Malformed(String),
+    #[error("gauge parameter {name:?} has an invalid value {value:?}")]
+    InvalidValue { name: String, value: String },
+    #[error("urge {urge} has no gauge parameter {name:?}; it has {known}")]
+    UnknownParameter {
+        urge: UrgeName,
+        name: String,
+        known: String,
+    },
+    #[error("urge {0} has no gauge to tune; give it factors with `urge set --factor`")]
+    Untunable(UrgeName),
+}
+
+/// One gauge parameter override, written `NAME=VALUE` on the command line,
+/// e.g. `time_midpoint=6` or, for a declared gauge, `factors.0.weight=0.5`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub(crate) struct GaugeParam {
+    pub(crate) name: String,
+    pub(crate) value: f64,
+}
+
+impl core::str::FromStr for GaugeParam {
+    type Err = GaugeTuningError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, value) = s
+            .split_once('=')
+            .ok_or_else(|| GaugeTuningError::Malformed(s.to_string()))?;
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(GaugeTuningError::Malformed(s.to_string()));
+        }
+        let value = value
+            .trim()
+            .parse()
+            .map_err(|_| GaugeTuningError::InvalidValue {
+                name: name.to_string(),
+                value: value.to_string(),
+            })?;
+
+        Ok(Self {
+            name: name.to_string(),
+            value,
+        })
+    }
+}
+
+/// Where a gauge tuning applies: the whole project, or only agents of one
+/// persona. Persona tunings layer over project tunings, which layer over
+/// the gauge's defaults.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "layer", content = "persona", rename_all = "kebab-case")]
+pub(crate) enum TuningLayer {
+    Project,
+    Persona(PersonaName),
+}
+
+impl core::fmt::Display for TuningLayer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Project => f.write_str("project"),
+            Self::Persona(persona) => write!(f, "persona {persona}"),
+        }
+    }
+}
+
+/// Overrides for an urge's gauge parameters at one layer. Parameters
+/// not named keep whatever the layer beneath gives them.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub(crate) struct GaugeTuning {
+    pub(crate) params: BTreeMap<String, f64>,
+}
+
+impl GaugeTuning {
+    pub(crate) fn new(params: impl IntoIterator<Item = GaugeParam>) -> Self {
+        Self {
+            params: params.into_iter().map(|p| (p.name, p.value)).collect(),
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    /// Apply these overrides to a gauge configuration, addressing its
+    /// fields by name and array elements by index.
+    pub(crate) fn apply<T: Serialize + DeserializeOwned>(
+        &self,
+        config: T,
+    ) -> Result<T, serde_json::Error> {
+        if self.is_empty() {
+            return Ok(config);
+        }
+        let mut value = serde_json::to_value(config)?;
+        for (name, param) in &self.params {
+            if let Some(slot) = value.pointer_mut(&Self::pointer(name))
+                && slot.is_number()
+            {
+                *slot = serde_json::json!(param);
+            }
+        }
+        serde_json::from_value(value)
+    }
+
+    /// Check every parameter names a number in the urge's gauge
+    /// configuration.
+    pub(crate) fn validate_for(&self, urge: &Urge) -> Result<(), GaugeTuningError> {
+        let config = GaugeParams::defaults_for(urge)
+            .ok_or_else(|| GaugeTuningError::Untunable(urge.name.clone()))?;
+        for name in self.params.keys() {
+            if !config.contains_key(name) {
+                return Err(GaugeTuningError::UnknownParameter {
+                    urge: urge.name.clone(),
+                    name: name.clone(),
+                    known: config.keys().cloned().collect::<Vec<_>>().join(", "),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn pointer(name: &str) -> String {
+        format!("/{}", name.replace('.', "/"))
+    }
+}
+
+/// Every gauge tuning in a project, by urge and layer.
+#[derive(Default, Clone, Debug)]
+pub(crate) struct GaugeTunings {
+    urges: HashMap<UrgeName, HashMap<TuningLayer, GaugeTuning>>,
+}
+
+impl GaugeTunings {
+    /// Record a layer's tuning for an urge; an empty tuning clears it.
+    pub(crate) fn set(&mut self, urge: &UrgeName, layer: TuningLayer, tuning: GaugeTuning) {
+        let layers = self.urges.entry(urge.clone()).or_default();
+        if tuning.is_empty() {
+            layers.remove(&layer);
+        } else {
+            layers.insert(layer, tuning);
+        }
+    }
+
+    pub(crate) fn remove_urge(&mut self, urge: &UrgeName) {
+        self.urges.remove(urge);
+    }
+
+    /// The tunings that apply to an agent of `persona`, in the order they
+    /// layer: the project's first, then the persona's.
+    pub(crate) fn layered(
+        &self,
+        urge: &UrgeName,
+        persona: &PersonaName,
+    ) -> Vec<(TuningLayer, GaugeTuning)> {
+        let Some(layers) = self.urges.get(urge) else {
+            return vec![];
+        };
+        [TuningLayer::Project, TuningLayer::Persona(persona.clone())]
+            .into_iter()
+            .filter_map(|layer| {
+                let tuning = layers.get(&layer)?.clone();
+                Some((layer, tuning))
+            })
+            .collect()
+    }
+
+    /// Apply the tunings that reach an agent of `persona` to `config`,
+    /// falling back to `config` untouched if they don't fit it.
+    pub(crate) fn tune<T: Serialize + DeserializeOwned + Clone>(
+        &self,
+        urge: &UrgeName,
+        persona: &PersonaName,
+        config: T,
+    ) -> T {
+        self.layered(urge, persona)
+            .iter()
+            .try_fold(config.clone(), |config, (_, tuning)| tuning.apply(config))
+            .unwrap_or(config)
+    }
+}
+
+/// A gauge configuration flattened into its tunable parameters, by the
+/// names `GaugeTuning` addresses them with.
+pub(crate) struct GaugeParams;
+
+impl GaugeParams {
+    /// The default parameters of the gauge an urge is measured with, or
+    /// `None` when the urge has no gauge.
+    pub(crate) fn defaults_for(urge: &Urge) -> Option<BTreeMap<String, f64>> {
+        let config = match (&urge.gauge, urge.name.as_str()) {
+            (Some(definition), _) => serde_json::to_value(definition),
+            (None, "introspect") => serde_json::to_value(IntrospectConfig::default()),
+            (None, "catharsis") => serde_json::to_value(CatharsisConfig::default()),
+            (None, "recollect") => serde_json::to_value(RecollectConfig::default()),
+            (None, "retrospect") => serde_json::to_value(RetrospectConfig::default()),
+            (None, _) => return None,
+        };
+        config.ok().map(|config| Self::flatten(&config))
+    }
+
+    /// The parameters a computed gauge was configured with.
+    pub(crate) fn of(gauge: &Gauge) -> BTreeMap<String, f64> {
+        let config = match gauge {
+            Gauge::Introspect(g) => serde_json::to_value(&g.config),
+            Gauge::Catharsis(g) => serde_json::to_value(&g.config),
+            Gauge::Recollect(g) => serde_json::to_value(&g.config),
+            Gauge::Retrospect(g) => serde_json::to_value(&g.config),
+            Gauge::Declared(g) => serde_json::to_value(GaugeDefinition::new(
+                g.readings.iter().map(|reading| reading.factor.clone()),
+            )),
+        };
+        config
+            .map(|config| Self::flatten(&config))
+            .unwrap_or_default()
+    }
+
+    fn flatten(value: &serde_json::Value) -> BTreeMap<String, f64> {
+        let mut params = BTreeMap::new();
+        Self::walk(value, String::new(), &mut params);
+        params
+    }
+
+    fn walk(value: &serde_json::Value, prefix: String, out: &mut BTreeMap<String, f64>) {
+        let join = |key: &str| {
+            if prefix.is_empty() {
+                key.to_string()
+            } else {
+                format!("{prefix}.{key}")
+            }
+        };
+        match value {
+            serde_json::Value::Number(n) => {
+                if let Some(n) = n.as_f64() {
+                    out.insert(prefix, n);
+                }
+            }
+            serde_json::Value::Object(map) => {
+                for (key, value) in map {
+                    Self::walk(value, join(key), out);
+                }
+            }
+            serde_json::Value::Array(items) => {
+                for (index, value) in items.iter().enumerate() {
+                    Self::walk(value, join(&index.to_string()), out);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn urge(name: &str) -> Urge {
+        Urge::builder()
+            .name(name)
+            .description("")
+            .prompt("")
+            .build()
+    }
+
+    #[test]
+    fn param_parses_name_and_value() {
+        let param: GaugeParam = "time_midpoint=6".parse().unwrap();
+        assert_eq!(param.name, "time_midpoint");
+        assert!((param.value - 6.0).abs() < f64::EPSILON);
+
+        assert!(matches!(
+            "time_midpoint".parse::<GaugeParam>(),
+            Err(GaugeTuningError::Malformed(_))
+        ));
+        assert!(matches!(
+            "time_midpoint=soon".parse::<GaugeParam>(),
+            Err(GaugeTuningError::InvalidValue { .. })
+        ));
+    }
+
+    #[test]
+    fn tuning_overrides_only_named_fields() {
+        let tuning = GaugeTuning::new(["time_midpoint=6".parse().unwrap()]);
+        let config = tuning.apply(IntrospectConfig::default()).unwrap();
+
+        assert!((config.time_midpoint - 6.0).abs() < f64::EPSILON);
+        assert_eq!(
+            config.session_midpoint,
+            IntrospectConfig::default().session_midpoint
+        );
+    }
+
+    #[test]
+    fn tuning_reaches_declared_factors_by_index() {
+        let definition = GaugeDefinition::new([
+            "texture:5:0.5=question".parse().unwrap(),
+            "orphan-ratio:1:0.5".parse().unwrap(),
+        ]);
+        let tuning = GaugeTuning::new(["factors.1.weight=0.25".parse().unwrap()]);
+        let tuned = tuning.apply(definition).unwrap();
+
+        assert!((tuned.factors[0].weight - 0.5).abs() < f64::EPSILON);
+        assert!((tuned.factors[1].weight - 0.25).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn validation_knows_each_gauges_parameters() {
+        let tuning = GaugeTuning::new(["tensions_weight=0.5".parse().unwrap()]);
+        assert!(tuning.validate_for(&urge("catharsis")).is_ok());
+        assert!(matches!(
+            tuning.validate_for(&urge("introspect")),
+            Err(GaugeTuningError::UnknownParameter { .. })
+        ));
+        assert!(matches!(
+            tuning.validate_for(&urge("wanderlust")),
+            Err(GaugeTuningError::Untunable(_))
+        ));
+    }
+}
@@ -0,0 +1,94 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// An agent harness oneiros can wire itself into — an editor or agent
+/// CLI with its own MCP config file and conventions for skills and hooks.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema, clap::ValueEnum,
+)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum Harness {
+    /// Claude Code: `.mcp.json`, with skills and hooks shipped as a plugin.
+    #[default]
+    Claude,
+    /// Codex CLI: `.codex/config.toml`.
+    Codex,
+    /// Cursor: `.cursor/mcp.json`.
+    Cursor,
+    /// Gemini CLI: `.gemini/settings.json`.
+    Gemini,
+}
+
+/// How a harness stores its MCP server table on disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum HarnessFormat {
+    Json,
+    Toml,
+}
+
+impl Harness {
+    pub(crate) const ALL: [Harness; 4] = [
+        Harness::Claude,
+        Harness::Codex,
+        Harness::Cursor,
+        Harness::Gemini,
+    ];
+
+    /// The project-relative path of the file this harness reads MCP
+    /// servers from.
+    pub(crate) fn config_path(&self) -> &'static str {
+        match self {
+            Self::Claude => ".mcp.json",
+            Self::Codex => ".codex/config.toml",
+            Self::Cursor => ".cursor/mcp.json",
+            Self::Gemini => ".gemini/settings.json",
+        }
+    }
+
+    pub(crate) fn format(&self) -> HarnessFormat {
+        match self {
+            Self::Codex => HarnessFormat::Toml,
+            Self::Claude | Self::Cursor | Self::Gemini => HarnessFormat::Json,
+        }
+    }
+
+    /// The key of the table holding MCP servers within the config file.
+    pub(crate) fn servers_key(&self) -> &'static str {
+        match self {
+            Self::Codex => "mcp_servers",
+            Self::Claude | Self::Cursor | Self::Gemini => "mcpServers",
+        }
+    }
+
+    /// The project-relative directory skills are installed into, or
+    /// `None` when the harness receives them another way — Claude Code
+    /// installs the whole package as a plugin.
+    pub(crate) fn skills_dir(&self) -> Option<&'static str> {
+        match self {
+            Self::Claude => None,
+            Self::Codex => Some(".codex/skills"),
+            Self::Cursor => Some(".cursor/skills"),
+            Self::Gemini => Some(".gemini/skills"),
+        }
+    }
+
+    /// Where the package's hooks file belongs, if this harness understands
+    /// it. The hooks are written against Claude Code's hook events.
+    pub(crate) fn hooks_path(&self) -> Option<&'static str> {
+        match self {
+            Self::Claude => Some("hooks/hooks.json"),
+            Self::Codex | Self::Cursor | Self::Gemini => None,
+        }
+    }
+}
+
+impl core::fmt::Display for Harness {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Claude => f.write_str("claude"),
+            Self::Codex => f.write_str("codex"),
+            Self::Cursor => f.write_str("cursor"),
+            Self::Gemini => f.write_str("gemini"),
+        }
+    }
+}
@@ -294,6 +294,26 @@ impl Ledger {
             None => BTreeMap::new(),
         }
     }
+
+    /// Mark every node reachable from `root` into `live`, keyed by hash.
+    ///
+    /// Subtrees already marked are skipped, so marking many roots that
+    /// share structure only walks each shared node once. Leaf entries
+    /// point at event content, not nodes, and are not marked.
+    pub(crate) fn mark_reachable(
+        root: &ContentHash,
+        resolve: &impl Fn(&ContentHash) -> Option<LedgerNode>,
+        live: &mut std::collections::HashSet<String>,
+    ) {
+        if !live.insert(root.to_string()) {
+            return;
+        }
+        if let Some(LedgerNode::Interior { children }) = resolve(root) {
+            for child_hash in children.values() {
+                Self::mark_reachable(child_hash, resolve, live);
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -401,4 +421,36 @@ mod tests {
         assert_eq!(changes.len(), 1);
         assert!(matches!(&changes[0], LedgerChange::Added(id) if *id == id2));
     }
+
+    #[test]
+    fn mark_reachable_skips_superseded_nodes() {
+        let (store, resolve) = memory_store();
+
+        let first = Ledger::record(
+            None,
+            &EventId::new(),
+            ContentHash::compute(b"one"),
+            &resolve,
+            &store,
+        );
+        let second = Ledger::record(
+            Some(&first),
+            &EventId::new(),
+            ContentHash::compute(b"two"),
+            &resolve,
+            &store,
+        );
+
+        let mut live = std::collections::HashSet::new();
+        Ledger::mark_reachable(&second, &resolve, &mut live);
+
+        assert!(live.contains(&second.to_string()));
+        assert!(
+            !live.contains(&first.to_string()),
+            "the first root is superseded by the second"
+        );
+
+        Ledger::mark_reachable(&first, &resolve, &mut live);
+        assert_eq!(live.len(), 2);
+    }
 }
@@ -0,0 +1,25 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// How an MCP client reaches oneiros.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema, clap::ValueEnum,
+)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum McpTransport {
+    /// The running service's streamable HTTP endpoint at `/mcp`.
+    #[default]
+    Http,
+    /// A `oneiros mcp serve --stdio` child process, for harnesses that
+    /// can't reach the service over the network.
+    Stdio,
+}
+
+impl core::fmt::Display for McpTransport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Http => f.write_str("http"),
+            Self::Stdio => f.write_str("stdio"),
+        }
+    }
+}
@@ -12,18 +12,28 @@ mod dashboard_bootstrap;
 mod database_config;
 mod description;
 mod detail;
+mod dream_budget;
 mod dream_config;
 mod dream_context;
+mod dream_diff;
+mod dream_recipe;
 mod entity_index;
 mod entity_ref;
 mod error_response;
 mod error_view;
+mod event_feed;
+mod event_signature;
 mod expression;
 mod fetch;
 mod follow_source;
+mod follow_status_index;
 mod frames;
 mod gauge;
+mod gauge_definition;
+mod gauge_lenses;
+mod gauge_tuning;
 mod general_config;
+mod harness;
 mod hints;
 mod host_canon;
 mod host_identity;
@@ -35,6 +45,7 @@ mod limit;
 mod link;
 mod listed;
 mod mcp_response;
+mod mcp_transport;
 mod offset;
 mod oneiros_uri;
 mod output_mode;
@@ -51,6 +62,8 @@ mod project_canon;
 mod project_summary;
 mod projection;
 mod prompt;
+mod query_flag;
+mod recall_score;
 mod reducer;
 mod ref_token;
 mod relevant_pressures;
@@ -59,14 +72,18 @@ mod resource;
 mod resource_def;
 mod resource_docs;
 mod resource_key;
+mod resource_subscriptions;
 mod resource_uri;
 mod response;
+mod retention_config;
+mod retention_policy;
 mod search_filters;
 mod service_config;
 mod size;
 mod skill;
 mod source;
 mod storage_ref;
+mod sync_config;
 mod table;
 mod tag;
 mod timestamp;
@@ -74,6 +91,7 @@ mod token;
 mod token_version;
 mod tool_def;
 mod tool_name;
+mod trust_config;
 mod verbosity;
 
 pub(crate) use agent_activity::*;
@@ -90,18 +108,28 @@ pub(crate) use dashboard_bootstrap::*;
 pub(crate) use database_config::*;
 pub(crate) use description::*;
 pub(crate) use detail::*;
+pub(crate) use dream_budget::*;
 pub(crate) use dream_config::*;
 pub(crate) use dream_context::*;
+pub(crate) use dream_diff::*;
+pub(crate) use dream_recipe::*;
 pub(crate) use entity_index::*;
 pub(crate) use entity_ref::*;
 pub(crate) use error_response::*;
 pub(crate) use error_view::*;
+pub(crate) use event_feed::*;
+pub(crate) use event_signature::*;
 pub(crate) use expression::*;
 pub(crate) use fetch::*;
 pub(crate) use follow_source::*;
+pub(crate) use follow_status_index::*;
 pub(crate) use frames::*;
 pub(crate) use gauge::*;
+pub(crate) use gauge_definition::*;
+pub(crate) use gauge_lenses::*;
+pub(crate) use gauge_tuning::*;
 pub(crate) use general_config::*;
+pub(crate) use harness::*;
 pub(crate) use hints::*;
 pub(crate) use host_canon::*;
 pub(crate) use host_identity::*;
@@ -113,6 +141,7 @@ pub(crate) use limit::*;
 pub(crate) use link::*;
 pub(crate) use listed::*;
 pub(crate) use mcp_response::*;
+pub(crate) use mcp_transport::*;
 pub(crate) use offset::*;
 pub(crate) use oneiros_uri::*;
 pub(crate) use output_mode::*;
@@ -129,6 +158,8 @@ pub(crate) use project_canon::*;
 pub(crate) use project_summary::*;
 pub(crate) use projection::*;
 pub(crate) use prompt::*;
+pub(crate) use query_flag::*;
+pub(crate) use recall_score::*;
 pub(crate) use reducer::*;
 pub(crate) use ref_token::*;
 pub(crate) use relevant_pressures::*;
@@ -137,18 +168,23 @@ pub(crate) use resource::*;
 pub(crate) use resource_def::*;
 pub(crate) use resource_docs::*;
 pub(crate) use resource_key::*;
+pub(crate) use resource_subscriptions::*;
 pub(crate) use resource_uri::*;
 pub(crate) use response::*;
+pub(crate) use retention_config::*;
+pub(crate) use retention_policy::*;
 pub(crate) use search_filters::*;
 pub(crate) use service_config::*;
 pub(crate) use size::*;
 pub(crate) use skill::*;
 pub(crate) use source::*;
 pub(crate) use storage_ref::*;
+pub(crate) use sync_config::*;
 pub(crate) use table::*;
 pub(crate) use tag::*;
 pub(crate) use timestamp::*;
 pub(crate) use token::*;
 pub(crate) use tool_def::*;
 pub(crate) use tool_name::*;
+pub(crate) use trust_config::*;
 pub(crate) use verbosity::*;
@@ -1,6 +1,8 @@
 use data_encoding::BASE64URL_NOPAD;
 use serde::{Deserialize, Serialize};
 
+use crate::*;
+
 /// The reachability information for a peer — wraps `iroh::EndpointAddr`.
 ///
 /// Contains the peer's endpoint identifier plus any known relay URLs and
@@ -33,6 +35,11 @@ impl PeerAddress {
         &self.0
     }
 
+    /// The public key of the host at this address.
+    pub(crate) fn key(&self) -> PeerKey {
+        PeerKey::from_bytes(*self.0.id.as_bytes())
+    }
+
     /// Serialize to postcard bytes. Used when composing URIs.
     pub(crate) fn to_bytes(&self) -> Vec<u8> {
         postcard::to_allocvec(&self.0).expect("EndpointAddr serialization should not fail")
@@ -3,17 +3,24 @@ use serde::{Deserialize, Serialize};
 
 /// A capability operation that a ticket holder may perform.
 ///
-/// These map to the bridge operations a remote ticket authorizes:
+/// Bridge requests and HTTP routes each require exactly one operation:
 ///
 /// - `BookmarkSubmit` — submit a bookmark to the remote (BridgeSubmitBookmark)
 /// - `BookmarkList` — list bookmarks on the remote (BridgeListBookmarks)
+/// - `BookmarkCollect` — pull events through the chronicle diff protocol
+///   (BridgeDiff, BridgeResolve, BridgeFetchEvents), or have them pushed
+///   (BridgeSubscribe)
+/// - `EventsRead` — read the raw event log over HTTP
+/// - `CognitionWrite` / `MemoryWrite` — add cognitions or memories
+/// - `StorageRead` / `StorageWrite` — read or change stored blobs
+/// - `Search` — run full-text search
+/// - `ProjectRead` / `ProjectWrite` — every other project-scoped route,
+///   split by whether it changes anything
+/// - `HostAdmin` — host-tier routes: tickets, projects, tenants, actors,
+///   peers and host setup
 ///
-/// Bookmark collection from a remote uses the existing chronicle diff
-/// protocol (BridgeDiff + BridgeFetchEvents) rather than a dedicated
-/// bridge operation, so no separate permission is needed.
-///
-/// When a ticket's `permissions` vec is empty (V0, all existing tickets),
-/// all operations are granted — the ticket IS the permission.
+/// Tickets issued before scoped permissions existed are unscoped; see
+/// [`Ticket::can`](crate::Ticket::can) for how they are judged.
 #[derive(
     Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema, clap::ValueEnum,
 )]
@@ -22,6 +29,43 @@ use serde::{Deserialize, Serialize};
 pub(crate) enum PermissionOp {
     BookmarkSubmit,
     BookmarkList,
+    BookmarkCollect,
+    EventsRead,
+    CognitionWrite,
+    MemoryWrite,
+    StorageRead,
+    StorageWrite,
+    Search,
+    ProjectRead,
+    ProjectWrite,
+    HostAdmin,
+}
+
+impl PermissionOp {
+    /// Every operation — the grant for a full-access ticket.
+    pub(crate) const ALL: &[PermissionOp] = &[
+        Self::BookmarkSubmit,
+        Self::BookmarkList,
+        Self::BookmarkCollect,
+        Self::EventsRead,
+        Self::CognitionWrite,
+        Self::MemoryWrite,
+        Self::StorageRead,
+        Self::StorageWrite,
+        Self::Search,
+        Self::ProjectRead,
+        Self::ProjectWrite,
+        Self::HostAdmin,
+    ];
+}
+
+impl core::fmt::Display for PermissionOp {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let name = clap::ValueEnum::to_possible_value(self)
+            .map(|value| value.get_name().to_string())
+            .unwrap_or_default();
+        f.write_str(&name)
+    }
 }
 
 #[cfg(test)]
@@ -46,6 +90,19 @@ mod tests {
                 .get_name(),
             "bookmark-list"
         );
+        assert_eq!(
+            PermissionOp::BookmarkCollect
+                .to_possible_value()
+                .unwrap()
+                .get_name(),
+            "bookmark-collect"
+        );
+        assert_eq!(PermissionOp::HostAdmin.to_string(), "host-admin");
+    }
+
+    #[test]
+    fn all_covers_every_value() {
+        assert_eq!(PermissionOp::ALL, PermissionOp::value_variants());
     }
 
     #[test]
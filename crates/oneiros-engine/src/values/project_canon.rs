@@ -25,6 +25,10 @@ pub(crate) struct ProjectCanon {
     /// Per-agent continuity timestamps for pressure computation.
     /// Not CRDT-synced — derived from events during replay.
     pub(crate) continuity_timestamps: ContinuityTimestamps,
+    /// Gauge tunings per urge and layer, folded from `urge-tuned` events.
+    pub(crate) tunings: GaugeTunings,
+    /// The urges' gauge lenses, compiled as the urges are set.
+    pub(crate) gauge_lenses: GaugeLenses,
 }
 
 /// Tracks the most recent continuity event timestamps per agent.
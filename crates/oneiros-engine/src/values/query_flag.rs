@@ -0,0 +1,54 @@
+use serde::{Deserializer, de};
+
+/// Deserialize a boolean flag from JSON or a query string.
+///
+/// Query-string values are always strings on the wire, and the derived
+/// bool impl rejects them once a request is buffered through an untagged
+/// enum — the same trap [`Limit`](crate::Limit) works around.
+pub(crate) fn query_flag<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+    struct FlagVisitor;
+
+    impl<'de> de::Visitor<'de> for FlagVisitor {
+        type Value = bool;
+
+        fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+            formatter.write_str("a boolean or boolean string")
+        }
+
+        fn visit_bool<E: de::Error>(self, value: bool) -> Result<bool, E> {
+            Ok(value)
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<bool, E> {
+            value.parse::<bool>().map_err(de::Error::custom)
+        }
+    }
+
+    deserializer.deserialize_any(FlagVisitor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(serde::Deserialize)]
+    #[serde(untagged)]
+    enum Listing {
+        V1 {
+            #[serde(default, deserialize_with = "query_flag")]
+            include_retracted: bool,
+        },
+    }
+
+    #[test]
+    fn reads_flags_from_strings_and_booleans() {
+        for (value, expected) in [
+            (serde_json::json!({ "include_retracted": "true" }), true),
+            (serde_json::json!({ "include_retracted": false }), false),
+            (serde_json::json!({}), false),
+        ] {
+            let Listing::V1 { include_retracted } = serde_json::from_value(value).unwrap();
+            assert_eq!(include_retracted, expected);
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+/// Days for a memory's recency factor to halve.
+const RECENCY_HALF_LIFE_DAYS: f64 = 30.0;
+/// Connections or surfacings at which the factor reaches one half.
+const SATURATION: f64 = 3.0;
+
+const LEVEL_WEIGHT: f64 = 0.3;
+const RECENCY_WEIGHT: f64 = 0.25;
+const DEGREE_WEIGHT: f64 = 0.15;
+const SURFACED_WEIGHT: f64 = 0.1;
+const FOCUS_WEIGHT: f64 = 0.2;
+
+/// Why a memory made it into a dream: each factor in `0.0..=1.0`, and
+/// their weighted sum.
+///
+/// Level priority, recency decay, connection degree and how often
+/// earlier dreams surfaced the memory always count. Relevance to the
+/// dream's focus only counts when a focus was given; without one the
+/// other weights are scaled up to fill its share.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub(crate) struct RecallScore {
+    pub(crate) memory: MemoryId,
+    pub(crate) level: f64,
+    pub(crate) recency: f64,
+    pub(crate) degree: f64,
+    pub(crate) surfaced: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) focus: Option<f64>,
+    pub(crate) total: f64,
+}
+
+/// What's known about a memory when it's scored.
+pub(crate) struct RecallInputs {
+    /// Level priority, normalized so core is 1.0.
+    pub(crate) level: f64,
+    pub(crate) age: std::time::Duration,
+    pub(crate) connections: usize,
+    pub(crate) surfaced: usize,
+    /// Search relevance to the focus, when there is one.
+    pub(crate) focus: Option<f64>,
+}
+
+impl RecallScore {
+    pub(crate) fn new(memory: MemoryId, inputs: RecallInputs) -> Self {
+        let days = inputs.age.as_secs_f64() / 86_400.0;
+        let level = inputs.level.clamp(0.0, 1.0);
+        let recency = 0.5_f64.powf(days / RECENCY_HALF_LIFE_DAYS);
+        let degree = Self::saturate(inputs.connections);
+        let surfaced = Self::saturate(inputs.surfaced);
+        let focus = inputs.focus.map(|f| f.clamp(0.0, 1.0));
+
+        let weighted = LEVEL_WEIGHT * level
+            + RECENCY_WEIGHT * recency
+            + DEGREE_WEIGHT * degree
+            + SURFACED_WEIGHT * surfaced;
+        let total = match focus {
+            Some(focus) => weighted + FOCUS_WEIGHT * focus,
+            None => weighted / (1.0 - FOCUS_WEIGHT),
+        };
+
+        Self {
+            memory,
+            level,
+            recency,
+            degree,
+            surfaced,
+            focus,
+            total,
+        }
+    }
+
+    fn saturate(count: usize) -> f64 {
+        let count = count as f64;
+        count / (count + SATURATION)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inputs() -> RecallInputs {
+        RecallInputs {
+            level: 0.5,
+            age: std::time::Duration::ZERO,
+            connections: 0,
+            surfaced: 0,
+            focus: None,
+        }
+    }
+
+    #[test]
+    fn recency_halves_each_half_life() {
+        let score = RecallScore::new(
+            MemoryId::new(),
+            RecallInputs {
+                age: std::time::Duration::from_secs(30 * 86_400),
+                ..inputs()
+            },
+        );
+        assert!((score.recency - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn connected_old_memory_outranks_fresh_orphan() {
+        let fresh = RecallScore::new(MemoryId::new(), inputs());
+        let old = RecallScore::new(
+            MemoryId::new(),
+            RecallInputs {
+                age: std::time::Duration::from_secs(42 * 86_400),
+                connections: 12,
+                surfaced: 9,
+                ..inputs()
+            },
+        );
+        assert!(old.total > fresh.total);
+    }
+
+    #[test]
+    fn focus_reweights_the_total() {
+        let unfocused = RecallScore::new(MemoryId::new(), inputs());
+        let on_topic = RecallScore::new(
+            MemoryId::new(),
+            RecallInputs {
+                focus: Some(1.0),
+                ..inputs()
+            },
+        );
+        let off_topic = RecallScore::new(
+            MemoryId::new(),
+            RecallInputs {
+                focus: Some(0.0),
+                ..inputs()
+            },
+        );
+        assert!(on_topic.total > unfocused.total);
+        assert!(unfocused.total > off_topic.total);
+        assert_eq!(unfocused.focus, None);
+    }
+}
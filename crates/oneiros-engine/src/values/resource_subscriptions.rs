@@ -0,0 +1,129 @@
+use std::sync::{Arc, PoisonError, RwLock};
+
+use tokio::sync::Notify;
+
+use crate::*;
+
+/// The resources an MCP session has subscribed to.
+///
+/// Shared between the session's handler, which adds and removes
+/// subscriptions, and its watcher, which asks which of them an event
+/// touched. Dropping the last subscription wakes the watcher, so it
+/// can stop without waiting for another event.
+#[derive(Clone, Default)]
+pub(crate) struct ResourceSubscriptions {
+    uris: Arc<RwLock<Vec<ResourceUri>>>,
+    emptied: Arc<Notify>,
+}
+
+impl ResourceSubscriptions {
+    pub(crate) fn subscribe(&self, uri: ResourceUri) {
+        let mut uris = self.uris.write().unwrap_or_else(PoisonError::into_inner);
+        if !uris.contains(&uri) {
+            uris.push(uri);
+        }
+    }
+
+    pub(crate) fn unsubscribe(&self, uri: &ResourceUri) {
+        let mut uris = self.uris.write().unwrap_or_else(PoisonError::into_inner);
+        uris.retain(|subscribed| subscribed != uri);
+        if uris.is_empty() {
+            self.emptied.notify_one();
+        }
+    }
+
+    /// Resolves once an unsubscribe leaves nothing subscribed. Check
+    /// [`is_empty`] after: a subscribe may have come in since.
+    ///
+    /// [`is_empty`]: ResourceSubscriptions::is_empty
+    pub(crate) async fn emptied(&self) {
+        self.emptied.notified().await;
+    }
+
+    /// Whether nothing is subscribed.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.uris
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .is_empty()
+    }
+
+    /// Every subscribed resource.
+    pub(crate) fn all(&self) -> Vec<ResourceUri> {
+        self.uris
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// The subscribed resources among `touched`.
+    pub(crate) fn matching(&self, touched: &[ResourcePath]) -> Vec<ResourceUri> {
+        self.uris
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .filter(|uri| touched.contains(uri.path()))
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uri(raw: &str) -> ResourceUri {
+        raw.parse().unwrap()
+    }
+
+    #[test]
+    fn matching_keeps_only_touched_subscriptions() {
+        let subscriptions = ResourceSubscriptions::default();
+        subscriptions.subscribe(uri("oneiros-mcp://agent/governor.process/pressure"));
+        subscriptions.subscribe(uri("oneiros-mcp://agent/watcher.process/memories"));
+        subscriptions.subscribe(uri("oneiros-mcp://status"));
+
+        let touched = vec![
+            ResourcePath::AgentPressure(AgentName::new("governor.process")),
+            ResourcePath::Status,
+        ];
+
+        assert_eq!(
+            subscriptions.matching(&touched),
+            vec![
+                uri("oneiros-mcp://agent/governor.process/pressure"),
+                uri("oneiros-mcp://status"),
+            ]
+        );
+    }
+
+    #[test]
+    fn unsubscribe_forgets_the_resource() {
+        let subscriptions = ResourceSubscriptions::default();
+        subscriptions.subscribe(uri("oneiros-mcp://status"));
+        subscriptions.subscribe(uri("oneiros-mcp://status"));
+        subscriptions.unsubscribe(&uri("oneiros-mcp://status"));
+
+        assert!(subscriptions.all().is_empty());
+    }
+
+    #[tokio::test]
+    async fn dropping_the_last_subscription_wakes_the_watcher() {
+        let subscriptions = ResourceSubscriptions::default();
+        subscriptions.subscribe(uri("oneiros-mcp://status"));
+        subscriptions.subscribe(uri("oneiros-mcp://levels"));
+
+        subscriptions.unsubscribe(&uri("oneiros-mcp://status"));
+        let early = tokio::time::timeout(
+            std::time::Duration::from_millis(50),
+            subscriptions.emptied(),
+        )
+        .await;
+        assert!(early.is_err(), "one subscription is still held");
+
+        subscriptions.unsubscribe(&uri("oneiros-mcp://levels"));
+        tokio::time::timeout(std::time::Duration::from_secs(1), subscriptions.emptied())
+            .await
+            .expect("the last unsubscribe should wake the watcher");
+    }
+}
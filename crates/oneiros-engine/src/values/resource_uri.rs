@@ -242,6 +242,159 @@ impl ResourcePath {
     }
 }
 
+/// The agent an event is about, as the event names it.
+///
+/// Records carry the agent's id; agent and continuity events carry its
+/// name. Subscribers resolve ids to names to match agent resources.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ActingAgent {
+    Id(AgentId),
+    Name(AgentName),
+}
+
+impl ActingAgent {
+    pub(crate) fn of(event: &Events) -> Option<Self> {
+        match event {
+            Events::Cognition(CognitionEvents::CognitionAdded(added)) => added
+                .clone()
+                .current()
+                .ok()
+                .map(|v| Self::Id(v.cognition.agent_id)),
+            Events::Memory(MemoryEvents::MemoryAdded(added)) => added
+                .clone()
+                .current()
+                .ok()
+                .map(|v| Self::Id(v.memory.agent_id)),
+            Events::Memory(MemoryEvents::MemorySuperseded(superseded)) => superseded
+                .clone()
+                .current()
+                .ok()
+                .map(|v| Self::Id(v.replacement.agent_id)),
+            Events::Experience(ExperienceEvents::ExperienceCreated(created)) => created
+                .clone()
+                .current()
+                .ok()
+                .map(|v| Self::Id(v.experience.agent_id)),
+            Events::Experience(ExperienceEvents::ExperienceAmended(amended)) => amended
+                .clone()
+                .current()
+                .ok()
+                .map(|v| Self::Id(v.replacement.agent_id)),
+            Events::Agent(AgentEvents::AgentRemoved(removed)) => {
+                removed.clone().current().ok().map(|v| Self::Name(v.name))
+            }
+            Events::Agent(agent) => agent.maybe_agent().map(|a| Self::Name(a.name)),
+            Events::Continuity(continuity) => Some(Self::Name(continuity.agent().clone())),
+            _ => None,
+        }
+    }
+}
+
+impl ResourcePath {
+    /// The resources an event changes, given the agent it's about.
+    ///
+    /// Anything an agent records moves its listings, its pressure and
+    /// the continuity status; continuity events move pressure and
+    /// status. Entity resources change when their entity is written.
+    pub(crate) fn touched_by(event: &Events, agent: Option<&AgentName>) -> Vec<ResourcePath> {
+        let mut touched = match event {
+            Events::Cognition(CognitionEvents::CognitionAdded(added)) => added
+                .clone()
+                .current()
+                .map(|v| vec![Self::Cognition(v.cognition.id)])
+                .unwrap_or_default(),
+            Events::Memory(MemoryEvents::MemoryAdded(added)) => added
+                .clone()
+                .current()
+                .map(|v| vec![Self::Memory(v.memory.id)])
+                .unwrap_or_default(),
+            Events::Cognition(CognitionEvents::CognitionRetracted(retracted)) => retracted
+                .clone()
+                .current()
+                .map(|v| vec![Self::Cognition(v.id)])
+                .unwrap_or_default(),
+            Events::Memory(MemoryEvents::MemorySuperseded(superseded)) => superseded
+                .clone()
+                .current()
+                .map(|v| vec![Self::Memory(v.id), Self::Memory(v.replacement.id)])
+                .unwrap_or_default(),
+            Events::Memory(MemoryEvents::MemoryDemoted(demoted)) => demoted
+                .clone()
+                .current()
+                .map(|v| vec![Self::Memory(v.id)])
+                .unwrap_or_default(),
+            Events::Memory(MemoryEvents::MemoryArchived(archived)) => archived
+                .clone()
+                .current()
+                .map(|v| vec![Self::Memory(v.id)])
+                .unwrap_or_default(),
+            Events::Experience(ExperienceEvents::ExperienceCreated(created)) => created
+                .clone()
+                .current()
+                .map(|v| vec![Self::Experience(v.experience.id)])
+                .unwrap_or_default(),
+            Events::Experience(ExperienceEvents::ExperienceDescriptionUpdated(updated)) => updated
+                .clone()
+                .current()
+                .map(|v| vec![Self::Experience(v.id)])
+                .unwrap_or_default(),
+            Events::Experience(ExperienceEvents::ExperienceSensationUpdated(updated)) => updated
+                .clone()
+                .current()
+                .map(|v| vec![Self::Experience(v.id)])
+                .unwrap_or_default(),
+            Events::Experience(ExperienceEvents::ExperienceAmended(amended)) => amended
+                .clone()
+                .current()
+                .map(|v| vec![Self::Experience(v.id), Self::Experience(v.replacement.id)])
+                .unwrap_or_default(),
+            Events::Connection(ConnectionEvents::ConnectionCreated(created)) => created
+                .clone()
+                .current()
+                .map(|v| vec![Self::Connection(v.connection.id)])
+                .unwrap_or_default(),
+            Events::Agent(_) => vec![Self::Agents, Self::Status],
+            Events::Continuity(_) => vec![Self::Status, Self::Pressure],
+            Events::Level(_) => vec![Self::Levels],
+            Events::Texture(_) => vec![Self::Textures],
+            Events::Sensation(_) => vec![Self::Sensations],
+            Events::Nature(_) => vec![Self::Natures],
+            Events::Persona(_) => vec![Self::Personas],
+            Events::Urge(_) => vec![Self::Urges, Self::Pressure],
+            _ => vec![],
+        };
+
+        if let Some(agent) = agent {
+            let agent = agent.clone();
+            match event {
+                Events::Cognition(_) => touched.extend([
+                    Self::AgentCognitions(agent.clone()),
+                    Self::AgentPressure(agent),
+                    Self::Pressure,
+                    Self::Status,
+                ]),
+                Events::Memory(_) => touched.extend([
+                    Self::AgentMemories(agent.clone()),
+                    Self::AgentPressure(agent),
+                    Self::Pressure,
+                    Self::Status,
+                ]),
+                Events::Experience(_) => touched.extend([
+                    Self::AgentExperiences(agent.clone()),
+                    Self::AgentPressure(agent),
+                    Self::Pressure,
+                    Self::Status,
+                ]),
+                Events::Agent(_) => touched.push(Self::Agent(agent)),
+                Events::Continuity(_) => touched.push(Self::AgentPressure(agent)),
+                _ => {}
+            }
+        }
+
+        touched
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub(crate) enum ResourceUriError {
     #[error("Unknown URI scheme: {0}")]
@@ -416,6 +569,52 @@ mod tests {
         assert_eq!(*parsed.path(), ResourcePath::Agents);
     }
 
+    #[test]
+    fn memory_added_touches_the_agents_memories_and_pressure() {
+        let memory = Memory::builder()
+            .agent_id(AgentId::new())
+            .level("project")
+            .content("A consolidated insight")
+            .build();
+        let event = Events::Memory(MemoryEvents::MemoryAdded(MemoryAdded::V1(MemoryAddedV1 {
+            memory: memory.clone(),
+        })));
+        let agent = AgentName::new("governor.process");
+
+        assert_eq!(
+            ActingAgent::of(&event),
+            Some(ActingAgent::Id(memory.agent_id))
+        );
+
+        let touched = ResourcePath::touched_by(&event, Some(&agent));
+        assert!(touched.contains(&ResourcePath::Memory(memory.id)));
+        assert!(touched.contains(&ResourcePath::AgentMemories(agent.clone())));
+        assert!(touched.contains(&ResourcePath::AgentPressure(agent)));
+        assert!(touched.contains(&ResourcePath::Status));
+    }
+
+    #[test]
+    fn continuity_events_touch_status_and_pressure() {
+        let event = Events::Continuity(ContinuityEvents::Introspected(
+            Introspected::builder_v1()
+                .agent("governor.process")
+                .created_at(Timestamp::now())
+                .build()
+                .into(),
+        ));
+        let agent = AgentName::new("governor.process");
+
+        assert_eq!(
+            ActingAgent::of(&event),
+            Some(ActingAgent::Name(agent.clone()))
+        );
+
+        let touched = ResourcePath::touched_by(&event, Some(&agent));
+        assert!(touched.contains(&ResourcePath::Status));
+        assert!(touched.contains(&ResourcePath::AgentPressure(agent)));
+        assert!(!touched.contains(&ResourcePath::Agents));
+    }
+
     #[test]
     fn round_trip_parameterized() {
         let name = AgentName::new("test.agent");
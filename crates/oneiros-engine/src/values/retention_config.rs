@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+use bon::Builder;
+use serde::{Deserialize, Serialize};
+
+fn default_interval() -> Duration {
+    Duration::from_secs(60 * 60)
+}
+
+/// Configuration for enforcing level retention policies.
+///
+/// Lives inside `Config` as the `[retention]` section. The host's
+/// retention actor sweeps every bookmark of every project once per
+/// `interval`, demoting or archiving the memories their levels no
+/// longer keep. Sweeping rewrites memories, so it is off until a host
+/// opts in with `enabled = true`.
+#[derive(Builder, Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct RetentionConfig {
+    /// Whether the retention actor sweeps at all.
+    #[builder(default)]
+    #[serde(default)]
+    pub(crate) enabled: bool,
+    /// How often each bookmark is swept.
+    #[builder(default = default_interval())]
+    #[serde(default = "default_interval", with = "humantime_serde")]
+    pub(crate) interval: Duration,
+}
+
+impl Default for RetentionConfig {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
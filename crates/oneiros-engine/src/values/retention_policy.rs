@@ -0,0 +1,108 @@
+use std::time::Duration;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+/// How long a level keeps its memories, and what becomes of them after.
+///
+/// A memory expires once it is older than `ttl`, or once an agent has
+/// more than `max_per_agent` newer memories at the level. Expired
+/// memories move down to `demote_to` if it's set, and are archived
+/// otherwise.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub(crate) struct RetentionPolicy {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "humantime_serde"
+    )]
+    #[schemars(with = "Option<String>")]
+    pub(crate) ttl: Option<Duration>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) max_per_agent: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) demote_to: Option<LevelName>,
+}
+
+impl RetentionPolicy {
+    /// Whether the policy would ever expire anything.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.ttl.is_none() && self.max_per_agent.is_none()
+    }
+
+    /// Whether a memory of `age`, with `newer` memories from the same
+    /// agent ahead of it at this level, has outstayed the policy.
+    pub(crate) fn expires(&self, age: Duration, newer: usize) -> bool {
+        self.ttl.is_some_and(|ttl| age > ttl) || self.max_per_agent.is_some_and(|max| newer >= max)
+    }
+}
+
+/// What one retention pass did to a bookmark's memories.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct RetentionSweep {
+    pub(crate) demoted: usize,
+    pub(crate) archived: usize,
+}
+
+impl core::fmt::Display for RetentionPolicy {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut parts = vec![];
+        if let Some(ttl) = self.ttl {
+            parts.push(format!("ttl {}", humantime::format_duration(ttl)));
+        }
+        if let Some(max) = self.max_per_agent {
+            parts.push(format!("at most {max} per agent"));
+        }
+        parts.push(match &self.demote_to {
+            Some(level) => format!("then demote to {level}"),
+            None => "then archive".to_string(),
+        });
+        f.write_str(&parts.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expires_by_age_or_by_count() {
+        let policy = RetentionPolicy {
+            ttl: Some(Duration::from_secs(60)),
+            max_per_agent: Some(2),
+            demote_to: None,
+        };
+
+        assert!(!policy.expires(Duration::from_secs(30), 0));
+        assert!(policy.expires(Duration::from_secs(90), 0));
+        assert!(!policy.expires(Duration::from_secs(30), 1));
+        assert!(policy.expires(Duration::from_secs(30), 2));
+    }
+
+    #[test]
+    fn an_empty_policy_keeps_everything() {
+        let policy = RetentionPolicy::default();
+
+        assert!(policy.is_empty());
+        assert!(!policy.expires(Duration::from_secs(u32::MAX.into()), usize::MAX));
+    }
+
+    #[test]
+    fn ttl_is_written_in_humantime() {
+        let policy = RetentionPolicy {
+            ttl: Some(Duration::from_secs(7 * 24 * 60 * 60)),
+            max_per_agent: None,
+            demote_to: Some(LevelName::new("archival")),
+        };
+
+        let json = serde_json::to_value(&policy).unwrap();
+        assert_eq!(json["ttl"], "7days");
+        assert_eq!(json["demote_to"], "archival");
+        assert!(json.get("max_per_agent").is_none());
+
+        let back: RetentionPolicy = serde_json::from_value(json).unwrap();
+        assert_eq!(back, policy);
+    }
+}
@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+use bon::Builder;
+use serde::{Deserialize, Serialize};
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_interval() -> Duration {
+    Duration::from_secs(60)
+}
+
+fn default_max_backoff() -> Duration {
+    Duration::from_secs(30 * 60)
+}
+
+/// Configuration for background sync of peer follows.
+///
+/// Lives inside `Config` as the `[sync]` section. The host's sync actor
+/// collects every peer follow once per `interval`. A follow that keeps
+/// failing waits twice as long after each failure, up to `max_backoff`.
+/// With `on_change`, a local write to a project also collects that
+/// project's peer follows shortly after; a burst of writes collects
+/// once. With `push`, the actor also holds a subscription open to each
+/// followed peer, which streams new events as they are appended; the
+/// scheduled collects then only catch up on what a dropped subscription
+/// missed.
+#[derive(Builder, Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct SyncConfig {
+    /// Whether the sync actor collects peer follows at all.
+    #[builder(default = default_enabled())]
+    #[serde(default = "default_enabled")]
+    pub(crate) enabled: bool,
+    /// How often each peer follow is collected.
+    #[builder(default = default_interval())]
+    #[serde(default = "default_interval", with = "humantime_serde")]
+    pub(crate) interval: Duration,
+    /// The longest a failing follow waits between attempts.
+    #[builder(default = default_max_backoff())]
+    #[serde(default = "default_max_backoff", with = "humantime_serde")]
+    pub(crate) max_backoff: Duration,
+    /// Collect a project's peer follows as soon as it changes locally.
+    #[builder(default)]
+    #[serde(default)]
+    pub(crate) on_change: bool,
+    /// Hold a push subscription open to each peer follow.
+    #[builder(default)]
+    #[serde(default)]
+    pub(crate) push: bool,
+}
+
+impl Default for SyncConfig {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
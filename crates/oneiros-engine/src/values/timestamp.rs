@@ -36,6 +36,14 @@ impl Timestamp {
         self.0.format("%Y-%m-%d").to_string()
     }
 
+    /// Time from `earlier` to this timestamp, or zero if `earlier` is later.
+    pub(crate) fn duration_since(&self, earlier: Timestamp) -> std::time::Duration {
+        self.0
+            .signed_duration_since(earlier.0)
+            .to_std()
+            .unwrap_or_default()
+    }
+
     pub(crate) fn elapsed(&self) -> String {
         let elapsed = Utc::now().signed_duration_since(self.0);
         let secs = elapsed.num_seconds();
@@ -65,3 +73,16 @@ impl From<DateTime<Utc>> for Timestamp {
         Self(value)
     }
 }
+
+/// Accepts RFC 3339, or a bare `YYYY-MM-DD` meaning midnight UTC — how
+/// timestamps arrive from the command line.
+impl core::str::FromStr for Timestamp {
+    type Err = TimestampParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+            Ok(date) => Ok(Self(date.and_time(chrono::NaiveTime::MIN).and_utc())),
+            Err(_) => Self::parse_str(value),
+        }
+    }
+}
@@ -15,9 +15,9 @@ pub(crate) enum TokenError {
 pub(crate) struct Token(pub(crate) String);
 
 impl Token {
-    /// Issue a new token encoding the given claims.
-    pub(crate) fn issue(claims: Claim) -> Self {
-        let versioned = TokenVersion::V0(claims);
+    /// Issue a new token for a ticket, encoding the given claims.
+    pub(crate) fn issue(ticket_id: crate::TicketId, claims: Claim) -> Self {
+        let versioned = TokenVersion::V1 { claims, ticket_id };
         let bytes = postcard::to_allocvec(&versioned).expect("token serialization should not fail");
         Self(data_encoding::BASE32_NOPAD.encode(&bytes).to_lowercase())
     }
@@ -29,8 +29,9 @@ impl Token {
             .decode(upper.as_bytes())
             .map_err(|_| TokenError::Encoding)?;
         let versioned: TokenVersion = postcard::from_bytes(&bytes)?;
-        let TokenVersion::V0(claims) = versioned;
-        Ok(claims)
+        match versioned {
+            TokenVersion::V0(claims) | TokenVersion::V1 { claims, .. } => Ok(claims),
+        }
     }
 
     pub(crate) fn as_str(&self) -> &str {
@@ -52,3 +53,37 @@ impl core::fmt::Display for Token {
         self.0.fmt(f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    fn claims() -> Claim {
+        Claim::builder()
+            .project_id(ProjectId::new())
+            .tenant_id(TenantId::new())
+            .actor_id(ActorId::new())
+            .build()
+    }
+
+    #[test]
+    fn tokens_are_unique_per_ticket() {
+        let claims = claims();
+        let first = Token::issue(TicketId::new(), claims.clone());
+        let second = Token::issue(TicketId::new(), claims.clone());
+
+        assert_ne!(first, second);
+        assert_eq!(first.decode().unwrap(), claims);
+        assert_eq!(second.decode().unwrap(), claims);
+    }
+
+    #[test]
+    fn v0_tokens_still_decode() {
+        let claims = claims();
+        let bytes = postcard::to_allocvec(&TokenVersion::V0(claims.clone())).unwrap();
+        let legacy = Token(data_encoding::BASE32_NOPAD.encode(&bytes).to_lowercase());
+
+        assert_eq!(legacy.decode().unwrap(), claims);
+    }
+}
@@ -1,4 +1,10 @@
 #[derive(serde::Serialize, serde::Deserialize)]
 pub(super) enum TokenVersion {
     V0(super::claim::TokenClaims),
+    /// Bound to the ticket it was issued for, so two tickets carrying the
+    /// same claims never share a token.
+    V1 {
+        claims: super::claim::TokenClaims,
+        ticket_id: crate::TicketId,
+    },
 }
@@ -1,9 +1,10 @@
 use core::fmt;
 use std::marker::PhantomData;
 
-use crate::{Description, ToolName};
+use crate::{Description, PermissionOp, ToolName};
 
-/// A type-erased tool definition — name, description, and JSON schema.
+/// A type-erased tool definition — name, description, JSON schema, and
+/// the operation a session's ticket must grant to call it.
 ///
 /// Produced by `Tool<T>::def()`. The MCP collector gathers these into
 /// the tool catalog.
@@ -11,23 +12,31 @@ pub(crate) struct ToolDef {
     pub(crate) name: ToolName,
     pub(crate) description: Description,
     pub(crate) input_schema: serde_json::Value,
+    pub(crate) permission: PermissionOp,
 }
 
 /// A typed tool — binds a request struct to a name and description.
 ///
 /// The type parameter `T` provides the JSON schema via `schemars::JsonSchema`.
 /// The name is derived from the request type's `Display` implementation.
+/// The permission is the same operation the matching HTTP route needs.
 pub(crate) struct Tool<T> {
     name: ToolName,
     description: Description,
+    permission: PermissionOp,
     _marker: PhantomData<T>,
 }
 
 impl<T: schemars::JsonSchema> Tool<T> {
-    pub(crate) fn new(name: impl fmt::Display, description: impl Into<Description>) -> Self {
+    pub(crate) fn new(
+        name: impl fmt::Display,
+        description: impl Into<Description>,
+        permission: PermissionOp,
+    ) -> Self {
         Self {
             name: ToolName::new(name),
             description: description.into(),
+            permission,
             _marker: PhantomData,
         }
     }
@@ -37,6 +46,7 @@ impl<T: schemars::JsonSchema> Tool<T> {
             name: self.name.clone(),
             description: self.description.clone(),
             input_schema: schema_for::<T>(),
+            permission: self.permission,
         }
     }
 }
@@ -0,0 +1,238 @@
+use std::collections::HashMap;
+
+use bon::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+/// How much a host trusts events attributed to a peer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum TrustLevel {
+    /// Signed events must verify; unsigned events and tombstones are
+    /// accepted.
+    #[default]
+    Allow,
+    /// Every event must carry a signature that verifies.
+    Require,
+    /// Nothing from this peer is accepted.
+    Deny,
+}
+
+impl std::fmt::Display for TrustLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Allow => write!(f, "allow"),
+            Self::Require => write!(f, "require"),
+            Self::Deny => write!(f, "deny"),
+        }
+    }
+}
+
+/// The host's trust policy for incoming events.
+///
+/// Lives inside [`Config`] as the `[trust]` section. `default` covers
+/// every peer without an entry in `peers`, which is keyed by the peer's
+/// hex-encoded public key. A tampered event is never accepted, whatever
+/// the policy says.
+#[derive(Builder, Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct TrustConfig {
+    /// The level applied to peers without their own entry.
+    #[builder(default)]
+    pub(crate) default: TrustLevel,
+    /// Per-peer overrides.
+    #[builder(default)]
+    pub(crate) peers: HashMap<PeerKey, TrustLevel>,
+}
+
+impl TrustConfig {
+    /// The trust level for a peer.
+    pub(crate) fn level(&self, peer: &PeerKey) -> TrustLevel {
+        self.peers.get(peer).copied().unwrap_or(self.default)
+    }
+
+    /// Decide whether an incoming event may be imported.
+    ///
+    /// Signed events are judged by their signer. Unsigned events are
+    /// judged by `origin` — the peer they were fetched from — or by the
+    /// default level when they came from a file. A tombstone has lost the
+    /// signature along with the content it covered, so it is judged like
+    /// an unsigned event — a forged one would otherwise shadow the real
+    /// event it names for good.
+    pub(crate) fn admit(
+        &self,
+        event: &StoredEvent,
+        origin: Option<&PeerKey>,
+    ) -> Result<(), ProvenanceError> {
+        if let Some(origin) = origin
+            && self.level(origin) == TrustLevel::Deny
+        {
+            return Err(ProvenanceError::Untrusted {
+                id: event.id,
+                peer: *origin,
+            });
+        }
+
+        match event.provenance() {
+            Provenance::Tampered(signer) => Err(ProvenanceError::Tampered {
+                id: event.id,
+                signer,
+            }),
+            Provenance::Signed(signer) => match self.level(&signer) {
+                TrustLevel::Deny => Err(ProvenanceError::Untrusted {
+                    id: event.id,
+                    peer: signer,
+                }),
+                TrustLevel::Allow | TrustLevel::Require => Ok(()),
+            },
+            provenance @ (Provenance::Unsigned | Provenance::Redacted) => {
+                let level = origin.map_or(self.default, |origin| self.level(origin));
+                match (level, provenance) {
+                    (TrustLevel::Allow, _) => Ok(()),
+                    (_, Provenance::Redacted) => Err(ProvenanceError::Redacted(event.id)),
+                    _ => Err(ProvenanceError::Unsigned(event.id)),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event() -> StoredEvent {
+        StoredEvent::builder()
+            .id(EventId::new())
+            .sequence(0)
+            .data(Event::Known(Events::Level(LevelEvents::LevelRemoved(
+                LevelRemoved::builder_v1()
+                    .name(LevelName::new("session"))
+                    .build()
+                    .into(),
+            ))))
+            .source(Source::default())
+            .created_at(Timestamp::now())
+            .build()
+    }
+
+    fn key(secret: &iroh::SecretKey) -> PeerKey {
+        PeerKey::from_bytes(*secret.public().as_bytes())
+    }
+
+    #[test]
+    fn unsigned_events_follow_the_origin_level() {
+        let origin = key(&iroh::SecretKey::generate());
+        let unsigned = event();
+
+        let lenient = TrustConfig::default();
+        assert!(lenient.admit(&unsigned, Some(&origin)).is_ok());
+        assert!(lenient.admit(&unsigned, None).is_ok());
+
+        let strict = TrustConfig::builder()
+            .peers(HashMap::from([(origin, TrustLevel::Require)]))
+            .build();
+        assert!(matches!(
+            strict.admit(&unsigned, Some(&origin)),
+            Err(ProvenanceError::Unsigned(_))
+        ));
+        assert!(strict.admit(&unsigned, None).is_ok());
+    }
+
+    #[test]
+    fn signed_events_follow_the_signer_level() {
+        let secret = iroh::SecretKey::generate();
+        let signed = event().signed(&secret).unwrap();
+
+        let strict = TrustConfig::builder().default(TrustLevel::Require).build();
+        assert!(strict.admit(&signed, None).is_ok());
+
+        let denied = TrustConfig::builder()
+            .peers(HashMap::from([(key(&secret), TrustLevel::Deny)]))
+            .build();
+        assert!(matches!(
+            denied.admit(&signed, None),
+            Err(ProvenanceError::Untrusted { .. })
+        ));
+    }
+
+    #[test]
+    fn tampered_events_are_always_refused() {
+        let secret = iroh::SecretKey::generate();
+        let mut tampered = event().signed(&secret).unwrap();
+        tampered.created_at = Timestamp::now();
+
+        assert!(matches!(
+            TrustConfig::default().admit(&tampered, None),
+            Err(ProvenanceError::Tampered { .. })
+        ));
+    }
+
+    #[test]
+    fn tombstones_are_judged_like_unsigned_events() {
+        let secret = iroh::SecretKey::generate();
+        let tombstone = event()
+            .signed(&secret)
+            .unwrap()
+            .tombstoned(Timestamp::now());
+
+        assert!(tombstone.signature.is_none());
+        assert!(TrustConfig::default().admit(&tombstone, None).is_ok());
+
+        let strict = TrustConfig::builder().default(TrustLevel::Require).build();
+        assert!(matches!(
+            strict.admit(&tombstone, None),
+            Err(ProvenanceError::Redacted(_))
+        ));
+    }
+
+    /// An event signed by a newer host, in the shape it arrives in.
+    fn signed_by_newer_host(secret: &iroh::SecretKey, data: &str) -> String {
+        let written = format!(
+            r#"{{"id":"{}","data":{data},"source":{},"created_at":"{}"}}"#,
+            EventId::new(),
+            serde_json::to_string(&Source::default()).unwrap(),
+            Timestamp::now().as_string(),
+        );
+        let event: StoredEvent = serde_json::from_str(&written).unwrap();
+        serde_json::to_string(&event.signed(secret).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn events_this_version_cannot_fully_read_keep_their_signatures() {
+        let secret = iroh::SecretKey::generate();
+        let strict = TrustConfig::builder().default(TrustLevel::Require).build();
+
+        let mut known = serde_json::to_value(&event().data).unwrap();
+        known["data"]["added_later"] = serde_json::json!("a field this version drops");
+        let data = [
+            known.to_string(),
+            r#"{"type":"level-polished","data":{"zeta":1,"alpha":[true]}}"#.to_string(),
+        ];
+
+        for data in data {
+            let wire = signed_by_newer_host(&secret, &data);
+            let received: StoredEvent = serde_json::from_str(&wire).unwrap();
+
+            assert!(strict.admit(&received, None).is_ok(), "{data}");
+            assert_eq!(received.payload().unwrap().get(), data);
+
+            // Passed on again, it still verifies.
+            let forwarded: StoredEvent =
+                serde_json::from_str(&serde_json::to_string(&received).unwrap()).unwrap();
+            assert!(strict.admit(&forwarded, None).is_ok(), "{data}");
+        }
+    }
+
+    #[test]
+    fn peers_are_keyed_by_hex_in_toml() {
+        let peer = key(&iroh::SecretKey::generate());
+        let toml = format!("default = \"require\"\n\n[peers]\n\"{peer}\" = \"deny\"\n");
+
+        let config: TrustConfig = toml::from_str(&toml).unwrap();
+
+        assert_eq!(config.default, TrustLevel::Require);
+        assert_eq!(config.level(&peer), TrustLevel::Deny);
+    }
+}